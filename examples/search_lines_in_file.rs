@@ -29,11 +29,12 @@ fn inner_main() -> Result<(), Box<dyn Error>> {
         .nth(1)
         .ok_or("Please provide the name of the file to read lines from")?;
 
-    let file = File::open(&filename).map_err(|e| format!("Failed to open {filename:?}: {e}"))?;
+    let file =
+        File::open(&filename).map_err(|e| format!("Failed to open {}: {e}", filename.display()))?;
     let lines = BufReader::new(file)
         .lines()
         .collect::<io::Result<Vec<_>>>()
-        .map_err(|e| format!("Failed to read the contents of {filename:?}: {e}"))?;
+        .map_err(|e| format!("Failed to read the contents of {}: {e}", filename.display()))?;
 
     let root: SearchTree = lines.iter().map(Deref::deref).enumerate().collect();
     let mut searcher = Searcher::new(&root, lookalikes::all);
@@ -72,7 +73,7 @@ fn inner_main() -> Result<(), Box<dyn Error>> {
             }
             KeyCode::Esc => break,
             _ => continue,
-        };
+        }
 
         stdout
             .execute(Clear(CurrentLine))?
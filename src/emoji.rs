@@ -0,0 +1,4134 @@
+//! Names of emoji & other symbols, for finding them by their names or keywords, e.g. "thumbs up"
+//! or ":thumbs" for `👍`.
+//!
+//! Only available with the `emoji` feature. Covers every emoji of Emoji 16.0, along with some
+//! common symbols that aren't emoji, see [`EMOJI`].
+//! ```rust
+//! use permissive_search::*;
+//!
+//! let index = emoji::EmojiIndex::new();
+//! let mut searcher = Searcher::new(index.tree(), lookalikes::all);
+//! searcher.extend(":thumbs".chars());
+//! index.for_each_candidate::<()>(&searcher, |i| Ok(println!("{}", emoji::EMOJI[i].0)));
+//! ```
+
+use {
+    crate::{Cursor, SearchTree, Searcher, lookalikes::Lookalikes},
+    alloc::{collections::BTreeSet, string::String, vec::Vec},
+};
+
+/// Emoji & symbols as `(emoji, short name, keywords, shortcodes)`, in the order of CLDR.
+///
+/// Generated from the CLDR short names & the [gemoji](https://github.com/github/gemoji) shortcodes
+/// of every fully-qualified emoji of Emoji 16.0, except for the skin tone variants. The keywords
+/// from the CLDR annotations are only listed for about 250 common symbols, since the words of the
+/// short names are searched too, see [`EmojiIndex`].
+pub static EMOJI: &[(&str, &str, &[&str], &[&str])] = &[
+    ("😀", "grinning face", &["face", "grin"], &["grinning"]),
+    (
+        "😃",
+        "grinning face with big eyes",
+        &["face", "mouth", "open", "smile"],
+        &["smiley"],
+    ),
+    (
+        "😄",
+        "grinning face with smiling eyes",
+        &["eye", "face", "mouth", "open", "smile"],
+        &["smile"],
+    ),
+    (
+        "😁",
+        "beaming face with smiling eyes",
+        &["eye", "face", "grin", "smile"],
+        &["grin"],
+    ),
+    (
+        "😆",
+        "grinning squinting face",
+        &["face", "laugh", "mouth", "satisfied", "smile"],
+        &["laughing", "satisfied"],
+    ),
+    (
+        "😅",
+        "grinning face with sweat",
+        &["cold", "face", "open", "smile", "sweat"],
+        &["sweat_smile"],
+    ),
+    (
+        "🤣",
+        "rolling on the floor laughing",
+        &["face", "floor", "laugh", "rofl", "rolling", "rotfl"],
+        &["rofl"],
+    ),
+    (
+        "😂",
+        "face with tears of joy",
+        &["face", "joy", "laugh", "tear"],
+        &["joy"],
+    ),
+    (
+        "🙂",
+        "slightly smiling face",
+        &["face", "smile"],
+        &["slightly_smiling_face"],
+    ),
+    (
+        "🙃",
+        "upside-down face",
+        &["face", "upside-down"],
+        &["upside_down_face"],
+    ),
+    ("🫠", "melting face", &[], &["melting_face"]),
+    ("😉", "winking face", &["face", "wink"], &["wink"]),
+    (
+        "😊",
+        "smiling face with smiling eyes",
+        &["blush", "eye", "face", "smile"],
+        &["blush"],
+    ),
+    (
+        "😇",
+        "smiling face with halo",
+        &["angel", "face", "fantasy", "halo", "innocent"],
+        &["innocent"],
+    ),
+    (
+        "🥰",
+        "smiling face with hearts",
+        &["adore", "crush", "hearts", "in love"],
+        &["smiling_face_with_three_hearts"],
+    ),
+    (
+        "😍",
+        "smiling face with heart-eyes",
+        &["eye", "face", "love", "smile"],
+        &["heart_eyes"],
+    ),
+    (
+        "🤩",
+        "star-struck",
+        &["eyes", "face", "grinning", "star"],
+        &["star_struck"],
+    ),
+    (
+        "😘",
+        "face blowing a kiss",
+        &["face", "kiss"],
+        &["kissing_heart"],
+    ),
+    ("😗", "kissing face", &[], &["kissing"]),
+    ("☺\u{FE0F}", "smiling face", &[], &["relaxed"]),
+    (
+        "😚",
+        "kissing face with closed eyes",
+        &[],
+        &["kissing_closed_eyes"],
+    ),
+    (
+        "😙",
+        "kissing face with smiling eyes",
+        &[],
+        &["kissing_smiling_eyes"],
+    ),
+    (
+        "🥲",
+        "smiling face with tear",
+        &[],
+        &["smiling_face_with_tear"],
+    ),
+    (
+        "😋",
+        "face savoring food",
+        &["delicious", "face", "savouring", "smile", "yum"],
+        &["yum"],
+    ),
+    (
+        "😛",
+        "face with tongue",
+        &["face", "tongue"],
+        &["stuck_out_tongue"],
+    ),
+    (
+        "😜",
+        "winking face with tongue",
+        &["eye", "face", "joke", "tongue", "wink"],
+        &["stuck_out_tongue_winking_eye"],
+    ),
+    (
+        "🤪",
+        "zany face",
+        &["eye", "goofy", "large", "small"],
+        &["zany_face"],
+    ),
+    (
+        "😝",
+        "squinting face with tongue",
+        &[],
+        &["stuck_out_tongue_closed_eyes"],
+    ),
+    ("🤑", "money-mouth face", &[], &["money_mouth_face"]),
+    (
+        "🤗",
+        "smiling face with open hands",
+        &["face", "hug", "hugging"],
+        &["hugs"],
+    ),
+    ("🤭", "face with hand over mouth", &[], &["hand_over_mouth"]),
+    (
+        "🫢",
+        "face with open eyes and hand over mouth",
+        &[],
+        &["face_with_open_eyes_and_hand_over_mouth"],
+    ),
+    (
+        "🫣",
+        "face with peeking eye",
+        &[],
+        &["face_with_peeking_eye"],
+    ),
+    ("🤫", "shushing face", &[], &["shushing_face"]),
+    ("🤔", "thinking face", &["face", "thinking"], &["thinking"]),
+    ("🫡", "saluting face", &[], &["saluting_face"]),
+    (
+        "🤐",
+        "zipper-mouth face",
+        &["face", "mouth", "zipper"],
+        &["zipper_mouth_face"],
+    ),
+    (
+        "🤨",
+        "face with raised eyebrow",
+        &["distrust", "skeptic"],
+        &["raised_eyebrow"],
+    ),
+    (
+        "😐",
+        "neutral face",
+        &["deadpan", "face", "meh", "neutral"],
+        &["neutral_face"],
+    ),
+    (
+        "😑",
+        "expressionless face",
+        &[
+            "expressionless",
+            "face",
+            "inexpressive",
+            "meh",
+            "unexpressive",
+        ],
+        &["expressionless"],
+    ),
+    (
+        "😶",
+        "face without mouth",
+        &["face", "mouth", "quiet", "silent"],
+        &["no_mouth"],
+    ),
+    ("🫥", "dotted line face", &[], &["dotted_line_face"]),
+    (
+        "😶\u{200D}🌫\u{FE0F}",
+        "face in clouds",
+        &[],
+        &["face_in_clouds"],
+    ),
+    ("😏", "smirking face", &["face", "smirk"], &["smirk"]),
+    (
+        "😒",
+        "unamused face",
+        &["face", "unamused", "unhappy"],
+        &["unamused"],
+    ),
+    (
+        "🙄",
+        "face with rolling eyes",
+        &["eyeroll", "eyes", "face", "rolling"],
+        &["roll_eyes"],
+    ),
+    ("😬", "grimacing face", &["face", "grimace"], &["grimacing"]),
+    ("😮\u{200D}💨", "face exhaling", &[], &["face_exhaling"]),
+    ("🤥", "lying face", &[], &["lying_face"]),
+    ("🫨", "shaking face", &[], &["shaking_face"]),
+    ("🙂\u{200D}↔\u{FE0F}", "head shaking horizontally", &[], &[]),
+    ("🙂\u{200D}↕\u{FE0F}", "head shaking vertically", &[], &[]),
+    ("😌", "relieved face", &["face", "relieved"], &["relieved"]),
+    (
+        "😔",
+        "pensive face",
+        &["dejected", "face", "pensive"],
+        &["pensive"],
+    ),
+    (
+        "😪",
+        "sleepy face",
+        &["face", "good night", "sleep"],
+        &["sleepy"],
+    ),
+    ("🤤", "drooling face", &[], &["drooling_face"]),
+    (
+        "😴",
+        "sleeping face",
+        &["face", "good night", "sleep", "ZZZ"],
+        &["sleeping"],
+    ),
+    ("🫩", "face with bags under eyes", &[], &[]),
+    (
+        "😷",
+        "face with medical mask",
+        &["cold", "doctor", "face", "mask", "sick"],
+        &["mask"],
+    ),
+    (
+        "🤒",
+        "face with thermometer",
+        &["face", "ill", "sick", "thermometer"],
+        &["face_with_thermometer"],
+    ),
+    (
+        "🤕",
+        "face with head-bandage",
+        &[],
+        &["face_with_head_bandage"],
+    ),
+    (
+        "🤢",
+        "nauseated face",
+        &["face", "nauseated", "vomit"],
+        &["nauseated_face"],
+    ),
+    (
+        "🤮",
+        "face vomiting",
+        &["puke", "sick", "vomit"],
+        &["vomiting_face"],
+    ),
+    (
+        "🤧",
+        "sneezing face",
+        &["face", "gesundheit", "sneeze"],
+        &["sneezing_face"],
+    ),
+    (
+        "🥵",
+        "hot face",
+        &["feverish", "heat stroke", "hot", "red-faced", "sweating"],
+        &["hot_face"],
+    ),
+    (
+        "🥶",
+        "cold face",
+        &["blue-faced", "cold", "freezing", "frostbite", "icicles"],
+        &["cold_face"],
+    ),
+    ("🥴", "woozy face", &[], &["woozy_face"]),
+    (
+        "😵",
+        "face with crossed-out eyes",
+        &["crossed-out eyes", "dead", "face", "knocked out"],
+        &["dizzy_face"],
+    ),
+    (
+        "😵\u{200D}💫",
+        "face with spiral eyes",
+        &[],
+        &["face_with_spiral_eyes"],
+    ),
+    (
+        "🤯",
+        "exploding head",
+        &["mind blown", "shocked"],
+        &["exploding_head"],
+    ),
+    (
+        "🤠",
+        "cowboy hat face",
+        &["cowboy", "cowgirl", "face", "hat"],
+        &["cowboy_hat_face"],
+    ),
+    (
+        "🥳",
+        "partying face",
+        &["celebration", "hat", "horn", "party"],
+        &["partying_face"],
+    ),
+    ("🥸", "disguised face", &[], &["disguised_face"]),
+    (
+        "😎",
+        "smiling face with sunglasses",
+        &["bright", "cool", "face", "sun", "sunglasses"],
+        &["sunglasses"],
+    ),
+    ("🤓", "nerd face", &["face", "geek", "nerd"], &["nerd_face"]),
+    ("🧐", "face with monocle", &[], &["monocle_face"]),
+    (
+        "😕",
+        "confused face",
+        &["confused", "face", "meh"],
+        &["confused"],
+    ),
+    (
+        "🫤",
+        "face with diagonal mouth",
+        &[],
+        &["face_with_diagonal_mouth"],
+    ),
+    ("😟", "worried face", &["face", "worried"], &["worried"]),
+    (
+        "🙁",
+        "slightly frowning face",
+        &["face", "frown"],
+        &["slightly_frowning_face"],
+    ),
+    ("☹\u{FE0F}", "frowning face", &[], &["frowning_face"]),
+    (
+        "😮",
+        "face with open mouth",
+        &["face", "mouth", "open", "sympathy"],
+        &["open_mouth"],
+    ),
+    ("😯", "hushed face", &[], &["hushed"]),
+    (
+        "😲",
+        "astonished face",
+        &["astonished", "face", "shocked", "totally"],
+        &["astonished"],
+    ),
+    (
+        "😳",
+        "flushed face",
+        &["dazed", "face", "flushed"],
+        &["flushed"],
+    ),
+    (
+        "🥺",
+        "pleading face",
+        &["begging", "mercy", "puppy eyes"],
+        &["pleading_face"],
+    ),
+    (
+        "🥹",
+        "face holding back tears",
+        &[],
+        &["face_holding_back_tears"],
+    ),
+    ("😦", "frowning face with open mouth", &[], &["frowning"]),
+    ("😧", "anguished face", &[], &["anguished"]),
+    (
+        "😨",
+        "fearful face",
+        &["face", "fear", "fearful", "scared"],
+        &["fearful"],
+    ),
+    (
+        "😰",
+        "anxious face with sweat",
+        &["blue", "cold", "face", "rushed", "sweat"],
+        &["cold_sweat"],
+    ),
+    (
+        "😥",
+        "sad but relieved face",
+        &[],
+        &["disappointed_relieved"],
+    ),
+    (
+        "😢",
+        "crying face",
+        &["cry", "face", "sad", "tear"],
+        &["cry"],
+    ),
+    (
+        "😭",
+        "loudly crying face",
+        &["cry", "face", "sad", "sob", "tear"],
+        &["sob"],
+    ),
+    (
+        "😱",
+        "face screaming in fear",
+        &["face", "fear", "munch", "scared", "scream"],
+        &["scream"],
+    ),
+    (
+        "😖",
+        "confounded face",
+        &["confounded", "face"],
+        &["confounded"],
+    ),
+    ("😣", "persevering face", &[], &["persevere"]),
+    (
+        "😞",
+        "disappointed face",
+        &["disappointed", "face"],
+        &["disappointed"],
+    ),
+    (
+        "😓",
+        "downcast face with sweat",
+        &["cold", "face", "sweat"],
+        &["sweat"],
+    ),
+    ("😩", "weary face", &["face", "tired", "weary"], &["weary"]),
+    ("😫", "tired face", &["face", "tired"], &["tired_face"]),
+    (
+        "🥱",
+        "yawning face",
+        &["bored", "tired", "yawn"],
+        &["yawning_face"],
+    ),
+    (
+        "😤",
+        "face with steam from nose",
+        &["face", "triumph", "won"],
+        &["triumph"],
+    ),
+    (
+        "😡",
+        "enraged face",
+        &["angry", "enraged", "face", "mad", "pouting", "rage", "red"],
+        &["rage", "pout"],
+    ),
+    (
+        "😠",
+        "angry face",
+        &["anger", "angry", "face", "mad"],
+        &["angry"],
+    ),
+    (
+        "🤬",
+        "face with symbols on mouth",
+        &["swearing"],
+        &["cursing_face"],
+    ),
+    (
+        "😈",
+        "smiling face with horns",
+        &["face", "fairy tale", "fantasy", "horns", "smile"],
+        &["smiling_imp"],
+    ),
+    ("👿", "angry face with horns", &[], &["imp"]),
+    (
+        "💀",
+        "skull",
+        &["death", "face", "fairy tale", "monster"],
+        &["skull"],
+    ),
+    (
+        "☠\u{FE0F}",
+        "skull and crossbones",
+        &[],
+        &["skull_and_crossbones"],
+    ),
+    (
+        "💩",
+        "pile of poo",
+        &["dung", "face", "monster", "poo", "poop"],
+        &["hankey", "poop", "shit"],
+    ),
+    ("🤡", "clown face", &["clown", "face"], &["clown_face"]),
+    ("👹", "ogre", &[], &["japanese_ogre"]),
+    ("👺", "goblin", &[], &["japanese_goblin"]),
+    (
+        "👻",
+        "ghost",
+        &["creature", "face", "fairy tale", "fantasy", "monster"],
+        &["ghost"],
+    ),
+    (
+        "👽",
+        "alien",
+        &["creature", "extraterrestrial", "face", "fantasy", "ufo"],
+        &["alien"],
+    ),
+    ("👾", "alien monster", &[], &["space_invader"]),
+    ("🤖", "robot", &["face", "monster"], &["robot"]),
+    ("😺", "grinning cat", &[], &["smiley_cat"]),
+    ("😸", "grinning cat with smiling eyes", &[], &["smile_cat"]),
+    ("😹", "cat with tears of joy", &[], &["joy_cat"]),
+    (
+        "😻",
+        "smiling cat with heart-eyes",
+        &[],
+        &["heart_eyes_cat"],
+    ),
+    ("😼", "cat with wry smile", &[], &["smirk_cat"]),
+    ("😽", "kissing cat", &[], &["kissing_cat"]),
+    ("🙀", "weary cat", &[], &["scream_cat"]),
+    ("😿", "crying cat", &[], &["crying_cat_face"]),
+    ("😾", "pouting cat", &[], &["pouting_cat"]),
+    (
+        "🙈",
+        "see-no-evil monkey",
+        &["evil", "face", "forbidden", "monkey", "see"],
+        &["see_no_evil"],
+    ),
+    ("🙉", "hear-no-evil monkey", &[], &["hear_no_evil"]),
+    ("🙊", "speak-no-evil monkey", &[], &["speak_no_evil"]),
+    ("💌", "love letter", &[], &["love_letter"]),
+    ("💘", "heart with arrow", &[], &["cupid"]),
+    ("💝", "heart with ribbon", &[], &["gift_heart"]),
+    ("💖", "sparkling heart", &[], &["sparkling_heart"]),
+    ("💗", "growing heart", &[], &["heartpulse"]),
+    ("💓", "beating heart", &[], &["heartbeat"]),
+    ("💞", "revolving hearts", &[], &["revolving_hearts"]),
+    ("💕", "two hearts", &[], &["two_hearts"]),
+    ("💟", "heart decoration", &[], &["heart_decoration"]),
+    (
+        "❣\u{FE0F}",
+        "heart exclamation",
+        &[],
+        &["heavy_heart_exclamation"],
+    ),
+    (
+        "💔",
+        "broken heart",
+        &["break", "broken"],
+        &["broken_heart"],
+    ),
+    (
+        "❤\u{FE0F}\u{200D}🔥",
+        "heart on fire",
+        &[],
+        &["heart_on_fire"],
+    ),
+    (
+        "❤\u{FE0F}\u{200D}🩹",
+        "mending heart",
+        &[],
+        &["mending_heart"],
+    ),
+    ("❤\u{FE0F}", "red heart", &["heart"], &["heart"]),
+    ("🩷", "pink heart", &[], &["pink_heart"]),
+    ("🧡", "orange heart", &["orange"], &["orange_heart"]),
+    ("💛", "yellow heart", &["yellow"], &["yellow_heart"]),
+    ("💚", "green heart", &["green"], &["green_heart"]),
+    ("💙", "blue heart", &["blue"], &["blue_heart"]),
+    ("🩵", "light blue heart", &[], &["light_blue_heart"]),
+    ("💜", "purple heart", &["purple"], &["purple_heart"]),
+    ("🤎", "brown heart", &[], &["brown_heart"]),
+    (
+        "🖤",
+        "black heart",
+        &["black", "evil", "wicked"],
+        &["black_heart"],
+    ),
+    ("🩶", "grey heart", &[], &["grey_heart"]),
+    ("🤍", "white heart", &[], &["white_heart"]),
+    ("💋", "kiss mark", &[], &["kiss"]),
+    (
+        "💯",
+        "hundred points",
+        &["100", "full", "hundred", "score"],
+        &["100"],
+    ),
+    ("💢", "anger symbol", &[], &["anger"]),
+    (
+        "💥",
+        "collision",
+        &["boom", "comic"],
+        &["boom", "collision"],
+    ),
+    ("💫", "dizzy", &[], &["dizzy"]),
+    ("💦", "sweat droplets", &[], &["sweat_drops"]),
+    ("💨", "dashing away", &[], &["dash"]),
+    ("🕳\u{FE0F}", "hole", &[], &["hole"]),
+    (
+        "💬",
+        "speech balloon",
+        &["balloon", "bubble", "comic", "dialog", "speech"],
+        &["speech_balloon"],
+    ),
+    (
+        "👁\u{FE0F}\u{200D}🗨\u{FE0F}",
+        "eye in speech bubble",
+        &[],
+        &["eye_speech_bubble"],
+    ),
+    (
+        "🗨\u{FE0F}",
+        "left speech bubble",
+        &[],
+        &["left_speech_bubble"],
+    ),
+    (
+        "🗯\u{FE0F}",
+        "right anger bubble",
+        &[],
+        &["right_anger_bubble"],
+    ),
+    ("💭", "thought balloon", &[], &["thought_balloon"]),
+    ("💤", "ZZZ", &["comic", "good night", "sleep"], &["zzz"]),
+    ("👋", "waving hand", &["hand", "wave", "waving"], &["wave"]),
+    (
+        "🤚",
+        "raised back of hand",
+        &["backhand", "raised"],
+        &["raised_back_of_hand"],
+    ),
+    (
+        "🖐\u{FE0F}",
+        "hand with fingers splayed",
+        &[],
+        &["raised_hand_with_fingers_splayed"],
+    ),
+    (
+        "✋",
+        "raised hand",
+        &["hand", "high 5", "high five"],
+        &["hand", "raised_hand"],
+    ),
+    ("🖖", "vulcan salute", &[], &["vulcan_salute"]),
+    ("🫱", "rightwards hand", &[], &["rightwards_hand"]),
+    ("🫲", "leftwards hand", &[], &["leftwards_hand"]),
+    ("🫳", "palm down hand", &[], &["palm_down_hand"]),
+    ("🫴", "palm up hand", &[], &["palm_up_hand"]),
+    (
+        "🫷",
+        "leftwards pushing hand",
+        &[],
+        &["leftwards_pushing_hand"],
+    ),
+    (
+        "🫸",
+        "rightwards pushing hand",
+        &[],
+        &["rightwards_pushing_hand"],
+    ),
+    ("👌", "OK hand", &["hand", "OK", "perfect"], &["ok_hand"]),
+    ("🤌", "pinched fingers", &[], &["pinched_fingers"]),
+    ("🤏", "pinching hand", &[], &["pinching_hand"]),
+    (
+        "✌\u{FE0F}",
+        "victory hand",
+        &["hand", "v", "victory"],
+        &["v"],
+    ),
+    (
+        "🤞",
+        "crossed fingers",
+        &["cross", "finger", "hand", "luck"],
+        &["crossed_fingers"],
+    ),
+    (
+        "🫰",
+        "hand with index finger and thumb crossed",
+        &[],
+        &["hand_with_index_finger_and_thumb_crossed"],
+    ),
+    ("🤟", "love-you gesture", &[], &["love_you_gesture"]),
+    (
+        "🤘",
+        "sign of the horns",
+        &["finger", "hand", "horns", "rock-on"],
+        &["metal"],
+    ),
+    (
+        "🤙",
+        "call me hand",
+        &["call", "hand", "hang loose", "Shaka"],
+        &["call_me_hand"],
+    ),
+    (
+        "👈",
+        "backhand index pointing left",
+        &["backhand", "finger", "hand", "index", "point"],
+        &["point_left"],
+    ),
+    (
+        "👉",
+        "backhand index pointing right",
+        &["backhand", "finger", "hand", "index", "point"],
+        &["point_right"],
+    ),
+    (
+        "👆",
+        "backhand index pointing up",
+        &["backhand", "finger", "hand", "point", "up"],
+        &["point_up_2"],
+    ),
+    ("🖕", "middle finger", &[], &["middle_finger", "fu"]),
+    (
+        "👇",
+        "backhand index pointing down",
+        &["backhand", "down", "finger", "hand", "point"],
+        &["point_down"],
+    ),
+    ("☝\u{FE0F}", "index pointing up", &[], &["point_up"]),
+    (
+        "🫵",
+        "index pointing at the viewer",
+        &[],
+        &["index_pointing_at_the_viewer"],
+    ),
+    (
+        "👍",
+        "thumbs up",
+        &["+1", "hand", "thumb", "up"],
+        &["+1", "thumbsup"],
+    ),
+    (
+        "👎",
+        "thumbs down",
+        &["-1", "down", "hand", "thumb"],
+        &["-1", "thumbsdown"],
+    ),
+    (
+        "✊",
+        "raised fist",
+        &["clenched", "fist", "hand", "punch"],
+        &["fist_raised", "fist"],
+    ),
+    (
+        "👊",
+        "oncoming fist",
+        &["clenched", "fist", "hand", "punch"],
+        &["fist_oncoming", "facepunch", "punch"],
+    ),
+    ("🤛", "left-facing fist", &[], &["fist_left"]),
+    ("🤜", "right-facing fist", &[], &["fist_right"]),
+    ("👏", "clapping hands", &["clap", "hand"], &["clap"]),
+    (
+        "🙌",
+        "raising hands",
+        &["celebration", "gesture", "hand", "hooray", "raised"],
+        &["raised_hands"],
+    ),
+    ("🫶", "heart hands", &[], &["heart_hands"]),
+    ("👐", "open hands", &[], &["open_hands"]),
+    ("🤲", "palms up together", &[], &["palms_up_together"]),
+    ("🤝", "handshake", &[], &["handshake"]),
+    (
+        "🙏",
+        "folded hands",
+        &[
+            "ask",
+            "hand",
+            "high 5",
+            "high five",
+            "please",
+            "pray",
+            "thanks",
+        ],
+        &["pray"],
+    ),
+    ("✍\u{FE0F}", "writing hand", &[], &["writing_hand"]),
+    ("💅", "nail polish", &[], &["nail_care"]),
+    ("🤳", "selfie", &[], &["selfie"]),
+    (
+        "💪",
+        "flexed biceps",
+        &["biceps", "comic", "flex", "muscle"],
+        &["muscle"],
+    ),
+    ("🦾", "mechanical arm", &[], &["mechanical_arm"]),
+    ("🦿", "mechanical leg", &[], &["mechanical_leg"]),
+    ("🦵", "leg", &[], &["leg"]),
+    ("🦶", "foot", &[], &["foot"]),
+    ("👂", "ear", &[], &["ear"]),
+    ("🦻", "ear with hearing aid", &[], &["ear_with_hearing_aid"]),
+    ("👃", "nose", &[], &["nose"]),
+    ("🧠", "brain", &["intelligent"], &["brain"]),
+    ("🫀", "anatomical heart", &[], &["anatomical_heart"]),
+    ("🫁", "lungs", &[], &["lungs"]),
+    ("🦷", "tooth", &[], &["tooth"]),
+    ("🦴", "bone", &[], &["bone"]),
+    ("👀", "eyes", &["eye", "face"], &["eyes"]),
+    ("👁\u{FE0F}", "eye", &[], &["eye"]),
+    ("👅", "tongue", &[], &["tongue"]),
+    ("👄", "mouth", &[], &["lips"]),
+    ("🫦", "biting lip", &[], &["biting_lip"]),
+    ("👶", "baby", &[], &["baby"]),
+    ("🧒", "child", &[], &["child"]),
+    ("👦", "boy", &[], &["boy"]),
+    ("👧", "girl", &[], &["girl"]),
+    ("🧑", "person", &[], &["adult"]),
+    ("👱", "person: blond hair", &[], &["blond_haired_person"]),
+    ("👨", "man", &[], &["man"]),
+    ("🧔", "person: beard", &[], &["bearded_person"]),
+    ("🧔\u{200D}♂\u{FE0F}", "man: beard", &[], &["man_beard"]),
+    ("🧔\u{200D}♀\u{FE0F}", "woman: beard", &[], &["woman_beard"]),
+    ("👨\u{200D}🦰", "man: red hair", &[], &["red_haired_man"]),
+    (
+        "👨\u{200D}🦱",
+        "man: curly hair",
+        &[],
+        &["curly_haired_man"],
+    ),
+    (
+        "👨\u{200D}🦳",
+        "man: white hair",
+        &[],
+        &["white_haired_man"],
+    ),
+    ("👨\u{200D}🦲", "man: bald", &[], &["bald_man"]),
+    ("👩", "woman", &[], &["woman"]),
+    (
+        "👩\u{200D}🦰",
+        "woman: red hair",
+        &[],
+        &["red_haired_woman"],
+    ),
+    (
+        "🧑\u{200D}🦰",
+        "person: red hair",
+        &[],
+        &["person_red_hair"],
+    ),
+    (
+        "👩\u{200D}🦱",
+        "woman: curly hair",
+        &[],
+        &["curly_haired_woman"],
+    ),
+    (
+        "🧑\u{200D}🦱",
+        "person: curly hair",
+        &[],
+        &["person_curly_hair"],
+    ),
+    (
+        "👩\u{200D}🦳",
+        "woman: white hair",
+        &[],
+        &["white_haired_woman"],
+    ),
+    (
+        "🧑\u{200D}🦳",
+        "person: white hair",
+        &[],
+        &["person_white_hair"],
+    ),
+    ("👩\u{200D}🦲", "woman: bald", &[], &["bald_woman"]),
+    ("🧑\u{200D}🦲", "person: bald", &[], &["person_bald"]),
+    (
+        "👱\u{200D}♀\u{FE0F}",
+        "woman: blond hair",
+        &[],
+        &["blond_haired_woman", "blonde_woman"],
+    ),
+    (
+        "👱\u{200D}♂\u{FE0F}",
+        "man: blond hair",
+        &[],
+        &["blond_haired_man"],
+    ),
+    ("🧓", "older person", &[], &["older_adult"]),
+    ("👴", "old man", &[], &["older_man"]),
+    ("👵", "old woman", &[], &["older_woman"]),
+    ("🙍", "person frowning", &[], &["frowning_person"]),
+    (
+        "🙍\u{200D}♂\u{FE0F}",
+        "man frowning",
+        &[],
+        &["frowning_man"],
+    ),
+    (
+        "🙍\u{200D}♀\u{FE0F}",
+        "woman frowning",
+        &[],
+        &["frowning_woman"],
+    ),
+    ("🙎", "person pouting", &[], &["pouting_face"]),
+    ("🙎\u{200D}♂\u{FE0F}", "man pouting", &[], &["pouting_man"]),
+    (
+        "🙎\u{200D}♀\u{FE0F}",
+        "woman pouting",
+        &[],
+        &["pouting_woman"],
+    ),
+    ("🙅", "person gesturing NO", &[], &["no_good"]),
+    (
+        "🙅\u{200D}♂\u{FE0F}",
+        "man gesturing NO",
+        &[],
+        &["no_good_man", "ng_man"],
+    ),
+    (
+        "🙅\u{200D}♀\u{FE0F}",
+        "woman gesturing NO",
+        &[],
+        &["no_good_woman", "ng_woman"],
+    ),
+    ("🙆", "person gesturing OK", &[], &["ok_person"]),
+    ("🙆\u{200D}♂\u{FE0F}", "man gesturing OK", &[], &["ok_man"]),
+    (
+        "🙆\u{200D}♀\u{FE0F}",
+        "woman gesturing OK",
+        &[],
+        &["ok_woman"],
+    ),
+    (
+        "💁",
+        "person tipping hand",
+        &[],
+        &["tipping_hand_person", "information_desk_person"],
+    ),
+    (
+        "💁\u{200D}♂\u{FE0F}",
+        "man tipping hand",
+        &[],
+        &["tipping_hand_man", "sassy_man"],
+    ),
+    (
+        "💁\u{200D}♀\u{FE0F}",
+        "woman tipping hand",
+        &[],
+        &["tipping_hand_woman", "sassy_woman"],
+    ),
+    ("🙋", "person raising hand", &[], &["raising_hand"]),
+    (
+        "🙋\u{200D}♂\u{FE0F}",
+        "man raising hand",
+        &[],
+        &["raising_hand_man"],
+    ),
+    (
+        "🙋\u{200D}♀\u{FE0F}",
+        "woman raising hand",
+        &[],
+        &["raising_hand_woman"],
+    ),
+    ("🧏", "deaf person", &[], &["deaf_person"]),
+    ("🧏\u{200D}♂\u{FE0F}", "deaf man", &[], &["deaf_man"]),
+    ("🧏\u{200D}♀\u{FE0F}", "deaf woman", &[], &["deaf_woman"]),
+    ("🙇", "person bowing", &[], &["bow"]),
+    ("🙇\u{200D}♂\u{FE0F}", "man bowing", &[], &["bowing_man"]),
+    (
+        "🙇\u{200D}♀\u{FE0F}",
+        "woman bowing",
+        &[],
+        &["bowing_woman"],
+    ),
+    ("🤦", "person facepalming", &[], &["facepalm"]),
+    (
+        "🤦\u{200D}♂\u{FE0F}",
+        "man facepalming",
+        &[],
+        &["man_facepalming"],
+    ),
+    (
+        "🤦\u{200D}♀\u{FE0F}",
+        "woman facepalming",
+        &[],
+        &["woman_facepalming"],
+    ),
+    ("🤷", "person shrugging", &[], &["shrug"]),
+    (
+        "🤷\u{200D}♂\u{FE0F}",
+        "man shrugging",
+        &[],
+        &["man_shrugging"],
+    ),
+    (
+        "🤷\u{200D}♀\u{FE0F}",
+        "woman shrugging",
+        &[],
+        &["woman_shrugging"],
+    ),
+    (
+        "🧑\u{200D}⚕\u{FE0F}",
+        "health worker",
+        &[],
+        &["health_worker"],
+    ),
+    (
+        "👨\u{200D}⚕\u{FE0F}",
+        "man health worker",
+        &[],
+        &["man_health_worker"],
+    ),
+    (
+        "👩\u{200D}⚕\u{FE0F}",
+        "woman health worker",
+        &[],
+        &["woman_health_worker"],
+    ),
+    ("🧑\u{200D}🎓", "student", &[], &["student"]),
+    ("👨\u{200D}🎓", "man student", &[], &["man_student"]),
+    ("👩\u{200D}🎓", "woman student", &[], &["woman_student"]),
+    ("🧑\u{200D}🏫", "teacher", &[], &["teacher"]),
+    ("👨\u{200D}🏫", "man teacher", &[], &["man_teacher"]),
+    ("👩\u{200D}🏫", "woman teacher", &[], &["woman_teacher"]),
+    ("🧑\u{200D}⚖\u{FE0F}", "judge", &[], &["judge"]),
+    ("👨\u{200D}⚖\u{FE0F}", "man judge", &[], &["man_judge"]),
+    ("👩\u{200D}⚖\u{FE0F}", "woman judge", &[], &["woman_judge"]),
+    ("🧑\u{200D}🌾", "farmer", &[], &["farmer"]),
+    ("👨\u{200D}🌾", "man farmer", &[], &["man_farmer"]),
+    ("👩\u{200D}🌾", "woman farmer", &[], &["woman_farmer"]),
+    ("🧑\u{200D}🍳", "cook", &[], &["cook"]),
+    ("👨\u{200D}🍳", "man cook", &[], &["man_cook"]),
+    ("👩\u{200D}🍳", "woman cook", &[], &["woman_cook"]),
+    ("🧑\u{200D}🔧", "mechanic", &[], &["mechanic"]),
+    ("👨\u{200D}🔧", "man mechanic", &[], &["man_mechanic"]),
+    ("👩\u{200D}🔧", "woman mechanic", &[], &["woman_mechanic"]),
+    ("🧑\u{200D}🏭", "factory worker", &[], &["factory_worker"]),
+    (
+        "👨\u{200D}🏭",
+        "man factory worker",
+        &[],
+        &["man_factory_worker"],
+    ),
+    (
+        "👩\u{200D}🏭",
+        "woman factory worker",
+        &[],
+        &["woman_factory_worker"],
+    ),
+    ("🧑\u{200D}💼", "office worker", &[], &["office_worker"]),
+    (
+        "👨\u{200D}💼",
+        "man office worker",
+        &[],
+        &["man_office_worker"],
+    ),
+    (
+        "👩\u{200D}💼",
+        "woman office worker",
+        &[],
+        &["woman_office_worker"],
+    ),
+    ("🧑\u{200D}🔬", "scientist", &[], &["scientist"]),
+    ("👨\u{200D}🔬", "man scientist", &[], &["man_scientist"]),
+    ("👩\u{200D}🔬", "woman scientist", &[], &["woman_scientist"]),
+    ("🧑\u{200D}💻", "technologist", &[], &["technologist"]),
+    (
+        "👨\u{200D}💻",
+        "man technologist",
+        &[],
+        &["man_technologist"],
+    ),
+    (
+        "👩\u{200D}💻",
+        "woman technologist",
+        &[],
+        &["woman_technologist"],
+    ),
+    ("🧑\u{200D}🎤", "singer", &[], &["singer"]),
+    ("👨\u{200D}🎤", "man singer", &[], &["man_singer"]),
+    ("👩\u{200D}🎤", "woman singer", &[], &["woman_singer"]),
+    ("🧑\u{200D}🎨", "artist", &[], &["artist"]),
+    ("👨\u{200D}🎨", "man artist", &[], &["man_artist"]),
+    ("👩\u{200D}🎨", "woman artist", &[], &["woman_artist"]),
+    ("🧑\u{200D}✈\u{FE0F}", "pilot", &[], &["pilot"]),
+    ("👨\u{200D}✈\u{FE0F}", "man pilot", &[], &["man_pilot"]),
+    ("👩\u{200D}✈\u{FE0F}", "woman pilot", &[], &["woman_pilot"]),
+    ("🧑\u{200D}🚀", "astronaut", &[], &["astronaut"]),
+    ("👨\u{200D}🚀", "man astronaut", &[], &["man_astronaut"]),
+    ("👩\u{200D}🚀", "woman astronaut", &[], &["woman_astronaut"]),
+    ("🧑\u{200D}🚒", "firefighter", &[], &["firefighter"]),
+    ("👨\u{200D}🚒", "man firefighter", &[], &["man_firefighter"]),
+    (
+        "👩\u{200D}🚒",
+        "woman firefighter",
+        &[],
+        &["woman_firefighter"],
+    ),
+    ("👮", "police officer", &[], &["police_officer", "cop"]),
+    (
+        "👮\u{200D}♂\u{FE0F}",
+        "man police officer",
+        &[],
+        &["policeman"],
+    ),
+    (
+        "👮\u{200D}♀\u{FE0F}",
+        "woman police officer",
+        &[],
+        &["policewoman"],
+    ),
+    ("🕵\u{FE0F}", "detective", &[], &["detective"]),
+    (
+        "🕵\u{FE0F}\u{200D}♂\u{FE0F}",
+        "man detective",
+        &[],
+        &["male_detective"],
+    ),
+    (
+        "🕵\u{FE0F}\u{200D}♀\u{FE0F}",
+        "woman detective",
+        &[],
+        &["female_detective"],
+    ),
+    ("💂", "guard", &[], &["guard"]),
+    ("💂\u{200D}♂\u{FE0F}", "man guard", &[], &["guardsman"]),
+    ("💂\u{200D}♀\u{FE0F}", "woman guard", &[], &["guardswoman"]),
+    ("🥷", "ninja", &[], &["ninja"]),
+    ("👷", "construction worker", &[], &["construction_worker"]),
+    (
+        "👷\u{200D}♂\u{FE0F}",
+        "man construction worker",
+        &[],
+        &["construction_worker_man"],
+    ),
+    (
+        "👷\u{200D}♀\u{FE0F}",
+        "woman construction worker",
+        &[],
+        &["construction_worker_woman"],
+    ),
+    ("🫅", "person with crown", &[], &["person_with_crown"]),
+    ("🤴", "prince", &[], &["prince"]),
+    ("👸", "princess", &[], &["princess"]),
+    ("👳", "person wearing turban", &[], &["person_with_turban"]),
+    (
+        "👳\u{200D}♂\u{FE0F}",
+        "man wearing turban",
+        &[],
+        &["man_with_turban"],
+    ),
+    (
+        "👳\u{200D}♀\u{FE0F}",
+        "woman wearing turban",
+        &[],
+        &["woman_with_turban"],
+    ),
+    ("👲", "person with skullcap", &[], &["man_with_gua_pi_mao"]),
+    ("🧕", "woman with headscarf", &[], &["woman_with_headscarf"]),
+    ("🤵", "person in tuxedo", &[], &["person_in_tuxedo"]),
+    (
+        "🤵\u{200D}♂\u{FE0F}",
+        "man in tuxedo",
+        &[],
+        &["man_in_tuxedo"],
+    ),
+    (
+        "🤵\u{200D}♀\u{FE0F}",
+        "woman in tuxedo",
+        &[],
+        &["woman_in_tuxedo"],
+    ),
+    ("👰", "person with veil", &[], &["person_with_veil"]),
+    (
+        "👰\u{200D}♂\u{FE0F}",
+        "man with veil",
+        &[],
+        &["man_with_veil"],
+    ),
+    (
+        "👰\u{200D}♀\u{FE0F}",
+        "woman with veil",
+        &[],
+        &["woman_with_veil", "bride_with_veil"],
+    ),
+    ("🤰", "pregnant woman", &[], &["pregnant_woman"]),
+    ("🫃", "pregnant man", &[], &["pregnant_man"]),
+    ("🫄", "pregnant person", &[], &["pregnant_person"]),
+    ("🤱", "breast-feeding", &[], &["breast_feeding"]),
+    (
+        "👩\u{200D}🍼",
+        "woman feeding baby",
+        &[],
+        &["woman_feeding_baby"],
+    ),
+    (
+        "👨\u{200D}🍼",
+        "man feeding baby",
+        &[],
+        &["man_feeding_baby"],
+    ),
+    (
+        "🧑\u{200D}🍼",
+        "person feeding baby",
+        &[],
+        &["person_feeding_baby"],
+    ),
+    ("👼", "baby angel", &[], &["angel"]),
+    ("🎅", "Santa Claus", &[], &["santa"]),
+    ("🤶", "Mrs. Claus", &[], &["mrs_claus"]),
+    ("🧑\u{200D}🎄", "Mx Claus", &[], &["mx_claus"]),
+    ("🦸", "superhero", &[], &["superhero"]),
+    (
+        "🦸\u{200D}♂\u{FE0F}",
+        "man superhero",
+        &[],
+        &["superhero_man"],
+    ),
+    (
+        "🦸\u{200D}♀\u{FE0F}",
+        "woman superhero",
+        &[],
+        &["superhero_woman"],
+    ),
+    ("🦹", "supervillain", &[], &["supervillain"]),
+    (
+        "🦹\u{200D}♂\u{FE0F}",
+        "man supervillain",
+        &[],
+        &["supervillain_man"],
+    ),
+    (
+        "🦹\u{200D}♀\u{FE0F}",
+        "woman supervillain",
+        &[],
+        &["supervillain_woman"],
+    ),
+    ("🧙", "mage", &[], &["mage"]),
+    ("🧙\u{200D}♂\u{FE0F}", "man mage", &[], &["mage_man"]),
+    ("🧙\u{200D}♀\u{FE0F}", "woman mage", &[], &["mage_woman"]),
+    ("🧚", "fairy", &[], &["fairy"]),
+    ("🧚\u{200D}♂\u{FE0F}", "man fairy", &[], &["fairy_man"]),
+    ("🧚\u{200D}♀\u{FE0F}", "woman fairy", &[], &["fairy_woman"]),
+    ("🧛", "vampire", &[], &["vampire"]),
+    ("🧛\u{200D}♂\u{FE0F}", "man vampire", &[], &["vampire_man"]),
+    (
+        "🧛\u{200D}♀\u{FE0F}",
+        "woman vampire",
+        &[],
+        &["vampire_woman"],
+    ),
+    ("🧜", "merperson", &[], &["merperson"]),
+    ("🧜\u{200D}♂\u{FE0F}", "merman", &[], &["merman"]),
+    ("🧜\u{200D}♀\u{FE0F}", "mermaid", &[], &["mermaid"]),
+    ("🧝", "elf", &[], &["elf"]),
+    ("🧝\u{200D}♂\u{FE0F}", "man elf", &[], &["elf_man"]),
+    ("🧝\u{200D}♀\u{FE0F}", "woman elf", &[], &["elf_woman"]),
+    ("🧞", "genie", &[], &["genie"]),
+    ("🧞\u{200D}♂\u{FE0F}", "man genie", &[], &["genie_man"]),
+    ("🧞\u{200D}♀\u{FE0F}", "woman genie", &[], &["genie_woman"]),
+    ("🧟", "zombie", &[], &["zombie"]),
+    ("🧟\u{200D}♂\u{FE0F}", "man zombie", &[], &["zombie_man"]),
+    (
+        "🧟\u{200D}♀\u{FE0F}",
+        "woman zombie",
+        &[],
+        &["zombie_woman"],
+    ),
+    ("🧌", "troll", &[], &["troll"]),
+    ("💆", "person getting massage", &[], &["massage"]),
+    (
+        "💆\u{200D}♂\u{FE0F}",
+        "man getting massage",
+        &[],
+        &["massage_man"],
+    ),
+    (
+        "💆\u{200D}♀\u{FE0F}",
+        "woman getting massage",
+        &[],
+        &["massage_woman"],
+    ),
+    ("💇", "person getting haircut", &[], &["haircut"]),
+    (
+        "💇\u{200D}♂\u{FE0F}",
+        "man getting haircut",
+        &[],
+        &["haircut_man"],
+    ),
+    (
+        "💇\u{200D}♀\u{FE0F}",
+        "woman getting haircut",
+        &[],
+        &["haircut_woman"],
+    ),
+    ("🚶", "person walking", &[], &["walking"]),
+    ("🚶\u{200D}♂\u{FE0F}", "man walking", &[], &["walking_man"]),
+    (
+        "🚶\u{200D}♀\u{FE0F}",
+        "woman walking",
+        &[],
+        &["walking_woman"],
+    ),
+    (
+        "🚶\u{200D}➡\u{FE0F}",
+        "person walking facing right",
+        &[],
+        &[],
+    ),
+    (
+        "🚶\u{200D}♀\u{FE0F}\u{200D}➡\u{FE0F}",
+        "woman walking facing right",
+        &[],
+        &[],
+    ),
+    (
+        "🚶\u{200D}♂\u{FE0F}\u{200D}➡\u{FE0F}",
+        "man walking facing right",
+        &[],
+        &[],
+    ),
+    ("🧍", "person standing", &[], &["standing_person"]),
+    (
+        "🧍\u{200D}♂\u{FE0F}",
+        "man standing",
+        &[],
+        &["standing_man"],
+    ),
+    (
+        "🧍\u{200D}♀\u{FE0F}",
+        "woman standing",
+        &[],
+        &["standing_woman"],
+    ),
+    ("🧎", "person kneeling", &[], &["kneeling_person"]),
+    (
+        "🧎\u{200D}♂\u{FE0F}",
+        "man kneeling",
+        &[],
+        &["kneeling_man"],
+    ),
+    (
+        "🧎\u{200D}♀\u{FE0F}",
+        "woman kneeling",
+        &[],
+        &["kneeling_woman"],
+    ),
+    (
+        "🧎\u{200D}➡\u{FE0F}",
+        "person kneeling facing right",
+        &[],
+        &[],
+    ),
+    (
+        "🧎\u{200D}♀\u{FE0F}\u{200D}➡\u{FE0F}",
+        "woman kneeling facing right",
+        &[],
+        &[],
+    ),
+    (
+        "🧎\u{200D}♂\u{FE0F}\u{200D}➡\u{FE0F}",
+        "man kneeling facing right",
+        &[],
+        &[],
+    ),
+    (
+        "🧑\u{200D}🦯",
+        "person with white cane",
+        &[],
+        &["person_with_probing_cane"],
+    ),
+    (
+        "🧑\u{200D}🦯\u{200D}➡\u{FE0F}",
+        "person with white cane facing right",
+        &[],
+        &[],
+    ),
+    (
+        "👨\u{200D}🦯",
+        "man with white cane",
+        &[],
+        &["man_with_probing_cane"],
+    ),
+    (
+        "👨\u{200D}🦯\u{200D}➡\u{FE0F}",
+        "man with white cane facing right",
+        &[],
+        &[],
+    ),
+    (
+        "👩\u{200D}🦯",
+        "woman with white cane",
+        &[],
+        &["woman_with_probing_cane"],
+    ),
+    (
+        "👩\u{200D}🦯\u{200D}➡\u{FE0F}",
+        "woman with white cane facing right",
+        &[],
+        &[],
+    ),
+    (
+        "🧑\u{200D}🦼",
+        "person in motorized wheelchair",
+        &[],
+        &["person_in_motorized_wheelchair"],
+    ),
+    (
+        "🧑\u{200D}🦼\u{200D}➡\u{FE0F}",
+        "person in motorized wheelchair facing right",
+        &[],
+        &[],
+    ),
+    (
+        "👨\u{200D}🦼",
+        "man in motorized wheelchair",
+        &[],
+        &["man_in_motorized_wheelchair"],
+    ),
+    (
+        "👨\u{200D}🦼\u{200D}➡\u{FE0F}",
+        "man in motorized wheelchair facing right",
+        &[],
+        &[],
+    ),
+    (
+        "👩\u{200D}🦼",
+        "woman in motorized wheelchair",
+        &[],
+        &["woman_in_motorized_wheelchair"],
+    ),
+    (
+        "👩\u{200D}🦼\u{200D}➡\u{FE0F}",
+        "woman in motorized wheelchair facing right",
+        &[],
+        &[],
+    ),
+    (
+        "🧑\u{200D}🦽",
+        "person in manual wheelchair",
+        &[],
+        &["person_in_manual_wheelchair"],
+    ),
+    (
+        "🧑\u{200D}🦽\u{200D}➡\u{FE0F}",
+        "person in manual wheelchair facing right",
+        &[],
+        &[],
+    ),
+    (
+        "👨\u{200D}🦽",
+        "man in manual wheelchair",
+        &[],
+        &["man_in_manual_wheelchair"],
+    ),
+    (
+        "👨\u{200D}🦽\u{200D}➡\u{FE0F}",
+        "man in manual wheelchair facing right",
+        &[],
+        &[],
+    ),
+    (
+        "👩\u{200D}🦽",
+        "woman in manual wheelchair",
+        &[],
+        &["woman_in_manual_wheelchair"],
+    ),
+    (
+        "👩\u{200D}🦽\u{200D}➡\u{FE0F}",
+        "woman in manual wheelchair facing right",
+        &[],
+        &[],
+    ),
+    ("🏃", "person running", &[], &["runner", "running"]),
+    ("🏃\u{200D}♂\u{FE0F}", "man running", &[], &["running_man"]),
+    (
+        "🏃\u{200D}♀\u{FE0F}",
+        "woman running",
+        &[],
+        &["running_woman"],
+    ),
+    (
+        "🏃\u{200D}➡\u{FE0F}",
+        "person running facing right",
+        &[],
+        &[],
+    ),
+    (
+        "🏃\u{200D}♀\u{FE0F}\u{200D}➡\u{FE0F}",
+        "woman running facing right",
+        &[],
+        &[],
+    ),
+    (
+        "🏃\u{200D}♂\u{FE0F}\u{200D}➡\u{FE0F}",
+        "man running facing right",
+        &[],
+        &[],
+    ),
+    ("💃", "woman dancing", &[], &["woman_dancing", "dancer"]),
+    ("🕺", "man dancing", &[], &["man_dancing"]),
+    (
+        "🕴\u{FE0F}",
+        "person in suit levitating",
+        &[],
+        &["business_suit_levitating"],
+    ),
+    ("👯", "people with bunny ears", &[], &["dancers"]),
+    (
+        "👯\u{200D}♂\u{FE0F}",
+        "men with bunny ears",
+        &[],
+        &["dancing_men"],
+    ),
+    (
+        "👯\u{200D}♀\u{FE0F}",
+        "women with bunny ears",
+        &[],
+        &["dancing_women"],
+    ),
+    ("🧖", "person in steamy room", &[], &["sauna_person"]),
+    (
+        "🧖\u{200D}♂\u{FE0F}",
+        "man in steamy room",
+        &[],
+        &["sauna_man"],
+    ),
+    (
+        "🧖\u{200D}♀\u{FE0F}",
+        "woman in steamy room",
+        &[],
+        &["sauna_woman"],
+    ),
+    ("🧗", "person climbing", &[], &["climbing"]),
+    (
+        "🧗\u{200D}♂\u{FE0F}",
+        "man climbing",
+        &[],
+        &["climbing_man"],
+    ),
+    (
+        "🧗\u{200D}♀\u{FE0F}",
+        "woman climbing",
+        &[],
+        &["climbing_woman"],
+    ),
+    ("🤺", "person fencing", &[], &["person_fencing"]),
+    ("🏇", "horse racing", &[], &["horse_racing"]),
+    ("⛷\u{FE0F}", "skier", &[], &["skier"]),
+    ("🏂", "snowboarder", &[], &["snowboarder"]),
+    ("🏌\u{FE0F}", "person golfing", &[], &["golfing"]),
+    (
+        "🏌\u{FE0F}\u{200D}♂\u{FE0F}",
+        "man golfing",
+        &[],
+        &["golfing_man"],
+    ),
+    (
+        "🏌\u{FE0F}\u{200D}♀\u{FE0F}",
+        "woman golfing",
+        &[],
+        &["golfing_woman"],
+    ),
+    ("🏄", "person surfing", &[], &["surfer"]),
+    ("🏄\u{200D}♂\u{FE0F}", "man surfing", &[], &["surfing_man"]),
+    (
+        "🏄\u{200D}♀\u{FE0F}",
+        "woman surfing",
+        &[],
+        &["surfing_woman"],
+    ),
+    ("🚣", "person rowing boat", &[], &["rowboat"]),
+    (
+        "🚣\u{200D}♂\u{FE0F}",
+        "man rowing boat",
+        &[],
+        &["rowing_man"],
+    ),
+    (
+        "🚣\u{200D}♀\u{FE0F}",
+        "woman rowing boat",
+        &[],
+        &["rowing_woman"],
+    ),
+    ("🏊", "person swimming", &[], &["swimmer"]),
+    (
+        "🏊\u{200D}♂\u{FE0F}",
+        "man swimming",
+        &[],
+        &["swimming_man"],
+    ),
+    (
+        "🏊\u{200D}♀\u{FE0F}",
+        "woman swimming",
+        &[],
+        &["swimming_woman"],
+    ),
+    (
+        "⛹\u{FE0F}",
+        "person bouncing ball",
+        &[],
+        &["bouncing_ball_person"],
+    ),
+    (
+        "⛹\u{FE0F}\u{200D}♂\u{FE0F}",
+        "man bouncing ball",
+        &[],
+        &["bouncing_ball_man", "basketball_man"],
+    ),
+    (
+        "⛹\u{FE0F}\u{200D}♀\u{FE0F}",
+        "woman bouncing ball",
+        &[],
+        &["bouncing_ball_woman", "basketball_woman"],
+    ),
+    (
+        "🏋\u{FE0F}",
+        "person lifting weights",
+        &[],
+        &["weight_lifting"],
+    ),
+    (
+        "🏋\u{FE0F}\u{200D}♂\u{FE0F}",
+        "man lifting weights",
+        &[],
+        &["weight_lifting_man"],
+    ),
+    (
+        "🏋\u{FE0F}\u{200D}♀\u{FE0F}",
+        "woman lifting weights",
+        &[],
+        &["weight_lifting_woman"],
+    ),
+    ("🚴", "person biking", &[], &["bicyclist"]),
+    ("🚴\u{200D}♂\u{FE0F}", "man biking", &[], &["biking_man"]),
+    (
+        "🚴\u{200D}♀\u{FE0F}",
+        "woman biking",
+        &[],
+        &["biking_woman"],
+    ),
+    ("🚵", "person mountain biking", &[], &["mountain_bicyclist"]),
+    (
+        "🚵\u{200D}♂\u{FE0F}",
+        "man mountain biking",
+        &[],
+        &["mountain_biking_man"],
+    ),
+    (
+        "🚵\u{200D}♀\u{FE0F}",
+        "woman mountain biking",
+        &[],
+        &["mountain_biking_woman"],
+    ),
+    ("🤸", "person cartwheeling", &[], &["cartwheeling"]),
+    (
+        "🤸\u{200D}♂\u{FE0F}",
+        "man cartwheeling",
+        &[],
+        &["man_cartwheeling"],
+    ),
+    (
+        "🤸\u{200D}♀\u{FE0F}",
+        "woman cartwheeling",
+        &[],
+        &["woman_cartwheeling"],
+    ),
+    ("🤼", "people wrestling", &[], &["wrestling"]),
+    (
+        "🤼\u{200D}♂\u{FE0F}",
+        "men wrestling",
+        &[],
+        &["men_wrestling"],
+    ),
+    (
+        "🤼\u{200D}♀\u{FE0F}",
+        "women wrestling",
+        &[],
+        &["women_wrestling"],
+    ),
+    ("🤽", "person playing water polo", &[], &["water_polo"]),
+    (
+        "🤽\u{200D}♂\u{FE0F}",
+        "man playing water polo",
+        &[],
+        &["man_playing_water_polo"],
+    ),
+    (
+        "🤽\u{200D}♀\u{FE0F}",
+        "woman playing water polo",
+        &[],
+        &["woman_playing_water_polo"],
+    ),
+    ("🤾", "person playing handball", &[], &["handball_person"]),
+    (
+        "🤾\u{200D}♂\u{FE0F}",
+        "man playing handball",
+        &[],
+        &["man_playing_handball"],
+    ),
+    (
+        "🤾\u{200D}♀\u{FE0F}",
+        "woman playing handball",
+        &[],
+        &["woman_playing_handball"],
+    ),
+    ("🤹", "person juggling", &[], &["juggling_person"]),
+    (
+        "🤹\u{200D}♂\u{FE0F}",
+        "man juggling",
+        &[],
+        &["man_juggling"],
+    ),
+    (
+        "🤹\u{200D}♀\u{FE0F}",
+        "woman juggling",
+        &[],
+        &["woman_juggling"],
+    ),
+    ("🧘", "person in lotus position", &[], &["lotus_position"]),
+    (
+        "🧘\u{200D}♂\u{FE0F}",
+        "man in lotus position",
+        &[],
+        &["lotus_position_man"],
+    ),
+    (
+        "🧘\u{200D}♀\u{FE0F}",
+        "woman in lotus position",
+        &[],
+        &["lotus_position_woman"],
+    ),
+    ("🛀", "person taking bath", &[], &["bath"]),
+    ("🛌", "person in bed", &[], &["sleeping_bed"]),
+    (
+        "🧑\u{200D}🤝\u{200D}🧑",
+        "people holding hands",
+        &[],
+        &["people_holding_hands"],
+    ),
+    (
+        "👭",
+        "women holding hands",
+        &[],
+        &["two_women_holding_hands"],
+    ),
+    ("👫", "woman and man holding hands", &[], &["couple"]),
+    ("👬", "men holding hands", &[], &["two_men_holding_hands"]),
+    ("💏", "kiss", &[], &["couplekiss"]),
+    (
+        "👩\u{200D}❤\u{FE0F}\u{200D}💋\u{200D}👨",
+        "kiss: woman, man",
+        &[],
+        &["couplekiss_man_woman"],
+    ),
+    (
+        "👨\u{200D}❤\u{FE0F}\u{200D}💋\u{200D}👨",
+        "kiss: man, man",
+        &[],
+        &["couplekiss_man_man"],
+    ),
+    (
+        "👩\u{200D}❤\u{FE0F}\u{200D}💋\u{200D}👩",
+        "kiss: woman, woman",
+        &[],
+        &["couplekiss_woman_woman"],
+    ),
+    ("💑", "couple with heart", &[], &["couple_with_heart"]),
+    (
+        "👩\u{200D}❤\u{FE0F}\u{200D}👨",
+        "couple with heart: woman, man",
+        &[],
+        &["couple_with_heart_woman_man"],
+    ),
+    (
+        "👨\u{200D}❤\u{FE0F}\u{200D}👨",
+        "couple with heart: man, man",
+        &[],
+        &["couple_with_heart_man_man"],
+    ),
+    (
+        "👩\u{200D}❤\u{FE0F}\u{200D}👩",
+        "couple with heart: woman, woman",
+        &[],
+        &["couple_with_heart_woman_woman"],
+    ),
+    (
+        "👨\u{200D}👩\u{200D}👦",
+        "family: man, woman, boy",
+        &[],
+        &["family_man_woman_boy"],
+    ),
+    (
+        "👨\u{200D}👩\u{200D}👧",
+        "family: man, woman, girl",
+        &[],
+        &["family_man_woman_girl"],
+    ),
+    (
+        "👨\u{200D}👩\u{200D}👧\u{200D}👦",
+        "family: man, woman, girl, boy",
+        &[],
+        &["family_man_woman_girl_boy"],
+    ),
+    (
+        "👨\u{200D}👩\u{200D}👦\u{200D}👦",
+        "family: man, woman, boy, boy",
+        &[],
+        &["family_man_woman_boy_boy"],
+    ),
+    (
+        "👨\u{200D}👩\u{200D}👧\u{200D}👧",
+        "family: man, woman, girl, girl",
+        &[],
+        &["family_man_woman_girl_girl"],
+    ),
+    (
+        "👨\u{200D}👨\u{200D}👦",
+        "family: man, man, boy",
+        &[],
+        &["family_man_man_boy"],
+    ),
+    (
+        "👨\u{200D}👨\u{200D}👧",
+        "family: man, man, girl",
+        &[],
+        &["family_man_man_girl"],
+    ),
+    (
+        "👨\u{200D}👨\u{200D}👧\u{200D}👦",
+        "family: man, man, girl, boy",
+        &[],
+        &["family_man_man_girl_boy"],
+    ),
+    (
+        "👨\u{200D}👨\u{200D}👦\u{200D}👦",
+        "family: man, man, boy, boy",
+        &[],
+        &["family_man_man_boy_boy"],
+    ),
+    (
+        "👨\u{200D}👨\u{200D}👧\u{200D}👧",
+        "family: man, man, girl, girl",
+        &[],
+        &["family_man_man_girl_girl"],
+    ),
+    (
+        "👩\u{200D}👩\u{200D}👦",
+        "family: woman, woman, boy",
+        &[],
+        &["family_woman_woman_boy"],
+    ),
+    (
+        "👩\u{200D}👩\u{200D}👧",
+        "family: woman, woman, girl",
+        &[],
+        &["family_woman_woman_girl"],
+    ),
+    (
+        "👩\u{200D}👩\u{200D}👧\u{200D}👦",
+        "family: woman, woman, girl, boy",
+        &[],
+        &["family_woman_woman_girl_boy"],
+    ),
+    (
+        "👩\u{200D}👩\u{200D}👦\u{200D}👦",
+        "family: woman, woman, boy, boy",
+        &[],
+        &["family_woman_woman_boy_boy"],
+    ),
+    (
+        "👩\u{200D}👩\u{200D}👧\u{200D}👧",
+        "family: woman, woman, girl, girl",
+        &[],
+        &["family_woman_woman_girl_girl"],
+    ),
+    ("👨\u{200D}👦", "family: man, boy", &[], &["family_man_boy"]),
+    (
+        "👨\u{200D}👦\u{200D}👦",
+        "family: man, boy, boy",
+        &[],
+        &["family_man_boy_boy"],
+    ),
+    (
+        "👨\u{200D}👧",
+        "family: man, girl",
+        &[],
+        &["family_man_girl"],
+    ),
+    (
+        "👨\u{200D}👧\u{200D}👦",
+        "family: man, girl, boy",
+        &[],
+        &["family_man_girl_boy"],
+    ),
+    (
+        "👨\u{200D}👧\u{200D}👧",
+        "family: man, girl, girl",
+        &[],
+        &["family_man_girl_girl"],
+    ),
+    (
+        "👩\u{200D}👦",
+        "family: woman, boy",
+        &[],
+        &["family_woman_boy"],
+    ),
+    (
+        "👩\u{200D}👦\u{200D}👦",
+        "family: woman, boy, boy",
+        &[],
+        &["family_woman_boy_boy"],
+    ),
+    (
+        "👩\u{200D}👧",
+        "family: woman, girl",
+        &[],
+        &["family_woman_girl"],
+    ),
+    (
+        "👩\u{200D}👧\u{200D}👦",
+        "family: woman, girl, boy",
+        &[],
+        &["family_woman_girl_boy"],
+    ),
+    (
+        "👩\u{200D}👧\u{200D}👧",
+        "family: woman, girl, girl",
+        &[],
+        &["family_woman_girl_girl"],
+    ),
+    ("🗣\u{FE0F}", "speaking head", &[], &["speaking_head"]),
+    ("👤", "bust in silhouette", &[], &["bust_in_silhouette"]),
+    ("👥", "busts in silhouette", &[], &["busts_in_silhouette"]),
+    ("🫂", "people hugging", &[], &["people_hugging"]),
+    ("👪", "family", &[], &["family"]),
+    (
+        "🧑\u{200D}🧑\u{200D}🧒",
+        "family: adult, adult, child",
+        &[],
+        &[],
+    ),
+    (
+        "🧑\u{200D}🧑\u{200D}🧒\u{200D}🧒",
+        "family: adult, adult, child, child",
+        &[],
+        &[],
+    ),
+    ("🧑\u{200D}🧒", "family: adult, child", &[], &[]),
+    (
+        "🧑\u{200D}🧒\u{200D}🧒",
+        "family: adult, child, child",
+        &[],
+        &[],
+    ),
+    ("👣", "footprints", &[], &["footprints"]),
+    ("🫆", "fingerprint", &[], &[]),
+    ("🐵", "monkey face", &["face", "monkey"], &["monkey_face"]),
+    ("🐒", "monkey", &[], &["monkey"]),
+    ("🦍", "gorilla", &[], &["gorilla"]),
+    ("🦧", "orangutan", &[], &["orangutan"]),
+    ("🐶", "dog face", &["dog", "face", "pet"], &["dog"]),
+    ("🐕", "dog", &[], &["dog2"]),
+    ("🦮", "guide dog", &[], &["guide_dog"]),
+    ("🐕\u{200D}🦺", "service dog", &[], &["service_dog"]),
+    ("🐩", "poodle", &[], &["poodle"]),
+    ("🐺", "wolf", &[], &["wolf"]),
+    ("🦊", "fox", &["face"], &["fox_face"]),
+    ("🦝", "raccoon", &[], &["raccoon"]),
+    ("🐱", "cat face", &["cat", "face", "pet"], &["cat"]),
+    ("🐈", "cat", &[], &["cat2"]),
+    ("🐈\u{200D}⬛", "black cat", &[], &["black_cat"]),
+    ("🦁", "lion", &["face", "Leo", "zodiac"], &["lion"]),
+    ("🐯", "tiger face", &["face", "tiger"], &["tiger"]),
+    ("🐅", "tiger", &[], &["tiger2"]),
+    ("🐆", "leopard", &[], &["leopard"]),
+    ("🐴", "horse face", &[], &["horse"]),
+    ("🫎", "moose", &[], &["moose"]),
+    ("🫏", "donkey", &[], &["donkey"]),
+    ("🐎", "horse", &[], &["racehorse"]),
+    ("🦄", "unicorn", &["face"], &["unicorn"]),
+    ("🦓", "zebra", &[], &["zebra"]),
+    ("🦌", "deer", &[], &["deer"]),
+    ("🦬", "bison", &[], &["bison"]),
+    ("🐮", "cow face", &["cow", "face"], &["cow"]),
+    ("🐂", "ox", &[], &["ox"]),
+    ("🐃", "water buffalo", &[], &["water_buffalo"]),
+    ("🐄", "cow", &[], &["cow2"]),
+    ("🐷", "pig face", &["face", "pig"], &["pig"]),
+    ("🐖", "pig", &[], &["pig2"]),
+    ("🐗", "boar", &[], &["boar"]),
+    ("🐽", "pig nose", &[], &["pig_nose"]),
+    ("🐏", "ram", &[], &["ram"]),
+    ("🐑", "ewe", &[], &["sheep"]),
+    ("🐐", "goat", &[], &["goat"]),
+    ("🐪", "camel", &[], &["dromedary_camel"]),
+    ("🐫", "two-hump camel", &[], &["camel"]),
+    ("🦙", "llama", &[], &["llama"]),
+    ("🦒", "giraffe", &[], &["giraffe"]),
+    ("🐘", "elephant", &[], &["elephant"]),
+    ("🦣", "mammoth", &[], &["mammoth"]),
+    ("🦏", "rhinoceros", &[], &["rhinoceros"]),
+    ("🦛", "hippopotamus", &[], &["hippopotamus"]),
+    ("🐭", "mouse face", &["face", "mouse"], &["mouse"]),
+    ("🐁", "mouse", &[], &["mouse2"]),
+    ("🐀", "rat", &[], &["rat"]),
+    ("🐹", "hamster", &[], &["hamster"]),
+    (
+        "🐰",
+        "rabbit face",
+        &["bunny", "face", "pet", "rabbit"],
+        &["rabbit"],
+    ),
+    ("🐇", "rabbit", &[], &["rabbit2"]),
+    ("🐿\u{FE0F}", "chipmunk", &[], &["chipmunk"]),
+    ("🦫", "beaver", &[], &["beaver"]),
+    ("🦔", "hedgehog", &[], &["hedgehog"]),
+    ("🦇", "bat", &[], &["bat"]),
+    ("🐻", "bear", &["face"], &["bear"]),
+    ("🐻\u{200D}❄\u{FE0F}", "polar bear", &[], &["polar_bear"]),
+    ("🐨", "koala", &["face", "marsupial"], &["koala"]),
+    ("🐼", "panda", &["face"], &["panda_face"]),
+    ("🦥", "sloth", &[], &["sloth"]),
+    ("🦦", "otter", &[], &["otter"]),
+    ("🦨", "skunk", &[], &["skunk"]),
+    ("🦘", "kangaroo", &[], &["kangaroo"]),
+    ("🦡", "badger", &[], &["badger"]),
+    ("🐾", "paw prints", &[], &["feet", "paw_prints"]),
+    ("🦃", "turkey", &[], &["turkey"]),
+    ("🐔", "chicken", &["bird"], &["chicken"]),
+    ("🐓", "rooster", &[], &["rooster"]),
+    ("🐣", "hatching chick", &[], &["hatching_chick"]),
+    ("🐤", "baby chick", &[], &["baby_chick"]),
+    ("🐥", "front-facing baby chick", &[], &["hatched_chick"]),
+    ("🐦", "bird", &["bird"], &["bird"]),
+    ("🐧", "penguin", &["bird"], &["penguin"]),
+    ("🕊\u{FE0F}", "dove", &[], &["dove"]),
+    ("🦅", "eagle", &[], &["eagle"]),
+    ("🦆", "duck", &[], &["duck"]),
+    ("🦢", "swan", &[], &["swan"]),
+    ("🦉", "owl", &[], &["owl"]),
+    ("🦤", "dodo", &[], &["dodo"]),
+    ("🪶", "feather", &[], &["feather"]),
+    ("🦩", "flamingo", &[], &["flamingo"]),
+    ("🦚", "peacock", &[], &["peacock"]),
+    ("🦜", "parrot", &[], &["parrot"]),
+    ("🪽", "wing", &[], &["wing"]),
+    ("🐦\u{200D}⬛", "black bird", &[], &["black_bird"]),
+    ("🪿", "goose", &[], &["goose"]),
+    ("🐦\u{200D}🔥", "phoenix", &[], &[]),
+    ("🐸", "frog", &["face"], &["frog"]),
+    ("🐊", "crocodile", &[], &["crocodile"]),
+    ("🐢", "turtle", &["terrapin", "tortoise"], &["turtle"]),
+    ("🦎", "lizard", &[], &["lizard"]),
+    (
+        "🐍",
+        "snake",
+        &["bearer", "Ophiuchus", "serpent", "zodiac"],
+        &["snake"],
+    ),
+    ("🐲", "dragon face", &[], &["dragon_face"]),
+    ("🐉", "dragon", &[], &["dragon"]),
+    ("🦕", "sauropod", &[], &["sauropod"]),
+    ("🦖", "T-Rex", &[], &["t-rex"]),
+    (
+        "🐳",
+        "spouting whale",
+        &["face", "spouting", "whale"],
+        &["whale"],
+    ),
+    ("🐋", "whale", &[], &["whale2"]),
+    ("🐬", "dolphin", &["flipper"], &["dolphin", "flipper"]),
+    ("🦭", "seal", &[], &["seal"]),
+    ("🐟", "fish", &[], &["fish"]),
+    ("🐠", "tropical fish", &[], &["tropical_fish"]),
+    ("🐡", "blowfish", &[], &["blowfish"]),
+    ("🦈", "shark", &["fish"], &["shark"]),
+    ("🐙", "octopus", &["octopus"], &["octopus"]),
+    ("🐚", "spiral shell", &[], &["shell"]),
+    ("🪸", "coral", &[], &["coral"]),
+    ("🪼", "jellyfish", &[], &["jellyfish"]),
+    ("🦀", "crab", &[], &["crab"]),
+    ("🦞", "lobster", &[], &["lobster"]),
+    ("🦐", "shrimp", &[], &["shrimp"]),
+    ("🦑", "squid", &[], &["squid"]),
+    ("🦪", "oyster", &[], &["oyster"]),
+    ("🐌", "snail", &[], &["snail"]),
+    ("🦋", "butterfly", &["insect", "pretty"], &["butterfly"]),
+    ("🐛", "bug", &[], &["bug"]),
+    ("🐜", "ant", &[], &["ant"]),
+    ("🐝", "honeybee", &["bee", "insect"], &["bee", "honeybee"]),
+    ("🪲", "beetle", &[], &["beetle"]),
+    ("🐞", "lady beetle", &[], &["lady_beetle"]),
+    ("🦗", "cricket", &[], &["cricket"]),
+    ("🪳", "cockroach", &[], &["cockroach"]),
+    ("🕷\u{FE0F}", "spider", &[], &["spider"]),
+    ("🕸\u{FE0F}", "spider web", &[], &["spider_web"]),
+    ("🦂", "scorpion", &[], &["scorpion"]),
+    ("🦟", "mosquito", &[], &["mosquito"]),
+    ("🪰", "fly", &[], &["fly"]),
+    ("🪱", "worm", &[], &["worm"]),
+    ("🦠", "microbe", &[], &["microbe"]),
+    ("💐", "bouquet", &[], &["bouquet"]),
+    (
+        "🌸",
+        "cherry blossom",
+        &["blossom", "cherry", "flower"],
+        &["cherry_blossom"],
+    ),
+    ("💮", "white flower", &[], &["white_flower"]),
+    ("🪷", "lotus", &[], &["lotus"]),
+    ("🏵\u{FE0F}", "rosette", &[], &["rosette"]),
+    ("🌹", "rose", &["flower"], &["rose"]),
+    ("🥀", "wilted flower", &[], &["wilted_flower"]),
+    ("🌺", "hibiscus", &[], &["hibiscus"]),
+    ("🌻", "sunflower", &["flower", "sun"], &["sunflower"]),
+    ("🌼", "blossom", &[], &["blossom"]),
+    ("🌷", "tulip", &[], &["tulip"]),
+    ("🪻", "hyacinth", &[], &["hyacinth"]),
+    ("🌱", "seedling", &[], &["seedling"]),
+    ("🪴", "potted plant", &[], &["potted_plant"]),
+    ("🌲", "evergreen tree", &["tree"], &["evergreen_tree"]),
+    ("🌳", "deciduous tree", &[], &["deciduous_tree"]),
+    ("🌴", "palm tree", &[], &["palm_tree"]),
+    ("🌵", "cactus", &["plant"], &["cactus"]),
+    ("🌾", "sheaf of rice", &[], &["ear_of_rice"]),
+    ("🌿", "herb", &[], &["herb"]),
+    ("☘\u{FE0F}", "shamrock", &[], &["shamrock"]),
+    (
+        "🍀",
+        "four leaf clover",
+        &["4", "clover", "four", "four-leaf clover", "leaf"],
+        &["four_leaf_clover"],
+    ),
+    (
+        "🍁",
+        "maple leaf",
+        &["falling", "leaf", "maple"],
+        &["maple_leaf"],
+    ),
+    ("🍂", "fallen leaf", &[], &["fallen_leaf"]),
+    ("🍃", "leaf fluttering in wind", &[], &["leaves"]),
+    ("🪹", "empty nest", &[], &["empty_nest"]),
+    ("🪺", "nest with eggs", &[], &["nest_with_eggs"]),
+    ("🍄", "mushroom", &[], &["mushroom"]),
+    ("🪾", "leafless tree", &[], &[]),
+    ("🍇", "grapes", &["fruit", "grape"], &["grapes"]),
+    ("🍈", "melon", &[], &["melon"]),
+    ("🍉", "watermelon", &["fruit"], &["watermelon"]),
+    ("🍊", "tangerine", &[], &["tangerine", "orange", "mandarin"]),
+    ("🍋", "lemon", &[], &["lemon"]),
+    ("🍋\u{200D}🟩", "lime", &[], &[]),
+    ("🍌", "banana", &["fruit"], &["banana"]),
+    ("🍍", "pineapple", &[], &["pineapple"]),
+    ("🥭", "mango", &[], &["mango"]),
+    ("🍎", "red apple", &["apple", "fruit", "red"], &["apple"]),
+    ("🍏", "green apple", &[], &["green_apple"]),
+    ("🍐", "pear", &[], &["pear"]),
+    ("🍑", "peach", &["fruit"], &["peach"]),
+    ("🍒", "cherries", &[], &["cherries"]),
+    ("🍓", "strawberry", &["berry", "fruit"], &["strawberry"]),
+    ("🫐", "blueberries", &[], &["blueberries"]),
+    ("🥝", "kiwi fruit", &[], &["kiwi_fruit"]),
+    ("🍅", "tomato", &[], &["tomato"]),
+    ("🫒", "olive", &[], &["olive"]),
+    ("🥥", "coconut", &[], &["coconut"]),
+    ("🥑", "avocado", &[], &["avocado"]),
+    ("🍆", "eggplant", &[], &["eggplant"]),
+    ("🥔", "potato", &[], &["potato"]),
+    ("🥕", "carrot", &[], &["carrot"]),
+    ("🌽", "ear of corn", &[], &["corn"]),
+    ("🌶\u{FE0F}", "hot pepper", &[], &["hot_pepper"]),
+    ("🫑", "bell pepper", &[], &["bell_pepper"]),
+    ("🥒", "cucumber", &[], &["cucumber"]),
+    ("🥬", "leafy green", &[], &["leafy_green"]),
+    ("🥦", "broccoli", &[], &["broccoli"]),
+    ("🧄", "garlic", &[], &["garlic"]),
+    ("🧅", "onion", &[], &["onion"]),
+    ("🥜", "peanuts", &[], &["peanuts"]),
+    ("🫘", "beans", &[], &["beans"]),
+    ("🌰", "chestnut", &[], &["chestnut"]),
+    ("🫚", "ginger root", &[], &["ginger_root"]),
+    ("🫛", "pea pod", &[], &["pea_pod"]),
+    ("🍄\u{200D}🟫", "brown mushroom", &[], &[]),
+    ("🫜", "root vegetable", &[], &[]),
+    ("🍞", "bread", &[], &["bread"]),
+    ("🥐", "croissant", &[], &["croissant"]),
+    ("🥖", "baguette bread", &[], &["baguette_bread"]),
+    ("🫓", "flatbread", &[], &["flatbread"]),
+    ("🥨", "pretzel", &[], &["pretzel"]),
+    ("🥯", "bagel", &[], &["bagel"]),
+    ("🥞", "pancakes", &[], &["pancakes"]),
+    ("🧇", "waffle", &[], &["waffle"]),
+    ("🧀", "cheese wedge", &[], &["cheese"]),
+    ("🍖", "meat on bone", &[], &["meat_on_bone"]),
+    ("🍗", "poultry leg", &[], &["poultry_leg"]),
+    ("🥩", "cut of meat", &[], &["cut_of_meat"]),
+    ("🥓", "bacon", &[], &["bacon"]),
+    ("🍔", "hamburger", &["burger"], &["hamburger"]),
+    ("🍟", "french fries", &["french", "fries"], &["fries"]),
+    ("🍕", "pizza", &["cheese", "slice"], &["pizza"]),
+    (
+        "🌭",
+        "hot dog",
+        &["frankfurter", "hotdog", "sausage"],
+        &["hotdog"],
+    ),
+    ("🥪", "sandwich", &[], &["sandwich"]),
+    ("🌮", "taco", &["mexican"], &["taco"]),
+    ("🌯", "burrito", &[], &["burrito"]),
+    ("🫔", "tamale", &[], &["tamale"]),
+    ("🥙", "stuffed flatbread", &[], &["stuffed_flatbread"]),
+    ("🧆", "falafel", &[], &["falafel"]),
+    ("🥚", "egg", &[], &["egg"]),
+    ("🍳", "cooking", &[], &["fried_egg"]),
+    ("🥘", "shallow pan of food", &[], &["shallow_pan_of_food"]),
+    ("🍲", "pot of food", &[], &["stew"]),
+    ("🫕", "fondue", &[], &["fondue"]),
+    ("🥣", "bowl with spoon", &[], &["bowl_with_spoon"]),
+    ("🥗", "green salad", &[], &["green_salad"]),
+    ("🍿", "popcorn", &[], &["popcorn"]),
+    ("🧈", "butter", &[], &["butter"]),
+    ("🧂", "salt", &[], &["salt"]),
+    ("🥫", "canned food", &[], &["canned_food"]),
+    ("🍱", "bento box", &[], &["bento"]),
+    ("🍘", "rice cracker", &[], &["rice_cracker"]),
+    ("🍙", "rice ball", &[], &["rice_ball"]),
+    ("🍚", "cooked rice", &[], &["rice"]),
+    ("🍛", "curry rice", &[], &["curry"]),
+    (
+        "🍜",
+        "steaming bowl",
+        &["bowl", "noodle", "ramen", "steaming"],
+        &["ramen"],
+    ),
+    ("🍝", "spaghetti", &[], &["spaghetti"]),
+    ("🍠", "roasted sweet potato", &[], &["sweet_potato"]),
+    ("🍢", "oden", &[], &["oden"]),
+    ("🍣", "sushi", &["sushi"], &["sushi"]),
+    ("🍤", "fried shrimp", &[], &["fried_shrimp"]),
+    ("🍥", "fish cake with swirl", &[], &["fish_cake"]),
+    ("🥮", "moon cake", &[], &["moon_cake"]),
+    ("🍡", "dango", &[], &["dango"]),
+    ("🥟", "dumpling", &[], &["dumpling"]),
+    ("🥠", "fortune cookie", &[], &["fortune_cookie"]),
+    ("🥡", "takeout box", &[], &["takeout_box"]),
+    ("🍦", "soft ice cream", &[], &["icecream"]),
+    ("🍧", "shaved ice", &[], &["shaved_ice"]),
+    ("🍨", "ice cream", &[], &["ice_cream"]),
+    (
+        "🍩",
+        "doughnut",
+        &["breakfast", "dessert", "donut", "sweet"],
+        &["doughnut"],
+    ),
+    ("🍪", "cookie", &["dessert", "sweet"], &["cookie"]),
+    (
+        "🎂",
+        "birthday cake",
+        &[
+            "birthday",
+            "cake",
+            "celebration",
+            "dessert",
+            "pastry",
+            "sweet",
+        ],
+        &["birthday"],
+    ),
+    (
+        "🍰",
+        "shortcake",
+        &["cake", "dessert", "pastry", "slice", "sweet"],
+        &["cake"],
+    ),
+    ("🧁", "cupcake", &[], &["cupcake"]),
+    ("🥧", "pie", &[], &["pie"]),
+    (
+        "🍫",
+        "chocolate bar",
+        &["bar", "chocolate", "dessert", "sweet"],
+        &["chocolate_bar"],
+    ),
+    ("🍬", "candy", &[], &["candy"]),
+    ("🍭", "lollipop", &[], &["lollipop"]),
+    ("🍮", "custard", &[], &["custard"]),
+    ("🍯", "honey pot", &[], &["honey_pot"]),
+    ("🍼", "baby bottle", &[], &["baby_bottle"]),
+    ("🥛", "glass of milk", &[], &["milk_glass"]),
+    (
+        "☕",
+        "hot beverage",
+        &["beverage", "coffee", "drink", "hot", "steaming", "tea"],
+        &["coffee"],
+    ),
+    ("🫖", "teapot", &[], &["teapot"]),
+    ("🍵", "teacup without handle", &[], &["tea"]),
+    ("🍶", "sake", &[], &["sake"]),
+    ("🍾", "bottle with popping cork", &[], &["champagne"]),
+    (
+        "🍷",
+        "wine glass",
+        &["bar", "beverage", "drink", "glass", "wine"],
+        &["wine_glass"],
+    ),
+    ("🍸", "cocktail glass", &[], &["cocktail"]),
+    ("🍹", "tropical drink", &[], &["tropical_drink"]),
+    (
+        "🍺",
+        "beer mug",
+        &["bar", "beer", "drink", "mug"],
+        &["beer"],
+    ),
+    (
+        "🍻",
+        "clinking beer mugs",
+        &["bar", "beer", "clink", "drink", "mug"],
+        &["beers"],
+    ),
+    (
+        "🥂",
+        "clinking glasses",
+        &["celebrate", "clink", "drink", "glass"],
+        &["clinking_glasses"],
+    ),
+    ("🥃", "tumbler glass", &[], &["tumbler_glass"]),
+    ("🫗", "pouring liquid", &[], &["pouring_liquid"]),
+    ("🥤", "cup with straw", &[], &["cup_with_straw"]),
+    ("🧋", "bubble tea", &[], &["bubble_tea"]),
+    ("🧃", "beverage box", &[], &["beverage_box"]),
+    ("🧉", "mate", &[], &["mate"]),
+    ("🧊", "ice", &[], &["ice_cube"]),
+    ("🥢", "chopsticks", &[], &["chopsticks"]),
+    (
+        "🍽\u{FE0F}",
+        "fork and knife with plate",
+        &[],
+        &["plate_with_cutlery"],
+    ),
+    ("🍴", "fork and knife", &[], &["fork_and_knife"]),
+    ("🥄", "spoon", &[], &["spoon"]),
+    ("🔪", "kitchen knife", &[], &["hocho", "knife"]),
+    ("🫙", "jar", &[], &["jar"]),
+    ("🏺", "amphora", &[], &["amphora"]),
+    (
+        "🌍",
+        "globe showing Europe-Africa",
+        &["Africa", "earth", "Europe", "globe", "world"],
+        &["earth_africa"],
+    ),
+    ("🌎", "globe showing Americas", &[], &["earth_americas"]),
+    ("🌏", "globe showing Asia-Australia", &[], &["earth_asia"]),
+    ("🌐", "globe with meridians", &[], &["globe_with_meridians"]),
+    ("🗺\u{FE0F}", "world map", &[], &["world_map"]),
+    ("🗾", "map of Japan", &[], &["japan"]),
+    ("🧭", "compass", &[], &["compass"]),
+    ("🏔\u{FE0F}", "snow-capped mountain", &[], &["mountain_snow"]),
+    ("⛰\u{FE0F}", "mountain", &[], &["mountain"]),
+    ("🌋", "volcano", &[], &["volcano"]),
+    ("🗻", "mount fuji", &[], &["mount_fuji"]),
+    ("🏕\u{FE0F}", "camping", &[], &["camping"]),
+    ("🏖\u{FE0F}", "beach with umbrella", &[], &["beach_umbrella"]),
+    ("🏜\u{FE0F}", "desert", &[], &["desert"]),
+    ("🏝\u{FE0F}", "desert island", &[], &["desert_island"]),
+    ("🏞\u{FE0F}", "national park", &[], &["national_park"]),
+    ("🏟\u{FE0F}", "stadium", &[], &["stadium"]),
+    (
+        "🏛\u{FE0F}",
+        "classical building",
+        &[],
+        &["classical_building"],
+    ),
+    (
+        "🏗\u{FE0F}",
+        "building construction",
+        &[],
+        &["building_construction"],
+    ),
+    ("🧱", "brick", &[], &["bricks"]),
+    ("🪨", "rock", &[], &["rock"]),
+    ("🪵", "wood", &[], &["wood"]),
+    ("🛖", "hut", &[], &["hut"]),
+    ("🏘\u{FE0F}", "houses", &[], &["houses"]),
+    ("🏚\u{FE0F}", "derelict house", &[], &["derelict_house"]),
+    ("🏠", "house", &["home"], &["house"]),
+    ("🏡", "house with garden", &[], &["house_with_garden"]),
+    ("🏢", "office building", &["building"], &["office"]),
+    ("🏣", "Japanese post office", &[], &["post_office"]),
+    ("🏤", "post office", &[], &["european_post_office"]),
+    ("🏥", "hospital", &[], &["hospital"]),
+    ("🏦", "bank", &[], &["bank"]),
+    ("🏨", "hotel", &[], &["hotel"]),
+    ("🏩", "love hotel", &[], &["love_hotel"]),
+    ("🏪", "convenience store", &[], &["convenience_store"]),
+    ("🏫", "school", &[], &["school"]),
+    ("🏬", "department store", &[], &["department_store"]),
+    ("🏭", "factory", &[], &["factory"]),
+    ("🏯", "Japanese castle", &[], &["japanese_castle"]),
+    ("🏰", "castle", &[], &["european_castle"]),
+    ("💒", "wedding", &[], &["wedding"]),
+    ("🗼", "Tokyo tower", &[], &["tokyo_tower"]),
+    ("🗽", "Statue of Liberty", &[], &["statue_of_liberty"]),
+    ("⛪", "church", &[], &["church"]),
+    ("🕌", "mosque", &[], &["mosque"]),
+    ("🛕", "hindu temple", &[], &["hindu_temple"]),
+    ("🕍", "synagogue", &[], &["synagogue"]),
+    ("⛩\u{FE0F}", "shinto shrine", &[], &["shinto_shrine"]),
+    ("🕋", "kaaba", &[], &["kaaba"]),
+    ("⛲", "fountain", &[], &["fountain"]),
+    ("⛺", "tent", &[], &["tent"]),
+    ("🌁", "foggy", &[], &["foggy"]),
+    ("🌃", "night with stars", &[], &["night_with_stars"]),
+    ("🏙\u{FE0F}", "cityscape", &[], &["cityscape"]),
+    (
+        "🌄",
+        "sunrise over mountains",
+        &[],
+        &["sunrise_over_mountains"],
+    ),
+    ("🌅", "sunrise", &[], &["sunrise"]),
+    ("🌆", "cityscape at dusk", &[], &["city_sunset"]),
+    ("🌇", "sunset", &[], &["city_sunrise"]),
+    ("🌉", "bridge at night", &[], &["bridge_at_night"]),
+    ("♨\u{FE0F}", "hot springs", &[], &["hotsprings"]),
+    ("🎠", "carousel horse", &[], &["carousel_horse"]),
+    ("🛝", "playground slide", &[], &["playground_slide"]),
+    ("🎡", "ferris wheel", &[], &["ferris_wheel"]),
+    ("🎢", "roller coaster", &[], &["roller_coaster"]),
+    ("💈", "barber pole", &[], &["barber"]),
+    ("🎪", "circus tent", &[], &["circus_tent"]),
+    ("🚂", "locomotive", &[], &["steam_locomotive"]),
+    ("🚃", "railway car", &[], &["railway_car"]),
+    ("🚄", "high-speed train", &[], &["bullettrain_side"]),
+    ("🚅", "bullet train", &[], &["bullettrain_front"]),
+    ("🚆", "train", &[], &["train2"]),
+    ("🚇", "metro", &[], &["metro"]),
+    ("🚈", "light rail", &[], &["light_rail"]),
+    ("🚉", "station", &[], &["station"]),
+    ("🚊", "tram", &[], &["tram"]),
+    ("🚝", "monorail", &[], &["monorail"]),
+    ("🚞", "mountain railway", &[], &["mountain_railway"]),
+    ("🚋", "tram car", &[], &["train"]),
+    ("🚌", "bus", &[], &["bus"]),
+    ("🚍", "oncoming bus", &[], &["oncoming_bus"]),
+    ("🚎", "trolleybus", &[], &["trolleybus"]),
+    ("🚐", "minibus", &[], &["minibus"]),
+    ("🚑", "ambulance", &[], &["ambulance"]),
+    ("🚒", "fire engine", &[], &["fire_engine"]),
+    ("🚓", "police car", &[], &["police_car"]),
+    ("🚔", "oncoming police car", &[], &["oncoming_police_car"]),
+    ("🚕", "taxi", &[], &["taxi"]),
+    ("🚖", "oncoming taxi", &[], &["oncoming_taxi"]),
+    ("🚗", "automobile", &["car"], &["car", "red_car"]),
+    ("🚘", "oncoming automobile", &[], &["oncoming_automobile"]),
+    ("🚙", "sport utility vehicle", &[], &["blue_car"]),
+    ("🛻", "pickup truck", &[], &["pickup_truck"]),
+    ("🚚", "delivery truck", &[], &["truck"]),
+    ("🚛", "articulated lorry", &[], &["articulated_lorry"]),
+    ("🚜", "tractor", &[], &["tractor"]),
+    ("🏎\u{FE0F}", "racing car", &[], &["racing_car"]),
+    ("🏍\u{FE0F}", "motorcycle", &[], &["motorcycle"]),
+    ("🛵", "motor scooter", &[], &["motor_scooter"]),
+    ("🦽", "manual wheelchair", &[], &["manual_wheelchair"]),
+    ("🦼", "motorized wheelchair", &[], &["motorized_wheelchair"]),
+    ("🛺", "auto rickshaw", &[], &["auto_rickshaw"]),
+    ("🚲", "bicycle", &["bike"], &["bike"]),
+    ("🛴", "kick scooter", &[], &["kick_scooter"]),
+    ("🛹", "skateboard", &[], &["skateboard"]),
+    ("🛼", "roller skate", &[], &["roller_skate"]),
+    ("🚏", "bus stop", &[], &["busstop"]),
+    ("🛣\u{FE0F}", "motorway", &[], &["motorway"]),
+    ("🛤\u{FE0F}", "railway track", &[], &["railway_track"]),
+    ("🛢\u{FE0F}", "oil drum", &[], &["oil_drum"]),
+    ("⛽", "fuel pump", &[], &["fuelpump"]),
+    ("🛞", "wheel", &[], &["wheel"]),
+    ("🚨", "police car light", &[], &["rotating_light"]),
+    ("🚥", "horizontal traffic light", &[], &["traffic_light"]),
+    (
+        "🚦",
+        "vertical traffic light",
+        &[],
+        &["vertical_traffic_light"],
+    ),
+    ("🛑", "stop sign", &[], &["stop_sign"]),
+    ("🚧", "construction", &[], &["construction"]),
+    ("⚓", "anchor", &[], &["anchor"]),
+    ("🛟", "ring buoy", &[], &["ring_buoy"]),
+    ("⛵", "sailboat", &[], &["boat", "sailboat"]),
+    ("🛶", "canoe", &[], &["canoe"]),
+    ("🚤", "speedboat", &[], &["speedboat"]),
+    ("🛳\u{FE0F}", "passenger ship", &[], &["passenger_ship"]),
+    ("⛴\u{FE0F}", "ferry", &[], &["ferry"]),
+    ("🛥\u{FE0F}", "motor boat", &[], &["motor_boat"]),
+    ("🚢", "ship", &[], &["ship"]),
+    ("✈\u{FE0F}", "airplane", &["aeroplane"], &["airplane"]),
+    ("🛩\u{FE0F}", "small airplane", &[], &["small_airplane"]),
+    ("🛫", "airplane departure", &[], &["flight_departure"]),
+    ("🛬", "airplane arrival", &[], &["flight_arrival"]),
+    ("🪂", "parachute", &[], &["parachute"]),
+    ("💺", "seat", &[], &["seat"]),
+    ("🚁", "helicopter", &[], &["helicopter"]),
+    ("🚟", "suspension railway", &[], &["suspension_railway"]),
+    ("🚠", "mountain cableway", &[], &["mountain_cableway"]),
+    ("🚡", "aerial tramway", &[], &["aerial_tramway"]),
+    ("🛰\u{FE0F}", "satellite", &[], &["artificial_satellite"]),
+    ("🚀", "rocket", &["space"], &["rocket"]),
+    ("🛸", "flying saucer", &[], &["flying_saucer"]),
+    ("🛎\u{FE0F}", "bellhop bell", &[], &["bellhop_bell"]),
+    ("🧳", "luggage", &[], &["luggage"]),
+    ("⌛", "hourglass done", &["sand", "timer"], &["hourglass"]),
+    ("⏳", "hourglass not done", &[], &["hourglass_flowing_sand"]),
+    ("⌚", "watch", &[], &["watch"]),
+    ("⏰", "alarm clock", &["alarm", "clock"], &["alarm_clock"]),
+    ("⏱\u{FE0F}", "stopwatch", &[], &["stopwatch"]),
+    ("⏲\u{FE0F}", "timer clock", &[], &["timer_clock"]),
+    (
+        "🕰\u{FE0F}",
+        "mantelpiece clock",
+        &[],
+        &["mantelpiece_clock"],
+    ),
+    ("🕛", "twelve o’clock", &[], &["clock12"]),
+    ("🕧", "twelve-thirty", &[], &["clock1230"]),
+    ("🕐", "one o’clock", &[], &["clock1"]),
+    ("🕜", "one-thirty", &[], &["clock130"]),
+    ("🕑", "two o’clock", &[], &["clock2"]),
+    ("🕝", "two-thirty", &[], &["clock230"]),
+    ("🕒", "three o’clock", &[], &["clock3"]),
+    ("🕞", "three-thirty", &[], &["clock330"]),
+    ("🕓", "four o’clock", &[], &["clock4"]),
+    ("🕟", "four-thirty", &[], &["clock430"]),
+    ("🕔", "five o’clock", &[], &["clock5"]),
+    ("🕠", "five-thirty", &[], &["clock530"]),
+    ("🕕", "six o’clock", &[], &["clock6"]),
+    ("🕡", "six-thirty", &[], &["clock630"]),
+    ("🕖", "seven o’clock", &[], &["clock7"]),
+    ("🕢", "seven-thirty", &[], &["clock730"]),
+    ("🕗", "eight o’clock", &[], &["clock8"]),
+    ("🕣", "eight-thirty", &[], &["clock830"]),
+    ("🕘", "nine o’clock", &[], &["clock9"]),
+    ("🕤", "nine-thirty", &[], &["clock930"]),
+    ("🕙", "ten o’clock", &[], &["clock10"]),
+    ("🕥", "ten-thirty", &[], &["clock1030"]),
+    ("🕚", "eleven o’clock", &[], &["clock11"]),
+    ("🕦", "eleven-thirty", &[], &["clock1130"]),
+    ("🌑", "new moon", &[], &["new_moon"]),
+    ("🌒", "waxing crescent moon", &[], &["waxing_crescent_moon"]),
+    ("🌓", "first quarter moon", &[], &["first_quarter_moon"]),
+    (
+        "🌔",
+        "waxing gibbous moon",
+        &[],
+        &["moon", "waxing_gibbous_moon"],
+    ),
+    ("🌕", "full moon", &[], &["full_moon"]),
+    ("🌖", "waning gibbous moon", &[], &["waning_gibbous_moon"]),
+    ("🌗", "last quarter moon", &[], &["last_quarter_moon"]),
+    ("🌘", "waning crescent moon", &[], &["waning_crescent_moon"]),
+    (
+        "🌙",
+        "crescent moon",
+        &["crescent", "moon"],
+        &["crescent_moon"],
+    ),
+    ("🌚", "new moon face", &[], &["new_moon_with_face"]),
+    (
+        "🌛",
+        "first quarter moon face",
+        &[],
+        &["first_quarter_moon_with_face"],
+    ),
+    (
+        "🌜",
+        "last quarter moon face",
+        &[],
+        &["last_quarter_moon_with_face"],
+    ),
+    ("🌡\u{FE0F}", "thermometer", &[], &["thermometer"]),
+    ("☀\u{FE0F}", "sun", &["bright", "rays", "sunny"], &["sunny"]),
+    ("🌝", "full moon face", &[], &["full_moon_with_face"]),
+    ("🌞", "sun with face", &[], &["sun_with_face"]),
+    ("🪐", "ringed planet", &[], &["ringed_planet"]),
+    ("⭐", "star", &["star"], &["star"]),
+    (
+        "🌟",
+        "glowing star",
+        &["glittery", "glow", "shining", "sparkle", "star"],
+        &["star2"],
+    ),
+    ("🌠", "shooting star", &[], &["stars"]),
+    ("🌌", "milky way", &[], &["milky_way"]),
+    ("☁\u{FE0F}", "cloud", &["weather"], &["cloud"]),
+    (
+        "⛅",
+        "sun behind cloud",
+        &["cloud", "sun"],
+        &["partly_sunny"],
+    ),
+    (
+        "⛈\u{FE0F}",
+        "cloud with lightning and rain",
+        &[],
+        &["cloud_with_lightning_and_rain"],
+    ),
+    (
+        "🌤\u{FE0F}",
+        "sun behind small cloud",
+        &[],
+        &["sun_behind_small_cloud"],
+    ),
+    (
+        "🌥\u{FE0F}",
+        "sun behind large cloud",
+        &[],
+        &["sun_behind_large_cloud"],
+    ),
+    (
+        "🌦\u{FE0F}",
+        "sun behind rain cloud",
+        &[],
+        &["sun_behind_rain_cloud"],
+    ),
+    (
+        "🌧\u{FE0F}",
+        "cloud with rain",
+        &["cloud", "rain"],
+        &["cloud_with_rain"],
+    ),
+    ("🌨\u{FE0F}", "cloud with snow", &[], &["cloud_with_snow"]),
+    (
+        "🌩\u{FE0F}",
+        "cloud with lightning",
+        &[],
+        &["cloud_with_lightning"],
+    ),
+    ("🌪\u{FE0F}", "tornado", &[], &["tornado"]),
+    ("🌫\u{FE0F}", "fog", &[], &["fog"]),
+    ("🌬\u{FE0F}", "wind face", &[], &["wind_face"]),
+    ("🌀", "cyclone", &[], &["cyclone"]),
+    ("🌈", "rainbow", &["rain"], &["rainbow"]),
+    ("🌂", "closed umbrella", &[], &["closed_umbrella"]),
+    ("☂\u{FE0F}", "umbrella", &[], &["open_umbrella"]),
+    ("☔", "umbrella with rain drops", &[], &["umbrella"]),
+    (
+        "⛱\u{FE0F}",
+        "umbrella on ground",
+        &[],
+        &["parasol_on_ground"],
+    ),
+    (
+        "⚡",
+        "high voltage",
+        &["danger", "electric", "lightning", "voltage", "zap"],
+        &["zap"],
+    ),
+    ("❄\u{FE0F}", "snowflake", &["cold", "snow"], &["snowflake"]),
+    ("☃\u{FE0F}", "snowman", &[], &["snowman_with_snow"]),
+    (
+        "⛄",
+        "snowman without snow",
+        &["cold", "snow", "snowman"],
+        &["snowman"],
+    ),
+    ("☄\u{FE0F}", "comet", &[], &["comet"]),
+    ("🔥", "fire", &["flame", "tool"], &["fire"]),
+    (
+        "💧",
+        "droplet",
+        &["cold", "comic", "drop", "sweat"],
+        &["droplet"],
+    ),
+    ("🌊", "water wave", &["ocean", "water", "wave"], &["ocean"]),
+    (
+        "🎃",
+        "jack-o-lantern",
+        &["celebration", "halloween", "jack", "lantern"],
+        &["jack_o_lantern"],
+    ),
+    (
+        "🎄",
+        "Christmas tree",
+        &["celebration", "Christmas", "tree"],
+        &["christmas_tree"],
+    ),
+    ("🎆", "fireworks", &[], &["fireworks"]),
+    ("🎇", "sparkler", &[], &["sparkler"]),
+    ("🧨", "firecracker", &[], &["firecracker"]),
+    ("✨", "sparkles", &[], &["sparkles"]),
+    ("🎈", "balloon", &["celebration"], &["balloon"]),
+    (
+        "🎉",
+        "party popper",
+        &["celebration", "party", "popper", "tada"],
+        &["tada"],
+    ),
+    (
+        "🎊",
+        "confetti ball",
+        &["ball", "celebration", "confetti"],
+        &["confetti_ball"],
+    ),
+    ("🎋", "tanabata tree", &[], &["tanabata_tree"]),
+    ("🎍", "pine decoration", &[], &["bamboo"]),
+    ("🎎", "Japanese dolls", &[], &["dolls"]),
+    ("🎏", "carp streamer", &[], &["flags"]),
+    ("🎐", "wind chime", &[], &["wind_chime"]),
+    ("🎑", "moon viewing ceremony", &[], &["rice_scene"]),
+    ("🧧", "red envelope", &[], &["red_envelope"]),
+    ("🎀", "ribbon", &[], &["ribbon"]),
+    (
+        "🎁",
+        "wrapped gift",
+        &["box", "gift", "present", "wrapped"],
+        &["gift"],
+    ),
+    ("🎗\u{FE0F}", "reminder ribbon", &[], &["reminder_ribbon"]),
+    ("🎟\u{FE0F}", "admission tickets", &[], &["tickets"]),
+    ("🎫", "ticket", &[], &["ticket"]),
+    ("🎖\u{FE0F}", "military medal", &[], &["medal_military"]),
+    ("🏆", "trophy", &["prize"], &["trophy"]),
+    ("🏅", "sports medal", &[], &["medal_sports"]),
+    (
+        "🥇",
+        "1st place medal",
+        &["first", "gold", "medal"],
+        &["1st_place_medal"],
+    ),
+    ("🥈", "2nd place medal", &[], &["2nd_place_medal"]),
+    ("🥉", "3rd place medal", &[], &["3rd_place_medal"]),
+    (
+        "⚽",
+        "soccer ball",
+        &["ball", "football", "soccer"],
+        &["soccer"],
+    ),
+    ("⚾", "baseball", &[], &["baseball"]),
+    ("🥎", "softball", &[], &["softball"]),
+    ("🏀", "basketball", &["ball", "hoop"], &["basketball"]),
+    ("🏐", "volleyball", &[], &["volleyball"]),
+    ("🏈", "american football", &[], &["football"]),
+    ("🏉", "rugby football", &[], &["rugby_football"]),
+    ("🎾", "tennis", &[], &["tennis"]),
+    ("🥏", "flying disc", &[], &["flying_disc"]),
+    ("🎳", "bowling", &[], &["bowling"]),
+    ("🏏", "cricket game", &[], &["cricket_game"]),
+    ("🏑", "field hockey", &[], &["field_hockey"]),
+    ("🏒", "ice hockey", &[], &["ice_hockey"]),
+    ("🥍", "lacrosse", &[], &["lacrosse"]),
+    ("🏓", "ping pong", &[], &["ping_pong"]),
+    ("🏸", "badminton", &[], &["badminton"]),
+    ("🥊", "boxing glove", &[], &["boxing_glove"]),
+    ("🥋", "martial arts uniform", &[], &["martial_arts_uniform"]),
+    ("🥅", "goal net", &[], &["goal_net"]),
+    ("⛳", "flag in hole", &[], &["golf"]),
+    ("⛸\u{FE0F}", "ice skate", &[], &["ice_skate"]),
+    ("🎣", "fishing pole", &[], &["fishing_pole_and_fish"]),
+    ("🤿", "diving mask", &[], &["diving_mask"]),
+    ("🎽", "running shirt", &[], &["running_shirt_with_sash"]),
+    ("🎿", "skis", &[], &["ski"]),
+    ("🛷", "sled", &[], &["sled"]),
+    ("🥌", "curling stone", &[], &["curling_stone"]),
+    ("🎯", "bullseye", &[], &["dart"]),
+    ("🪀", "yo-yo", &[], &["yo_yo"]),
+    ("🪁", "kite", &[], &["kite"]),
+    ("🔫", "water pistol", &[], &["gun"]),
+    ("🎱", "pool 8 ball", &[], &["8ball"]),
+    ("🔮", "crystal ball", &[], &["crystal_ball"]),
+    ("🪄", "magic wand", &[], &["magic_wand"]),
+    ("🎮", "video game", &["controller", "game"], &["video_game"]),
+    ("🕹\u{FE0F}", "joystick", &[], &["joystick"]),
+    ("🎰", "slot machine", &[], &["slot_machine"]),
+    ("🎲", "game die", &[], &["game_die"]),
+    ("🧩", "puzzle piece", &[], &["jigsaw"]),
+    ("🧸", "teddy bear", &[], &["teddy_bear"]),
+    ("🪅", "piñata", &[], &["pinata"]),
+    ("🪩", "mirror ball", &[], &["mirror_ball"]),
+    ("🪆", "nesting dolls", &[], &["nesting_dolls"]),
+    ("♠\u{FE0F}", "spade suit", &[], &["spades"]),
+    ("♥\u{FE0F}", "heart suit", &[], &["hearts"]),
+    ("♦\u{FE0F}", "diamond suit", &[], &["diamonds"]),
+    ("♣\u{FE0F}", "club suit", &[], &["clubs"]),
+    ("♟\u{FE0F}", "chess pawn", &[], &["chess_pawn"]),
+    ("🃏", "joker", &[], &["black_joker"]),
+    ("🀄", "mahjong red dragon", &[], &["mahjong"]),
+    ("🎴", "flower playing cards", &[], &["flower_playing_cards"]),
+    ("🎭", "performing arts", &[], &["performing_arts"]),
+    ("🖼\u{FE0F}", "framed picture", &[], &["framed_picture"]),
+    ("🎨", "artist palette", &[], &["art"]),
+    ("🧵", "thread", &[], &["thread"]),
+    ("🪡", "sewing needle", &[], &["sewing_needle"]),
+    ("🧶", "yarn", &[], &["yarn"]),
+    ("🪢", "knot", &[], &["knot"]),
+    ("👓", "glasses", &[], &["eyeglasses"]),
+    ("🕶\u{FE0F}", "sunglasses", &[], &["dark_sunglasses"]),
+    ("🥽", "goggles", &[], &["goggles"]),
+    ("🥼", "lab coat", &[], &["lab_coat"]),
+    ("🦺", "safety vest", &[], &["safety_vest"]),
+    ("👔", "necktie", &[], &["necktie"]),
+    ("👕", "t-shirt", &[], &["shirt", "tshirt"]),
+    ("👖", "jeans", &[], &["jeans"]),
+    ("🧣", "scarf", &[], &["scarf"]),
+    ("🧤", "gloves", &[], &["gloves"]),
+    ("🧥", "coat", &[], &["coat"]),
+    ("🧦", "socks", &[], &["socks"]),
+    ("👗", "dress", &[], &["dress"]),
+    ("👘", "kimono", &[], &["kimono"]),
+    ("🥻", "sari", &[], &["sari"]),
+    ("🩱", "one-piece swimsuit", &[], &["one_piece_swimsuit"]),
+    ("🩲", "briefs", &[], &["swim_brief"]),
+    ("🩳", "shorts", &[], &["shorts"]),
+    ("👙", "bikini", &[], &["bikini"]),
+    ("👚", "woman’s clothes", &[], &["womans_clothes"]),
+    ("🪭", "folding hand fan", &[], &["folding_hand_fan"]),
+    ("👛", "purse", &[], &["purse"]),
+    ("👜", "handbag", &[], &["handbag"]),
+    ("👝", "clutch bag", &[], &["pouch"]),
+    ("🛍\u{FE0F}", "shopping bags", &[], &["shopping"]),
+    ("🎒", "backpack", &[], &["school_satchel"]),
+    ("🩴", "thong sandal", &[], &["thong_sandal"]),
+    ("👞", "man’s shoe", &[], &["mans_shoe", "shoe"]),
+    ("👟", "running shoe", &[], &["athletic_shoe"]),
+    ("🥾", "hiking boot", &[], &["hiking_boot"]),
+    ("🥿", "flat shoe", &[], &["flat_shoe"]),
+    ("👠", "high-heeled shoe", &[], &["high_heel"]),
+    ("👡", "woman’s sandal", &[], &["sandal"]),
+    ("🩰", "ballet shoes", &[], &["ballet_shoes"]),
+    ("👢", "woman’s boot", &[], &["boot"]),
+    ("🪮", "hair pick", &[], &["hair_pick"]),
+    ("👑", "crown", &[], &["crown"]),
+    ("👒", "woman’s hat", &[], &["womans_hat"]),
+    ("🎩", "top hat", &[], &["tophat"]),
+    ("🎓", "graduation cap", &[], &["mortar_board"]),
+    ("🧢", "billed cap", &[], &["billed_cap"]),
+    ("🪖", "military helmet", &[], &["military_helmet"]),
+    (
+        "⛑\u{FE0F}",
+        "rescue worker’s helmet",
+        &[],
+        &["rescue_worker_helmet"],
+    ),
+    ("📿", "prayer beads", &[], &["prayer_beads"]),
+    ("💄", "lipstick", &[], &["lipstick"]),
+    ("💍", "ring", &[], &["ring"]),
+    ("💎", "gem stone", &[], &["gem"]),
+    ("🔇", "muted speaker", &[], &["mute"]),
+    ("🔈", "speaker low volume", &[], &["speaker"]),
+    ("🔉", "speaker medium volume", &[], &["sound"]),
+    ("🔊", "speaker high volume", &[], &["loud_sound"]),
+    ("📢", "loudspeaker", &[], &["loudspeaker"]),
+    ("📣", "megaphone", &[], &["mega"]),
+    ("📯", "postal horn", &[], &["postal_horn"]),
+    ("🔔", "bell", &[], &["bell"]),
+    ("🔕", "bell with slash", &[], &["no_bell"]),
+    ("🎼", "musical score", &[], &["musical_score"]),
+    ("🎵", "musical note", &["music", "note"], &["musical_note"]),
+    ("🎶", "musical notes", &[], &["notes"]),
+    (
+        "🎙\u{FE0F}",
+        "studio microphone",
+        &[],
+        &["studio_microphone"],
+    ),
+    ("🎚\u{FE0F}", "level slider", &[], &["level_slider"]),
+    ("🎛\u{FE0F}", "control knobs", &[], &["control_knobs"]),
+    ("🎤", "microphone", &[], &["microphone"]),
+    ("🎧", "headphone", &["earbud"], &["headphones"]),
+    ("📻", "radio", &[], &["radio"]),
+    ("🎷", "saxophone", &[], &["saxophone"]),
+    ("🪗", "accordion", &[], &["accordion"]),
+    ("🎸", "guitar", &["instrument", "music"], &["guitar"]),
+    ("🎹", "musical keyboard", &[], &["musical_keyboard"]),
+    ("🎺", "trumpet", &[], &["trumpet"]),
+    ("🎻", "violin", &[], &["violin"]),
+    ("🪕", "banjo", &[], &["banjo"]),
+    ("🥁", "drum", &[], &["drum"]),
+    ("🪘", "long drum", &[], &["long_drum"]),
+    ("🪇", "maracas", &[], &["maracas"]),
+    ("🪈", "flute", &[], &["flute"]),
+    ("🪉", "harp", &[], &[]),
+    (
+        "📱",
+        "mobile phone",
+        &["cell", "mobile", "phone", "telephone"],
+        &["iphone"],
+    ),
+    ("📲", "mobile phone with arrow", &[], &["calling"]),
+    ("☎\u{FE0F}", "telephone", &[], &["phone", "telephone"]),
+    ("📞", "telephone receiver", &[], &["telephone_receiver"]),
+    ("📟", "pager", &[], &["pager"]),
+    ("📠", "fax machine", &[], &["fax"]),
+    ("🔋", "battery", &[], &["battery"]),
+    ("🪫", "low battery", &[], &["low_battery"]),
+    ("🔌", "electric plug", &[], &["electric_plug"]),
+    (
+        "💻",
+        "laptop",
+        &["computer", "pc", "personal"],
+        &["computer"],
+    ),
+    ("🖥\u{FE0F}", "desktop computer", &[], &["desktop_computer"]),
+    ("🖨\u{FE0F}", "printer", &[], &["printer"]),
+    ("⌨\u{FE0F}", "keyboard", &["computer"], &["keyboard"]),
+    ("🖱\u{FE0F}", "computer mouse", &[], &["computer_mouse"]),
+    ("🖲\u{FE0F}", "trackball", &[], &["trackball"]),
+    ("💽", "computer disk", &[], &["minidisc"]),
+    ("💾", "floppy disk", &[], &["floppy_disk"]),
+    ("💿", "optical disk", &[], &["cd"]),
+    ("📀", "dvd", &[], &["dvd"]),
+    ("🧮", "abacus", &[], &["abacus"]),
+    ("🎥", "movie camera", &[], &["movie_camera"]),
+    ("🎞\u{FE0F}", "film frames", &[], &["film_strip"]),
+    ("📽\u{FE0F}", "film projector", &[], &["film_projector"]),
+    ("🎬", "clapper board", &[], &["clapper"]),
+    ("📺", "television", &[], &["tv"]),
+    ("📷", "camera", &["video"], &["camera"]),
+    ("📸", "camera with flash", &[], &["camera_flash"]),
+    ("📹", "video camera", &[], &["video_camera"]),
+    ("📼", "videocassette", &[], &["vhs"]),
+    (
+        "🔍",
+        "magnifying glass tilted left",
+        &["glass", "magnifying", "search", "tool"],
+        &["mag"],
+    ),
+    ("🔎", "magnifying glass tilted right", &[], &["mag_right"]),
+    ("🕯\u{FE0F}", "candle", &[], &["candle"]),
+    (
+        "💡",
+        "light bulb",
+        &["bulb", "comic", "electric", "idea", "light"],
+        &["bulb"],
+    ),
+    ("🔦", "flashlight", &[], &["flashlight"]),
+    (
+        "🏮",
+        "red paper lantern",
+        &[],
+        &["izakaya_lantern", "lantern"],
+    ),
+    ("🪔", "diya lamp", &[], &["diya_lamp"]),
+    (
+        "📔",
+        "notebook with decorative cover",
+        &[],
+        &["notebook_with_decorative_cover"],
+    ),
+    ("📕", "closed book", &[], &["closed_book"]),
+    ("📖", "open book", &[], &["book", "open_book"]),
+    ("📗", "green book", &[], &["green_book"]),
+    ("📘", "blue book", &[], &["blue_book"]),
+    ("📙", "orange book", &[], &["orange_book"]),
+    ("📚", "books", &["book"], &["books"]),
+    ("📓", "notebook", &[], &["notebook"]),
+    ("📒", "ledger", &[], &["ledger"]),
+    ("📃", "page with curl", &[], &["page_with_curl"]),
+    ("📜", "scroll", &[], &["scroll"]),
+    ("📄", "page facing up", &[], &["page_facing_up"]),
+    ("📰", "newspaper", &[], &["newspaper"]),
+    ("🗞\u{FE0F}", "rolled-up newspaper", &[], &["newspaper_roll"]),
+    ("📑", "bookmark tabs", &[], &["bookmark_tabs"]),
+    ("🔖", "bookmark", &[], &["bookmark"]),
+    ("🏷\u{FE0F}", "label", &[], &["label"]),
+    (
+        "💰",
+        "money bag",
+        &["bag", "dollar", "money", "moneybag"],
+        &["moneybag"],
+    ),
+    ("🪙", "coin", &[], &["coin"]),
+    ("💴", "yen banknote", &[], &["yen"]),
+    ("💵", "dollar banknote", &[], &["dollar"]),
+    ("💶", "euro banknote", &[], &["euro"]),
+    ("💷", "pound banknote", &[], &["pound"]),
+    (
+        "💸",
+        "money with wings",
+        &["bank", "banknote", "bill", "fly", "money", "wings"],
+        &["money_with_wings"],
+    ),
+    ("💳", "credit card", &[], &["credit_card"]),
+    ("🧾", "receipt", &[], &["receipt"]),
+    ("💹", "chart increasing with yen", &[], &["chart"]),
+    ("✉\u{FE0F}", "envelope", &[], &["envelope"]),
+    ("📧", "e-mail", &[], &["email", "e-mail"]),
+    ("📨", "incoming envelope", &[], &["incoming_envelope"]),
+    ("📩", "envelope with arrow", &[], &["envelope_with_arrow"]),
+    ("📤", "outbox tray", &[], &["outbox_tray"]),
+    ("📥", "inbox tray", &[], &["inbox_tray"]),
+    ("📦", "package", &[], &["package"]),
+    ("📫", "closed mailbox with raised flag", &[], &["mailbox"]),
+    (
+        "📪",
+        "closed mailbox with lowered flag",
+        &[],
+        &["mailbox_closed"],
+    ),
+    (
+        "📬",
+        "open mailbox with raised flag",
+        &[],
+        &["mailbox_with_mail"],
+    ),
+    (
+        "📭",
+        "open mailbox with lowered flag",
+        &[],
+        &["mailbox_with_no_mail"],
+    ),
+    ("📮", "postbox", &[], &["postbox"]),
+    ("🗳\u{FE0F}", "ballot box with ballot", &[], &["ballot_box"]),
+    ("✏\u{FE0F}", "pencil", &["pencil"], &["pencil2"]),
+    ("✒\u{FE0F}", "black nib", &[], &["black_nib"]),
+    ("🖋\u{FE0F}", "fountain pen", &[], &["fountain_pen"]),
+    ("🖊\u{FE0F}", "pen", &[], &["pen"]),
+    ("🖌\u{FE0F}", "paintbrush", &[], &["paintbrush"]),
+    ("🖍\u{FE0F}", "crayon", &[], &["crayon"]),
+    ("📝", "memo", &["pencil"], &["memo", "pencil"]),
+    ("💼", "briefcase", &[], &["briefcase"]),
+    ("📁", "file folder", &[], &["file_folder"]),
+    ("📂", "open file folder", &[], &["open_file_folder"]),
+    (
+        "🗂\u{FE0F}",
+        "card index dividers",
+        &[],
+        &["card_index_dividers"],
+    ),
+    ("📅", "calendar", &["date"], &["date"]),
+    ("📆", "tear-off calendar", &[], &["calendar"]),
+    ("🗒\u{FE0F}", "spiral notepad", &[], &["spiral_notepad"]),
+    ("🗓\u{FE0F}", "spiral calendar", &[], &["spiral_calendar"]),
+    ("📇", "card index", &[], &["card_index"]),
+    (
+        "📈",
+        "chart increasing",
+        &["chart", "graph", "growth", "trend", "upward"],
+        &["chart_with_upwards_trend"],
+    ),
+    (
+        "📉",
+        "chart decreasing",
+        &["chart", "down", "graph", "trend"],
+        &["chart_with_downwards_trend"],
+    ),
+    ("📊", "bar chart", &[], &["bar_chart"]),
+    ("📋", "clipboard", &[], &["clipboard"]),
+    ("📌", "pushpin", &["pin"], &["pushpin"]),
+    ("📍", "round pushpin", &[], &["round_pushpin"]),
+    ("📎", "paperclip", &["paperclip"], &["paperclip"]),
+    ("🖇\u{FE0F}", "linked paperclips", &[], &["paperclips"]),
+    ("📏", "straight ruler", &[], &["straight_ruler"]),
+    ("📐", "triangular ruler", &[], &["triangular_ruler"]),
+    ("✂\u{FE0F}", "scissors", &[], &["scissors"]),
+    ("🗃\u{FE0F}", "card file box", &[], &["card_file_box"]),
+    ("🗄\u{FE0F}", "file cabinet", &[], &["file_cabinet"]),
+    (
+        "🗑\u{FE0F}",
+        "wastebasket",
+        &["wastebasket"],
+        &["wastebasket"],
+    ),
+    ("🔒", "locked", &["closed"], &["lock"]),
+    ("🔓", "unlocked", &[], &["unlock"]),
+    ("🔏", "locked with pen", &[], &["lock_with_ink_pen"]),
+    ("🔐", "locked with key", &[], &["closed_lock_with_key"]),
+    ("🔑", "key", &["lock", "password"], &["key"]),
+    ("🗝\u{FE0F}", "old key", &[], &["old_key"]),
+    ("🔨", "hammer", &[], &["hammer"]),
+    ("🪓", "axe", &[], &["axe"]),
+    ("⛏\u{FE0F}", "pick", &[], &["pick"]),
+    ("⚒\u{FE0F}", "hammer and pick", &[], &["hammer_and_pick"]),
+    (
+        "🛠\u{FE0F}",
+        "hammer and wrench",
+        &[],
+        &["hammer_and_wrench"],
+    ),
+    ("🗡\u{FE0F}", "dagger", &[], &["dagger"]),
+    ("⚔\u{FE0F}", "crossed swords", &[], &["crossed_swords"]),
+    ("💣", "bomb", &[], &["bomb"]),
+    ("🪃", "boomerang", &[], &["boomerang"]),
+    ("🏹", "bow and arrow", &[], &["bow_and_arrow"]),
+    ("🛡\u{FE0F}", "shield", &[], &["shield"]),
+    ("🪚", "carpentry saw", &[], &["carpentry_saw"]),
+    ("🔧", "wrench", &[], &["wrench"]),
+    ("🪛", "screwdriver", &[], &["screwdriver"]),
+    ("🔩", "nut and bolt", &[], &["nut_and_bolt"]),
+    ("⚙\u{FE0F}", "gear", &[], &["gear"]),
+    ("🗜\u{FE0F}", "clamp", &[], &["clamp"]),
+    ("⚖\u{FE0F}", "balance scale", &[], &["balance_scale"]),
+    ("🦯", "white cane", &[], &["probing_cane"]),
+    ("🔗", "link", &[], &["link"]),
+    ("⛓\u{FE0F}\u{200D}💥", "broken chain", &[], &[]),
+    ("⛓\u{FE0F}", "chains", &[], &["chains"]),
+    ("🪝", "hook", &[], &["hook"]),
+    ("🧰", "toolbox", &[], &["toolbox"]),
+    ("🧲", "magnet", &[], &["magnet"]),
+    ("🪜", "ladder", &[], &["ladder"]),
+    ("🪏", "shovel", &[], &[]),
+    ("⚗\u{FE0F}", "alembic", &[], &["alembic"]),
+    ("🧪", "test tube", &[], &["test_tube"]),
+    ("🧫", "petri dish", &[], &["petri_dish"]),
+    ("🧬", "dna", &[], &["dna"]),
+    ("🔬", "microscope", &[], &["microscope"]),
+    ("🔭", "telescope", &[], &["telescope"]),
+    ("📡", "satellite antenna", &[], &["satellite"]),
+    ("💉", "syringe", &[], &["syringe"]),
+    ("🩸", "drop of blood", &[], &["drop_of_blood"]),
+    ("💊", "pill", &[], &["pill"]),
+    ("🩹", "adhesive bandage", &[], &["adhesive_bandage"]),
+    ("🩼", "crutch", &[], &["crutch"]),
+    ("🩺", "stethoscope", &[], &["stethoscope"]),
+    ("🩻", "x-ray", &[], &["x_ray"]),
+    ("🚪", "door", &[], &["door"]),
+    ("🛗", "elevator", &[], &["elevator"]),
+    ("🪞", "mirror", &[], &["mirror"]),
+    ("🪟", "window", &[], &["window"]),
+    ("🛏\u{FE0F}", "bed", &[], &["bed"]),
+    ("🛋\u{FE0F}", "couch and lamp", &[], &["couch_and_lamp"]),
+    ("🪑", "chair", &[], &["chair"]),
+    ("🚽", "toilet", &[], &["toilet"]),
+    ("🪠", "plunger", &[], &["plunger"]),
+    ("🚿", "shower", &[], &["shower"]),
+    ("🛁", "bathtub", &[], &["bathtub"]),
+    ("🪤", "mouse trap", &[], &["mouse_trap"]),
+    ("🪒", "razor", &[], &["razor"]),
+    ("🧴", "lotion bottle", &[], &["lotion_bottle"]),
+    ("🧷", "safety pin", &[], &["safety_pin"]),
+    ("🧹", "broom", &[], &["broom"]),
+    ("🧺", "basket", &[], &["basket"]),
+    ("🧻", "roll of paper", &[], &["roll_of_paper"]),
+    ("🪣", "bucket", &[], &["bucket"]),
+    ("🧼", "soap", &[], &["soap"]),
+    ("🫧", "bubbles", &[], &["bubbles"]),
+    ("🪥", "toothbrush", &[], &["toothbrush"]),
+    ("🧽", "sponge", &[], &["sponge"]),
+    ("🧯", "fire extinguisher", &[], &["fire_extinguisher"]),
+    ("🛒", "shopping cart", &[], &["shopping_cart"]),
+    ("🚬", "cigarette", &[], &["smoking"]),
+    ("⚰\u{FE0F}", "coffin", &[], &["coffin"]),
+    ("🪦", "headstone", &[], &["headstone"]),
+    ("⚱\u{FE0F}", "funeral urn", &[], &["funeral_urn"]),
+    ("🧿", "nazar amulet", &[], &["nazar_amulet"]),
+    ("🪬", "hamsa", &[], &["hamsa"]),
+    ("🗿", "moai", &[], &["moyai"]),
+    ("🪧", "placard", &[], &["placard"]),
+    ("🪪", "identification card", &[], &["identification_card"]),
+    ("🏧", "ATM sign", &[], &["atm"]),
+    (
+        "🚮",
+        "litter in bin sign",
+        &[],
+        &["put_litter_in_its_place"],
+    ),
+    ("🚰", "potable water", &[], &["potable_water"]),
+    ("♿", "wheelchair symbol", &[], &["wheelchair"]),
+    ("🚹", "men’s room", &[], &["mens"]),
+    ("🚺", "women’s room", &[], &["womens"]),
+    ("🚻", "restroom", &[], &["restroom"]),
+    ("🚼", "baby symbol", &[], &["baby_symbol"]),
+    ("🚾", "water closet", &[], &["wc"]),
+    ("🛂", "passport control", &[], &["passport_control"]),
+    ("🛃", "customs", &[], &["customs"]),
+    ("🛄", "baggage claim", &[], &["baggage_claim"]),
+    ("🛅", "left luggage", &[], &["left_luggage"]),
+    ("⚠\u{FE0F}", "warning", &["warning"], &["warning"]),
+    ("🚸", "children crossing", &[], &["children_crossing"]),
+    ("⛔", "no entry", &[], &["no_entry"]),
+    (
+        "🚫",
+        "prohibited",
+        &["entry", "forbidden", "no", "not"],
+        &["no_entry_sign"],
+    ),
+    ("🚳", "no bicycles", &[], &["no_bicycles"]),
+    ("🚭", "no smoking", &[], &["no_smoking"]),
+    ("🚯", "no littering", &[], &["do_not_litter"]),
+    ("🚱", "non-potable water", &[], &["non-potable_water"]),
+    ("🚷", "no pedestrians", &[], &["no_pedestrians"]),
+    ("📵", "no mobile phones", &[], &["no_mobile_phones"]),
+    ("🔞", "no one under eighteen", &[], &["underage"]),
+    ("☢\u{FE0F}", "radioactive", &[], &["radioactive"]),
+    ("☣\u{FE0F}", "biohazard", &[], &["biohazard"]),
+    (
+        "⬆\u{FE0F}",
+        "up arrow",
+        &["arrow", "cardinal", "direction", "north"],
+        &["arrow_up"],
+    ),
+    ("↗\u{FE0F}", "up-right arrow", &[], &["arrow_upper_right"]),
+    (
+        "➡\u{FE0F}",
+        "right arrow",
+        &["arrow", "cardinal", "direction", "east"],
+        &["arrow_right"],
+    ),
+    ("↘\u{FE0F}", "down-right arrow", &[], &["arrow_lower_right"]),
+    (
+        "⬇\u{FE0F}",
+        "down arrow",
+        &["arrow", "cardinal", "direction", "down", "south"],
+        &["arrow_down"],
+    ),
+    ("↙\u{FE0F}", "down-left arrow", &[], &["arrow_lower_left"]),
+    (
+        "⬅\u{FE0F}",
+        "left arrow",
+        &["arrow", "cardinal", "direction", "west"],
+        &["arrow_left"],
+    ),
+    ("↖\u{FE0F}", "up-left arrow", &[], &["arrow_upper_left"]),
+    ("↕\u{FE0F}", "up-down arrow", &[], &["arrow_up_down"]),
+    ("↔\u{FE0F}", "left-right arrow", &[], &["left_right_arrow"]),
+    (
+        "↩\u{FE0F}",
+        "right arrow curving left",
+        &[],
+        &["leftwards_arrow_with_hook"],
+    ),
+    (
+        "↪\u{FE0F}",
+        "left arrow curving right",
+        &[],
+        &["arrow_right_hook"],
+    ),
+    (
+        "⤴\u{FE0F}",
+        "right arrow curving up",
+        &[],
+        &["arrow_heading_up"],
+    ),
+    (
+        "⤵\u{FE0F}",
+        "right arrow curving down",
+        &[],
+        &["arrow_heading_down"],
+    ),
+    (
+        "🔃",
+        "clockwise vertical arrows",
+        &[],
+        &["arrows_clockwise"],
+    ),
+    (
+        "🔄",
+        "counterclockwise arrows button",
+        &["anticlockwise", "arrow", "counterclockwise", "withershins"],
+        &["arrows_counterclockwise"],
+    ),
+    ("🔙", "BACK arrow", &[], &["back"]),
+    ("🔚", "END arrow", &[], &["end"]),
+    ("🔛", "ON! arrow", &[], &["on"]),
+    ("🔜", "SOON arrow", &[], &["soon"]),
+    ("🔝", "TOP arrow", &[], &["top"]),
+    ("🛐", "place of worship", &[], &["place_of_worship"]),
+    ("⚛\u{FE0F}", "atom symbol", &[], &["atom_symbol"]),
+    ("🕉\u{FE0F}", "om", &[], &["om"]),
+    ("✡\u{FE0F}", "star of David", &[], &["star_of_david"]),
+    ("☸\u{FE0F}", "wheel of dharma", &[], &["wheel_of_dharma"]),
+    ("☯\u{FE0F}", "yin yang", &[], &["yin_yang"]),
+    ("✝\u{FE0F}", "latin cross", &[], &["latin_cross"]),
+    ("☦\u{FE0F}", "orthodox cross", &[], &["orthodox_cross"]),
+    (
+        "☪\u{FE0F}",
+        "star and crescent",
+        &[],
+        &["star_and_crescent"],
+    ),
+    ("☮\u{FE0F}", "peace symbol", &[], &["peace_symbol"]),
+    ("🕎", "menorah", &[], &["menorah"]),
+    ("🔯", "dotted six-pointed star", &[], &["six_pointed_star"]),
+    ("🪯", "khanda", &[], &["khanda"]),
+    ("♈", "Aries", &[], &["aries"]),
+    ("♉", "Taurus", &[], &["taurus"]),
+    ("♊", "Gemini", &[], &["gemini"]),
+    ("♋", "Cancer", &[], &["cancer"]),
+    ("♌", "Leo", &[], &["leo"]),
+    ("♍", "Virgo", &[], &["virgo"]),
+    ("♎", "Libra", &[], &["libra"]),
+    ("♏", "Scorpio", &[], &["scorpius"]),
+    ("♐", "Sagittarius", &[], &["sagittarius"]),
+    ("♑", "Capricorn", &[], &["capricorn"]),
+    ("♒", "Aquarius", &[], &["aquarius"]),
+    ("♓", "Pisces", &[], &["pisces"]),
+    ("⛎", "Ophiuchus", &[], &["ophiuchus"]),
+    (
+        "🔀",
+        "shuffle tracks button",
+        &[],
+        &["twisted_rightwards_arrows"],
+    ),
+    ("🔁", "repeat button", &[], &["repeat"]),
+    ("🔂", "repeat single button", &[], &["repeat_one"]),
+    ("▶\u{FE0F}", "play button", &[], &["arrow_forward"]),
+    ("⏩", "fast-forward button", &[], &["fast_forward"]),
+    (
+        "⏭\u{FE0F}",
+        "next track button",
+        &[],
+        &["next_track_button"],
+    ),
+    (
+        "⏯\u{FE0F}",
+        "play or pause button",
+        &[],
+        &["play_or_pause_button"],
+    ),
+    ("◀\u{FE0F}", "reverse button", &[], &["arrow_backward"]),
+    ("⏪", "fast reverse button", &[], &["rewind"]),
+    (
+        "⏮\u{FE0F}",
+        "last track button",
+        &[],
+        &["previous_track_button"],
+    ),
+    ("🔼", "upwards button", &[], &["arrow_up_small"]),
+    ("⏫", "fast up button", &[], &["arrow_double_up"]),
+    ("🔽", "downwards button", &[], &["arrow_down_small"]),
+    ("⏬", "fast down button", &[], &["arrow_double_down"]),
+    ("⏸\u{FE0F}", "pause button", &[], &["pause_button"]),
+    ("⏹\u{FE0F}", "stop button", &[], &["stop_button"]),
+    ("⏺\u{FE0F}", "record button", &[], &["record_button"]),
+    ("⏏\u{FE0F}", "eject button", &[], &["eject_button"]),
+    ("🎦", "cinema", &[], &["cinema"]),
+    ("🔅", "dim button", &[], &["low_brightness"]),
+    ("🔆", "bright button", &[], &["high_brightness"]),
+    ("📶", "antenna bars", &[], &["signal_strength"]),
+    ("🛜", "wireless", &[], &["wireless"]),
+    ("📳", "vibration mode", &[], &["vibration_mode"]),
+    ("📴", "mobile phone off", &[], &["mobile_phone_off"]),
+    ("♀\u{FE0F}", "female sign", &[], &["female_sign"]),
+    ("♂\u{FE0F}", "male sign", &[], &["male_sign"]),
+    (
+        "⚧\u{FE0F}",
+        "transgender symbol",
+        &[],
+        &["transgender_symbol"],
+    ),
+    ("✖\u{FE0F}", "multiply", &[], &["heavy_multiplication_x"]),
+    ("➕", "plus", &[], &["heavy_plus_sign"]),
+    ("➖", "minus", &[], &["heavy_minus_sign"]),
+    ("➗", "divide", &[], &["heavy_division_sign"]),
+    ("🟰", "heavy equals sign", &[], &["heavy_equals_sign"]),
+    ("♾\u{FE0F}", "infinity", &[], &["infinity"]),
+    ("‼\u{FE0F}", "double exclamation mark", &[], &["bangbang"]),
+    (
+        "⁉\u{FE0F}",
+        "exclamation question mark",
+        &[],
+        &["interrobang"],
+    ),
+    (
+        "❓",
+        "red question mark",
+        &["?", "mark", "punctuation", "question"],
+        &["question"],
+    ),
+    ("❔", "white question mark", &[], &["grey_question"]),
+    ("❕", "white exclamation mark", &[], &["grey_exclamation"]),
+    (
+        "❗",
+        "red exclamation mark",
+        &["!", "exclamation", "mark", "punctuation"],
+        &["exclamation", "heavy_exclamation_mark"],
+    ),
+    ("〰\u{FE0F}", "wavy dash", &[], &["wavy_dash"]),
+    ("💱", "currency exchange", &[], &["currency_exchange"]),
+    ("💲", "heavy dollar sign", &[], &["heavy_dollar_sign"]),
+    ("⚕\u{FE0F}", "medical symbol", &[], &["medical_symbol"]),
+    ("♻\u{FE0F}", "recycling symbol", &["recycle"], &["recycle"]),
+    ("⚜\u{FE0F}", "fleur-de-lis", &[], &["fleur_de_lis"]),
+    ("🔱", "trident emblem", &[], &["trident"]),
+    ("📛", "name badge", &[], &["name_badge"]),
+    ("🔰", "Japanese symbol for beginner", &[], &["beginner"]),
+    ("⭕", "hollow red circle", &[], &["o"]),
+    (
+        "✅",
+        "check mark button",
+        &["button", "check", "mark", "✓"],
+        &["white_check_mark"],
+    ),
+    (
+        "☑\u{FE0F}",
+        "check box with check",
+        &[],
+        &["ballot_box_with_check"],
+    ),
+    (
+        "✔\u{FE0F}",
+        "check mark",
+        &["✓", "check", "mark"],
+        &["heavy_check_mark"],
+    ),
+    (
+        "❌",
+        "cross mark",
+        &[
+            "×",
+            "cancel",
+            "cross",
+            "mark",
+            "multiplication",
+            "multiply",
+            "x",
+        ],
+        &["x"],
+    ),
+    (
+        "❎",
+        "cross mark button",
+        &[],
+        &["negative_squared_cross_mark"],
+    ),
+    ("➰", "curly loop", &[], &["curly_loop"]),
+    ("➿", "double curly loop", &[], &["loop"]),
+    (
+        "〽\u{FE0F}",
+        "part alternation mark",
+        &[],
+        &["part_alternation_mark"],
+    ),
+    (
+        "✳\u{FE0F}",
+        "eight-spoked asterisk",
+        &[],
+        &["eight_spoked_asterisk"],
+    ),
+    (
+        "✴\u{FE0F}",
+        "eight-pointed star",
+        &[],
+        &["eight_pointed_black_star"],
+    ),
+    ("❇\u{FE0F}", "sparkle", &[], &["sparkle"]),
+    ("©\u{FE0F}", "copyright", &["C"], &["copyright"]),
+    ("®\u{FE0F}", "registered", &["R"], &["registered"]),
+    (
+        "™\u{FE0F}",
+        "trade mark",
+        &["mark", "TM", "trademark"],
+        &["tm"],
+    ),
+    ("🫟", "splatter", &[], &[]),
+    ("#\u{FE0F}\u{20E3}", "keycap: #", &[], &["hash"]),
+    ("*\u{FE0F}\u{20E3}", "keycap: *", &[], &["asterisk"]),
+    ("0\u{FE0F}\u{20E3}", "keycap: 0", &[], &["zero"]),
+    ("1\u{FE0F}\u{20E3}", "keycap: 1", &[], &["one"]),
+    ("2\u{FE0F}\u{20E3}", "keycap: 2", &[], &["two"]),
+    ("3\u{FE0F}\u{20E3}", "keycap: 3", &[], &["three"]),
+    ("4\u{FE0F}\u{20E3}", "keycap: 4", &[], &["four"]),
+    ("5\u{FE0F}\u{20E3}", "keycap: 5", &[], &["five"]),
+    ("6\u{FE0F}\u{20E3}", "keycap: 6", &[], &["six"]),
+    ("7\u{FE0F}\u{20E3}", "keycap: 7", &[], &["seven"]),
+    ("8\u{FE0F}\u{20E3}", "keycap: 8", &[], &["eight"]),
+    ("9\u{FE0F}\u{20E3}", "keycap: 9", &[], &["nine"]),
+    ("🔟", "keycap: 10", &[], &["keycap_ten"]),
+    ("🔠", "input latin uppercase", &[], &["capital_abcd"]),
+    ("🔡", "input latin lowercase", &[], &["abcd"]),
+    ("🔢", "input numbers", &[], &["1234"]),
+    ("🔣", "input symbols", &[], &["symbols"]),
+    ("🔤", "input latin letters", &[], &["abc"]),
+    ("🅰\u{FE0F}", "A button (blood type)", &[], &["a"]),
+    ("🆎", "AB button (blood type)", &[], &["ab"]),
+    ("🅱\u{FE0F}", "B button (blood type)", &[], &["b"]),
+    ("🆑", "CL button", &[], &["cl"]),
+    ("🆒", "COOL button", &[], &["cool"]),
+    ("🆓", "FREE button", &[], &["free"]),
+    ("ℹ\u{FE0F}", "information", &[], &["information_source"]),
+    ("🆔", "ID button", &[], &["id"]),
+    ("Ⓜ\u{FE0F}", "circled M", &[], &["m"]),
+    ("🆕", "NEW button", &[], &["new"]),
+    ("🆖", "NG button", &[], &["ng"]),
+    ("🅾\u{FE0F}", "O button (blood type)", &[], &["o2"]),
+    ("🆗", "OK button", &[], &["ok"]),
+    ("🅿\u{FE0F}", "P button", &[], &["parking"]),
+    ("🆘", "SOS button", &[], &["sos"]),
+    ("🆙", "UP! button", &[], &["up"]),
+    ("🆚", "VS button", &[], &["vs"]),
+    ("🈁", "Japanese “here” button", &[], &["koko"]),
+    (
+        "🈂\u{FE0F}",
+        "Japanese “service charge” button",
+        &[],
+        &["sa"],
+    ),
+    (
+        "🈷\u{FE0F}",
+        "Japanese “monthly amount” button",
+        &[],
+        &["u6708"],
+    ),
+    (
+        "🈶",
+        "Japanese “not free of charge” button",
+        &[],
+        &["u6709"],
+    ),
+    ("🈯", "Japanese “reserved” button", &[], &["u6307"]),
+    (
+        "🉐",
+        "Japanese “bargain” button",
+        &[],
+        &["ideograph_advantage"],
+    ),
+    ("🈹", "Japanese “discount” button", &[], &["u5272"]),
+    ("🈚", "Japanese “free of charge” button", &[], &["u7121"]),
+    ("🈲", "Japanese “prohibited” button", &[], &["u7981"]),
+    ("🉑", "Japanese “acceptable” button", &[], &["accept"]),
+    ("🈸", "Japanese “application” button", &[], &["u7533"]),
+    ("🈴", "Japanese “passing grade” button", &[], &["u5408"]),
+    ("🈳", "Japanese “vacancy” button", &[], &["u7a7a"]),
+    (
+        "㊗\u{FE0F}",
+        "Japanese “congratulations” button",
+        &[],
+        &["congratulations"],
+    ),
+    ("㊙\u{FE0F}", "Japanese “secret” button", &[], &["secret"]),
+    ("🈺", "Japanese “open for business” button", &[], &["u55b6"]),
+    ("🈵", "Japanese “no vacancy” button", &[], &["u6e80"]),
+    ("🔴", "red circle", &[], &["red_circle"]),
+    ("🟠", "orange circle", &[], &["orange_circle"]),
+    ("🟡", "yellow circle", &[], &["yellow_circle"]),
+    ("🟢", "green circle", &[], &["green_circle"]),
+    ("🔵", "blue circle", &[], &["large_blue_circle"]),
+    ("🟣", "purple circle", &[], &["purple_circle"]),
+    ("🟤", "brown circle", &[], &["brown_circle"]),
+    ("⚫", "black circle", &[], &["black_circle"]),
+    ("⚪", "white circle", &[], &["white_circle"]),
+    ("🟥", "red square", &[], &["red_square"]),
+    ("🟧", "orange square", &[], &["orange_square"]),
+    ("🟨", "yellow square", &[], &["yellow_square"]),
+    ("🟩", "green square", &[], &["green_square"]),
+    ("🟦", "blue square", &[], &["blue_square"]),
+    ("🟪", "purple square", &[], &["purple_square"]),
+    ("🟫", "brown square", &[], &["brown_square"]),
+    ("⬛", "black large square", &[], &["black_large_square"]),
+    ("⬜", "white large square", &[], &["white_large_square"]),
+    (
+        "◼\u{FE0F}",
+        "black medium square",
+        &[],
+        &["black_medium_square"],
+    ),
+    (
+        "◻\u{FE0F}",
+        "white medium square",
+        &[],
+        &["white_medium_square"],
+    ),
+    (
+        "◾",
+        "black medium-small square",
+        &[],
+        &["black_medium_small_square"],
+    ),
+    (
+        "◽",
+        "white medium-small square",
+        &[],
+        &["white_medium_small_square"],
+    ),
+    (
+        "▪\u{FE0F}",
+        "black small square",
+        &[],
+        &["black_small_square"],
+    ),
+    (
+        "▫\u{FE0F}",
+        "white small square",
+        &[],
+        &["white_small_square"],
+    ),
+    ("🔶", "large orange diamond", &[], &["large_orange_diamond"]),
+    ("🔷", "large blue diamond", &[], &["large_blue_diamond"]),
+    ("🔸", "small orange diamond", &[], &["small_orange_diamond"]),
+    ("🔹", "small blue diamond", &[], &["small_blue_diamond"]),
+    (
+        "🔺",
+        "red triangle pointed up",
+        &[],
+        &["small_red_triangle"],
+    ),
+    (
+        "🔻",
+        "red triangle pointed down",
+        &[],
+        &["small_red_triangle_down"],
+    ),
+    (
+        "💠",
+        "diamond with a dot",
+        &[],
+        &["diamond_shape_with_a_dot_inside"],
+    ),
+    ("🔘", "radio button", &[], &["radio_button"]),
+    ("🔳", "white square button", &[], &["white_square_button"]),
+    ("🔲", "black square button", &[], &["black_square_button"]),
+    ("🏁", "chequered flag", &[], &["checkered_flag"]),
+    ("🚩", "triangular flag", &[], &["triangular_flag_on_post"]),
+    ("🎌", "crossed flags", &[], &["crossed_flags"]),
+    ("🏴", "black flag", &[], &["black_flag"]),
+    ("🏳\u{FE0F}", "white flag", &[], &["white_flag"]),
+    (
+        "🏳\u{FE0F}\u{200D}🌈",
+        "rainbow flag",
+        &[],
+        &["rainbow_flag"],
+    ),
+    (
+        "🏳\u{FE0F}\u{200D}⚧\u{FE0F}",
+        "transgender flag",
+        &[],
+        &["transgender_flag"],
+    ),
+    ("🏴\u{200D}☠\u{FE0F}", "pirate flag", &[], &["pirate_flag"]),
+    ("🇦🇨", "flag: Ascension Island", &[], &["ascension_island"]),
+    ("🇦🇩", "flag: Andorra", &[], &["andorra"]),
+    (
+        "🇦🇪",
+        "flag: United Arab Emirates",
+        &[],
+        &["united_arab_emirates"],
+    ),
+    ("🇦🇫", "flag: Afghanistan", &[], &["afghanistan"]),
+    ("🇦🇬", "flag: Antigua & Barbuda", &[], &["antigua_barbuda"]),
+    ("🇦🇮", "flag: Anguilla", &[], &["anguilla"]),
+    ("🇦🇱", "flag: Albania", &[], &["albania"]),
+    ("🇦🇲", "flag: Armenia", &[], &["armenia"]),
+    ("🇦🇴", "flag: Angola", &[], &["angola"]),
+    ("🇦🇶", "flag: Antarctica", &[], &["antarctica"]),
+    ("🇦🇷", "flag: Argentina", &[], &["argentina"]),
+    ("🇦🇸", "flag: American Samoa", &[], &["american_samoa"]),
+    ("🇦🇹", "flag: Austria", &[], &["austria"]),
+    ("🇦🇺", "flag: Australia", &[], &["australia"]),
+    ("🇦🇼", "flag: Aruba", &[], &["aruba"]),
+    ("🇦🇽", "flag: Åland Islands", &[], &["aland_islands"]),
+    ("🇦🇿", "flag: Azerbaijan", &[], &["azerbaijan"]),
+    (
+        "🇧🇦",
+        "flag: Bosnia & Herzegovina",
+        &[],
+        &["bosnia_herzegovina"],
+    ),
+    ("🇧🇧", "flag: Barbados", &[], &["barbados"]),
+    ("🇧🇩", "flag: Bangladesh", &[], &["bangladesh"]),
+    ("🇧🇪", "flag: Belgium", &[], &["belgium"]),
+    ("🇧🇫", "flag: Burkina Faso", &[], &["burkina_faso"]),
+    ("🇧🇬", "flag: Bulgaria", &[], &["bulgaria"]),
+    ("🇧🇭", "flag: Bahrain", &[], &["bahrain"]),
+    ("🇧🇮", "flag: Burundi", &[], &["burundi"]),
+    ("🇧🇯", "flag: Benin", &[], &["benin"]),
+    ("🇧🇱", "flag: St. Barthélemy", &[], &["st_barthelemy"]),
+    ("🇧🇲", "flag: Bermuda", &[], &["bermuda"]),
+    ("🇧🇳", "flag: Brunei", &[], &["brunei"]),
+    ("🇧🇴", "flag: Bolivia", &[], &["bolivia"]),
+    (
+        "🇧🇶",
+        "flag: Caribbean Netherlands",
+        &[],
+        &["caribbean_netherlands"],
+    ),
+    ("🇧🇷", "flag: Brazil", &[], &["brazil"]),
+    ("🇧🇸", "flag: Bahamas", &[], &["bahamas"]),
+    ("🇧🇹", "flag: Bhutan", &[], &["bhutan"]),
+    ("🇧🇻", "flag: Bouvet Island", &[], &["bouvet_island"]),
+    ("🇧🇼", "flag: Botswana", &[], &["botswana"]),
+    ("🇧🇾", "flag: Belarus", &[], &["belarus"]),
+    ("🇧🇿", "flag: Belize", &[], &["belize"]),
+    ("🇨🇦", "flag: Canada", &[], &["canada"]),
+    (
+        "🇨🇨",
+        "flag: Cocos (Keeling) Islands",
+        &[],
+        &["cocos_islands"],
+    ),
+    ("🇨🇩", "flag: Congo - Kinshasa", &[], &["congo_kinshasa"]),
+    (
+        "🇨🇫",
+        "flag: Central African Republic",
+        &[],
+        &["central_african_republic"],
+    ),
+    (
+        "🇨🇬",
+        "flag: Congo - Brazzaville",
+        &[],
+        &["congo_brazzaville"],
+    ),
+    ("🇨🇭", "flag: Switzerland", &[], &["switzerland"]),
+    ("🇨🇮", "flag: Côte d’Ivoire", &[], &["cote_divoire"]),
+    ("🇨🇰", "flag: Cook Islands", &[], &["cook_islands"]),
+    ("🇨🇱", "flag: Chile", &[], &["chile"]),
+    ("🇨🇲", "flag: Cameroon", &[], &["cameroon"]),
+    ("🇨🇳", "flag: China", &[], &["cn"]),
+    ("🇨🇴", "flag: Colombia", &[], &["colombia"]),
+    ("🇨🇵", "flag: Clipperton Island", &[], &["clipperton_island"]),
+    ("🇨🇶", "flag: Sark", &[], &[]),
+    ("🇨🇷", "flag: Costa Rica", &[], &["costa_rica"]),
+    ("🇨🇺", "flag: Cuba", &[], &["cuba"]),
+    ("🇨🇻", "flag: Cape Verde", &[], &["cape_verde"]),
+    ("🇨🇼", "flag: Curaçao", &[], &["curacao"]),
+    ("🇨🇽", "flag: Christmas Island", &[], &["christmas_island"]),
+    ("🇨🇾", "flag: Cyprus", &[], &["cyprus"]),
+    ("🇨🇿", "flag: Czechia", &[], &["czech_republic"]),
+    ("🇩🇪", "flag: Germany", &[], &["de"]),
+    ("🇩🇬", "flag: Diego Garcia", &[], &["diego_garcia"]),
+    ("🇩🇯", "flag: Djibouti", &[], &["djibouti"]),
+    ("🇩🇰", "flag: Denmark", &[], &["denmark"]),
+    ("🇩🇲", "flag: Dominica", &[], &["dominica"]),
+    (
+        "🇩🇴",
+        "flag: Dominican Republic",
+        &[],
+        &["dominican_republic"],
+    ),
+    ("🇩🇿", "flag: Algeria", &[], &["algeria"]),
+    ("🇪🇦", "flag: Ceuta & Melilla", &[], &["ceuta_melilla"]),
+    ("🇪🇨", "flag: Ecuador", &[], &["ecuador"]),
+    ("🇪🇪", "flag: Estonia", &[], &["estonia"]),
+    ("🇪🇬", "flag: Egypt", &[], &["egypt"]),
+    ("🇪🇭", "flag: Western Sahara", &[], &["western_sahara"]),
+    ("🇪🇷", "flag: Eritrea", &[], &["eritrea"]),
+    ("🇪🇸", "flag: Spain", &[], &["es"]),
+    ("🇪🇹", "flag: Ethiopia", &[], &["ethiopia"]),
+    ("🇪🇺", "flag: European Union", &[], &["eu", "european_union"]),
+    ("🇫🇮", "flag: Finland", &[], &["finland"]),
+    ("🇫🇯", "flag: Fiji", &[], &["fiji"]),
+    ("🇫🇰", "flag: Falkland Islands", &[], &["falkland_islands"]),
+    ("🇫🇲", "flag: Micronesia", &[], &["micronesia"]),
+    ("🇫🇴", "flag: Faroe Islands", &[], &["faroe_islands"]),
+    ("🇫🇷", "flag: France", &[], &["fr"]),
+    ("🇬🇦", "flag: Gabon", &[], &["gabon"]),
+    ("🇬🇧", "flag: United Kingdom", &[], &["gb", "uk"]),
+    ("🇬🇩", "flag: Grenada", &[], &["grenada"]),
+    ("🇬🇪", "flag: Georgia", &[], &["georgia"]),
+    ("🇬🇫", "flag: French Guiana", &[], &["french_guiana"]),
+    ("🇬🇬", "flag: Guernsey", &[], &["guernsey"]),
+    ("🇬🇭", "flag: Ghana", &[], &["ghana"]),
+    ("🇬🇮", "flag: Gibraltar", &[], &["gibraltar"]),
+    ("🇬🇱", "flag: Greenland", &[], &["greenland"]),
+    ("🇬🇲", "flag: Gambia", &[], &["gambia"]),
+    ("🇬🇳", "flag: Guinea", &[], &["guinea"]),
+    ("🇬🇵", "flag: Guadeloupe", &[], &["guadeloupe"]),
+    ("🇬🇶", "flag: Equatorial Guinea", &[], &["equatorial_guinea"]),
+    ("🇬🇷", "flag: Greece", &[], &["greece"]),
+    (
+        "🇬🇸",
+        "flag: South Georgia & South Sandwich Islands",
+        &[],
+        &["south_georgia_south_sandwich_islands"],
+    ),
+    ("🇬🇹", "flag: Guatemala", &[], &["guatemala"]),
+    ("🇬🇺", "flag: Guam", &[], &["guam"]),
+    ("🇬🇼", "flag: Guinea-Bissau", &[], &["guinea_bissau"]),
+    ("🇬🇾", "flag: Guyana", &[], &["guyana"]),
+    ("🇭🇰", "flag: Hong Kong SAR China", &[], &["hong_kong"]),
+    (
+        "🇭🇲",
+        "flag: Heard & McDonald Islands",
+        &[],
+        &["heard_mcdonald_islands"],
+    ),
+    ("🇭🇳", "flag: Honduras", &[], &["honduras"]),
+    ("🇭🇷", "flag: Croatia", &[], &["croatia"]),
+    ("🇭🇹", "flag: Haiti", &[], &["haiti"]),
+    ("🇭🇺", "flag: Hungary", &[], &["hungary"]),
+    ("🇮🇨", "flag: Canary Islands", &[], &["canary_islands"]),
+    ("🇮🇩", "flag: Indonesia", &[], &["indonesia"]),
+    ("🇮🇪", "flag: Ireland", &[], &["ireland"]),
+    ("🇮🇱", "flag: Israel", &[], &["israel"]),
+    ("🇮🇲", "flag: Isle of Man", &[], &["isle_of_man"]),
+    ("🇮🇳", "flag: India", &[], &["india"]),
+    (
+        "🇮🇴",
+        "flag: British Indian Ocean Territory",
+        &[],
+        &["british_indian_ocean_territory"],
+    ),
+    ("🇮🇶", "flag: Iraq", &[], &["iraq"]),
+    ("🇮🇷", "flag: Iran", &[], &["iran"]),
+    ("🇮🇸", "flag: Iceland", &[], &["iceland"]),
+    ("🇮🇹", "flag: Italy", &[], &["it"]),
+    ("🇯🇪", "flag: Jersey", &[], &["jersey"]),
+    ("🇯🇲", "flag: Jamaica", &[], &["jamaica"]),
+    ("🇯🇴", "flag: Jordan", &[], &["jordan"]),
+    ("🇯🇵", "flag: Japan", &[], &["jp"]),
+    ("🇰🇪", "flag: Kenya", &[], &["kenya"]),
+    ("🇰🇬", "flag: Kyrgyzstan", &[], &["kyrgyzstan"]),
+    ("🇰🇭", "flag: Cambodia", &[], &["cambodia"]),
+    ("🇰🇮", "flag: Kiribati", &[], &["kiribati"]),
+    ("🇰🇲", "flag: Comoros", &[], &["comoros"]),
+    ("🇰🇳", "flag: St. Kitts & Nevis", &[], &["st_kitts_nevis"]),
+    ("🇰🇵", "flag: North Korea", &[], &["north_korea"]),
+    ("🇰🇷", "flag: South Korea", &[], &["kr"]),
+    ("🇰🇼", "flag: Kuwait", &[], &["kuwait"]),
+    ("🇰🇾", "flag: Cayman Islands", &[], &["cayman_islands"]),
+    ("🇰🇿", "flag: Kazakhstan", &[], &["kazakhstan"]),
+    ("🇱🇦", "flag: Laos", &[], &["laos"]),
+    ("🇱🇧", "flag: Lebanon", &[], &["lebanon"]),
+    ("🇱🇨", "flag: St. Lucia", &[], &["st_lucia"]),
+    ("🇱🇮", "flag: Liechtenstein", &[], &["liechtenstein"]),
+    ("🇱🇰", "flag: Sri Lanka", &[], &["sri_lanka"]),
+    ("🇱🇷", "flag: Liberia", &[], &["liberia"]),
+    ("🇱🇸", "flag: Lesotho", &[], &["lesotho"]),
+    ("🇱🇹", "flag: Lithuania", &[], &["lithuania"]),
+    ("🇱🇺", "flag: Luxembourg", &[], &["luxembourg"]),
+    ("🇱🇻", "flag: Latvia", &[], &["latvia"]),
+    ("🇱🇾", "flag: Libya", &[], &["libya"]),
+    ("🇲🇦", "flag: Morocco", &[], &["morocco"]),
+    ("🇲🇨", "flag: Monaco", &[], &["monaco"]),
+    ("🇲🇩", "flag: Moldova", &[], &["moldova"]),
+    ("🇲🇪", "flag: Montenegro", &[], &["montenegro"]),
+    ("🇲🇫", "flag: St. Martin", &[], &["st_martin"]),
+    ("🇲🇬", "flag: Madagascar", &[], &["madagascar"]),
+    ("🇲🇭", "flag: Marshall Islands", &[], &["marshall_islands"]),
+    ("🇲🇰", "flag: North Macedonia", &[], &["macedonia"]),
+    ("🇲🇱", "flag: Mali", &[], &["mali"]),
+    ("🇲🇲", "flag: Myanmar (Burma)", &[], &["myanmar"]),
+    ("🇲🇳", "flag: Mongolia", &[], &["mongolia"]),
+    ("🇲🇴", "flag: Macao SAR China", &[], &["macau"]),
+    (
+        "🇲🇵",
+        "flag: Northern Mariana Islands",
+        &[],
+        &["northern_mariana_islands"],
+    ),
+    ("🇲🇶", "flag: Martinique", &[], &["martinique"]),
+    ("🇲🇷", "flag: Mauritania", &[], &["mauritania"]),
+    ("🇲🇸", "flag: Montserrat", &[], &["montserrat"]),
+    ("🇲🇹", "flag: Malta", &[], &["malta"]),
+    ("🇲🇺", "flag: Mauritius", &[], &["mauritius"]),
+    ("🇲🇻", "flag: Maldives", &[], &["maldives"]),
+    ("🇲🇼", "flag: Malawi", &[], &["malawi"]),
+    ("🇲🇽", "flag: Mexico", &[], &["mexico"]),
+    ("🇲🇾", "flag: Malaysia", &[], &["malaysia"]),
+    ("🇲🇿", "flag: Mozambique", &[], &["mozambique"]),
+    ("🇳🇦", "flag: Namibia", &[], &["namibia"]),
+    ("🇳🇨", "flag: New Caledonia", &[], &["new_caledonia"]),
+    ("🇳🇪", "flag: Niger", &[], &["niger"]),
+    ("🇳🇫", "flag: Norfolk Island", &[], &["norfolk_island"]),
+    ("🇳🇬", "flag: Nigeria", &[], &["nigeria"]),
+    ("🇳🇮", "flag: Nicaragua", &[], &["nicaragua"]),
+    ("🇳🇱", "flag: Netherlands", &[], &["netherlands"]),
+    ("🇳🇴", "flag: Norway", &[], &["norway"]),
+    ("🇳🇵", "flag: Nepal", &[], &["nepal"]),
+    ("🇳🇷", "flag: Nauru", &[], &["nauru"]),
+    ("🇳🇺", "flag: Niue", &[], &["niue"]),
+    ("🇳🇿", "flag: New Zealand", &[], &["new_zealand"]),
+    ("🇴🇲", "flag: Oman", &[], &["oman"]),
+    ("🇵🇦", "flag: Panama", &[], &["panama"]),
+    ("🇵🇪", "flag: Peru", &[], &["peru"]),
+    ("🇵🇫", "flag: French Polynesia", &[], &["french_polynesia"]),
+    ("🇵🇬", "flag: Papua New Guinea", &[], &["papua_new_guinea"]),
+    ("🇵🇭", "flag: Philippines", &[], &["philippines"]),
+    ("🇵🇰", "flag: Pakistan", &[], &["pakistan"]),
+    ("🇵🇱", "flag: Poland", &[], &["poland"]),
+    (
+        "🇵🇲",
+        "flag: St. Pierre & Miquelon",
+        &[],
+        &["st_pierre_miquelon"],
+    ),
+    ("🇵🇳", "flag: Pitcairn Islands", &[], &["pitcairn_islands"]),
+    ("🇵🇷", "flag: Puerto Rico", &[], &["puerto_rico"]),
+    (
+        "🇵🇸",
+        "flag: Palestinian Territories",
+        &[],
+        &["palestinian_territories"],
+    ),
+    ("🇵🇹", "flag: Portugal", &[], &["portugal"]),
+    ("🇵🇼", "flag: Palau", &[], &["palau"]),
+    ("🇵🇾", "flag: Paraguay", &[], &["paraguay"]),
+    ("🇶🇦", "flag: Qatar", &[], &["qatar"]),
+    ("🇷🇪", "flag: Réunion", &[], &["reunion"]),
+    ("🇷🇴", "flag: Romania", &[], &["romania"]),
+    ("🇷🇸", "flag: Serbia", &[], &["serbia"]),
+    ("🇷🇺", "flag: Russia", &[], &["ru"]),
+    ("🇷🇼", "flag: Rwanda", &[], &["rwanda"]),
+    ("🇸🇦", "flag: Saudi Arabia", &[], &["saudi_arabia"]),
+    ("🇸🇧", "flag: Solomon Islands", &[], &["solomon_islands"]),
+    ("🇸🇨", "flag: Seychelles", &[], &["seychelles"]),
+    ("🇸🇩", "flag: Sudan", &[], &["sudan"]),
+    ("🇸🇪", "flag: Sweden", &[], &["sweden"]),
+    ("🇸🇬", "flag: Singapore", &[], &["singapore"]),
+    ("🇸🇭", "flag: St. Helena", &[], &["st_helena"]),
+    ("🇸🇮", "flag: Slovenia", &[], &["slovenia"]),
+    (
+        "🇸🇯",
+        "flag: Svalbard & Jan Mayen",
+        &[],
+        &["svalbard_jan_mayen"],
+    ),
+    ("🇸🇰", "flag: Slovakia", &[], &["slovakia"]),
+    ("🇸🇱", "flag: Sierra Leone", &[], &["sierra_leone"]),
+    ("🇸🇲", "flag: San Marino", &[], &["san_marino"]),
+    ("🇸🇳", "flag: Senegal", &[], &["senegal"]),
+    ("🇸🇴", "flag: Somalia", &[], &["somalia"]),
+    ("🇸🇷", "flag: Suriname", &[], &["suriname"]),
+    ("🇸🇸", "flag: South Sudan", &[], &["south_sudan"]),
+    (
+        "🇸🇹",
+        "flag: São Tomé & Príncipe",
+        &[],
+        &["sao_tome_principe"],
+    ),
+    ("🇸🇻", "flag: El Salvador", &[], &["el_salvador"]),
+    ("🇸🇽", "flag: Sint Maarten", &[], &["sint_maarten"]),
+    ("🇸🇾", "flag: Syria", &[], &["syria"]),
+    ("🇸🇿", "flag: Eswatini", &[], &["swaziland"]),
+    ("🇹🇦", "flag: Tristan da Cunha", &[], &["tristan_da_cunha"]),
+    (
+        "🇹🇨",
+        "flag: Turks & Caicos Islands",
+        &[],
+        &["turks_caicos_islands"],
+    ),
+    ("🇹🇩", "flag: Chad", &[], &["chad"]),
+    (
+        "🇹🇫",
+        "flag: French Southern Territories",
+        &[],
+        &["french_southern_territories"],
+    ),
+    ("🇹🇬", "flag: Togo", &[], &["togo"]),
+    ("🇹🇭", "flag: Thailand", &[], &["thailand"]),
+    ("🇹🇯", "flag: Tajikistan", &[], &["tajikistan"]),
+    ("🇹🇰", "flag: Tokelau", &[], &["tokelau"]),
+    ("🇹🇱", "flag: Timor-Leste", &[], &["timor_leste"]),
+    ("🇹🇲", "flag: Turkmenistan", &[], &["turkmenistan"]),
+    ("🇹🇳", "flag: Tunisia", &[], &["tunisia"]),
+    ("🇹🇴", "flag: Tonga", &[], &["tonga"]),
+    ("🇹🇷", "flag: Türkiye", &[], &["tr"]),
+    ("🇹🇹", "flag: Trinidad & Tobago", &[], &["trinidad_tobago"]),
+    ("🇹🇻", "flag: Tuvalu", &[], &["tuvalu"]),
+    ("🇹🇼", "flag: Taiwan", &[], &["taiwan"]),
+    ("🇹🇿", "flag: Tanzania", &[], &["tanzania"]),
+    ("🇺🇦", "flag: Ukraine", &[], &["ukraine"]),
+    ("🇺🇬", "flag: Uganda", &[], &["uganda"]),
+    (
+        "🇺🇲",
+        "flag: U.S. Outlying Islands",
+        &[],
+        &["us_outlying_islands"],
+    ),
+    ("🇺🇳", "flag: United Nations", &[], &["united_nations"]),
+    ("🇺🇸", "flag: United States", &[], &["us"]),
+    ("🇺🇾", "flag: Uruguay", &[], &["uruguay"]),
+    ("🇺🇿", "flag: Uzbekistan", &[], &["uzbekistan"]),
+    ("🇻🇦", "flag: Vatican City", &[], &["vatican_city"]),
+    (
+        "🇻🇨",
+        "flag: St. Vincent & Grenadines",
+        &[],
+        &["st_vincent_grenadines"],
+    ),
+    ("🇻🇪", "flag: Venezuela", &[], &["venezuela"]),
+    (
+        "🇻🇬",
+        "flag: British Virgin Islands",
+        &[],
+        &["british_virgin_islands"],
+    ),
+    (
+        "🇻🇮",
+        "flag: U.S. Virgin Islands",
+        &[],
+        &["us_virgin_islands"],
+    ),
+    ("🇻🇳", "flag: Vietnam", &[], &["vietnam"]),
+    ("🇻🇺", "flag: Vanuatu", &[], &["vanuatu"]),
+    ("🇼🇫", "flag: Wallis & Futuna", &[], &["wallis_futuna"]),
+    ("🇼🇸", "flag: Samoa", &[], &["samoa"]),
+    ("🇽🇰", "flag: Kosovo", &[], &["kosovo"]),
+    ("🇾🇪", "flag: Yemen", &[], &["yemen"]),
+    ("🇾🇹", "flag: Mayotte", &[], &["mayotte"]),
+    ("🇿🇦", "flag: South Africa", &[], &["south_africa"]),
+    ("🇿🇲", "flag: Zambia", &[], &["zambia"]),
+    ("🇿🇼", "flag: Zimbabwe", &[], &["zimbabwe"]),
+    (
+        "🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+        "flag: England",
+        &[],
+        &["england"],
+    ),
+    (
+        "🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+        "flag: Scotland",
+        &[],
+        &["scotland"],
+    ),
+    (
+        "🏴\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}",
+        "flag: Wales",
+        &[],
+        &["wales"],
+    ),
+    // Symbols that aren't emoji
+    ("€", "euro", &["currency", "EUR"], &[]),
+    ("£", "pound", &["currency", "GBP"], &[]),
+    ("¥", "yen", &["currency", "JPY", "CNY"], &[]),
+    ("°", "degrees", &["degree", "hour", "temperature"], &[]),
+    ("±", "plus-minus", &["math", "plus", "minus"], &[]),
+    (
+        "×",
+        "multiplication sign",
+        &["cancel", "multiplication", "sign", "x"],
+        &[],
+    ),
+    (
+        "÷",
+        "division sign",
+        &["divide", "division", "math", "sign"],
+        &[],
+    ),
+    ("∞", "infinity", &["forever", "unbounded", "universal"], &[]),
+    ("→", "rightwards arrow", &["arrow", "right"], &[]),
+    ("←", "leftwards arrow", &["arrow", "left"], &[]),
+    ("•", "bullet", &["dot", "list"], &[]),
+    ("…", "horizontal ellipsis", &["dots", "ellipsis"], &[]),
+    ("§", "section", &["paragraph"], &[]),
+    ("¶", "pilcrow sign", &["paragraph"], &[]),
+    ("†", "dagger", &["obelisk"], &[]),
+];
+
+/// A [`SearchTree`] of the lowercase short names, the words of the short names, the keywords &
+/// the shortcodes of the symbols in [`EMOJI`], for finding the symbols with a [`Searcher`].
+///
+/// The shortcodes are searched between colons, e.g. `:+1:`, along with the short name with spaces
+/// replaced by underscores, e.g. `:thumbs_up:`.
+#[derive(Debug)]
+pub struct EmojiIndex {
+    /// Maps the names & keywords to their groups in `groups`
+    tree: SearchTree,
+    /// The indices in [`EMOJI`] of the symbols that have the same name or keyword
+    groups: Vec<Vec<usize>>,
+}
+
+impl Default for EmojiIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmojiIndex {
+    /// Create an index of every symbol in [`EMOJI`].
+    pub fn new() -> Self {
+        let mut tree = SearchTree::default();
+        let mut groups = Vec::new();
+        let mut shortcode = String::new();
+        for (index, &(_, name, keywords, shortcodes)) in EMOJI.iter().enumerate() {
+            let name = name.to_lowercase();
+            tree.push_to_group(&mut groups, &name, index);
+            for word in name.split(|ch: char| !ch.is_alphanumeric()) {
+                if !word.is_empty() && word != name {
+                    tree.push_to_group(&mut groups, word, index);
+                }
+            }
+
+            let spelled = name.chars().map(|ch| if ch == ' ' { '_' } else { ch });
+            for chars in shortcodes.iter().map(|shortcode| shortcode.chars()) {
+                shortcode.clear();
+                shortcode.push(':');
+                shortcode.extend(chars);
+                shortcode.push(':');
+                tree.push_to_group(&mut groups, &shortcode, index);
+            }
+            shortcode.clear();
+            shortcode.push(':');
+            shortcode.extend(spelled);
+            shortcode.push(':');
+            tree.push_to_group(&mut groups, &shortcode, index);
+
+            for keyword in keywords.iter().chain(shortcodes) {
+                tree.push_to_group(&mut groups, &keyword.to_lowercase(), index);
+            }
+        }
+        Self { tree, groups }
+    }
+
+    /// The tree to search with a [`Searcher`], whose candidates are to be passed to
+    /// [`EmojiIndex::for_each_candidate`].
+    pub const fn tree(&self) -> &SearchTree {
+        &self.tree
+    }
+
+    /// Calls a function on the index in [`EMOJI`] of every symbol found by `searcher`, once per
+    /// symbol, in the order of the candidates of `searcher`.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each_candidate<'tree, E>(
+        &self,
+        searcher: &Searcher<'tree, impl Lookalikes<'tree>, impl Cursor>,
+        mut f: impl FnMut(usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut visited = BTreeSet::new();
+        searcher.for_each_candidate(|group| {
+            self.groups[group]
+                .iter()
+                .filter(|&&index| visited.insert(index))
+                .try_for_each(|&index| f(index))
+        })
+    }
+}
@@ -0,0 +1,213 @@
+//! A frozen representation of a [`SearchTree`] with all of its nodes in a single buffer, for
+//! large trees that are built once & searched many times.
+//!
+//! # Example
+//! ```rust
+//! use permissive_search::{frozen::FrozenTree, *};
+//!
+//! let keys = ["apple", "apricot", "banana"];
+//! let tree: SearchTree = keys.into_iter().enumerate().collect();
+//! let frozen = FrozenTree::from(&tree);
+//! drop(tree);
+//!
+//! let mut searcher = Searcher::new(&frozen, lookalikes::all);
+//! searcher.extend("bababa".chars());
+//! searcher.for_each_candidate::<()>(|i| Ok(println!("{}", keys[i])));
+//! ```
+
+mod serialized;
+
+pub use serialized::{FormatError, SerializedNode, SerializedTree};
+
+use {
+    crate::{Cursor, IntoCursor, SearchNode, SearchTree},
+    alloc::{boxed::Box, vec, vec::Vec},
+    core::ops::RangeInclusive,
+};
+
+/// A node of a [`FrozenTree`], referring to other nodes & indices by their positions in it.
+#[derive(Debug, Clone, Copy, Default)]
+struct Node {
+    /// The children of the node are `nodes[children_start..children_end]`
+    children_start: u32,
+    children_end: u32,
+    /// The indices associated with the keys that end at the node or at its descendants are
+    /// `ends[ends_start..ends_end]`
+    ends_start: u32,
+    ends_end: u32,
+}
+
+/// A tree that associates string keys with `usize` indices, built from a [`SearchTree`] & no
+/// longer modifiable.
+///
+/// Unlike a [`SearchTree`], which allocates every node separately, a frozen tree is made of 2
+/// allocations: the nodes, with the children of every node next to each other, & the indices.
+#[derive(Debug, Clone)]
+pub struct FrozenTree {
+    /// The root comes first, followed by the rest of the nodes along with the characters leading
+    /// to them
+    nodes: Box<[(char, Node)]>,
+    /// Indices associated with the keys, in the order of a depth-first traversal of the tree, so
+    /// that the ones reachable from every node are next to each other
+    ends: Box<[usize]>,
+    /// Whether an index may be associated with several keys, copied from the [`SearchTree`]
+    repeats: bool,
+}
+
+/// Converts a position in a buffer of a [`FrozenTree`] into the type stored in it.
+fn position(i: usize) -> u32 {
+    u32::try_from(i).expect("a frozen tree can't have more than `u32::MAX` nodes or indices")
+}
+
+impl FrozenTree {
+    /// Copies the descendants & indices of `tree`, the node at `index`.
+    fn freeze(
+        nodes: &mut Vec<(char, Node)>,
+        ends: &mut Vec<usize>,
+        tree: &SearchNode,
+        index: usize,
+    ) {
+        let children_start = nodes.len();
+        nodes.extend(tree.nodes.iter().map(|(ch, _)| (*ch, Node::default())));
+        let ends_start = ends.len();
+        ends.extend(tree.end);
+        for (i, (_, child)) in tree.nodes.iter().enumerate() {
+            Self::freeze(nodes, ends, child, children_start + i);
+        }
+
+        nodes[index].1 = Node {
+            children_start: position(children_start),
+            children_end: position(children_start + tree.nodes.len()),
+            ends_start: position(ends_start),
+            ends_end: position(ends.len()),
+        };
+    }
+
+    /// The root of the tree.
+    pub const fn root(&self) -> FrozenNode<'_> {
+        FrozenNode {
+            tree: self,
+            index: 0,
+        }
+    }
+
+    /// Get an immediate child of the root associated with the provided character.
+    pub fn get(&self, ch: char) -> Option<FrozenNode<'_>> {
+        self.root().get(ch)
+    }
+
+    /// Get a descendant of the root associated with the provided string, e.g. an empty string
+    /// refers to the root itself.
+    pub fn get_str(&self, key: &str) -> Option<FrozenNode<'_>> {
+        self.root().get_str(key)
+    }
+
+    /// Calls a function on all the keys in the tree.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each<E>(&self, f: impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        self.root().for_each(f)
+    }
+}
+
+impl From<&SearchTree> for FrozenTree {
+    /// # Panics
+    /// Panics if the tree has more than `u32::MAX` nodes or indices.
+    fn from(tree: &SearchTree) -> Self {
+        let mut nodes = vec![('\0', Node::default())];
+        let mut ends = vec![];
+        Self::freeze(&mut nodes, &mut ends, tree.root(), 0);
+        Self {
+            nodes: nodes.into_boxed_slice(),
+            ends: ends.into_boxed_slice(),
+            repeats: tree.repeats,
+        }
+    }
+}
+
+/// A node of a [`FrozenTree`].
+#[derive(Debug, Clone, Copy)]
+pub struct FrozenNode<'tree> {
+    tree: &'tree FrozenTree,
+    /// The position of the node in `tree.nodes`
+    index: usize,
+}
+
+impl<'tree> FrozenNode<'tree> {
+    const fn node(self) -> Node {
+        self.tree.nodes[self.index].1
+    }
+
+    /// The position of the first child & the children along with their characters.
+    fn children(self) -> (usize, &'tree [(char, Node)]) {
+        let node = self.node();
+        let start = node.children_start as usize;
+        (start, &self.tree.nodes[start..node.children_end as usize])
+    }
+
+    /// Get an immediate child node associated with the provided character.
+    pub fn get(self, ch: char) -> Option<Self> {
+        let (start, children) = self.children();
+        let i = children.binary_search_by_key(&ch, |(ch, _)| *ch).ok()?;
+        Some(Self {
+            index: start + i,
+            ..self
+        })
+    }
+
+    /// Get a descendant node associated with the provided string, e.g. an empty string refers to
+    /// the node itself.
+    pub fn get_str(self, key: &str) -> Option<Self> {
+        key.chars().try_fold(self, Self::get)
+    }
+
+    /// Calls a function on all the keys reachable from this tree node.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each<E>(self, mut f: impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        self.for_each_index(&mut f)
+    }
+}
+
+impl<'tree> IntoCursor for &'tree FrozenTree {
+    type Cursor = FrozenNode<'tree>;
+
+    fn into_cursor(self) -> Self::Cursor {
+        self.root()
+    }
+
+    fn repeats_indices(&self) -> bool {
+        self.repeats
+    }
+}
+
+impl Cursor for FrozenNode<'_> {
+    type Id = usize;
+
+    fn id(self) -> Self::Id {
+        self.index
+    }
+
+    fn get(self, ch: char) -> Option<Self> {
+        Self::get(self, ch)
+    }
+
+    fn for_each_child_in(self, range: &RangeInclusive<char>, mut f: impl FnMut(char, Self)) {
+        let (start, children) = self.children();
+        let first = children.partition_point(|(ch, _)| ch < range.start());
+        children[first..]
+            .iter()
+            .take_while(|(ch, _)| ch <= range.end())
+            .zip(start + first..)
+            .for_each(|(&(ch, _), index)| f(ch, Self { index, ..self }));
+    }
+
+    fn for_each_index<E>(self, f: &mut impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        let node = self.node();
+        self.tree.ends[node.ends_start as usize..node.ends_end as usize]
+            .iter()
+            .try_for_each(|&index| f(index))
+    }
+}
@@ -0,0 +1,349 @@
+//! A binary format of a [`FrozenTree`] that can be searched without deserializing it.
+//!
+//! The format consists of little-endian integers, with no alignment requirements:
+//! - The header: [`SerializedTree::MAGIC`], the version of the format as a `u32`, followed by the
+//!   number of nodes, the number of indices & whether an index may be associated with several
+//!   keys, as `0` or `1`, also as `u32`s.
+//! - The nodes, in the order of [`FrozenTree`], each one being its character & the ranges of
+//!   its children & of its indices, as 5 `u32`s.
+//! - The indices, as `u64`s.
+
+use {
+    super::{FrozenTree, Node},
+    crate::{Cursor, IntoCursor},
+    alloc::vec::Vec,
+    core::{
+        error::Error,
+        fmt::{self, Display, Formatter},
+        ops::RangeInclusive,
+    },
+};
+
+/// Size of the header in bytes.
+const HEADER_SIZE: usize = 20;
+/// Size of a node in bytes.
+const NODE_SIZE: usize = 20;
+/// Size of an index in bytes.
+const INDEX_SIZE: usize = 8;
+
+/// A reason for a buffer to be rejected by [`SerializedTree::new`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatError {
+    /// The buffer doesn't start with [`SerializedTree::MAGIC`]
+    BadMagic,
+    /// The buffer is of a version of the format other than [`SerializedTree::VERSION`]
+    UnsupportedVersion(u32),
+    /// The header has a field with an impossible value
+    InvalidHeader,
+    /// The buffer is shorter than its header says
+    Truncated,
+    /// The buffer is longer than its header says
+    TrailingBytes,
+    /// The node at this position doesn't have a valid character, or its children or its indices
+    /// are out of bounds, or its children aren't sorted
+    InvalidNode(usize),
+    /// An index doesn't fit into a `usize`
+    IndexOverflow,
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::BadMagic => f.write_str("not a serialized search tree"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported version of the format: {version}")
+            }
+            Self::InvalidHeader => f.write_str("the header is corrupted"),
+            Self::Truncated => f.write_str("the buffer is truncated"),
+            Self::TrailingBytes => f.write_str("unexpected bytes after the end of the tree"),
+            Self::InvalidNode(i) => write!(f, "node {i} is corrupted"),
+            Self::IndexOverflow => f.write_str("an index doesn't fit into a `usize`"),
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+/// Reads the `i`th `u32` of `bytes`.
+fn read_u32(bytes: &[u8], i: usize) -> u32 {
+    let mut buf = [0; 4];
+    buf.copy_from_slice(&bytes[4 * i..4 * i + 4]);
+    u32::from_le_bytes(buf)
+}
+
+/// Reads a node along with its character.
+fn read_node(bytes: &[u8; NODE_SIZE]) -> (u32, Node) {
+    let node = Node {
+        children_start: read_u32(bytes, 1),
+        children_end: read_u32(bytes, 2),
+        ends_start: read_u32(bytes, 3),
+        ends_end: read_u32(bytes, 4),
+    };
+    (read_u32(bytes, 0), node)
+}
+
+impl FrozenTree {
+    /// Serializes the tree into the format of [`SerializedTree`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(
+            HEADER_SIZE + self.nodes.len() * NODE_SIZE + self.ends.len() * INDEX_SIZE,
+        );
+        res.extend(SerializedTree::MAGIC);
+        for n in [
+            SerializedTree::VERSION,
+            self.nodes.len() as u32,
+            self.ends.len() as u32,
+            u32::from(self.repeats),
+        ] {
+            res.extend(n.to_le_bytes());
+        }
+        for (ch, node) in &self.nodes {
+            for n in [
+                u32::from(*ch),
+                node.children_start,
+                node.children_end,
+                node.ends_start,
+                node.ends_end,
+            ] {
+                res.extend(n.to_le_bytes());
+            }
+        }
+        for index in &self.ends {
+            res.extend((*index as u64).to_le_bytes());
+        }
+        res
+    }
+}
+
+/// A [`FrozenTree`] serialized with [`FrozenTree::to_bytes`], searched in place.
+///
+/// # Example
+/// ```rust
+/// use permissive_search::{frozen::{FormatError, FrozenTree, SerializedTree}, *};
+///
+/// # let tree: SearchTree = ["apple", "banana"].into_iter().enumerate().collect();
+/// let bytes = FrozenTree::from(&tree).to_bytes();
+/// let serialized = SerializedTree::new(&bytes)?;
+/// let searcher = Searcher::new(&serialized, lookalikes::all);
+/// # _ = searcher;
+/// # Ok::<(), FormatError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SerializedTree<'buf> {
+    nodes: &'buf [[u8; NODE_SIZE]],
+    ends: &'buf [[u8; INDEX_SIZE]],
+    /// Whether an index may be associated with several keys
+    repeats: bool,
+}
+
+impl<'buf> SerializedTree<'buf> {
+    /// The first bytes of every serialized tree.
+    pub const MAGIC: [u8; 4] = *b"PSFT";
+
+    /// The version of the format written by [`FrozenTree::to_bytes`], the only one accepted by
+    /// [`SerializedTree::new`].
+    pub const VERSION: u32 = 1;
+
+    /// Validates a serialized tree, so that searching it can neither panic nor loop forever.
+    ///
+    /// Takes time proportional to the size of the tree, but doesn't allocate.
+    ///
+    /// # Errors
+    /// Returns the first problem found with the buffer, see [`FormatError`].
+    pub fn new(bytes: &'buf [u8]) -> Result<Self, FormatError> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(if Self::MAGIC.starts_with(&bytes[..bytes.len().min(4)]) {
+                FormatError::Truncated
+            } else {
+                FormatError::BadMagic
+            });
+        }
+        let (header, rest) = bytes.split_at(HEADER_SIZE);
+        if header[..4] != Self::MAGIC {
+            return Err(FormatError::BadMagic);
+        }
+        let version = read_u32(header, 1);
+        if version != Self::VERSION {
+            return Err(FormatError::UnsupportedVersion(version));
+        }
+
+        let n_nodes = read_u32(header, 2) as usize;
+        let n_ends = read_u32(header, 3) as usize;
+        let repeats = match read_u32(header, 4) {
+            0 => false,
+            1 => true,
+            _ => return Err(FormatError::InvalidHeader),
+        };
+        let nodes_size = n_nodes
+            .checked_mul(NODE_SIZE)
+            .ok_or(FormatError::Truncated)?;
+        let size = n_ends
+            .checked_mul(INDEX_SIZE)
+            .and_then(|ends_size| ends_size.checked_add(nodes_size))
+            .ok_or(FormatError::Truncated)?;
+        if rest.len() < size {
+            return Err(FormatError::Truncated);
+        }
+        if rest.len() > size {
+            return Err(FormatError::TrailingBytes);
+        }
+        let (nodes, ends) = rest.split_at(nodes_size);
+        let res = Self {
+            nodes: nodes.as_chunks().0,
+            ends: ends.as_chunks().0,
+            repeats,
+        };
+        // The root is always present
+        if res.nodes.is_empty() {
+            return Err(FormatError::InvalidNode(0));
+        }
+
+        for (i, bytes) in res.nodes.iter().enumerate() {
+            let (ch, node) = read_node(bytes);
+            let (children_start, children_end) =
+                (node.children_start as usize, node.children_end as usize);
+            // Children coming after their parents ensures that the tree has no cycles
+            let valid = char::from_u32(ch).is_some()
+                && i < children_start
+                && children_start <= children_end
+                && children_end <= n_nodes
+                && node.ends_start <= node.ends_end
+                && node.ends_end as usize <= n_ends
+                && res.nodes[children_start..children_end]
+                    .windows(2)
+                    .all(|pair| read_u32(&pair[0], 0) < read_u32(&pair[1], 0));
+            if !valid {
+                return Err(FormatError::InvalidNode(i));
+            }
+        }
+        if res
+            .ends
+            .iter()
+            .any(|index| usize::try_from(u64::from_le_bytes(*index)).is_err())
+        {
+            return Err(FormatError::IndexOverflow);
+        }
+
+        Ok(res)
+    }
+
+    /// The root of the tree.
+    pub const fn root(self) -> SerializedNode<'buf> {
+        SerializedNode {
+            tree: self,
+            index: 0,
+        }
+    }
+
+    /// Get an immediate child of the root associated with the provided character.
+    pub fn get(self, ch: char) -> Option<SerializedNode<'buf>> {
+        self.root().get(ch)
+    }
+
+    /// Get a descendant of the root associated with the provided string, e.g. an empty string
+    /// refers to the root itself.
+    pub fn get_str(self, key: &str) -> Option<SerializedNode<'buf>> {
+        self.root().get_str(key)
+    }
+
+    /// Calls a function on all the keys in the tree.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each<E>(self, f: impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        self.root().for_each(f)
+    }
+}
+
+/// A node of a [`SerializedTree`].
+#[derive(Debug, Clone, Copy)]
+pub struct SerializedNode<'buf> {
+    tree: SerializedTree<'buf>,
+    /// The position of the node in `tree.nodes`
+    index: usize,
+}
+
+impl<'buf> SerializedNode<'buf> {
+    fn node(self) -> Node {
+        read_node(&self.tree.nodes[self.index]).1
+    }
+
+    /// The position of the first child & the children.
+    fn children(self) -> (usize, &'buf [[u8; NODE_SIZE]]) {
+        let node = self.node();
+        let start = node.children_start as usize;
+        (start, &self.tree.nodes[start..node.children_end as usize])
+    }
+
+    /// Get an immediate child node associated with the provided character.
+    pub fn get(self, ch: char) -> Option<Self> {
+        let (start, children) = self.children();
+        let i = children
+            .binary_search_by_key(&u32::from(ch), |child| read_u32(child, 0))
+            .ok()?;
+        Some(Self {
+            index: start + i,
+            ..self
+        })
+    }
+
+    /// Get a descendant node associated with the provided string, e.g. an empty string refers to
+    /// the node itself.
+    pub fn get_str(self, key: &str) -> Option<Self> {
+        key.chars().try_fold(self, Self::get)
+    }
+
+    /// Calls a function on all the keys reachable from this tree node.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each<E>(self, mut f: impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        self.for_each_index(&mut f)
+    }
+}
+
+impl<'buf> IntoCursor for &SerializedTree<'buf> {
+    type Cursor = SerializedNode<'buf>;
+
+    fn into_cursor(self) -> Self::Cursor {
+        self.root()
+    }
+
+    fn repeats_indices(&self) -> bool {
+        self.repeats
+    }
+}
+
+impl Cursor for SerializedNode<'_> {
+    type Id = usize;
+
+    fn id(self) -> Self::Id {
+        self.index
+    }
+
+    fn get(self, ch: char) -> Option<Self> {
+        Self::get(self, ch)
+    }
+
+    fn for_each_child_in(self, range: &RangeInclusive<char>, mut f: impl FnMut(char, Self)) {
+        let (start, children) = self.children();
+        let (range_start, range_end) = (u32::from(*range.start()), u32::from(*range.end()));
+        let first = children.partition_point(|child| read_u32(child, 0) < range_start);
+        children[first..]
+            .iter()
+            .take_while(|&child| read_u32(child, 0) <= range_end)
+            .zip(start + first..)
+            .for_each(|(child, index)| {
+                // Validated by `SerializedTree::new`
+                let ch = char::from_u32(read_u32(child, 0)).unwrap_or_default();
+                f(ch, Self { index, ..self });
+            });
+    }
+
+    fn for_each_index<E>(self, f: &mut impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        let node = self.node();
+        self.tree.ends[node.ends_start as usize..node.ends_end as usize]
+            .iter()
+            .try_for_each(|index| f(u64::from_le_bytes(*index) as usize))
+    }
+}
@@ -0,0 +1,101 @@
+//! Decomposition of Hangul syllables into jamo.
+//!
+//! While typing in Korean, the last syllable of the input is usually incomplete, e.g. `하` on the
+//! way to `한`, so it isn't a prefix of the intended key. Decomposing both the keys & the input
+//! into jamo fixes this:
+//! ```rust
+//! use permissive_search::*;
+//!
+//! let keys = ["한국", "하늘"];
+//! let decomposed = keys.map(hangul::decompose_str);
+//! let root: SearchTree = decomposed.iter().map(String::as_str).enumerate().collect();
+//!
+//! let mut searcher = Searcher::new(&root, lookalikes::jamo);
+//! searcher.extend("하".chars().flat_map(hangul::decompose));
+//! searcher.for_each_candidate::<()>(|i| Ok(println!("{}", keys[i])));
+//! ```
+//! [`lookalikes::jamo`](crate::lookalikes::jamo) accounts for the final consonant of the last
+//! syllable being the initial consonant of the next one, e.g. `한` on the way to `하나`.
+
+use alloc::string::String;
+
+/// The first precomposed Hangul syllable, `가`
+const FIRST_SYLLABLE: u32 = 0xAC00;
+/// The number of precomposed Hangul syllables
+const N_SYLLABLES: u32 = 11172;
+/// The number of syllables with the same initial consonant
+const N_SYLLABLES_PER_INITIAL: u32 = 588;
+/// The number of final consonants, including the lack of one
+const N_FINALS: u32 = 28;
+
+/// The first conjoining initial consonant, `ᄀ`
+const FIRST_INITIAL: u32 = 0x1100;
+/// The first conjoining vowel, `ᅡ`
+const FIRST_VOWEL: u32 = 0x1161;
+/// The conjoining final consonant that precedes `ᆨ`, i.e. the lack of one
+const NO_FINAL: u32 = 0x11A7;
+/// The number of conjoining initial consonants, from `ᄀ` to `ᄒ`
+const N_INITIALS: u32 = 19;
+/// The number of conjoining vowels, from `ᅡ` to `ᅵ`
+const N_VOWELS: u32 = 21;
+
+/// Conjoining jamo for the compatibility consonants from `ㄱ` to `ㅎ`, i.e. the ones that IMEs
+/// produce while a syllable is being typed. Consonant clusters become final consonants.
+const COMPAT_CONSONANTS: [char; 30] = [
+    'ᄀ', 'ᄁ', 'ᆪ', 'ᄂ', 'ᆬ', 'ᆭ', 'ᄃ', 'ᄄ', 'ᄅ', 'ᆰ', 'ᆱ', 'ᆲ', 'ᆳ', 'ᆴ', 'ᆵ', 'ᆶ', 'ᄆ', 'ᄇ', 'ᄈ',
+    'ᆹ', 'ᄉ', 'ᄊ', 'ᄋ', 'ᄌ', 'ᄍ', 'ᄎ', 'ᄏ', 'ᄐ', 'ᄑ', 'ᄒ',
+];
+
+/// Decomposes a Hangul syllable into conjoining jamo, e.g. `한` into `ᄒ`, `ᅡ` & `ᆫ`.
+///
+/// Compatibility jamo, e.g. `ㅎ`, are converted to conjoining ones, any other character is yielded
+/// as is.
+pub fn decompose(ch: char) -> impl Iterator<Item = char> + Clone {
+    let index = u32::from(ch).wrapping_sub(FIRST_SYLLABLE);
+    let [first, vowel, last] = if index < N_SYLLABLES {
+        let initial = FIRST_INITIAL + index / N_SYLLABLES_PER_INITIAL;
+        let vowel = FIRST_VOWEL + index % N_SYLLABLES_PER_INITIAL / N_FINALS;
+        let last = match index % N_FINALS {
+            0 => None,
+            i => char::from_u32(NO_FINAL + i),
+        };
+        [char::from_u32(initial), char::from_u32(vowel), last]
+    } else if let Some(&consonant) = u32::from(ch)
+        .checked_sub('ㄱ'.into())
+        .and_then(|i| COMPAT_CONSONANTS.get(i as usize))
+    {
+        [Some(consonant), None, None]
+    } else if ('ㅏ'..='ㅣ').contains(&ch) {
+        let vowel = FIRST_VOWEL + (u32::from(ch) - u32::from('ㅏ'));
+        [char::from_u32(vowel), None, None]
+    } else {
+        [Some(ch), None, None]
+    };
+
+    [first, vowel, last].into_iter().flatten()
+}
+
+/// Composes 2 conjoining jamo or a syllable & a jamo into a Hangul syllable, e.g. `ᄒ` & `ᅡ` into
+/// `하`, or `하` & `ᆫ` into `한`.
+///
+/// This is the inverse of [`decompose`] for the syllables, compatibility jamo aren't composed.
+pub fn compose(first: char, second: char) -> Option<char> {
+    let (first, second) = (u32::from(first), u32::from(second));
+    let initial = first.wrapping_sub(FIRST_INITIAL);
+    let vowel = second.wrapping_sub(FIRST_VOWEL);
+    let syllable = first.wrapping_sub(FIRST_SYLLABLE);
+    let last = second.wrapping_sub(NO_FINAL);
+
+    if initial < N_INITIALS && vowel < N_VOWELS {
+        char::from_u32(FIRST_SYLLABLE + initial * N_SYLLABLES_PER_INITIAL + vowel * N_FINALS)
+    } else if syllable < N_SYLLABLES && syllable % N_FINALS == 0 && (1..N_FINALS).contains(&last) {
+        char::from_u32(first + last)
+    } else {
+        None
+    }
+}
+
+/// Decomposes all Hangul syllables in `s` into conjoining jamo, see [`decompose`].
+pub fn decompose_str(s: &str) -> String {
+    s.chars().flat_map(decompose).collect()
+}
@@ -7,17 +7,46 @@
 
 extern crate alloc;
 
+#[cfg(feature = "emoji")]
+pub mod emoji;
+pub mod frozen;
+pub mod hangul;
+pub mod locale;
 pub mod lookalikes;
+pub mod marks;
+pub mod normalization;
+pub mod phonetic;
+#[cfg(feature = "pinyin")]
+pub mod pinyin;
+pub mod radix;
+#[cfg(test)]
+mod tests;
 
 use {
-    alloc::{boxed::Box, string::String, vec, vec::Vec},
-    core::mem::swap,
+    alloc::{collections::BTreeSet, string::String, vec, vec::Vec},
+    core::{
+        mem::{swap, take},
+        ops::RangeInclusive,
+        ptr,
+    },
+    lookalikes::{Lookalike, Lookalikes},
 };
 
 /// A tree that associates a string key with an `usize` index.
 #[derive(Debug, Default)]
 pub struct SearchTree {
-    nodes: Vec<(char, SearchTree)>,
+    root: SearchNode,
+    /// The largest index pushed so far
+    max_index: Option<usize>,
+    /// Whether an index may be associated with several keys, i.e. the indices weren't pushed in
+    /// increasing order
+    repeats: bool,
+}
+
+/// A node of a [`SearchTree`], associated with the part of the keys leading to it.
+#[derive(Debug, Default)]
+pub struct SearchNode {
+    nodes: Vec<(char, Self)>,
     end: Option<usize>,
 }
 
@@ -32,6 +61,60 @@ impl<'key> FromIterator<(usize, &'key str)> for SearchTree {
 }
 
 impl SearchTree {
+    /// The root of the tree.
+    pub const fn root(&self) -> &SearchNode {
+        &self.root
+    }
+
+    /// Get an immediate child of the root associated with the provided character.
+    pub fn get(&self, ch: char) -> Option<&SearchNode> {
+        self.root.get(ch)
+    }
+
+    /// Get a descendant of the root associated with the provided string, e.g. an empty string
+    /// refers to the root itself.
+    pub fn get_str(&self, key: &str) -> Option<&SearchNode> {
+        self.root.get_str(key)
+    }
+
+    /// Add a key to the tree
+    pub fn push(&mut self, key: &str, index: usize) {
+        self.repeats |= self.max_index.is_some_and(|max| index <= max);
+        self.max_index = self.max_index.max(Some(index));
+        self.root.push(key, index);
+    }
+
+    /// Add a key to the tree in the Normalization Form C, for searching it with
+    /// [`Searcher::with_normalization`], e.g. `e` followed by U+0301 is added as `é`.
+    pub fn push_normalized(&mut self, key: &str, index: usize) {
+        self.push(&normalization::nfc(key), index);
+    }
+
+    /// Calls a function on all the keys in the tree.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each<E>(&self, f: impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        self.root.for_each(f)
+    }
+
+    /// Add an index to the group of indices of a key, for keys shared by several indices. The tree
+    /// associates the key with the position of its group in `groups`.
+    pub(crate) fn push_to_group(&mut self, groups: &mut Vec<Vec<usize>>, key: &str, index: usize) {
+        let group = self.get_str(key).and_then(|node| node.end);
+        let group = group.unwrap_or_else(|| {
+            self.push(key, groups.len());
+            groups.push(vec![]);
+            groups.len() - 1
+        });
+        let group = &mut groups[group];
+        if group.last() != Some(&index) {
+            group.push(index);
+        }
+    }
+}
+
+impl SearchNode {
     /// Get an immediate child node associated with the provided character.
     pub fn get(&self, index: char) -> Option<&Self> {
         if self.nodes.last().is_none_or(|(last, _)| index > *last) {
@@ -44,8 +127,13 @@ impl SearchTree {
             .map(|i| &self.nodes[i].1)
     }
 
-    /// Add a key to the tree
-    pub fn push(&mut self, key: &str, index: usize) {
+    /// Get a descendant node associated with the provided string, e.g. an empty string refers to
+    /// the node itself.
+    pub fn get_str(&self, key: &str) -> Option<&Self> {
+        key.chars().try_fold(self, Self::get)
+    }
+
+    fn push(&mut self, key: &str, index: usize) {
         let mut iter = key.chars();
         let Some(ch) = iter.next() else {
             self.end = Some(index);
@@ -79,21 +167,201 @@ impl SearchTree {
     }
 }
 
+/// A position in a tree of keys that a [`Searcher`] walks through, between 2 characters of a key.
+///
+/// Implemented for `&SearchNode`, where every position is a node, & for the cursors of the other
+/// representations of a tree, e.g. [`radix::RadixCursor`] & [`frozen::FrozenNode`].
+pub trait Cursor: Copy {
+    /// Identifies the position within its tree.
+    type Id: Ord;
+
+    fn id(self) -> Self::Id;
+
+    /// The position after `ch`, if any key continues with it.
+    fn get(self, ch: char) -> Option<Self>;
+
+    /// The position after `key`, e.g. an empty string refers to the position itself.
+    fn get_str(self, key: &str) -> Option<Self> {
+        key.chars().try_fold(self, Self::get)
+    }
+
+    /// Calls a function on each of the characters in `range` that any key continues with, along
+    /// with the position after it.
+    fn for_each_child_in(self, range: &RangeInclusive<char>, f: impl FnMut(char, Self));
+
+    /// Calls a function on all the keys reachable from this position.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    fn for_each_index<E>(self, f: &mut impl FnMut(usize) -> Result<(), E>) -> Result<(), E>;
+}
+
+/// Conversion into a [`Cursor`] at the root of a tree, e.g. from `&SearchTree` or
+/// `&radix::RadixTree`.
+pub trait IntoCursor {
+    type Cursor: Cursor;
+
+    fn into_cursor(self) -> Self::Cursor;
+
+    /// Whether an index may be associated with several keys of the tree, in which case a
+    /// [`Searcher`] has to make sure to report it once. Assumed unless the tree says otherwise.
+    fn repeats_indices(&self) -> bool {
+        true
+    }
+}
+
+impl<C: Cursor> IntoCursor for C {
+    type Cursor = Self;
+
+    fn into_cursor(self) -> Self {
+        self
+    }
+}
+
+impl<'tree> IntoCursor for &'tree SearchTree {
+    type Cursor = &'tree SearchNode;
+
+    fn into_cursor(self) -> Self::Cursor {
+        &self.root
+    }
+
+    fn repeats_indices(&self) -> bool {
+        self.repeats
+    }
+}
+
+impl Cursor for &SearchNode {
+    type Id = *const SearchNode;
+
+    fn id(self) -> Self::Id {
+        ptr::from_ref(self)
+    }
+
+    fn get(self, ch: char) -> Option<Self> {
+        SearchNode::get(self, ch)
+    }
+
+    fn for_each_child_in(self, range: &RangeInclusive<char>, mut f: impl FnMut(char, Self)) {
+        let start = self.nodes.partition_point(|(ch, _)| ch < range.start());
+        self.nodes[start..]
+            .iter()
+            .take_while(|(ch, _)| ch <= range.end())
+            .for_each(|(ch, node)| f(*ch, node));
+    }
+
+    fn for_each_index<E>(self, f: &mut impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        self.for_each_base(f)
+    }
+}
+
+/// Costs of the mistakes in the input that a [`Searcher`] accounts for, in the same units as
+/// [`Lookalike::cost`].
+///
+/// # Example
+/// ```rust
+/// use permissive_search::*;
+///
+/// # let root = SearchTree::default();
+/// let searcher = Searcher::new(&root, lookalikes::all).with_penalties(Penalties {
+///     repetition: 1,
+///     ..Penalties::default()
+/// });
+/// # _ = searcher;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Penalties {
+    /// Cost of an input character that doesn't correspond to any character of the key.
+    pub insertion: u32,
+    /// Cost of a letter being typed twice where the key has it once, or vice versa, e.g.
+    /// "hunngarian" or "acommodate".
+    pub repetition: u32,
+    /// Cost of the combining marks of a character differing between the input & the key, e.g.
+    /// "x̂" typed instead of "x̃", see [`Searcher::with_normalization`].
+    pub marks: u32,
+}
+
+impl Default for Penalties {
+    fn default() -> Self {
+        Self {
+            insertion: 2 * Lookalike::DEFAULT_COST,
+            repetition: Lookalike::DEFAULT_COST / 2,
+            marks: Lookalike::DEFAULT_COST,
+        }
+    }
+}
+
+/// A position in consideration, along with the total cost of the mistakes made to reach it.
+#[derive(Debug, Clone, Copy)]
+struct State<'tree, C> {
+    node: C,
+    /// Input that must follow for the multi-character lookalike that led to `node` to apply.
+    pending: &'tree str,
+    cost: u32,
+    /// Whether the last input character along with its combining marks already differs from the
+    /// key, so that the marks can differ too at no extra cost.
+    mismatched: bool,
+}
+
+impl<C> State<'_, C> {
+    /// The state with the combining marks of the last input character differing from the key's.
+    fn with_mismatched_marks(self, penalty: u32) -> Self {
+        let cost = if self.mismatched {
+            self.cost
+        } else {
+            self.cost.saturating_add(penalty)
+        };
+        Self {
+            cost,
+            mismatched: true,
+            ..self
+        }
+    }
+}
+
 /// Storage for the state of a search through a [`SearchTree`].
-pub struct Searcher<'tree> {
-    root: &'tree SearchTree,
+///
+/// - `C` is the position in the searched tree, see [`Cursor`], which allows for searching other
+///   representations of a tree, e.g. [`radix::RadixTree`].
+/// - `L` is the source of lookalikes, see [`Searcher::new`]. The searcher is [`Clone`], [`Send`] &
+///   [`Sync`] if `L` is. Searchers with different sources can be stored together as
+///   `Searcher<Box<dyn Lookalikes + Send>>`.
+///
+/// # Example
+/// ```rust
+/// use {permissive_search::{lookalikes::Lookalikes, *}, std::{collections::HashMap, thread}};
+///
+/// static ROOT: std::sync::LazyLock<SearchTree> =
+///     std::sync::LazyLock::new(|| ["apple", "banana"].into_iter().enumerate().collect());
+///
+/// let mut sessions = HashMap::<u32, Searcher<Box<dyn Lookalikes + Send>>>::new();
+/// sessions.insert(1, Searcher::new(&*ROOT, Box::new(lookalikes::all)));
+/// sessions.insert(2, Searcher::new(&*ROOT, Box::new(lookalikes::ocr)));
+///
+/// let mut searcher = sessions.remove(&1).unwrap();
+/// thread::spawn(move || searcher.extend("appel".chars())).join().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Searcher<'tree, L, C = &'tree SearchNode> {
+    root: C,
+    /// Whether an index may be reached from several keys, see [`IntoCursor::repeats_indices`]
+    repeats: bool,
     input: String,
-    /// Nodes in consideration
-    considered: Vec<&'tree SearchTree>,
+    /// Positions in consideration, sorted by cost
+    considered: Vec<State<'tree, C>>,
     /// To be swapped with `considered` after every char input
-    new: Vec<&'tree SearchTree>,
-    /// Temporary buffer for similar chars gathered from `lookalike_gen`
-    lookalikes_buf: Vec<char>,
-    #[allow(clippy::type_complexity, reason = "it's not lol")]
-    lookalike_gen: Box<dyn FnMut(char, &mut Vec<char>)>,
+    new: Vec<State<'tree, C>>,
+    /// Temporary buffer for similar chars gathered from `lookalikes`
+    lookalikes_buf: Vec<Lookalike<'tree>>,
+    lookalikes: L,
+    penalties: Penalties,
+    max_cost: u32,
+    /// Characters that are optional both in the keys & in the input, see [`marks`]
+    skippable: &'static [&'static [RangeInclusive<char>]],
+    /// Whether the input is kept in the Normalization Form C
+    normalize: bool,
 }
 
-impl Extend<char> for Searcher<'_> {
+impl<'tree, L: Lookalikes<'tree>, C: Cursor> Extend<char> for Searcher<'tree, L, C> {
     fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
         for ch in iter {
             self.push(ch);
@@ -101,12 +369,14 @@ impl Extend<char> for Searcher<'_> {
     }
 }
 
-impl<'tree> Searcher<'tree> {
+impl<'tree, L: Lookalikes<'tree>, C: Cursor> Searcher<'tree, L, C> {
     /// Create a new searcher.
-    /// - `root` is the root of the tree to be searched.
-    /// - `iter_gen` is the function that returns an iterator over characters similar to the input one.
+    /// - `root` is the root of the tree to be searched, e.g. `&SearchTree`.
+    /// - `lookalikes` is the source of characters similar to the input one, e.g. a function that
+    ///   returns an iterator over them, see [`Lookalikes`].
     ///
-    /// The definition of similarity is defined by `iter_gen`.
+    /// The definition of similarity is defined by `lookalikes`. Plain `char`s yielded by it are
+    /// assigned [`Lookalike::DEFAULT_COST`].
     ///
     /// # Example
     /// ```rust
@@ -117,92 +387,323 @@ impl<'tree> Searcher<'tree> {
     /// let searcher = Searcher::new(&root, lookalikes::qwerty_misclicks);
     /// # _ = searcher;
     /// ```
-    pub fn new<I: Iterator<Item = char>>(
-        root: &'tree SearchTree,
-        mut iter_gen: impl 'static + FnMut(char) -> I,
-    ) -> Self {
+    pub fn new(root: impl IntoCursor<Cursor = C>, lookalikes: L) -> Self {
+        let repeats = root.repeats_indices();
+        let root = root.into_cursor();
         Self {
             root,
+            repeats,
             input: String::new(),
-            considered: vec![root],
+            considered: vec![State {
+                node: root,
+                pending: "",
+                cost: 0,
+                mismatched: false,
+            }],
             new: vec![],
             lookalikes_buf: vec![],
-            lookalike_gen: Box::new(move |ch, dst| dst.extend(iter_gen(ch))),
+            lookalikes,
+            penalties: Penalties::default(),
+            max_cost: u32::MAX,
+            skippable: &[],
+            normalize: false,
         }
     }
 
-    pub const fn root(&self) -> &'tree SearchTree {
+    /// Replace the costs of the mistakes in the input.
+    #[must_use]
+    pub fn with_penalties(mut self, penalties: Penalties) -> Self {
+        self.penalties = penalties;
+        self.recompute();
+        self
+    }
+
+    /// Stop considering the keys that can only be reached by making mistakes worth more than
+    /// `max_cost` in total. By default, no key is ever discarded because of its cost.
+    #[must_use]
+    pub fn with_max_cost(mut self, max_cost: u32) -> Self {
+        self.max_cost = max_cost;
+        self.recompute();
+        self
+    }
+
+    /// Treat the characters from any of the sets in `skippable` as optional: they may be omitted
+    /// from the input when present in a key, or typed when absent from it, at no cost.
+    ///
+    /// Meant for marks that are rarely typed, see [`marks`].
+    #[must_use]
+    pub fn with_skippable(mut self, skippable: &'static [&'static [RangeInclusive<char>]]) -> Self {
+        self.skippable = skippable;
+        self.recompute();
+        self
+    }
+
+    /// Keep the input in the Normalization Form C, e.g. compose a combining acute accent typed
+    /// after `e` into `é`, for searching keys added with [`SearchTree::push_normalized`].
+    ///
+    /// A character is also matched along with the combining marks that follow it, e.g. in `x̃`, as
+    /// a single unit: marks that differ between the input & the key, or are missing from one of
+    /// them, cost [`Penalties::marks`] once per character, or nothing more if the character itself
+    /// was mistyped.
+    /// ```rust
+    /// use permissive_search::*;
+    ///
+    /// let mut root = SearchTree::default();
+    /// root.push_normalized("cafe\u{301}", 0);
+    /// let mut searcher = Searcher::new(&root, lookalikes::all).with_normalization(true);
+    /// searcher.extend("café".chars());
+    /// # _ = searcher;
+    /// ```
+    #[must_use]
+    pub fn with_normalization(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        let input = take(&mut self.input);
+        self.input = if normalize {
+            normalization::nfc(&input)
+        } else {
+            input
+        };
+        self.recompute();
+        self
+    }
+
+    pub const fn root(&self) -> C {
         self.root
     }
 
-    pub fn input(&self) -> &str {
+    pub const fn input(&self) -> &str {
         self.input.as_str()
     }
 
     /// Push a character into the searched string
     pub fn push(&mut self, ch: char) {
+        if self.normalize {
+            // Only the characters since the last one that doesn't combine can be affected
+            let start = self
+                .input
+                .char_indices()
+                .rfind(|&(_, ch)| normalization::combining_class(ch) == 0)
+                .map_or(0, |(i, _)| i);
+            let mut cluster = String::from(&self.input[start..]);
+            cluster.push(ch);
+            let normalized = normalization::nfc(&cluster);
+            if normalized != cluster {
+                self.input.truncate(start);
+                self.input.push_str(&normalized);
+                self.recompute();
+                return;
+            }
+        }
+
+        let prev = self.input.chars().next_back();
         self.input.push(ch);
-        Self::compute_considerations(
-            ch,
-            &mut self.lookalikes_buf,
-            &mut self.lookalike_gen,
-            &mut self.considered,
-            &mut self.new,
-        );
+        self.compute_considerations(ch, prev);
     }
 
     /// Common impl for [`Searcher::push`] & [`Searcher::pop`]
-    fn compute_considerations(
-        ch: char,
-        lookalikes_buf: &mut Vec<char>,
-        lookalike_gen: &mut dyn FnMut(char, &mut Vec<char>),
-        considered: &mut Vec<&'tree SearchTree>,
-        new: &mut Vec<&'tree SearchTree>,
-    ) {
-        lookalikes_buf.clear();
-        lookalikes_buf.push(ch);
-        lookalike_gen(ch, lookalikes_buf);
+    /// - `prev` is the character input before `ch`, if any.
+    fn compute_considerations(&mut self, ch: char, prev: Option<char>) {
+        let is_mark = self.normalize && normalization::combining_class(ch) != 0;
+        // A character that isn't a mark ends the previous one along with its marks
+        if self.normalize && !is_mark {
+            self.skip_key_marks();
+        }
+
+        self.lookalikes_buf.clear();
+        self.lookalikes_buf.push(Lookalike::new(ch, 0));
+        self.lookalikes.lookalikes(ch, &mut self.lookalikes_buf);
+
+        self.new.clear();
+        let skippable = marks::contains(self.skippable, ch);
+        for state in &self.considered {
+            // The key doesn't have the typed mark
+            if skippable {
+                self.new.push(*state);
+            }
+
+            if !state.pending.is_empty() {
+                if let Some(pending) = state.pending.strip_prefix(ch) {
+                    self.new.push(State { pending, ..*state });
+                }
+                continue;
+            }
+
+            self.new
+                .extend(self.lookalikes_buf.iter().filter_map(|lookalike| {
+                    Some(State {
+                        node: state.node.get(lookalike.ch)?.get_str(lookalike.key_rest)?,
+                        pending: lookalike.input_rest,
+                        cost: state.cost.saturating_add(lookalike.cost),
+                        mismatched: lookalike.cost > 0 || (is_mark && state.mismatched),
+                    })
+                }));
+
+            if is_mark {
+                let mismatched = state.with_mismatched_marks(self.penalties.marks);
+                // The key doesn't have the typed mark
+                self.new.push(mismatched);
+                // The key has another mark instead
+                state
+                    .node
+                    .for_each_child_in(&normalization::MARKS, |key_ch, node| {
+                        if key_ch != ch && normalization::combining_class(key_ch) != 0 {
+                            self.new.push(State { node, ..mismatched });
+                        }
+                    });
+            }
 
-        new.clear();
-        new.extend(
-            considered
-                .iter()
-                .flat_map(|n| lookalikes_buf.iter().filter_map(|&ch| n.get(ch))),
-        );
+            let repetition = state.cost.saturating_add(self.penalties.repetition);
+            // The letter was typed twice, but the key only has it once
+            if prev == Some(ch) {
+                self.new.push(State {
+                    cost: repetition,
+                    ..*state
+                });
+            }
+            // The key has the letter twice, but it was only typed once
+            if let Some(node) = state.node.get(ch).and_then(|node| node.get(ch)) {
+                self.new.push(State {
+                    node,
+                    pending: "",
+                    cost: repetition,
+                    mismatched: false,
+                });
+            }
+        }
 
-        if !new.is_empty() {
-            swap(new, considered);
+        if self.new.is_empty() {
+            for state in &mut self.considered {
+                state.cost = state.cost.saturating_add(self.penalties.insertion);
+            }
+        } else {
+            swap(&mut self.new, &mut self.considered);
         }
+        self.skip_marks();
+
+        let max_cost = self.max_cost;
+        self.considered.retain(|state| state.cost <= max_cost);
+        // Only keeping the cheapest way to reach every node
+        self.considered.sort_unstable_by_key(|state| {
+            (
+                state.node.id(),
+                state.pending,
+                state.cost,
+                !state.mismatched,
+            )
+        });
+        self.considered
+            .dedup_by_key(|state| (state.node.id(), state.pending));
+        self.considered.sort_by_key(|state| state.cost);
     }
 
-    /// Remove the last character from the searched string, if present.
-    pub fn pop(&mut self) {
-        if self.input.pop().is_none() {
-            return;
+    /// Adds the positions reachable from the ones in consideration by skipping the combining marks
+    /// that the keys have after the last input character, but the input doesn't.
+    fn skip_key_marks(&mut self) {
+        let mut i = 0;
+        while let Some(&state) = self.considered.get(i) {
+            i += 1;
+            if !state.pending.is_empty() {
+                continue;
+            }
+
+            let skipped = state.with_mismatched_marks(self.penalties.marks);
+            state
+                .node
+                .for_each_child_in(&normalization::MARKS, |ch, node| {
+                    if normalization::combining_class(ch) != 0 {
+                        self.considered.push(State { node, ..skipped });
+                    }
+                });
         }
+    }
+
+    /// Adds the positions reachable from the ones in consideration by skipping the skippable
+    /// characters of the keys.
+    fn skip_marks(&mut self) {
+        let mut i = 0;
+        while let Some(&state) = self.considered.get(i) {
+            i += 1;
+            if !state.pending.is_empty() {
+                continue;
+            }
+
+            for range in self.skippable.iter().flat_map(|set| set.iter()) {
+                state.node.for_each_child_in(range, |_, node| {
+                    self.considered.push(State { node, ..state });
+                });
+            }
+        }
+    }
+
+    /// Recompute the nodes in consideration from scratch.
+    fn recompute(&mut self) {
         self.considered.clear();
-        self.considered.push(self.root);
-        for ch in self.input.chars() {
-            Self::compute_considerations(
-                ch,
-                &mut self.lookalikes_buf,
-                &mut self.lookalike_gen,
-                &mut self.considered,
-                &mut self.new,
-            );
+        self.considered.push(State {
+            node: self.root,
+            pending: "",
+            cost: 0,
+            mismatched: false,
+        });
+        self.skip_marks();
+        let input = take(&mut self.input);
+        let mut prev = None;
+        for ch in input.chars() {
+            self.compute_considerations(ch, prev);
+            prev = Some(ch);
+        }
+        self.input = input;
+    }
+
+    /// Remove the last character from the searched string, if present.
+    pub fn pop(&mut self) {
+        if self.input.pop().is_some() {
+            self.recompute();
         }
     }
 
     /// Calls a function on every key that could've been referred to by the current input.
     ///
+    /// The keys that can be reached by making cheaper mistakes are visited first, each key is
+    /// visited once. This includes the keys that the input is a part of a multi-character
+    /// lookalike of, e.g. with [`lookalikes::ocr`], the input `r` already refers to the keys
+    /// with an `m`.
+    ///
     /// # Errors
     /// The function doesn't fail itself, but it does propagate errors from the callback
     pub fn for_each_candidate<E>(
         &self,
         mut f: impl FnMut(usize) -> Result<(), E>,
     ) -> Result<(), E> {
-        self.considered
-            .iter()
-            .try_for_each(|n| n.for_each_base(&mut f))
+        self.for_each_candidate_with_cost(|index, _| f(index))
+    }
+
+    /// Like [`Searcher::for_each_candidate`], but also passes the total cost of the mistakes that
+    /// had to be made for the key to be referred to by the current input.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each_candidate_with_cost<E>(
+        &self,
+        mut f: impl FnMut(usize, u32) -> Result<(), E>,
+    ) -> Result<(), E> {
+        if let [state] = self.considered[..]
+            && !self.repeats
+        {
+            return state.node.for_each_index(&mut |index| f(index, state.cost));
+        }
+
+        // Different nodes in consideration may lead to the same key, e.g. when one of them was
+        // reached by skipping a repeated letter, and a single node may lead to the same index
+        // several times, e.g. when it was pushed under several keys sharing a prefix.
+        let mut visited = BTreeSet::new();
+        self.considered.iter().try_for_each(|state| {
+            state.node.for_each_index(&mut |index| {
+                if visited.insert(index) {
+                    f(index, state.cost)
+                } else {
+                    Ok(())
+                }
+            })
+        })
     }
 }
@@ -0,0 +1,110 @@
+//! Presets of lookalikes & [`Searcher`] settings for the languages with their own keyboard
+//! layouts or writing conventions.
+//!
+//! # Example
+//! ```rust
+//! use permissive_search::{locale::Locale, *};
+//!
+//! let keys = ["Müller", "Straße", "Stadt"];
+//! let root: SearchTree = keys.into_iter().enumerate().collect();
+//! let mut searcher = Locale::German.searcher(&root);
+//! searcher.extend("Mueller".chars());
+//! searcher.for_each_candidate::<()>(|i| Ok(println!("{}", keys[i])));
+//! ```
+
+use {
+    crate::{
+        Cursor, IntoCursor, Searcher,
+        lookalikes::{self, Lookalike, Lookalikes},
+        marks,
+    },
+    alloc::vec::Vec,
+    core::ops::RangeInclusive,
+};
+
+/// A language, along with the lookalikes that are relevant for the users who type in it.
+///
+/// Implements [`Lookalikes`] & can be passed to [`Searcher::new`] directly, or turned into a
+/// searcher with the other settings of the locale applied by [`Locale::searcher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// [`lookalikes::all`]
+    #[default]
+    Generic,
+    /// Misclicks on a QWERTZ keyboard, [`lookalikes::variants`] & [`lookalikes::umlauts`]
+    German,
+    /// Misclicks on a Turkish Q keyboard & [`lookalikes::turkic_variants`]
+    Turkish,
+    /// Misclicks on an Azerbaijani Latin keyboard & [`lookalikes::turkic_variants`]
+    Azerbaijani,
+    /// [`lookalikes::all`], [`lookalikes::romaji`] & [`lookalikes::compatibility`], with the input
+    /// normalised to compose the combining voicing marks
+    Japanese,
+    /// [`lookalikes::all`] & [`lookalikes::transliterations`]
+    Russian,
+    /// [`lookalikes::all`] & [`lookalikes::transliterations`]
+    Greek,
+    /// [`lookalikes::all`], with [`marks::ARABIC`] skipped
+    Arabic,
+    /// [`lookalikes::all`], with [`marks::HEBREW`] skipped
+    Hebrew,
+    /// [`lookalikes::all`] & [`lookalikes::indic`], with [`marks::INDIC`] skipped
+    ///
+    /// The input isn't normalised, since the letters with a nukta such as U+095B are decomposed by
+    /// NFC. Instead, [`lookalikes::indic`] matches them with the letters without it, while the
+    /// nukta itself is skipped.
+    Hindi,
+}
+
+impl Locale {
+    /// The marks that are optional in this locale, see [`Searcher::with_skippable`].
+    pub const fn skippable(self) -> &'static [&'static [RangeInclusive<char>]] {
+        match self {
+            Self::Arabic => &[marks::ARABIC],
+            Self::Hebrew => &[marks::HEBREW],
+            Self::Hindi => &[marks::INDIC],
+            _ => &[],
+        }
+    }
+
+    /// Whether the input is normalised in this locale, see [`Searcher::with_normalization`].
+    pub const fn normalizes(self) -> bool {
+        matches!(self, Self::Japanese)
+    }
+
+    /// Create a searcher with the lookalikes & the settings of this locale.
+    pub fn searcher<'tree, C: Cursor>(
+        self,
+        root: impl IntoCursor<Cursor = C>,
+    ) -> Searcher<'tree, Self, C> {
+        Searcher::new(root, self)
+            .with_skippable(self.skippable())
+            .with_normalization(self.normalizes())
+    }
+}
+
+impl<'r> Lookalikes<'r> for Locale {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        match self {
+            Self::Generic | Self::Arabic | Self::Hebrew => lookalikes::all.lookalikes(ch, dst),
+            Self::German => lookalikes::qwertz_misclicks
+                .chain(lookalikes::variants)
+                .chain(lookalikes::umlauts)
+                .lookalikes(ch, dst),
+            Self::Turkish => lookalikes::turkish_q_misclicks
+                .chain(lookalikes::turkic_variants)
+                .lookalikes(ch, dst),
+            Self::Azerbaijani => lookalikes::azerbaijani_misclicks
+                .chain(lookalikes::turkic_variants)
+                .lookalikes(ch, dst),
+            Self::Japanese => lookalikes::all
+                .chain(lookalikes::romaji)
+                .chain(lookalikes::compatibility)
+                .lookalikes(ch, dst),
+            Self::Russian | Self::Greek => lookalikes::all
+                .chain(lookalikes::transliterations)
+                .lookalikes(ch, dst),
+            Self::Hindi => lookalikes::all.chain(lookalikes::indic).lookalikes(ch, dst),
+        }
+    }
+}
@@ -1,287 +1,1812 @@
 //! Functions that return iterators over similar characters.
 
-/// Returns `(row, col)`
-const fn find_char<const N_ROWS: usize, const N_COLS: usize>(
+mod confusables;
+mod rules;
+
+pub use rules::{ParseError, ParseErrorKind, RuleSet};
+
+use {
+    alloc::{boxed::Box, vec::Vec},
+    core::{iter, ops::RangeInclusive},
+};
+
+/// Declares a function that returns an iterator over the lookalikes of a character from a table,
+/// like [`variants`].
+///
+/// Every entry is a pattern for the typed character followed by its lookalikes. The table is
+/// compiled into a `match`, so the function doesn't allocate & a character typed in 2 entries is
+/// reported as an unreachable pattern.
+///
+/// # Example
+/// ```rust
+/// use permissive_search::{lookalikes::Lookalikes, *};
+///
+/// lookalike_table! {
+///     /// Letters that digits & symbols are typed in place of
+///     pub fn digit_letters {
+///         '0' => 'O' 'o',
+///         '1' => 'l' 'I',
+///         '5' | '$' => 'S',
+///     }
+/// }
+///
+/// # let root = SearchTree::default();
+/// let searcher = Searcher::new(&root, lookalikes::all.chain(digit_letters));
+/// # _ = searcher;
+/// ```
+#[macro_export]
+macro_rules! lookalike_table {
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident {
+            $($typed:pat => $($lookalike:literal)*),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis fn $name(ch: char) -> impl ::core::iter::Iterator<Item = char> + ::core::clone::Clone {
+            let chars: &[char] = match ch {
+                $($typed => &[$($lookalike),*],)*
+                _ => &[],
+            };
+            chars.iter().copied()
+        }
+    };
+}
+
+/// A character that could've been meant instead of the typed one.
+///
+/// More generally, the typed character followed by `input_rest` could've been meant as `ch`
+/// followed by `key_rest`, which allows for lookalikes spanning several characters, e.g. `rn`
+/// typed instead of `m`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lookalike<'a> {
+    /// The character in the key.
+    pub ch: char,
+    /// The characters that follow `ch` in the key.
+    pub key_rest: &'a str,
+    /// The characters that must be typed after the current one for this lookalike to apply.
+    pub input_rest: &'a str,
+    /// How unlikely it is for the typed character to be meant as `ch`, an exact match costs 0.
+    pub cost: u32,
+}
+
+impl From<char> for Lookalike<'_> {
+    fn from(ch: char) -> Self {
+        Self::new(ch, Self::DEFAULT_COST)
+    }
+}
+
+impl<'a> Lookalike<'a> {
+    /// The cost assigned to lookalikes produced from plain `char`s.
+    ///
+    /// Equals the cost of missing the intended key by one key's width in [`touch_misclicks`].
+    pub const DEFAULT_COST: u32 = 4;
+
+    /// The cost of the lookalikes that spell the intended character in another way rather than
+    /// being a typo, e.g. the transcriptions in [`umlauts`], [`transliterations`] & [`romaji`].
+    ///
+    /// Lower than [`Lookalike::DEFAULT_COST`], so that a key spelled in another way ranks above the
+    /// keys the input is a typo of.
+    pub const TRANSCRIPTION_COST: u32 = 1;
+
+    pub const fn new(ch: char, cost: u32) -> Self {
+        Self {
+            ch,
+            key_rest: "",
+            input_rest: "",
+            cost,
+        }
+    }
+
+    /// Create a lookalike spanning several characters: the typed one followed by `input_rest`
+    /// could've been meant as `key`.
+    ///
+    /// # Panics
+    /// Panics if `key` is empty.
+    pub fn sequence(input_rest: &'a str, key: &'a str, cost: u32) -> Self {
+        let mut key = key.chars();
+        let ch = key
+            .next()
+            .expect("the key of a lookalike must not be empty");
+        Self {
+            ch,
+            key_rest: key.as_str(),
+            input_rest,
+            cost,
+        }
+    }
+}
+
+/// A source of lookalikes, e.g. one of the functions of this module or a combination of them.
+///
+/// Implemented for any function that returns an iterator over `char`s or [`Lookalike`]s, plain
+/// `char`s are assigned [`Lookalike::DEFAULT_COST`]. Use [`Lookalikes::by_ref`] to share one
+/// source between several [`Searcher`](crate::Searcher)s.
+///
+/// # Example
+/// ```rust
+/// use permissive_search::{lookalikes::{self, Lookalikes, Script}, *};
+///
+/// let lookalikes = lookalikes::qwerty_misclicks
+///     .chain(lookalikes::variants)
+///     .chain(lookalikes::transliterations.only_for(Script::Latin).weighted(50))
+///     .without(';');
+/// # let root = SearchTree::default();
+/// let searcher = Searcher::new(&root, lookalikes);
+/// # _ = searcher;
+/// ```
+pub trait Lookalikes<'r> {
+    /// Appends the lookalikes of the typed character `ch` to `dst`.
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>);
+
+    /// Combine the lookalikes from `self` & `other`.
+    fn chain<L: Lookalikes<'r>>(self, other: L) -> Chain<Self, L>
+    where
+        Self: Sized,
+    {
+        Chain {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Only produce lookalikes for the typed characters of `script`.
+    fn only_for(self, script: Script) -> OnlyFor<Self>
+    where
+        Self: Sized,
+    {
+        OnlyFor {
+            inner: self,
+            script,
+        }
+    }
+
+    /// Scale the costs of the lookalikes, e.g. by 50 to make them half as costly.
+    fn weighted(self, percent: u32) -> Weighted<Self>
+    where
+        Self: Sized,
+    {
+        Weighted {
+            inner: self,
+            percent,
+        }
+    }
+
+    /// Also produce the inverse of every lookalike, e.g. `b` for `a` if `self` produces `a` for
+    /// `b`.
+    ///
+    /// This calls [`Lookalikes::lookalikes`] for every `char` there is, so it's best done once.
+    fn symmetric(self) -> Symmetric<'r, Self>
+    where
+        Self: Sized,
+    {
+        let mut inverse = Vec::new();
+        let mut buf = Vec::new();
+        for typed in char::MIN..=char::MAX {
+            buf.clear();
+            self.lookalikes(typed, &mut buf);
+            inverse.extend(buf.iter().filter_map(|&lookalike| {
+                let exact = lookalike.ch == typed
+                    && lookalike.key_rest.is_empty()
+                    && lookalike.input_rest.is_empty();
+                (!exact).then_some((
+                    lookalike.ch,
+                    Lookalike {
+                        ch: typed,
+                        key_rest: lookalike.input_rest,
+                        input_rest: lookalike.key_rest,
+                        cost: lookalike.cost,
+                    },
+                ))
+            }));
+        }
+        inverse.sort_by_key(|(ch, _)| *ch);
+
+        Symmetric {
+            inner: self,
+            inverse,
+        }
+    }
+
+    /// Never produce `ch` as a lookalike.
+    fn without(self, ch: char) -> Without<Self>
+    where
+        Self: Sized,
+    {
+        Without { inner: self, ch }
+    }
+
+    /// Borrow the source instead of consuming it, e.g. to pass it to several
+    /// [`Searcher`](crate::Searcher)s.
+    ///
+    /// # Example
+    /// ```rust
+    /// use permissive_search::{lookalikes::{self, Lookalike, Lookalikes}, *};
+    ///
+    /// // Rules loaded at runtime
+    /// let rules = vec![(String::from("ph"), String::from("f"))];
+    /// let lookalikes = lookalikes::all.chain(|ch| {
+    ///     rules.iter().filter_map(move |(input, key)| {
+    ///         Some(Lookalike::sequence(input.strip_prefix(ch)?, key, 1))
+    ///     })
+    /// });
+    ///
+    /// # let root = SearchTree::default();
+    /// let searcher = Searcher::new(&root, lookalikes.by_ref());
+    /// let other = Searcher::new(&root, lookalikes.by_ref());
+    /// # _ = (searcher, other);
+    /// ```
+    fn by_ref(&self) -> ByRef<'_, Self>
+    where
+        Self: Sized,
+    {
+        ByRef(self)
+    }
+}
+
+/// A value that can be used as a [`Lookalike`] that lives for `'r`, i.e. a `char` or a
+/// [`Lookalike`] that lives at least as long.
+pub trait IntoLookalike<'r> {
+    fn into_lookalike(self) -> Lookalike<'r>;
+}
+
+impl<'r> IntoLookalike<'r> for char {
+    fn into_lookalike(self) -> Lookalike<'r> {
+        self.into()
+    }
+}
+
+impl<'a: 'r, 'r> IntoLookalike<'r> for Lookalike<'a> {
+    fn into_lookalike(self) -> Lookalike<'r> {
+        self
+    }
+}
+
+impl<'r, F, I> Lookalikes<'r> for F
+where
+    F: Fn(char) -> I,
+    I: IntoIterator<Item: IntoLookalike<'r>>,
+{
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        dst.extend(self(ch).into_iter().map(IntoLookalike::into_lookalike));
+    }
+}
+
+impl<'r> Lookalikes<'r> for Box<dyn Lookalikes<'r> + '_> {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        (**self).lookalikes(ch, dst);
+    }
+}
+
+impl<'r> Lookalikes<'r> for Box<dyn Lookalikes<'r> + Send + '_> {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        (**self).lookalikes(ch, dst);
+    }
+}
+
+impl<'r> Lookalikes<'r> for Box<dyn Lookalikes<'r> + Send + Sync + '_> {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        (**self).lookalikes(ch, dst);
+    }
+}
+
+/// A borrowed source of lookalikes, see [`Lookalikes::by_ref`].
+#[derive(Debug)]
+pub struct ByRef<'a, L: ?Sized>(&'a L);
+
+impl<'a, L: ?Sized> ByRef<'a, L> {
+    /// Borrow a source of lookalikes, unlike [`Lookalikes::by_ref`], also works for trait objects.
+    pub const fn new(lookalikes: &'a L) -> Self {
+        Self(lookalikes)
+    }
+}
+
+impl<L: ?Sized> Clone for ByRef<'_, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: ?Sized> Copy for ByRef<'_, L> {}
+
+impl<'r, L: ?Sized + Lookalikes<'r>> Lookalikes<'r> for ByRef<'_, L> {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        self.0.lookalikes(ch, dst);
+    }
+}
+
+/// Lookalikes from 2 sources, see [`Lookalikes::chain`].
+#[derive(Debug, Clone, Copy)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<'r, A: Lookalikes<'r>, B: Lookalikes<'r>> Lookalikes<'r> for Chain<A, B> {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        self.first.lookalikes(ch, dst);
+        self.second.lookalikes(ch, dst);
+    }
+}
+
+/// Lookalikes for the characters of one script, see [`Lookalikes::only_for`].
+#[derive(Debug, Clone, Copy)]
+pub struct OnlyFor<L> {
+    inner: L,
+    script: Script,
+}
+
+impl<'r, L: Lookalikes<'r>> Lookalikes<'r> for OnlyFor<L> {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        if Script::of(ch) == Some(self.script) {
+            self.inner.lookalikes(ch, dst);
+        }
+    }
+}
+
+/// Lookalikes with scaled costs, see [`Lookalikes::weighted`].
+#[derive(Debug, Clone, Copy)]
+pub struct Weighted<L> {
+    inner: L,
+    percent: u32,
+}
+
+impl<'r, L: Lookalikes<'r>> Lookalikes<'r> for Weighted<L> {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        let start = dst.len();
+        self.inner.lookalikes(ch, dst);
+        for lookalike in &mut dst[start..] {
+            lookalike.cost = lookalike.cost.saturating_mul(self.percent) / 100;
+        }
+    }
+}
+
+/// Lookalikes along with their inverses, see [`Lookalikes::symmetric`].
+#[derive(Debug, Clone)]
+pub struct Symmetric<'r, L> {
+    inner: L,
+    /// Inverses of the lookalikes of `inner`, sorted by the typed character
+    inverse: Vec<(char, Lookalike<'r>)>,
+}
+
+impl<'r, L: Lookalikes<'r>> Lookalikes<'r> for Symmetric<'r, L> {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        self.inner.lookalikes(ch, dst);
+        let start = self.inverse.partition_point(|(typed, _)| *typed < ch);
+        dst.extend(
+            self.inverse[start..]
+                .iter()
+                .take_while(|(typed, _)| *typed == ch)
+                .map(|(_, lookalike)| *lookalike),
+        );
+    }
+}
+
+/// Lookalikes except for one character, see [`Lookalikes::without`].
+#[derive(Debug, Clone, Copy)]
+pub struct Without<L> {
+    inner: L,
     ch: char,
-    layout: &[[char; N_COLS]; N_ROWS],
-) -> Option<(usize, usize)> {
+}
+
+impl<'r, L: Lookalikes<'r>> Lookalikes<'r> for Without<L> {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        let start = dst.len();
+        self.inner.lookalikes(ch, dst);
+        let mut i = start;
+        while i < dst.len() {
+            if dst[i].ch == self.ch {
+                dst.swap_remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+/// A writing system, see [`Lookalikes::only_for`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Bengali,
+    Gurmukhi,
+    Gujarati,
+    Oriya,
+    Tamil,
+    Telugu,
+    Kannada,
+    Malayalam,
+    Thai,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Han,
+}
+
+impl Script {
+    /// The script of a letter or a mark, `None` for characters shared between scripts, e.g.
+    /// digits & punctuation, and for the scripts not listed in [`Script`].
+    pub fn of(ch: char) -> Option<Self> {
+        const RANGES: &[(RangeInclusive<char>, Script)] = &[
+            ('A'..='Z', Script::Latin),
+            ('a'..='z', Script::Latin),
+            ('ª'..='ª', Script::Latin),
+            ('º'..='º', Script::Latin),
+            ('À'..='Ö', Script::Latin),
+            ('Ø'..='ö', Script::Latin),
+            ('ø'..='ʯ', Script::Latin),
+            ('\u{370}'..='\u{3FF}', Script::Greek),
+            ('\u{400}'..='\u{52F}', Script::Cyrillic),
+            ('\u{530}'..='\u{58F}', Script::Armenian),
+            ('\u{590}'..='\u{5FF}', Script::Hebrew),
+            ('\u{600}'..='\u{6FF}', Script::Arabic),
+            ('\u{750}'..='\u{77F}', Script::Arabic),
+            ('\u{900}'..='\u{97F}', Script::Devanagari),
+            ('\u{980}'..='\u{9FF}', Script::Bengali),
+            ('\u{A00}'..='\u{A7F}', Script::Gurmukhi),
+            ('\u{A80}'..='\u{AFF}', Script::Gujarati),
+            ('\u{B00}'..='\u{B7F}', Script::Oriya),
+            ('\u{B80}'..='\u{BFF}', Script::Tamil),
+            ('\u{C00}'..='\u{C7F}', Script::Telugu),
+            ('\u{C80}'..='\u{CFF}', Script::Kannada),
+            ('\u{D00}'..='\u{D7F}', Script::Malayalam),
+            ('\u{E00}'..='\u{E7F}', Script::Thai),
+            ('\u{1100}'..='\u{11FF}', Script::Hangul),
+            ('\u{1E00}'..='\u{1EFF}', Script::Latin),
+            ('\u{1F00}'..='\u{1FFF}', Script::Greek),
+            ('\u{3040}'..='\u{309F}', Script::Hiragana),
+            ('\u{30A0}'..='\u{30FF}', Script::Katakana),
+            ('\u{3130}'..='\u{318F}', Script::Hangul),
+            ('\u{31F0}'..='\u{31FF}', Script::Katakana),
+            ('\u{3400}'..='\u{4DBF}', Script::Han),
+            ('\u{4E00}'..='\u{9FFF}', Script::Han),
+            ('\u{AC00}'..='\u{D7AF}', Script::Hangul),
+            ('\u{F900}'..='\u{FAFF}', Script::Han),
+            ('\u{FF66}'..='\u{FF9F}', Script::Katakana),
+            ('\u{20000}'..='\u{2FFFF}', Script::Han),
+        ];
+
+        RANGES
+            .iter()
+            .find(|(range, _)| range.contains(&ch))
+            .map(|(_, script)| *script)
+    }
+}
+
+/// Adds `ch` to the set of misclicks of `typed`, unless it's already there, is NUL or is `typed`.
+const fn insert_misclick<const N: usize>(set: &mut [char; N], typed: char, ch: char) {
+    if ch == '\0' || ch == typed {
+        return;
+    }
+    let mut i = 0;
+    while set[i] != '\0' {
+        if set[i] == ch {
+            return;
+        }
+        i += 1;
+    }
+    set[i] = ch;
+}
+
+/// A keyboard layout: the characters on the keys of every row, without & with Shift pressed.
+type Layout = [[[char; 14]; 5]; 2];
+
+/// The maximum number of misclicks of a character: the other character on its key & the 2
+/// characters on each of the 8 keys around it
+const N_MISCLICKS: usize = 17;
+
+/// The maximum number of distinct characters on a layout
+const N_CHARS: usize = size_of::<Layout>() / size_of::<char>();
+
+/// The misclicks of every character on a keyboard, sorted by the character & padded with
+/// `char::MAX`.
+type Misclicks = [(char, [char; N_MISCLICKS]); N_CHARS];
+
+/// Returns the index of the entry of `ch` in `misclicks`, inserting it if it's missing.
+const fn entry_of(misclicks: &mut Misclicks, ch: char) -> usize {
+    let mut i = 0;
+    while misclicks[i].0 < ch {
+        i += 1;
+    }
+    if misclicks[i].0 != ch {
+        let mut j = N_CHARS - 1;
+        while j > i {
+            misclicks[j] = misclicks[j - 1];
+            j -= 1;
+        }
+        misclicks[i] = (ch, ['\0'; N_MISCLICKS]);
+    }
+    i
+}
+
+/// Builds the sets of misclicks of all characters of a layout.
+const fn misclicks_of(layout: &Layout) -> Misclicks {
+    let [unshifted, shifted] = layout;
+    let n_rows = unshifted.len();
+    let n_cols = unshifted[0].len();
+
+    let mut res = [(char::MAX, ['\0'; N_MISCLICKS]); N_CHARS];
+    // Visiting every key & adding its neighbours to the sets of both characters on it
     let mut row = 0;
-    while row < N_ROWS {
+    while row < n_rows {
         let mut col = 0;
-        while col < N_COLS {
-            if layout[row][col] == ch {
-                return Some((row, col));
+        while col < n_cols {
+            let key = [unshifted[row][col], shifted[row][col]];
+            let mut k = 0;
+            while k < key.len() {
+                let ch = key[k];
+                k += 1;
+                if ch == '\0' {
+                    continue;
+                }
+                let i = entry_of(&mut res, ch);
+                let set = &mut res[i].1;
+                insert_misclick(set, ch, key[k % 2]);
+
+                let mut nb_row = row.saturating_sub(1);
+                while nb_row <= row + 1 && nb_row < n_rows {
+                    let mut nb_col = col.saturating_sub(1);
+                    while nb_col <= col + 1 && nb_col < n_cols {
+                        insert_misclick(set, ch, unshifted[nb_row][nb_col]);
+                        insert_misclick(set, ch, shifted[nb_row][nb_col]);
+                        nb_col += 1;
+                    }
+                    nb_row += 1;
+                }
             }
             col += 1;
         }
         row += 1;
     }
 
-    None
+    res
+}
+
+/// Looks up the misclicks of `ch` built by [`misclicks_of`].
+fn misclicks(misclicks: &'static Misclicks, ch: char) -> impl Iterator<Item = char> + Clone {
+    misclicks
+        .binary_search_by_key(&ch, |(ch, _)| *ch)
+        .map_or(&[][..], |i| &misclicks[i].1[..])
+        .iter()
+        .copied()
+        .take_while(|c| *c != '\0')
 }
 
 /// All characters that `ch` could've been a misclick of.
 ///
 /// E.g. if the user typed in `a`, it could mean that they meant `a`, or (assuming their keybaord
 /// is in the QWERTY layout) they've misclicked one of the following: `q`, `w`, `s`, `x`, `z`
+///
+/// The modelled keyboard has all the character keys of both the ANSI & the ISO layouts, including
+/// the space bar, which is considered to be right below the keys from `c` to `m`.
 pub fn qwerty_misclicks(ch: char) -> impl Iterator<Item = char> + Clone {
-    static LAYOUT: [[char; 10]; 4] = [
-        ['1', '2', '3', '4', '5', '6', '7', '8', '9', '0'],
-        ['q', 'w', 'e', 'r', 't', 'y', 'u', 'i', 'o', 'p'],
-        ['a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', ';'],
-        ['z', 'x', 'c', 'v', 'b', 'n', 'm', ',', '.', '/'],
+    // NULs are the gaps between the keys, the ISO key is to the left of `z`
+    static LAYOUT: Layout = [
+        [
+            [
+                '`', '1', '2', '3', '4', '5', '6', '7', '8', '9', '0', '-', '=', '\0',
+            ],
+            [
+                '\0', 'q', 'w', 'e', 'r', 't', 'y', 'u', 'i', 'o', 'p', '[', ']', '\\',
+            ],
+            [
+                '\0', 'a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', ';', '\'', '\0', '\0',
+            ],
+            [
+                '\\', 'z', 'x', 'c', 'v', 'b', 'n', 'm', ',', '.', '/', '\0', '\0', '\0',
+            ],
+            [
+                '\0', '\0', '\0', ' ', ' ', ' ', ' ', ' ', '\0', '\0', '\0', '\0', '\0', '\0',
+            ],
+        ],
+        [
+            [
+                '~', '!', '@', '#', '$', '%', '^', '&', '*', '(', ')', '_', '+', '\0',
+            ],
+            [
+                '\0', 'Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P', '{', '}', '|',
+            ],
+            [
+                '\0', 'A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L', ':', '"', '\0', '\0',
+            ],
+            [
+                '|', 'Z', 'X', 'C', 'V', 'B', 'N', 'M', '<', '>', '?', '\0', '\0', '\0',
+            ],
+            [
+                '\0', '\0', '\0', ' ', ' ', ' ', ' ', ' ', '\0', '\0', '\0', '\0', '\0', '\0',
+            ],
+        ],
     ];
-    static SHIFTED_LAYOUT: [[char; 10]; 4] = [
-        ['!', '@', '#', '$', '%', '^', '&', '*', '(', ')'],
-        ['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P'],
-        ['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L', ':'],
-        ['Z', 'X', 'C', 'V', 'B', 'N', 'M', '<', '>', '?'],
+    static MISCLICKS: Misclicks = misclicks_of(&LAYOUT);
+
+    misclicks(&MISCLICKS, ch)
+}
+
+/// All characters that `ch` could've been a misclick of on a keyboard in the German QWERTZ layout.
+///
+/// Same as [`qwerty_misclicks`], except for the layout: the ISO key, `<`, is to the left of `y`,
+/// and the characters reachable only with the right Alt key, e.g. `@` & `€`, aren't modelled.
+pub fn qwertz_misclicks(ch: char) -> impl Iterator<Item = char> + Clone {
+    // NULs are the gaps between the keys, `´` & `^` are dead keys but can still be typed
+    static LAYOUT: Layout = [
+        [
+            [
+                '^', '1', '2', '3', '4', '5', '6', '7', '8', '9', '0', 'ß', '´', '\0',
+            ],
+            [
+                '\0', 'q', 'w', 'e', 'r', 't', 'z', 'u', 'i', 'o', 'p', 'ü', '+', '\0',
+            ],
+            [
+                '\0', 'a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'ö', 'ä', '#', '\0',
+            ],
+            [
+                '<', 'y', 'x', 'c', 'v', 'b', 'n', 'm', ',', '.', '-', '\0', '\0', '\0',
+            ],
+            [
+                '\0', '\0', '\0', ' ', ' ', ' ', ' ', ' ', '\0', '\0', '\0', '\0', '\0', '\0',
+            ],
+        ],
+        [
+            [
+                '°', '!', '"', '§', '$', '%', '&', '/', '(', ')', '=', '?', '`', '\0',
+            ],
+            [
+                '\0', 'Q', 'W', 'E', 'R', 'T', 'Z', 'U', 'I', 'O', 'P', 'Ü', '*', '\0',
+            ],
+            [
+                '\0', 'A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L', 'Ö', 'Ä', '\'', '\0',
+            ],
+            [
+                '>', 'Y', 'X', 'C', 'V', 'B', 'N', 'M', ';', ':', '_', '\0', '\0', '\0',
+            ],
+            [
+                '\0', '\0', '\0', ' ', ' ', ' ', ' ', ' ', '\0', '\0', '\0', '\0', '\0', '\0',
+            ],
+        ],
     ];
+    static MISCLICKS: Misclicks = misclicks_of(&LAYOUT);
 
-    /// The number of ASCII characters that are printable & typeable
-    const N_TYPEABLES: usize = (b'~' - b' ') as usize;
-
-    /// The maximum number of misclicks this iterator can produce
-    const N_MISCLICKS: usize = 17;
-
-    static MISCLICKS: [[char; N_MISCLICKS]; N_TYPEABLES] = {
-        const N_ROWS: usize = LAYOUT.len();
-        const N_COLS: usize = LAYOUT[0].len();
-        assert!(size_of_val(&LAYOUT) == size_of_val(&SHIFTED_LAYOUT));
-
-        let mut res = [['\0'; 17]; N_TYPEABLES];
-        let mut next_byte = b' ';
-        while next_byte <= b'~' {
-            let ch = next_byte as char;
-
-            // Assembling the set
-            let byte = next_byte;
-            next_byte += 1;
-            let (row, col, toggled) = if let Some((row, col)) = find_char(ch, &LAYOUT) {
-                (row, col, SHIFTED_LAYOUT[row][col])
-            } else if let Some((row, col)) = find_char(ch, &SHIFTED_LAYOUT) {
-                (row, col, LAYOUT[row][col])
-            } else {
-                continue;
-            };
-            let (toggled_p, set) = res[(byte - b' ') as usize].split_first_mut().unwrap();
-            *toggled_p = toggled;
+    misclicks(&MISCLICKS, ch)
+}
 
-            if row > 0 {
-                if col > 0 {
-                    set[0] = LAYOUT[row - 1][col - 1];
-                    set[8] = SHIFTED_LAYOUT[row - 1][col - 1];
-                }
-                set[1] = LAYOUT[row - 1][col];
-                set[9] = SHIFTED_LAYOUT[row - 1][col];
-                if col < N_COLS - 1 {
-                    set[2] = LAYOUT[row - 1][col + 1];
-                    set[10] = SHIFTED_LAYOUT[row - 1][col + 1];
-                }
-            }
-            if col > 0 {
-                set[3] = LAYOUT[row][col - 1];
-                set[11] = SHIFTED_LAYOUT[row][col - 1];
-            }
-            if col < N_COLS - 1 {
-                set[4] = LAYOUT[row][col + 1];
-                set[12] = SHIFTED_LAYOUT[row][col + 1];
-            }
-            if row < N_ROWS - 1 {
-                if col > 0 {
-                    set[5] = LAYOUT[row + 1][col - 1];
-                    set[13] = SHIFTED_LAYOUT[row + 1][col - 1];
-                }
-                set[6] = LAYOUT[row + 1][col];
-                set[14] = SHIFTED_LAYOUT[row + 1][col];
-                if col < N_COLS - 1 {
-                    set[7] = LAYOUT[row + 1][col + 1];
-                    set[15] = SHIFTED_LAYOUT[row + 1][col + 1];
-                }
-            }
+/// All characters that `ch` could've been a misclick of on a keyboard in the Turkish Q layout.
+///
+/// Same as [`qwerty_misclicks`], except for the layout: the dotted `i` & the dotless `ı` are on
+/// separate keys, shifted into `İ` & `I` respectively.
+pub fn turkish_q_misclicks(ch: char) -> impl Iterator<Item = char> + Clone {
+    // NULs are the gaps between the keys
+    static LAYOUT: Layout = [
+        [
+            [
+                '"', '1', '2', '3', '4', '5', '6', '7', '8', '9', '0', '*', '-', '\0',
+            ],
+            [
+                '\0', 'q', 'w', 'e', 'r', 't', 'y', 'u', 'ı', 'o', 'p', 'ğ', 'ü', '\0',
+            ],
+            [
+                '\0', 'a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'ş', 'i', ',', '\0',
+            ],
+            [
+                '<', 'z', 'x', 'c', 'v', 'b', 'n', 'm', 'ö', 'ç', '.', '\0', '\0', '\0',
+            ],
+            [
+                '\0', '\0', '\0', ' ', ' ', ' ', ' ', ' ', '\0', '\0', '\0', '\0', '\0', '\0',
+            ],
+        ],
+        [
+            [
+                'é', '!', '\'', '^', '+', '%', '&', '/', '(', ')', '=', '?', '_', '\0',
+            ],
+            [
+                '\0', 'Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P', 'Ğ', 'Ü', '\0',
+            ],
+            [
+                '\0', 'A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L', 'Ş', 'İ', ';', '\0',
+            ],
+            [
+                '>', 'Z', 'X', 'C', 'V', 'B', 'N', 'M', 'Ö', 'Ç', ':', '\0', '\0', '\0',
+            ],
+            [
+                '\0', '\0', '\0', ' ', ' ', ' ', ' ', ' ', '\0', '\0', '\0', '\0', '\0', '\0',
+            ],
+        ],
+    ];
+    static MISCLICKS: Misclicks = misclicks_of(&LAYOUT);
+
+    misclicks(&MISCLICKS, ch)
+}
+
+/// All characters that `ch` could've been a misclick of on a keyboard in the Azerbaijani Latin
+/// layout.
+///
+/// Same as [`qwerty_misclicks`], except for the layout: the letters of the Azerbaijani alphabet
+/// take the place of `w` & of the punctuation to the right of the letters, with the dotted `i` &
+/// the dotless `ı` shifted into `İ` & `I` respectively.
+pub fn azerbaijani_misclicks(ch: char) -> impl Iterator<Item = char> + Clone {
+    // NULs are the gaps between the keys
+    static LAYOUT: Layout = [
+        [
+            [
+                '`', '1', '2', '3', '4', '5', '6', '7', '8', '9', '0', '-', '=', '\0',
+            ],
+            [
+                '\0', 'q', 'ü', 'e', 'r', 't', 'y', 'u', 'i', 'o', 'p', 'ö', 'ğ', '\\',
+            ],
+            [
+                '\0', 'a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'ı', 'ə', '\0', '\0',
+            ],
+            [
+                '\0', 'z', 'x', 'c', 'v', 'b', 'n', 'm', 'ç', 'ş', '.', '\0', '\0', '\0',
+            ],
+            [
+                '\0', '\0', '\0', ' ', ' ', ' ', ' ', ' ', '\0', '\0', '\0', '\0', '\0', '\0',
+            ],
+        ],
+        [
+            [
+                '~', '!', '"', '№', ';', '%', ':', '?', '*', '(', ')', '_', '+', '\0',
+            ],
+            [
+                '\0', 'Q', 'Ü', 'E', 'R', 'T', 'Y', 'U', 'İ', 'O', 'P', 'Ö', 'Ğ', '/',
+            ],
+            [
+                '\0', 'A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L', 'I', 'Ə', '\0', '\0',
+            ],
+            [
+                '\0', 'Z', 'X', 'C', 'V', 'B', 'N', 'M', 'Ç', 'Ş', ',', '\0', '\0', '\0',
+            ],
+            [
+                '\0', '\0', '\0', ' ', ' ', ' ', ' ', ' ', '\0', '\0', '\0', '\0', '\0', '\0',
+            ],
+        ],
+    ];
+    static MISCLICKS: Misclicks = misclicks_of(&LAYOUT);
 
-            // Moving all NULs to the end to enable short-curcuiting on the first NUL
-            let mut shift = 0;
-            let mut i = 0;
-            while i < 16 {
-                if set[i] == '\0' {
-                    shift += 1;
+    misclicks(&MISCLICKS, ch)
+}
+
+/// Position of a key on an on-screen keyboard, in quarters of a key's width.
+#[derive(Clone, Copy)]
+enum TouchKey {
+    /// Center of a letter key
+    Letter { x: i32, y: i32 },
+    /// The space bar, represented by a horizontal line through its center
+    Space { left: i32, right: i32, y: i32 },
+}
+
+impl TouchKey {
+    /// Squared distance between the centers of the keys
+    const fn distance_sq(self, other: Self) -> u32 {
+        let (dx, dy) = match (self, other) {
+            (Self::Letter { x: x1, y: y1 }, Self::Letter { x: x2, y: y2 }) => (x1 - x2, y1 - y2),
+            (Self::Letter { x, y: y1 }, Self::Space { left, right, y: y2 })
+            | (Self::Space { left, right, y: y2 }, Self::Letter { x, y: y1 }) => {
+                let dx = if x < left {
+                    left - x
+                } else if x > right {
+                    x - right
                 } else {
-                    set.swap(i, i - shift);
-                }
-                i += 1;
+                    0
+                };
+                (dx, y1 - y2)
             }
-        }
+            (Self::Space { .. }, Self::Space { .. }) => (0, 0),
+        };
+        dx.unsigned_abs().pow(2) + dy.unsigned_abs().pow(2)
+    }
+}
+
+/// All characters that `ch` could've been a misclick of on a touch screen, within the default
+/// distance of 1.75 key widths.
+///
+/// See [`touch_misclicks_within`] for details.
+pub fn touch_misclicks(ch: char) -> impl Iterator<Item = Lookalike<'static>> + Clone {
+    touch_misclicks_within(ch, 7)
+}
 
-        res
+/// All characters that `ch` could've been a misclick of on a touch screen.
+///
+/// Unlike [`qwerty_misclicks`], which considers the 8 keys around the typed one on a grid, this
+/// function models the staggered QWERTY layout of on-screen keyboards: it yields every letter
+/// (and the space bar) whose center is at most `max_cost` quarters of a key's width away from the
+/// center of the typed key, with the cost of each misclick being that distance. Thus, hitting
+/// an adjacent key in the same row costs [`Lookalike::DEFAULT_COST`].
+pub fn touch_misclicks_within(
+    ch: char,
+    max_cost: u32,
+) -> impl Iterator<Item = Lookalike<'static>> + Clone {
+    /// Key height relative to its width, in quarters of a key's width
+    const ROW_HEIGHT: i32 = 5;
+    static ROWS: [(&str, i32); 3] = [("qwertyuiop", 2), ("asdfghjkl", 4), ("zxcvbnm", 8)];
+    static SPACE: TouchKey = TouchKey::Space {
+        left: 16,
+        right: 28,
+        y: 3 * ROW_HEIGHT,
     };
 
-    u32::from(ch)
-        .checked_sub(b' '.into())
-        .and_then(|i| MISCLICKS.get(i as usize))
-        .unwrap_or(&['\0'; N_MISCLICKS])
-        .iter()
-        .copied()
-        .filter(|c| *c != '\0')
+    let keys = ROWS.iter().zip(0..).flat_map(|(&(row, first_x), y)| {
+        row.chars().zip(0..).map(move |(ch, x)| {
+            let key = TouchKey::Letter {
+                x: first_x + 4 * x,
+                y: y * ROW_HEIGHT,
+            };
+            (ch, key)
+        })
+    });
+
+    let uppercase = ch.is_ascii_uppercase();
+    let typed = if ch == ' ' {
+        Some(SPACE)
+    } else {
+        let ch = ch.to_ascii_lowercase();
+        keys.clone()
+            .find(|(key_ch, _)| *key_ch == ch)
+            .map(|(_, key)| key)
+    };
+
+    typed.into_iter().flat_map(move |typed| {
+        keys.clone()
+            .chain([(' ', SPACE)])
+            .filter_map(move |(key_ch, key)| {
+                let distance_sq = typed.distance_sq(key);
+                // In `u64`, since the square of `max_cost` may not fit into a `u32`
+                if distance_sq == 0 || u64::from(distance_sq) > u64::from(max_cost).pow(2) {
+                    return None;
+                }
+                let key_ch = if uppercase {
+                    key_ch.to_ascii_uppercase()
+                } else {
+                    key_ch
+                };
+                Some(Lookalike::new(key_ch, distance_sq.isqrt()))
+            })
+    })
 }
 
-/// All variants of `ch`, e.g. the base letter with diacritics.
-///
-/// The returned iterator variants of `ch` with diacritics, variants of other registers (except for
-/// case), e.g. for a base Katakana character, yields its variants in Hiragana, with Dakuten &
-/// Handakuten.
-#[allow(clippy::too_many_lines, reason = "how else u gonna write this lol")]
-pub fn variants(ch: char) -> impl Iterator<Item = char> + Clone {
-    let chars: &[char] = match ch {
+lookalike_table! {
+    /// All variants of `ch`, e.g. the base letter with diacritics.
+    ///
+    /// The returned iterator variants of `ch` with diacritics, variants of other registers (except
+    /// for case), e.g. for a base Katakana character, yields its variants in Hiragana, with Dakuten
+    /// & Handakuten. Arabic letters yield their forms with a hamza & the letters commonly written
+    /// in their place, e.g. `ه` & `ة`, Hebrew letters yield their final forms & vice versa.
+    pub fn variants {
         // Latin
-        'a' => &[
-            'â', 'ã', 'ä', 'à', 'á', 'ą', 'ā', 'Â', 'Ã', 'Ä', 'À', 'Á', 'Ą', 'Ā',
-        ],
-        'c' => &['ć', 'č', 'ç', 'Ć', 'Č', 'Ç'],
-        'd' => &['ď', 'đ', 'ð', 'Ď', 'Đ', 'Ð'],
-        'e' => &['ê', 'ë', 'è', 'é', 'ę', 'ē', 'Ê', 'Ë', 'È', 'É', 'Ę', 'Ē'],
-        'g' => &['ğ', 'ģ', 'Ğ', 'Ģ'],
-        'h' => &['ĥ', 'Ĥ'],
-        'i' => &[
-            'î', 'ï', 'ì', 'í', 'ī', 'į', 'ĩ', 'ı', 'İ', 'Î', 'Ï', 'Ì', 'Í', 'Ī', 'Į', 'Ĩ', 'I',
-            'İ',
-        ],
-        'j' => &['ĵ', 'Ĵ'],
-        'k' => &['ķ', 'Ķ'],
-        'l' => &['ĺ', 'ļ', 'ľ', 'ł', 'Ĺ', 'Ļ', 'Ľ', 'Ł'],
-        'n' => &['ñ', 'ń', 'ň', 'ņ', 'Ñ', 'Ń', 'Ň', 'Ņ'],
-        'o' => &[
-            'ô', 'õ', 'ö', 'ò', 'ó', 'ø', 'ō', 'ő', 'Ô', 'Õ', 'Ö', 'Ò', 'Ó', 'Ø', 'Ō', 'Ő',
-        ],
-        'r' => &['ř', 'ŕ', 'ŗ', 'Ř', 'Ŕ', 'Ŗ'],
-        's' => &['ś', 'š', 'ş', 'ș', 'ß', 'Ś', 'Š', 'Ş', 'Ș', 'ẞ'],
-        't' => &['ť', 'ţ', 'ț', 'Ť', 'Ţ', 'Ț'],
-        'u' => &['û', 'ü', 'ù', 'ú', 'ū', 'ű', 'Û', 'Ü', 'Ù', 'Ú', 'Ū', 'Ű'],
-        'w' => &['ŵ', 'Ŵ'],
-        'y' => &['ŷ', 'ÿ', 'ý', 'Ŷ', 'Ÿ', 'Ý'],
-        'z' => &['ž', 'ź', 'ż', 'Ž', 'Ź', 'Ż'],
+        'a' => 'â' 'ã' 'ä' 'à' 'á' 'ą' 'ā' 'Â' 'Ã' 'Ä' 'À' 'Á' 'Ą' 'Ā',
+        'c' => 'ć' 'č' 'ç' 'Ć' 'Č' 'Ç',
+        'd' => 'ď' 'đ' 'ð' 'Ď' 'Đ' 'Ð',
+        'e' => 'ê' 'ë' 'è' 'é' 'ę' 'ē' 'Ê' 'Ë' 'È' 'É' 'Ę' 'Ē',
+        'g' => 'ğ' 'ģ' 'Ğ' 'Ģ',
+        'h' => 'ĥ' 'Ĥ',
+        'i' => 'î' 'ï' 'ì' 'í' 'ī' 'į' 'ĩ' 'ı' 'İ' 'Î' 'Ï' 'Ì' 'Í' 'Ī' 'Į' 'Ĩ' 'I' 'İ',
+        'j' => 'ĵ' 'Ĵ',
+        'k' => 'ķ' 'Ķ',
+        'l' => 'ĺ' 'ļ' 'ľ' 'ł' 'Ĺ' 'Ļ' 'Ľ' 'Ł',
+        'n' => 'ñ' 'ń' 'ň' 'ņ' 'Ñ' 'Ń' 'Ň' 'Ņ',
+        'o' => 'ô' 'õ' 'ö' 'ò' 'ó' 'ø' 'ō' 'ő' 'Ô' 'Õ' 'Ö' 'Ò' 'Ó' 'Ø' 'Ō' 'Ő',
+        'r' => 'ř' 'ŕ' 'ŗ' 'Ř' 'Ŕ' 'Ŗ',
+        's' => 'ś' 'š' 'ş' 'ș' 'ß' 'Ś' 'Š' 'Ş' 'Ș' 'ẞ',
+        't' => 'ť' 'ţ' 'ț' 'Ť' 'Ţ' 'Ț',
+        'u' => 'û' 'ü' 'ù' 'ú' 'ū' 'ű' 'Û' 'Ü' 'Ù' 'Ú' 'Ū' 'Ű',
+        'w' => 'ŵ' 'Ŵ',
+        'y' => 'ŷ' 'ÿ' 'ý' 'Ŷ' 'Ÿ' 'Ý',
+        'z' => 'ž' 'ź' 'ż' 'Ž' 'Ź' 'Ż',
 
         // Cyrillic
-        'е' => &['ё', 'Ё'],
-        'и' => &['й', 'Й'],
-        'і' => &['ї', 'Ї'],
-        'у' => &['ў', 'Ў'],
-        'ь' => &['ъ', 'Ъ'],
-        'к' => &['қ', 'ќ'],
-        'г' => &['ґ', 'ѓ'],
-        'ж' => &['җ'],
+        'е' => 'ё' 'Ё',
+        'и' => 'й' 'Й',
+        'і' => 'ї' 'Ї',
+        'у' => 'ў' 'Ў',
+        'ь' => 'ъ' 'Ъ',
+        'к' => 'қ' 'ќ',
+        'г' => 'ґ' 'ѓ',
+        'ж' => 'җ',
 
         // Greek
-        'α' => &['ά', 'Ά'],
-        'ε' => &['έ', 'Έ'],
-        'η' => &['ή', 'Ή'],
-        'ι' => &['ί', 'ϊ', 'ΐ', 'Ί', 'Ϊ', 'ΐ'],
-        'ο' => &['ό', 'Ό'],
-        'υ' => &['ύ', 'ϋ', 'ΰ', 'Ύ', 'Ϋ', 'ΰ'],
-        'ω' => &['ώ', 'Ώ'],
+        'α' => 'ά' 'Ά',
+        'ε' => 'έ' 'Έ',
+        'η' => 'ή' 'Ή',
+        'ι' => 'ί' 'ϊ' 'ΐ' 'Ί' 'Ϊ' 'ΐ',
+        'ο' => 'ό' 'Ό',
+        'υ' => 'ύ' 'ϋ' 'ΰ' 'Ύ' 'Ϋ' 'ΰ',
+        'ω' => 'ώ' 'Ώ',
 
         // Hiragana
-        'あ' => &['ア', 'ぁ', 'ァ'],
-        'い' => &['イ', 'ぃ', 'ィ'],
-        'う' => &['ウ', 'ぅ', 'ゥ'],
-        'え' => &['エ', 'ぇ', 'ェ'],
-        'お' => &['オ', 'ぉ', 'ォ'],
-        'か' => &['カ', 'が', 'ガ', 'ゕ', 'ヵ'],
-        'き' => &['キ', 'ぎ', 'ギ'],
-        'く' => &['ク', 'ぐ', 'グ'],
-        'け' => &['ケ', 'げ', 'ゲ', 'ゖ', 'ヶ'],
-        'こ' => &['コ', 'ご', 'ゴ'],
-        'さ' => &['サ', 'ざ', 'ザ'],
-        'し' => &['シ', 'じ', 'ジ'],
-        'す' => &['ス', 'ず', 'ズ'],
-        'せ' => &['セ', 'ぜ', 'ゼ'],
-        'そ' => &['ソ', 'ぞ', 'ゾ'],
-        'た' => &['タ', 'だ', 'ダ'],
-        'ち' => &['チ', 'ぢ', 'ヂ'],
-        'つ' => &['ツ', 'づ', 'ヅ', 'っ', 'ッ'],
-        'て' => &['テ', 'で', 'デ'],
-        'と' => &['ト', 'ど', 'ド'],
-        'な' => &['ナ'],
-        'に' => &['ニ'],
-        'ぬ' => &['ヌ'],
-        'ね' => &['ネ'],
-        'の' => &['ノ'],
-        'は' => &['ハ', 'ば', 'バ', 'ぱ', 'パ'],
-        'ひ' => &['ヒ', 'び', 'ビ', 'ぴ', 'ピ'],
-        'ふ' => &['フ', 'ぶ', 'ブ', 'ぷ', 'プ'],
-        'へ' => &['ヘ', 'べ', 'ベ', 'ぺ', 'ペ'],
-        'ほ' => &['ホ', 'ぼ', 'ボ', 'ぽ', 'ポ'],
-        'ま' => &['マ'],
-        'み' => &['ミ'],
-        'む' => &['ム'],
-        'め' => &['メ'],
-        'も' => &['モ'],
-        'や' => &['ヤ', 'ゃ', 'ャ'],
-        'ゆ' => &['ユ', 'ゅ', 'ュ'],
-        'よ' => &['ヨ', 'ょ', 'ョ'],
-        'ら' => &['ラ'],
-        'り' => &['リ'],
-        'る' => &['ル'],
-        'れ' => &['レ'],
-        'ろ' => &['ロ'],
-        'わ' => &['ワ', 'ゎ', 'ヮ'],
-        'を' => &['ヲ'],
-        'ん' => &['ン'],
+        'あ' => 'ア' 'ぁ' 'ァ',
+        'い' => 'イ' 'ぃ' 'ィ',
+        'う' => 'ウ' 'ぅ' 'ゥ',
+        'え' => 'エ' 'ぇ' 'ェ',
+        'お' => 'オ' 'ぉ' 'ォ',
+        'か' => 'カ' 'が' 'ガ' 'ゕ' 'ヵ',
+        'き' => 'キ' 'ぎ' 'ギ',
+        'く' => 'ク' 'ぐ' 'グ',
+        'け' => 'ケ' 'げ' 'ゲ' 'ゖ' 'ヶ',
+        'こ' => 'コ' 'ご' 'ゴ',
+        'さ' => 'サ' 'ざ' 'ザ',
+        'し' => 'シ' 'じ' 'ジ',
+        'す' => 'ス' 'ず' 'ズ',
+        'せ' => 'セ' 'ぜ' 'ゼ',
+        'そ' => 'ソ' 'ぞ' 'ゾ',
+        'た' => 'タ' 'だ' 'ダ',
+        'ち' => 'チ' 'ぢ' 'ヂ',
+        'つ' => 'ツ' 'づ' 'ヅ' 'っ' 'ッ',
+        'て' => 'テ' 'で' 'デ',
+        'と' => 'ト' 'ど' 'ド',
+        'な' => 'ナ',
+        'に' => 'ニ',
+        'ぬ' => 'ヌ',
+        'ね' => 'ネ',
+        'の' => 'ノ',
+        'は' => 'ハ' 'ば' 'バ' 'ぱ' 'パ',
+        'ひ' => 'ヒ' 'び' 'ビ' 'ぴ' 'ピ',
+        'ふ' => 'フ' 'ぶ' 'ブ' 'ぷ' 'プ',
+        'へ' => 'ヘ' 'べ' 'ベ' 'ぺ' 'ペ',
+        'ほ' => 'ホ' 'ぼ' 'ボ' 'ぽ' 'ポ',
+        'ま' => 'マ',
+        'み' => 'ミ',
+        'む' => 'ム',
+        'め' => 'メ',
+        'も' => 'モ',
+        'や' => 'ヤ' 'ゃ' 'ャ',
+        'ゆ' => 'ユ' 'ゅ' 'ュ',
+        'よ' => 'ヨ' 'ょ' 'ョ',
+        'ら' => 'ラ',
+        'り' => 'リ',
+        'る' => 'ル',
+        'れ' => 'レ',
+        'ろ' => 'ロ',
+        'わ' => 'ワ' 'ゎ' 'ヮ',
+        'を' => 'ヲ',
+        'ん' => 'ン',
 
         // Katakana
-        'ア' => &['あ', 'ぁ', 'ァ'],
-        'イ' => &['い', 'ぃ', 'ィ'],
-        'ウ' => &['う', 'ぅ', 'ゥ'],
-        'エ' => &['え', 'ぇ', 'ェ'],
-        'オ' => &['お', 'ぉ', 'ォ'],
-        'カ' => &['か', 'が', 'ゕ', 'ヵ', 'ガ'],
-        'キ' => &['き', 'ぎ', 'ギ'],
-        'ク' => &['く', 'ぐ', 'グ'],
-        'ケ' => &['け', 'げ', 'ゖ', 'ヶ', 'ゲ'],
-        'コ' => &['こ', 'ご', 'ゴ'],
-        'サ' => &['さ', 'ざ', 'ザ'],
-        'シ' => &['し', 'じ', 'ジ'],
-        'ス' => &['す', 'ず', 'ズ'],
-        'セ' => &['せ', 'ぜ', 'ゼ'],
-        'ソ' => &['そ', 'ぞ', 'ゾ'],
-        'タ' => &['た', 'だ', 'ダ'],
-        'チ' => &['ち', 'ぢ', 'ヂ'],
-        'ツ' => &['つ', 'づ', 'ヅ', 'っ', 'ッ'],
-        'テ' => &['て', 'で', 'デ'],
-        'ト' => &['と', 'ど', 'ド'],
-        'ナ' => &['な'],
-        'ニ' => &['に'],
-        'ヌ' => &['ぬ'],
-        'ネ' => &['ね'],
-        'ノ' => &['の'],
-        'ハ' => &['は', 'ば', 'ぱ', 'バ', 'パ'],
-        'ヒ' => &['ひ', 'び', 'ぴ', 'ビ', 'ピ'],
-        'フ' => &['ふ', 'ぶ', 'ぷ', 'ブ', 'プ'],
-        'ヘ' => &['へ', 'べ', 'ぺ', 'ベ', 'ペ'],
-        'ホ' => &['ほ', 'ぼ', 'ぽ', 'ボ', 'ポ'],
-        'マ' => &['ま'],
-        'ミ' => &['み'],
-        'ム' => &['む'],
-        'メ' => &['め'],
-        'モ' => &['も'],
-        'ヤ' => &['や', 'ゃ', 'ャ'],
-        'ユ' => &['ゆ', 'ゅ', 'ュ'],
-        'ヨ' => &['よ', 'ょ', 'ョ'],
-        'ラ' => &['ら'],
-        'リ' => &['り'],
-        'ル' => &['る'],
-        'レ' => &['れ'],
-        'ロ' => &['ろ'],
-        'ワ' => &['わ', 'ゎ', 'ヮ'],
-        'ヲ' => &['を'],
-        'ン' => &['ん'],
-
-        _ => &[],
+        'ア' => 'あ' 'ぁ' 'ァ',
+        'イ' => 'い' 'ぃ' 'ィ',
+        'ウ' => 'う' 'ぅ' 'ゥ',
+        'エ' => 'え' 'ぇ' 'ェ',
+        'オ' => 'お' 'ぉ' 'ォ',
+        'カ' => 'か' 'が' 'ゕ' 'ヵ' 'ガ',
+        'キ' => 'き' 'ぎ' 'ギ',
+        'ク' => 'く' 'ぐ' 'グ',
+        'ケ' => 'け' 'げ' 'ゖ' 'ヶ' 'ゲ',
+        'コ' => 'こ' 'ご' 'ゴ',
+        'サ' => 'さ' 'ざ' 'ザ',
+        'シ' => 'し' 'じ' 'ジ',
+        'ス' => 'す' 'ず' 'ズ',
+        'セ' => 'せ' 'ぜ' 'ゼ',
+        'ソ' => 'そ' 'ぞ' 'ゾ',
+        'タ' => 'た' 'だ' 'ダ',
+        'チ' => 'ち' 'ぢ' 'ヂ',
+        'ツ' => 'つ' 'づ' 'ヅ' 'っ' 'ッ',
+        'テ' => 'て' 'で' 'デ',
+        'ト' => 'と' 'ど' 'ド',
+        'ナ' => 'な',
+        'ニ' => 'に',
+        'ヌ' => 'ぬ',
+        'ネ' => 'ね',
+        'ノ' => 'の',
+        'ハ' => 'は' 'ば' 'ぱ' 'バ' 'パ',
+        'ヒ' => 'ひ' 'び' 'ぴ' 'ビ' 'ピ',
+        'フ' => 'ふ' 'ぶ' 'ぷ' 'ブ' 'プ',
+        'ヘ' => 'へ' 'べ' 'ぺ' 'ベ' 'ペ',
+        'ホ' => 'ほ' 'ぼ' 'ぽ' 'ボ' 'ポ',
+        'マ' => 'ま',
+        'ミ' => 'み',
+        'ム' => 'む',
+        'メ' => 'め',
+        'モ' => 'も',
+        'ヤ' => 'や' 'ゃ' 'ャ',
+        'ユ' => 'ゆ' 'ゅ' 'ュ',
+        'ヨ' => 'よ' 'ょ' 'ョ',
+        'ラ' => 'ら',
+        'リ' => 'り',
+        'ル' => 'る',
+        'レ' => 'れ',
+        'ロ' => 'ろ',
+        'ワ' => 'わ' 'ゎ' 'ヮ',
+        'ヲ' => 'を',
+        'ン' => 'ん',
+
+        // Arabic
+        'ا' => 'أ' 'إ' 'آ' 'ٱ',
+        'أ' | 'إ' | 'آ' | 'ٱ' => 'ا',
+        'ء' => 'أ' 'إ' 'ؤ' 'ئ',
+        'و' => 'ؤ',
+        'ه' => 'ة',
+        'ة' => 'ه',
+        'ي' => 'ى' 'ئ' 'ی',
+        'ى' => 'ي' 'ی',
+        'ی' => 'ي' 'ى',
+        'ك' => 'ک',
+        'ک' => 'ك',
+
+        // Hebrew
+        'כ' => 'ך',
+        'ך' => 'כ',
+        'מ' => 'ם',
+        'ם' => 'מ',
+        'נ' => 'ן',
+        'ן' => 'נ',
+        'פ' => 'ף',
+        'ף' => 'פ',
+        'צ' => 'ץ',
+        'ץ' => 'צ',
+    }
+}
+
+lookalike_table! {
+    /// The other case of the dotted & dotless `i` under the rules of Turkish & Azerbaijani, which
+    /// pair `i` with `İ` & `ı` with `I`.
+    pub fn turkic_case {
+        'i' => 'İ',
+        'İ' => 'i',
+        'ı' => 'I',
+        'I' => 'ı',
+    }
+}
+
+/// Same as [`variants`], except that the dotted & dotless `i` are only paired with their other
+/// case under the rules of Turkish & Azerbaijani, see [`turkic_case`].
+///
+/// [`variants`] yields `ı`, `İ` & `I` for a typed `i`, which lets a dotless `ı` in a key match
+/// a dotted `i` in the input, even though they're different letters in the Turkic alphabets.
+pub fn turkic_variants(ch: char) -> impl Iterator<Item = char> + Clone {
+    variants(ch)
+        .filter(move |variant| ch != 'i' || !matches!(variant, 'ı' | 'İ' | 'I'))
+        .chain(turkic_case(ch))
+}
+
+/// Transcriptions of the German umlauts & `ß`, e.g. `a` followed by `e` yields `ä`, while `ä`
+/// yields `ae`.
+///
+/// The transcriptions cost [`Lookalike::TRANSCRIPTION_COST`].
+pub fn umlauts(ch: char) -> impl Iterator<Item = Lookalike<'static>> + Clone {
+    const COST: u32 = Lookalike::TRANSCRIPTION_COST;
+    const TABLE: &[(&str, char)] = &[
+        ("ae", 'ä'),
+        ("oe", 'ö'),
+        ("ue", 'ü'),
+        ("Ae", 'Ä'),
+        ("Oe", 'Ö'),
+        ("Ue", 'Ü'),
+        ("AE", 'Ä'),
+        ("OE", 'Ö'),
+        ("UE", 'Ü'),
+        ("ss", 'ß'),
+        ("SS", 'ẞ'),
+    ];
+
+    TABLE.iter().flat_map(move |&(transcription, umlaut)| {
+        let from_transcription = transcription.strip_prefix(ch).map(|input_rest| Lookalike {
+            input_rest,
+            ..Lookalike::new(umlaut, COST)
+        });
+        let to_transcription = (umlaut == ch).then(|| Lookalike::sequence("", transcription, COST));
+        from_transcription.into_iter().chain(to_transcription)
+    })
+}
+
+/// All character sequences that look identical or nearly identical to `ch`, e.g. Latin `a` &
+/// Cyrillic `а`, or `O` & `0`.
+///
+/// Based on the confusables data of [UTS #39](https://www.unicode.org/reports/tr39/): characters
+/// that have the same prototype in it are homoglyphs of each other & of the prototype. Prototypes
+/// spanning several characters work both ways, like in [`ocr`]: `m` yields `rn`, while `r`
+/// followed by `n` yields `m`.
+pub fn homoglyphs(ch: char) -> impl Iterator<Item = Lookalike<'static>> + Clone {
+    use confusables::{GROUPS, SOURCES};
+
+    const COST: u32 = Lookalike::DEFAULT_COST;
+
+    // The prototypes that start with the typed character
+    let mut buf = [0; 4];
+    let typed = &*ch.encode_utf8(&mut buf);
+    let start = GROUPS.partition_point(|(prototype, _)| *prototype < typed);
+    let len = GROUPS[start..].partition_point(|(prototype, _)| prototype.starts_with(typed));
+    let to_sources = GROUPS[start..start + len]
+        .iter()
+        .flat_map(move |(prototype, sources)| {
+            let input_rest = &prototype[ch.len_utf8()..];
+            sources.chars().map(move |source| Lookalike {
+                input_rest,
+                ..Lookalike::new(source, COST)
+            })
+        });
+
+    // The prototype of the typed character & the other characters that have it
+    let group = SOURCES
+        .binary_search_by_key(&ch, |(source, _)| *source)
+        .ok()
+        .map(|i| GROUPS[usize::from(SOURCES[i].1)]);
+    let from_source = group.into_iter().flat_map(move |(prototype, sources)| {
+        let others = sources.chars().filter(move |source| *source != ch);
+        iter::once(Lookalike::sequence("", prototype, COST))
+            .chain(others.map(|source| Lookalike::new(source, COST)))
+    });
+
+    to_sources.chain(from_source)
+}
+
+/// All character sequences that the typed character could've been mistaken for by OCR software,
+/// e.g. `l`, `1` & `I`, or `rn` & `m`.
+///
+/// The returned lookalikes work both ways: `cl` yields `d`, while `d` yields `cl`, and a
+/// [`Searcher`](crate::Searcher) considers the keys with an `m` as soon as the `r` of `rn` is typed.
+pub fn ocr(ch: char) -> impl Iterator<Item = Lookalike<'static>> + Clone {
+    const GROUPS: &[&[&str]] = &[
+        &["l", "1", "I", "i", "|"],
+        &["O", "0", "o", "Q", "D"],
+        &["S", "5", "s"],
+        &["Z", "2", "z"],
+        &["B", "8"],
+        &["G", "6", "b"],
+        &["g", "9", "q"],
+        &["c", "e"],
+        &["u", "v", "ii"],
+        &["f", "t"],
+        &["h", "li"],
+        &["m", "rn", "nn"],
+        &["d", "cl"],
+        &["w", "vv"],
+        &[",", "."],
+    ];
+
+    GROUPS.iter().flat_map(move |group| {
+        let typed = group
+            .iter()
+            .enumerate()
+            .filter_map(move |(i, seq)| Some((i, seq.strip_prefix(ch)?)));
+        typed.flat_map(move |(typed_i, input_rest)| {
+            group
+                .iter()
+                .enumerate()
+                .filter(move |(i, _)| *i != typed_i)
+                .map(move |(_, key)| Lookalike::sequence(input_rest, key, Lookalike::DEFAULT_COST))
+        })
+    })
+}
+
+/// `ch` along with its uppercase version, if it differs.
+fn with_uppercase(ch: char) -> impl Iterator<Item = char> + Clone {
+    let upper = ch.to_uppercase().next().filter(|upper| *upper != ch);
+    iter::once(ch).chain(upper)
+}
+
+/// All transliterations of `ch` between the Latin script & the Cyrillic or Greek scripts, e.g. `ж`
+/// yields `zh`, while `z` followed by `h` yields `ж`.
+///
+/// Covers ISO 9 and the common romanisations of Russian & Ukrainian, and the common romanisations
+/// of Greek. Lowercase input yields both lowercase & capitalised transliterations, so that e.g.
+/// "moskva" finds "Москва". The transliterations cost [`Lookalike::TRANSCRIPTION_COST`].
+#[allow(clippy::too_many_lines, reason = "it's mostly a table")]
+pub fn transliterations(ch: char) -> impl Iterator<Item = Lookalike<'static>> + Clone {
+    const TABLE: &[(&str, char)] = &[
+        // Russian
+        ("a", 'а'),
+        ("b", 'б'),
+        ("v", 'в'),
+        ("g", 'г'),
+        ("d", 'д'),
+        ("e", 'е'),
+        ("ye", 'е'),
+        ("yo", 'ё'),
+        ("jo", 'ё'),
+        ("e", 'ё'),
+        ("ë", 'ё'),
+        ("zh", 'ж'),
+        ("ž", 'ж'),
+        ("z", 'з'),
+        ("i", 'и'),
+        ("y", 'й'),
+        ("j", 'й'),
+        ("i", 'й'),
+        ("k", 'к'),
+        ("l", 'л'),
+        ("m", 'м'),
+        ("n", 'н'),
+        ("o", 'о'),
+        ("p", 'п'),
+        ("r", 'р'),
+        ("s", 'с'),
+        ("t", 'т'),
+        ("u", 'у'),
+        ("f", 'ф'),
+        ("kh", 'х'),
+        ("h", 'х'),
+        ("x", 'х'),
+        ("ts", 'ц'),
+        ("tz", 'ц'),
+        ("c", 'ц'),
+        ("ch", 'ч'),
+        ("č", 'ч'),
+        ("sh", 'ш'),
+        ("š", 'ш'),
+        ("shch", 'щ'),
+        ("sch", 'щ'),
+        ("ŝ", 'щ'),
+        ("ʺ", 'ъ'),
+        ("y", 'ы'),
+        ("ʹ", 'ь'),
+        ("'", 'ь'),
+        ("e", 'э'),
+        ("è", 'э'),
+        ("yu", 'ю'),
+        ("ju", 'ю'),
+        ("û", 'ю'),
+        ("ya", 'я'),
+        ("ja", 'я'),
+        ("â", 'я'),
+        // Ukrainian
+        ("h", 'г'),
+        ("g", 'ґ'),
+        ("ye", 'є'),
+        ("je", 'є'),
+        ("i", 'і'),
+        ("yi", 'ї'),
+        ("ji", 'ї'),
+        ("ï", 'ї'),
+        ("y", 'и'),
+        // Greek
+        ("a", 'α'),
+        ("v", 'β'),
+        ("b", 'β'),
+        ("g", 'γ'),
+        ("d", 'δ'),
+        ("e", 'ε'),
+        ("z", 'ζ'),
+        ("i", 'η'),
+        ("th", 'θ'),
+        ("i", 'ι'),
+        ("k", 'κ'),
+        ("l", 'λ'),
+        ("m", 'μ'),
+        ("n", 'ν'),
+        ("x", 'ξ'),
+        ("ks", 'ξ'),
+        ("o", 'ο'),
+        ("p", 'π'),
+        ("r", 'ρ'),
+        ("s", 'σ'),
+        ("s", 'ς'),
+        ("t", 'τ'),
+        ("y", 'υ'),
+        ("u", 'υ'),
+        ("i", 'υ'),
+        ("f", 'φ'),
+        ("ph", 'φ'),
+        ("ch", 'χ'),
+        ("kh", 'χ'),
+        ("h", 'χ'),
+        ("ps", 'ψ'),
+        ("o", 'ω'),
+    ];
+
+    let lower = ch.to_lowercase().next().unwrap_or(ch);
+    TABLE.iter().flat_map(move |&(latin, other)| {
+        let from_latin = latin.strip_prefix(lower).map(|input_rest| {
+            with_uppercase(other).map(|ch| Lookalike {
+                ch,
+                key_rest: "",
+                input_rest,
+                cost: Lookalike::TRANSCRIPTION_COST,
+            })
+        });
+        let to_latin = (other == lower).then(|| {
+            let mut latin = latin.chars();
+            let first = latin.next().unwrap_or_default();
+            let key_rest = latin.as_str();
+            with_uppercase(first).map(move |ch| Lookalike {
+                ch,
+                key_rest,
+                input_rest: "",
+                cost: Lookalike::TRANSCRIPTION_COST,
+            })
+        });
+        from_latin
+            .into_iter()
+            .flatten()
+            .chain(to_latin.into_iter().flatten())
+    })
+}
+
+/// All kana that `ch` could be the start of the romanisation of, e.g. `k` followed by `a` yields
+/// `か` & `カ`, and `k` followed by `yo` yields `きょ` & `キョ`.
+///
+/// Covers the Hepburn & Kunrei-shiki romanisations, small kana written with a leading `x` or `l`,
+/// and doubled consonants denoting `っ`. Since every prefix of a romanised syllable yields the
+/// kana it may be completed into, a [`Searcher`](crate::Searcher) narrows the candidates down to
+/// e.g. the `か`-row as soon as `k` is typed. The kana cost [`Lookalike::TRANSCRIPTION_COST`].
+#[allow(clippy::too_many_lines, reason = "it's mostly a table")]
+pub fn romaji(ch: char) -> impl Iterator<Item = Lookalike<'static>> + Clone {
+    /// Romanisations, along with the Hiragana & the Katakana they denote
+    const TABLE: &[(&str, &str, &str)] = &[
+        ("a", "あ", "ア"),
+        ("i", "い", "イ"),
+        ("u", "う", "ウ"),
+        ("e", "え", "エ"),
+        ("o", "お", "オ"),
+        ("ka", "か", "カ"),
+        ("ki", "き", "キ"),
+        ("ku", "く", "ク"),
+        ("ke", "け", "ケ"),
+        ("ko", "こ", "コ"),
+        ("ga", "が", "ガ"),
+        ("gi", "ぎ", "ギ"),
+        ("gu", "ぐ", "グ"),
+        ("ge", "げ", "ゲ"),
+        ("go", "ご", "ゴ"),
+        ("sa", "さ", "サ"),
+        ("si", "し", "シ"),
+        ("su", "す", "ス"),
+        ("se", "せ", "セ"),
+        ("so", "そ", "ソ"),
+        ("za", "ざ", "ザ"),
+        ("zi", "じ", "ジ"),
+        ("zu", "ず", "ズ"),
+        ("ze", "ぜ", "ゼ"),
+        ("zo", "ぞ", "ゾ"),
+        ("ta", "た", "タ"),
+        ("ti", "ち", "チ"),
+        ("tu", "つ", "ツ"),
+        ("te", "て", "テ"),
+        ("to", "と", "ト"),
+        ("da", "だ", "ダ"),
+        ("di", "ぢ", "ヂ"),
+        ("du", "づ", "ヅ"),
+        ("de", "で", "デ"),
+        ("do", "ど", "ド"),
+        ("na", "な", "ナ"),
+        ("ni", "に", "ニ"),
+        ("nu", "ぬ", "ヌ"),
+        ("ne", "ね", "ネ"),
+        ("no", "の", "ノ"),
+        ("ha", "は", "ハ"),
+        ("hi", "ひ", "ヒ"),
+        ("hu", "ふ", "フ"),
+        ("he", "へ", "ヘ"),
+        ("ho", "ほ", "ホ"),
+        ("ba", "ば", "バ"),
+        ("bi", "び", "ビ"),
+        ("bu", "ぶ", "ブ"),
+        ("be", "べ", "ベ"),
+        ("bo", "ぼ", "ボ"),
+        ("pa", "ぱ", "パ"),
+        ("pi", "ぴ", "ピ"),
+        ("pu", "ぷ", "プ"),
+        ("pe", "ぺ", "ペ"),
+        ("po", "ぽ", "ポ"),
+        ("ma", "ま", "マ"),
+        ("mi", "み", "ミ"),
+        ("mu", "む", "ム"),
+        ("me", "め", "メ"),
+        ("mo", "も", "モ"),
+        ("ra", "ら", "ラ"),
+        ("ri", "り", "リ"),
+        ("ru", "る", "ル"),
+        ("re", "れ", "レ"),
+        ("ro", "ろ", "ロ"),
+        ("ya", "や", "ヤ"),
+        ("yu", "ゆ", "ユ"),
+        ("yo", "よ", "ヨ"),
+        ("wa", "わ", "ワ"),
+        ("wo", "を", "ヲ"),
+        ("shi", "し", "シ"),
+        ("ji", "じ", "ジ"),
+        ("chi", "ち", "チ"),
+        ("tsu", "つ", "ツ"),
+        ("fu", "ふ", "フ"),
+        ("dzu", "づ", "ヅ"),
+        ("n", "ん", "ン"),
+        ("nn", "ん", "ン"),
+        ("n'", "ん", "ン"),
+        ("m", "ん", "ン"),
+        ("kya", "きゃ", "キャ"),
+        ("kyu", "きゅ", "キュ"),
+        ("kyo", "きょ", "キョ"),
+        ("gya", "ぎゃ", "ギャ"),
+        ("gyu", "ぎゅ", "ギュ"),
+        ("gyo", "ぎょ", "ギョ"),
+        ("sya", "しゃ", "シャ"),
+        ("syu", "しゅ", "シュ"),
+        ("syo", "しょ", "ショ"),
+        ("zya", "じゃ", "ジャ"),
+        ("zyu", "じゅ", "ジュ"),
+        ("zyo", "じょ", "ジョ"),
+        ("tya", "ちゃ", "チャ"),
+        ("tyu", "ちゅ", "チュ"),
+        ("tyo", "ちょ", "チョ"),
+        ("nya", "にゃ", "ニャ"),
+        ("nyu", "にゅ", "ニュ"),
+        ("nyo", "にょ", "ニョ"),
+        ("hya", "ひゃ", "ヒャ"),
+        ("hyu", "ひゅ", "ヒュ"),
+        ("hyo", "ひょ", "ヒョ"),
+        ("bya", "びゃ", "ビャ"),
+        ("byu", "びゅ", "ビュ"),
+        ("byo", "びょ", "ビョ"),
+        ("pya", "ぴゃ", "ピャ"),
+        ("pyu", "ぴゅ", "ピュ"),
+        ("pyo", "ぴょ", "ピョ"),
+        ("mya", "みゃ", "ミャ"),
+        ("myu", "みゅ", "ミュ"),
+        ("myo", "みょ", "ミョ"),
+        ("rya", "りゃ", "リャ"),
+        ("ryu", "りゅ", "リュ"),
+        ("ryo", "りょ", "リョ"),
+        ("dya", "ぢゃ", "ヂャ"),
+        ("dyu", "ぢゅ", "ヂュ"),
+        ("dyo", "ぢょ", "ヂョ"),
+        ("sha", "しゃ", "シャ"),
+        ("shu", "しゅ", "シュ"),
+        ("sho", "しょ", "ショ"),
+        ("she", "しぇ", "シェ"),
+        ("ja", "じゃ", "ジャ"),
+        ("ju", "じゅ", "ジュ"),
+        ("jo", "じょ", "ジョ"),
+        ("je", "じぇ", "ジェ"),
+        ("cha", "ちゃ", "チャ"),
+        ("chu", "ちゅ", "チュ"),
+        ("cho", "ちょ", "チョ"),
+        ("che", "ちぇ", "チェ"),
+        ("fa", "ふぁ", "ファ"),
+        ("fi", "ふぃ", "フィ"),
+        ("fe", "ふぇ", "フェ"),
+        ("fo", "ふぉ", "フォ"),
+        ("xa", "ぁ", "ァ"),
+        ("xi", "ぃ", "ィ"),
+        ("xu", "ぅ", "ゥ"),
+        ("xe", "ぇ", "ェ"),
+        ("xo", "ぉ", "ォ"),
+        ("xya", "ゃ", "ャ"),
+        ("xyu", "ゅ", "ュ"),
+        ("xyo", "ょ", "ョ"),
+        ("xtu", "っ", "ッ"),
+        ("xtsu", "っ", "ッ"),
+        ("la", "ぁ", "ァ"),
+        ("li", "ぃ", "ィ"),
+        ("lu", "ぅ", "ゥ"),
+        ("le", "ぇ", "ェ"),
+        ("lo", "ぉ", "ォ"),
+        ("lya", "ゃ", "ャ"),
+        ("lyu", "ゅ", "ュ"),
+        ("lyo", "ょ", "ョ"),
+        ("ltu", "っ", "ッ"),
+        ("ltsu", "っ", "ッ"),
+        ("-", "ー", "ー"),
+    ];
+    /// Consonants that are doubled to denote a small `っ` before them, e.g. `kk` in `kekka`
+    const GEMINATES: &str = "bcdfghjkmprstwz";
+
+    let ch = ch.to_ascii_lowercase();
+    let sokuon = GEMINATES.contains(ch).then_some([
+        Lookalike::new('っ', Lookalike::TRANSCRIPTION_COST),
+        Lookalike::new('ッ', Lookalike::TRANSCRIPTION_COST),
+    ]);
+    TABLE
+        .iter()
+        .filter_map(move |(romaji, hiragana, katakana)| {
+            let input_rest = romaji.strip_prefix(ch)?;
+            Some([
+                Lookalike::sequence(input_rest, hiragana, Lookalike::TRANSCRIPTION_COST),
+                Lookalike::sequence(input_rest, katakana, Lookalike::TRANSCRIPTION_COST),
+            ])
+        })
+        .chain(sokuon)
+        .flatten()
+}
+
+/// All jamo that the typed conjoining jamo could've been meant as in a Hangul key decomposed
+/// with [`hangul::decompose`](crate::hangul::decompose).
+///
+/// A typed final consonant yields:
+/// - The same consonant as the initial of the next syllable, since a final consonant of the last
+///   typed syllable moves to the next one once a vowel is typed, e.g. `한` on the way to `하나`.
+///   For consonant clusters, only the second consonant moves, e.g. `닭` on the way to `달걀`.
+///   Since the input is incomplete rather than mistyped, these cost
+///   [`Lookalike::TRANSCRIPTION_COST`].
+/// - The final consonants that are pronounced the same at the end of a syllable, e.g. `ㄷ`, `ㅅ`,
+///   `ㅈ`, `ㅊ`, `ㅌ` & `ㅎ`.
+pub fn jamo(ch: char) -> impl Iterator<Item = Lookalike<'static>> + Clone {
+    /// Final consonants, along with the consonants that they're made up of as initials
+    const FINALS: &[(char, &str)] = &[
+        ('ᆨ', "ᄀ"),
+        ('ᆩ', "ᄁ"),
+        ('ᆪ', "ᆨᄉ"),
+        ('ᆫ', "ᄂ"),
+        ('ᆬ', "ᆫᄌ"),
+        ('ᆭ', "ᆫᄒ"),
+        ('ᆮ', "ᄃ"),
+        ('ᆯ', "ᄅ"),
+        ('ᆰ', "ᆯᄀ"),
+        ('ᆱ', "ᆯᄆ"),
+        ('ᆲ', "ᆯᄇ"),
+        ('ᆳ', "ᆯᄉ"),
+        ('ᆴ', "ᆯᄐ"),
+        ('ᆵ', "ᆯᄑ"),
+        ('ᆶ', "ᆯᄒ"),
+        ('ᆷ', "ᄆ"),
+        ('ᆸ', "ᄇ"),
+        ('ᆹ', "ᆸᄉ"),
+        ('ᆺ', "ᄉ"),
+        ('ᆻ', "ᄊ"),
+        ('ᆼ', "ᄋ"),
+        ('ᆽ', "ᄌ"),
+        ('ᆾ', "ᄎ"),
+        ('ᆿ', "ᄏ"),
+        ('ᇀ', "ᄐ"),
+        ('ᇁ', "ᄑ"),
+        ('ᇂ', "ᄒ"),
+    ];
+    /// Groups of final consonants that are pronounced the same
+    const NEUTRALISED: &[&[char]] = &[
+        &['ᆨ', 'ᆩ', 'ᆪ', 'ᆰ', 'ᆿ'],
+        &['ᆫ', 'ᆬ', 'ᆭ'],
+        &['ᆮ', 'ᆺ', 'ᆻ', 'ᆽ', 'ᆾ', 'ᇀ', 'ᇂ'],
+        &['ᆯ', 'ᆲ', 'ᆳ', 'ᆴ', 'ᆶ'],
+        &['ᆷ', 'ᆱ'],
+        &['ᆸ', 'ᆹ', 'ᆵ', 'ᇁ'],
+    ];
+
+    let moved = FINALS
+        .iter()
+        .filter(move |(last, _)| *last == ch)
+        .map(|(_, initials)| Lookalike::sequence("", initials, Lookalike::TRANSCRIPTION_COST));
+    let neutralised = NEUTRALISED
+        .iter()
+        .filter(move |group| group.contains(&ch))
+        .flat_map(|group| group.iter())
+        .filter(move |last| **last != ch)
+        .map(|&last| Lookalike::from(last));
+    moved.chain(neutralised)
+}
+
+/// All characters that the typed character of an Indic script could've been meant as, e.g. the
+/// short & long forms of a vowel sign, `ि` & `ी`, or a letter with & without a nukta, `ज़` & `ज`.
+///
+/// The confusions are defined for Devanagari, and apply to the other scripts from Bengali to
+/// Malayalam through their common layout inherited from ISCII. Combine with [`marks::INDIC`] to
+/// also make nukta, anusvara & chandrabindu optional.
+///
+/// [`marks::INDIC`]: crate::marks::INDIC
+///
+/// # Example
+/// ```rust
+/// use permissive_search::*;
+///
+/// # let root = SearchTree::default();
+/// let searcher = Searcher::new(&root, lookalikes::indic).with_skippable(&[marks::INDIC]);
+/// # _ = searcher;
+/// ```
+pub fn indic(ch: char) -> impl Iterator<Item = char> + Clone {
+    /// The first character of the Devanagari block, the first Indic script
+    const FIRST: u32 = 0x900;
+    /// The size of the block of each Indic script
+    const BLOCK_SIZE: u32 = 0x80;
+    /// The number of Indic scripts, from Devanagari to Malayalam
+    const N_SCRIPTS: u32 = 9;
+    /// Groups of commonly confused Devanagari characters
+    const GROUPS: &[&[char]] = &[
+        // Vowel signs i & ii, u & uu, e & ai, o & au
+        &['\u{93F}', '\u{940}'],
+        &['\u{941}', '\u{942}'],
+        &['\u{947}', '\u{948}'],
+        &['\u{94B}', '\u{94C}'],
+        // Vocalic r & vowel sign i
+        &['\u{943}', '\u{93F}'],
+        // Anusvara & chandrabindu
+        &['\u{901}', '\u{902}'],
+        &['इ', 'ई'],
+        &['उ', 'ऊ'],
+        &['ए', 'ऐ'],
+        &['ओ', 'औ'],
+        &['श', 'ष', 'स'],
+        &['न', 'ण'],
+        &['ब', 'व'],
+    ];
+    /// Letters with a nukta that have precomposed forms, & the letters without it
+    const NUKTA: &[(char, char)] = &[
+        ('\u{929}', 'न'),
+        ('\u{931}', 'र'),
+        ('\u{934}', 'ळ'),
+        ('\u{958}', 'क'),
+        ('\u{959}', 'ख'),
+        ('\u{95A}', 'ग'),
+        ('\u{95B}', 'ज'),
+        ('\u{95C}', 'ड'),
+        ('\u{95D}', 'ढ'),
+        ('\u{95E}', 'फ'),
+        ('\u{95F}', 'य'),
+        ('\u{9DC}', 'ড'),
+        ('\u{9DD}', 'ঢ'),
+        ('\u{9DF}', 'য'),
+        ('\u{A33}', 'ਲ'),
+        ('\u{A36}', 'ਸ'),
+        ('\u{A59}', 'ਖ'),
+        ('\u{A5A}', 'ਗ'),
+        ('\u{A5B}', 'ਜ'),
+        ('\u{A5E}', 'ਫ'),
+        ('\u{B5C}', 'ଡ'),
+        ('\u{B5D}', 'ଢ'),
+    ];
+
+    let offset = u32::from(ch).wrapping_sub(FIRST);
+    let script = (offset < BLOCK_SIZE * N_SCRIPTS).then_some(offset / BLOCK_SIZE * BLOCK_SIZE);
+    let confused = script.into_iter().flat_map(move |script| {
+        let devanagari = char::from_u32(u32::from(ch) - script).unwrap_or_default();
+        GROUPS
+            .iter()
+            .filter(move |group| group.contains(&devanagari))
+            .flat_map(|group| group.iter())
+            .filter_map(move |&other| char::from_u32(u32::from(other) + script))
+            .filter(move |&other| other != ch)
+    });
+    let nukta = NUKTA.iter().filter_map(move |&(with, without)| {
+        if with == ch {
+            Some(without)
+        } else if without == ch {
+            Some(with)
+        } else {
+            None
+        }
+    });
+    confused.chain(nukta)
+}
+
+/// All characters that the typed character is equivalent to under compatibility folding, as in
+/// NFKC, e.g. the full-width `Ａ` & `A`, the half-width `ｶ` & `カ`, or `ﬁ` & `fi`.
+///
+/// The returned lookalikes work both ways and cost nothing. Half-width katakana followed by a
+/// half-width voicing mark yield the voiced katakana, e.g. `ｶﾞ` yields `ガ`.
+///
+/// # Example
+/// The lookalikes can be combined with the ones from other functions:
+/// ```rust
+/// use permissive_search::{lookalikes::{self, Lookalike}, *};
+///
+/// # let root = SearchTree::default();
+/// let generator = |ch| lookalikes::all(ch).map(Lookalike::from).chain(lookalikes::compatibility(ch));
+/// let searcher = Searcher::new(&root, generator);
+/// # _ = searcher;
+/// ```
+#[allow(clippy::too_many_lines, reason = "it's mostly a table")]
+pub fn compatibility(ch: char) -> impl Iterator<Item = Lookalike<'static>> + Clone {
+    /// The difference between a full-width ASCII character & its ASCII counterpart
+    const FULLWIDTH_OFFSET: u32 = 0xFEE0;
+    /// Characters from `｡` (U+FF61) to `ﾝ` (U+FF9D) without the half-width forms
+    const HALFWIDTH: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+    /// Katakana that a half-width dakuten, `ﾞ`, can be added to, & the results
+    const DAKUTEN: &[(char, char)] = &[
+        ('ウ', 'ヴ'),
+        ('カ', 'ガ'),
+        ('キ', 'ギ'),
+        ('ク', 'グ'),
+        ('ケ', 'ゲ'),
+        ('コ', 'ゴ'),
+        ('サ', 'ザ'),
+        ('シ', 'ジ'),
+        ('ス', 'ズ'),
+        ('セ', 'ゼ'),
+        ('ソ', 'ゾ'),
+        ('タ', 'ダ'),
+        ('チ', 'ヂ'),
+        ('ツ', 'ヅ'),
+        ('テ', 'デ'),
+        ('ト', 'ド'),
+        ('ハ', 'バ'),
+        ('ヒ', 'ビ'),
+        ('フ', 'ブ'),
+        ('ヘ', 'ベ'),
+        ('ホ', 'ボ'),
+    ];
+    /// Katakana that a half-width handakuten, `ﾟ`, can be added to, & the results
+    const HANDAKUTEN: &[(char, char)] = &[
+        ('ハ', 'パ'),
+        ('ヒ', 'ピ'),
+        ('フ', 'プ'),
+        ('ヘ', 'ペ'),
+        ('ホ', 'ポ'),
+    ];
+    /// Ligatures & other characters that fold into several ones
+    const SEQUENCES: &[(char, &str)] = &[
+        ('ﬀ', "ff"),
+        ('ﬁ', "fi"),
+        ('ﬂ', "fl"),
+        ('ﬃ', "ffi"),
+        ('ﬄ', "ffl"),
+        ('ﬅ', "st"),
+        ('ﬆ', "st"),
+        ('ĳ', "ij"),
+        ('Ĳ', "IJ"),
+        ('…', "..."),
+        ('™', "TM"),
+    ];
+
+    let code = u32::from(ch);
+    let fullwidth = match ch {
+        ' ' => Some('\u{3000}'),
+        '\u{3000}' => Some(' '),
+        '!'..='~' => char::from_u32(code + FULLWIDTH_OFFSET),
+        '！'..='～' => char::from_u32(code - FULLWIDTH_OFFSET),
+        _ => None,
+    };
+
+    let halfwidth_index = code.checked_sub(0xFF61).map(|i| i as usize);
+    let from_halfwidth = halfwidth_index
+        .and_then(|i| HALFWIDTH.chars().nth(i))
+        .into_iter()
+        .flat_map(|full| {
+            let voiced = DAKUTEN.iter().filter(move |(base, _)| *base == full);
+            let voiced = voiced.map(|&(_, voiced)| Lookalike {
+                input_rest: "ﾞ",
+                ..Lookalike::new(voiced, 0)
+            });
+            let semi_voiced = HANDAKUTEN.iter().filter(move |(base, _)| *base == full);
+            let semi_voiced = semi_voiced.map(|&(_, voiced)| Lookalike {
+                input_rest: "ﾟ",
+                ..Lookalike::new(voiced, 0)
+            });
+            iter::once(Lookalike::new(full, 0))
+                .chain(voiced)
+                .chain(semi_voiced)
+        });
+    let marks = match ch {
+        'ﾞ' => Some(['\u{3099}', '゛']),
+        'ﾟ' => Some(['\u{309A}', '゜']),
+        _ => None,
     };
-    chars.iter().copied()
+    let marks = marks
+        .into_iter()
+        .flatten()
+        .map(|mark| Lookalike::new(mark, 0));
+
+    let to_halfwidth = HALFWIDTH
+        .chars()
+        .zip('\u{FF61}'..)
+        .filter(move |(full, _)| *full == ch)
+        .map(|(_, half)| Lookalike::new(half, 0));
+    let voiced_to_halfwidth = DAKUTEN
+        .iter()
+        .map(|&(base, voiced)| (base, voiced, "ﾞ"))
+        .chain(HANDAKUTEN.iter().map(|&(base, voiced)| (base, voiced, "ﾟ")))
+        .filter(move |(_, voiced, _)| *voiced == ch)
+        .flat_map(|(base, _, mark)| {
+            HALFWIDTH
+                .chars()
+                .zip('\u{FF61}'..)
+                .filter(move |(full, _)| *full == base)
+                .map(move |(_, half)| Lookalike {
+                    key_rest: mark,
+                    ..Lookalike::new(half, 0)
+                })
+        });
+
+    let sequences = SEQUENCES.iter().flat_map(move |&(single, seq)| {
+        let folded = (single == ch).then(|| Lookalike::sequence("", seq, 0));
+        let unfolded = seq.strip_prefix(ch).map(|input_rest| Lookalike {
+            input_rest,
+            ..Lookalike::new(single, 0)
+        });
+        folded.into_iter().chain(unfolded)
+    });
+
+    fullwidth
+        .map(|ch| Lookalike::new(ch, 0))
+        .into_iter()
+        .chain(from_halfwidth)
+        .chain(marks)
+        .chain(to_halfwidth)
+        .chain(voiced_to_halfwidth)
+        .chain(sequences)
 }
 
-/// Returns an iterator that combines all iterators over lookalikes defined in this module.
+/// Returns an iterator that combines [`qwerty_misclicks`] & [`variants`], the lookalikes that are
+/// relevant for most search bars.
 pub fn all(ch: char) -> impl Iterator<Item = char> + Clone {
     qwerty_misclicks(ch).chain(variants(ch))
 }
@@ -0,0 +1,9813 @@
+//! The confusables data of [UTS #39](https://www.unicode.org/reports/tr39/), generated from
+//! `confusables.txt` of Unicode 16.0.
+//!
+//! Every line of `confusables.txt` maps a character to its prototype, the sequence that it's
+//! confusable with. Characters with the same prototype are confusable with each other, see
+//! [`homoglyphs`](super::homoglyphs).
+
+/// The characters that have each prototype, sorted by the prototype.
+pub static GROUPS: &[(&str, &str)] = &[
+    (
+        " ",
+        "\u{A0}\u{1680}\u{2000}\u{2001}\u{2002}\u{2003}\u{2004}\u{2005}\u{2006}\u{2007}\u{2008}\u{2009}\u{200A}\u{2028}\u{2029}\u{202F}\u{205F}",
+    ),
+    ("!", "ǃⵑ！"),
+    ("!!", "‼"),
+    ("!?", "⁉"),
+    ("$\u{20E0}", "🄏"),
+    ("&", "ꝸ"),
+    (
+        "'",
+        "`´\u{2B9}\u{2BB}\u{2BC}\u{2BD}\u{2BE}\u{2C8}\u{2CA}\u{2CB}˴\u{374}΄՚՝\u{5D9}\u{5F3}\u{7F4}\u{7F5}ᑊᛌ᾽᾿\u{1FEF}\u{1FFD}῾‘’‛′‵ꞌ＇｀\u{16F51}\u{16F52}",
+    ),
+    ("''", "\"\u{2BA}˝\u{2EE}˶\u{5F2}\u{5F4}᳓“”‟″‶〃＂"),
+    ("'''", "‴‷"),
+    ("''''", "⁗"),
+    ("'B", "Ɓ"),
+    ("'D", "Ɗ"),
+    ("'P", "Ƥ"),
+    ("'T", "Ƭ"),
+    ("'Y", "Ƴ"),
+    ("'n", "ŉ"),
+    ("(", "❨❲〔﴾［"),
+    ("((", "⸨"),
+    ("(2)", "⑵"),
+    ("(2O)", "⒇"),
+    ("(3)", "⑶"),
+    ("(4)", "⑷"),
+    ("(5)", "⑸"),
+    ("(6)", "⑹"),
+    ("(7)", "⑺"),
+    ("(8)", "⑻"),
+    ("(9)", "⑼"),
+    ("(A)", "🄐"),
+    ("(B)", "🄑"),
+    ("(C)", "🄒"),
+    ("(D)", "🄓"),
+    ("(E)", "🄔"),
+    ("(F)", "🄕"),
+    ("(G)", "🄖"),
+    ("(H)", "🄗"),
+    ("(J)", "🄙"),
+    ("(K)", "🄚"),
+    ("(L)", "🄛"),
+    ("(M)", "🄜"),
+    ("(N)", "🄝"),
+    ("(O)", "🄞"),
+    ("(P)", "🄟"),
+    ("(Q)", "🄠"),
+    ("(R)", "🄡"),
+    ("(S)", "🄢🄪"),
+    ("(T)", "🄣"),
+    ("(U)", "🄤"),
+    ("(V)", "🄥"),
+    ("(W)", "🄦"),
+    ("(X)", "🄧"),
+    ("(Y)", "🄨"),
+    ("(Z)", "🄩"),
+    ("(a)", "⒜"),
+    ("(b)", "⒝"),
+    ("(c)", "⒞"),
+    ("(d)", "⒟"),
+    ("(e)", "⒠"),
+    ("(f)", "⒡"),
+    ("(g)", "⒢"),
+    ("(h)", "⒣"),
+    ("(i)", "⒤"),
+    ("(j)", "⒥"),
+    ("(k)", "⒦"),
+    ("(l)", "⑴⒧🄘"),
+    ("(l2)", "⑿"),
+    ("(l3)", "⒀"),
+    ("(l4)", "⒁"),
+    ("(l5)", "⒂"),
+    ("(l6)", "⒃"),
+    ("(l7)", "⒄"),
+    ("(l8)", "⒅"),
+    ("(l9)", "⒆"),
+    ("(lO)", "⑽"),
+    ("(ll)", "⑾"),
+    ("(n)", "⒩"),
+    ("(o)", "⒪"),
+    ("(p)", "⒫"),
+    ("(q)", "⒬"),
+    ("(r)", "⒭"),
+    ("(rn)", "⒨"),
+    ("(s)", "⒮"),
+    ("(t)", "⒯"),
+    ("(u)", "⒰"),
+    ("(v)", "⒱"),
+    ("(w)", "⒲"),
+    ("(x)", "⒳"),
+    ("(y)", "⒴"),
+    ("(z)", "⒵"),
+    ("(ᄀ)", "㈀"),
+    ("(ᄂ)", "㈁"),
+    ("(ᄃ)", "㈂"),
+    ("(ᄅ)", "㈃"),
+    ("(ᄆ)", "㈄"),
+    ("(ᄇ)", "㈅"),
+    ("(ᄉ)", "㈆"),
+    ("(ᄋ)", "㈇"),
+    ("(ᄌ)", "㈈"),
+    ("(ᄎ)", "㈉"),
+    ("(ᄏ)", "㈊"),
+    ("(ᄐ)", "㈋"),
+    ("(ᄑ)", "㈌"),
+    ("(ᄒ)", "㈍"),
+    ("(\u{30FC})", "㈠"),
+    ("(七)", "㈦"),
+    ("(三)", "㈢🉁"),
+    ("(九)", "㈨"),
+    ("(二)", "㈡🉂"),
+    ("(五)", "㈤"),
+    ("(代)", "㈹"),
+    ("(企)", "㈽"),
+    ("(休)", "㉁"),
+    ("(八)", "㈧"),
+    ("(六)", "㈥"),
+    ("(労)", "㈸"),
+    ("(勝)", "🉇"),
+    ("(十)", "㈩"),
+    ("(協)", "㈿"),
+    ("(名)", "㈴"),
+    ("(呼)", "㈺"),
+    ("(四)", "㈣"),
+    ("(土)", "㈯"),
+    ("(学)", "㈻"),
+    ("(安)", "🉃"),
+    ("(打)", "🉅"),
+    ("(敗)", "🉈"),
+    ("(日)", "㈰"),
+    ("(月)", "㈪"),
+    ("(有)", "㈲"),
+    ("(木)", "㈭"),
+    ("(本)", "🉀"),
+    ("(株)", "㈱"),
+    ("(水)", "㈬"),
+    ("(火)", "㈫"),
+    ("(点)", "🉄"),
+    ("(特)", "㈵"),
+    ("(盗)", "🉆"),
+    ("(監)", "㈼"),
+    ("(社)", "㈳"),
+    ("(祝)", "㈷"),
+    ("(祭)", "㉀"),
+    ("(自)", "㉂"),
+    ("(至)", "㉃"),
+    ("(財)", "㈶"),
+    ("(資)", "㈾"),
+    ("(金)", "㈮"),
+    ("(가)", "㈎"),
+    ("(나)", "㈏"),
+    ("(다)", "㈐"),
+    ("(라)", "㈑"),
+    ("(마)", "㈒"),
+    ("(바)", "㈓"),
+    ("(사)", "㈔"),
+    ("(아)", "㈕"),
+    ("(오전)", "㈝"),
+    ("(오후)", "㈞"),
+    ("(자)", "㈖"),
+    ("(주)", "㈜"),
+    ("(차)", "㈗"),
+    ("(카)", "㈘"),
+    ("(타)", "㈙"),
+    ("(파)", "㈚"),
+    ("(하)", "㈛"),
+    (")", "❩❳〕﴿］"),
+    ("))", "⸩"),
+    ("*", "\u{66D}⁎∗𐌟"),
+    ("+", "᛭➕𐊛"),
+    ("+\u{302}", "⨣"),
+    ("+\u{303}", "⨤"),
+    ("+\u{307}", "∔"),
+    ("+\u{30A}", "⨢"),
+    ("+\u{323}", "⨥"),
+    ("+\u{330}", "⨦"),
+    ("+₂", "⨧"),
+    (",", "¸\u{60D}\u{66B}‚\u{A4F9}"),
+    ("-", "˗\u{6D4}‐‑‒–⁃−➖Ⲻ﹘"),
+    ("-.", "꓾"),
+    ("-\u{307}", "∸﬩"),
+    ("-\u{308}", "⸚"),
+    ("-\u{313}", "⨩"),
+    ("-\u{323}", "⨪"),
+    (".", "\u{660}۰\u{701}\u{702}․\u{A4F8}꘎\u{10A50}\u{1D16D}"),
+    (".,", "\u{A4FB}"),
+    ("..", "‥\u{A4FA}"),
+    ("...", "…"),
+    ("/", "᜵⁁⁄∕╱⟋⧸Ⳇ⼃\u{3033}ノ㇓丿𝈺"),
+    ("//", "⫽"),
+    ("///", "⫻"),
+    ("/\u{304}", "⧶"),
+    ("2", "ƧϨᒿꙄꛯꝚ\u{1CCF2}𝟐𝟚𝟤𝟮𝟸🯲"),
+    ("2,", "🄃"),
+    ("2.", "⒉"),
+    ("22日", "㏵"),
+    ("22点", "㍮"),
+    ("23日", "㏶"),
+    ("23点", "㍯"),
+    ("24日", "㏷"),
+    ("24点", "㍰"),
+    ("25日", "㏸"),
+    ("26日", "㏹"),
+    ("27日", "㏺"),
+    ("28日", "㏻"),
+    ("29日", "㏼"),
+    ("2O.", "⒛"),
+    ("2O日", "㏳"),
+    ("2O点", "㍬"),
+    ("2l日", "㏴"),
+    ("2l点", "㍭"),
+    ("2\u{335}", "ƻ"),
+    ("2日", "㏡"),
+    ("2月", "㋁"),
+    ("2点", "㍚"),
+    ("3", "ƷȜЗӠⳌꝪꞫ𑣊𖼻\u{1CCF3}𝈆𝟑𝟛𝟥𝟯𝟹🯳"),
+    ("3,", "🄄"),
+    ("3.", "⒊"),
+    ("3O日", "㏽"),
+    ("3l日", "㏾"),
+    ("3\u{326}", "Ҙ"),
+    ("3日", "㏢"),
+    ("3月", "㋂"),
+    ("3点", "㍛"),
+    ("4", "Ꮞ𑢯\u{1CCF4}𝟒𝟜𝟦𝟰𝟺🯴"),
+    ("4,", "🄅"),
+    ("4.", "⒋"),
+    ("4·", "ᔰ"),
+    ("4日", "㏣"),
+    ("4月", "㋃"),
+    ("4点", "㍜"),
+    ("5", "Ƽ𑢻\u{1CCF5}𝟓𝟝𝟧𝟱𝟻🯵"),
+    ("5,", "🄆"),
+    ("5.", "⒌"),
+    ("5日", "㏤"),
+    ("5月", "㋄"),
+    ("5点", "㍝"),
+    ("6", "бᏮⳒ𑣕\u{1CCF6}𝟔𝟞𝟨𝟲𝟼🯶"),
+    ("6,", "🄇"),
+    ("6.", "⒍"),
+    ("6日", "㏥"),
+    ("6月", "㋅"),
+    ("6点", "㍞"),
+    ("7", "𐓒𑣆\u{1CCF7}𝈒𝟕𝟟𝟩𝟳𝟽🯷"),
+    ("7,", "🄈"),
+    ("7.", "⒎"),
+    ("7日", "㏦"),
+    ("7月", "㋆"),
+    ("7点", "㍟"),
+    ("8", "Ȣȣ৪੪\u{B03}𐌚\u{1CCF8}𝟖𝟠𝟪𝟴𝟾\u{1E8CB}🯸"),
+    ("8,", "🄉"),
+    ("8.", "⒏"),
+    ("8日", "㏧"),
+    ("8月", "㋇"),
+    ("8点", "㍠"),
+    ("9", "৭੧୨൭ⳊꝮ𑢬𑣌𑣖\u{1CCF9}𝟗𝟡𝟫𝟵𝟿🯹"),
+    ("9,", "🄊"),
+    ("9.", "⒐"),
+    ("9日", "㏨"),
+    ("9月", "㋈"),
+    ("9点", "㍡"),
+    (
+        ":",
+        "\u{2D0}˸։\u{5C3}\u{703}\u{704}\u{903}\u{A83}᛬᠃᠉⁚∶\u{A4FD}꞉︰：",
+    ),
+    ("::=", "⩴"),
+    (":→", "⧴"),
+    (";", "\u{37E}"),
+    ("<", "˂ᐸᚲ‹❮𝈶"),
+    ("<<", "≪"),
+    ("<<<", "⋘"),
+    ("<·", "ᑅ⋖Ⲵ"),
+    ("=", "᐀⹀゠꓿"),
+    ("==", "⩵"),
+    ("===", "⩶"),
+    ("=\u{302}", "≙"),
+    ("=\u{306}", "≚"),
+    ("=\u{307}", "≐"),
+    ("=\u{307}\u{323}", "≑"),
+    ("=\u{30A}", "≗"),
+    ("=\u{36B}", "≞"),
+    ("=\u{20F0}", "⩮"),
+    (">", "˃ᐳ›❯𖼿𝈷"),
+    ("><", "⪥"),
+    (">>", "≫⨠"),
+    (">>>", "⋙"),
+    (">·", "ᑁ"),
+    ("?", "ɁʔॽᎮꛫ"),
+    ("?!", "⁈"),
+    ("??", "⁇"),
+    ("A", "ΑАᎪᗅꓮＡ𐊠𖽀\u{1CCD6}𝐀𝐴𝑨𝒜𝓐𝔄𝔸𝕬𝖠𝗔𝘈𝘼𝙰𝚨𝛢𝜜𝝖𝞐"),
+    ("AA", "Ꜳ"),
+    ("AE", "ÆӔ"),
+    ("AO", "Ꜵ"),
+    ("AR", "🜇"),
+    ("AU", "Ꜷ"),
+    ("AV", "ꜸꜺ"),
+    ("AY", "Ꜽ"),
+    ("B", "ΒВᏴᗷℬꓐꞴＢ𐊂𐊡𐌁\u{1CCD7}𝐁𝐵𝑩𝓑𝔅𝔹𝕭𝖡𝗕𝘉𝘽𝙱𝚩𝛣𝜝𝝗𝞑"),
+    ("C", "ϹСᏟℂℭⅭⲤꓚＣ𐊢𐌂𐐕𐔜𑣩𑣲\u{1CCD8}𝐂𝐶𝑪𝒞𝓒𝕮𝖢𝗖𝘊𝘾𝙲🝌"),
+    ("C'", "Ƈ"),
+    ("C\u{326}", "ÇҪ"),
+    ("C\u{20E0}", "🅮"),
+    ("C\u{20EB}", "₡"),
+    ("D", "ᎠᗞᗪⅅⅮꓓ\u{1CCD9}𝐃𝐷𝑫𝒟𝓓𝔇𝔻𝕯𝖣𝗗𝘋𝘿𝙳"),
+    ("DZ", "Ǳ"),
+    ("Dz", "ǲ"),
+    ("DŽ", "Ǆ"),
+    ("Dž", "ǅ"),
+    ("D\u{335}", "ÐĐƉ"),
+    ("E", "ΕЕᎬℰ⋿ⴹꓰＥ𐊆𑢦𑢮\u{1CCDA}𝐄𝐸𝑬𝓔𝔈𝔼𝕰𝖤𝗘𝘌𝙀𝙴𝚬𝛦𝜠𝝚𝞔"),
+    ("E\u{338}", "Ɇ"),
+    ("F", "ϜᖴℱꓝꞘ𐊇𐊥𐔥𑢢𑣂\u{1CCDB}𝈓𝐅𝐹𝑭𝓕𝔉𝔽𝕱𝖥𝗙𝘍𝙁𝙵𝟊"),
+    ("FAX", "℻"),
+    ("F\u{326}", "Ƒ"),
+    ("G", "ԌᏀᏳꓖ\u{1CCDC}𝐆𝐺𝑮𝒢𝓖𝔊𝔾𝕲𝖦𝗚𝘎𝙂𝙶"),
+    ("G'", "Ɠ"),
+    ("G\u{335}", "Ǥ"),
+    ("H", "ΗНᎻᕼℋℌℍⲎꓧＨ𐋏\u{1CCDD}𝐇𝐻𝑯𝓗𝕳𝖧𝗛𝘏𝙃𝙷𝚮𝛨𝜢𝝜𝞖"),
+    ("H\u{326}", "ӇӉ"),
+    ("H\u{329}", "ҢⱧ"),
+    ("H\u{335}", "Ħ"),
+    ("J", "ͿЈᎫᒍꓙꞲＪ\u{1CCDF}𝐉𝐽𝑱𝒥𝓙𝔍𝕁𝕵𝖩𝗝𝘑𝙅𝙹"),
+    ("J·", "ᒙ"),
+    ("J\u{335}", "Ɉ"),
+    ("K", "ΚКᏦᛕ\u{212A}ⲔꓗＫ𐔘\u{1CCE0}𝐊𝐾𝑲𝒦𝓚𝔎𝕂𝕶𝖪𝗞𝘒𝙆𝙺𝚱𝛫𝜥𝝟𝞙"),
+    ("K'", "Ƙ"),
+    ("K\u{329}", "ҚⱩ"),
+    ("K\u{335}", "Ҟ₭Ꝁ"),
+    ("L", "ᏞᒪℒⅬⳐꓡ𐐛𐔦𑢣𑢲𖼖\u{1CCE1}𝈪𝐋𝐿𝑳𝓛𝔏𝕃𝕷𝖫𝗟𝘓𝙇𝙻"),
+    ("LJ", "Ǉ"),
+    ("Lj", "ǈ"),
+    ("L\u{338}", "Ł"),
+    ("M", "ΜϺМᎷᗰᛖℳⅯⲘꓟＭ𐊰𐌑\u{1CCE2}𝐌𝑀𝑴𝓜𝔐𝕄𝕸𝖬𝗠𝘔𝙈𝙼𝚳𝛭𝜧𝝡𝞛"),
+    ("MB", "🝫"),
+    ("M\u{326}", "Ӎ"),
+    ("N", "ΝℕⲚꓠＮ𐔓\u{1CCE3}𝐍𝑁𝑵𝒩𝓝𝔑𝕹𝖭𝗡𝘕𝙉𝙽𝚴𝛮𝜨𝝢𝞜"),
+    ("NJ", "Ǌ"),
+    ("Nj", "ǋ"),
+    ("No", "№"),
+    ("N\u{30A}", "𐆎"),
+    ("N\u{326}", "Ɲ"),
+    (
+        "O",
+        "0ΟОՕ\u{7C0}০ଠ୦ዐⲞⵔ〇ꓳＯ𐊒𐊫𐐄𐓂𐔖𑓐𑢵𑣠\u{1CCE4}\u{1CCF0}𝐎𝑂𝑶𝒪𝓞𝔒𝕆𝕺𝖮𝗢𝘖𝙊𝙾𝚶𝛰𝜪𝝤𝞞𝟎𝟘𝟢𝟬𝟶🯰",
+    ),
+    ("O'", "ƠᎤ"),
+    ("O,", "🄁"),
+    ("O.", "🄀"),
+    ("OE", "Œ"),
+    ("OO", "ꚘꝎ"),
+    ("O\u{335}", "ƟΘθϑϴѲӨᎾᏫ⊖⊝⍬ⴱꝊ𝈚𝚯𝚹𝛉𝛝𝛩𝛳𝜃𝜗𝜣𝜭𝜽𝝑𝝝𝝧𝝷𝞋𝞗𝞡𝞱𝟅🜔"),
+    ("O\u{338}", "Øⵁ"),
+    ("O\u{338}\u{301}", "Ǿ"),
+    ("O点", "㍘"),
+    ("P", "ΡРᏢᑭℙⲢꓑＰ𐊕\u{1CCE5}𝐏𝑃𝑷𝒫𝓟𝔓𝕻𝖯𝗣𝘗𝙋𝙿𝚸𝛲𝜬𝝦𝞠"),
+    ("P'", "ᒆ"),
+    ("Q", "ℚⵕ\u{1CCE6}𝐐𝑄𝑸𝒬𝓠𝔔𝕼𝖰𝗤𝘘𝙌𝚀"),
+    ("QE", "🜀"),
+    ("R", "ƦᎡᏒᖇℛℜℝꓣ𐒴𖼵\u{1CCE7}𝈖𝐑𝑅𝑹𝓡𝕽𝖱𝗥𝘙𝙍𝚁"),
+    ("Rs", "₨"),
+    ("S", "ЅՏᏕᏚꓢＳ𐊖𐐠𖼺\u{1CCE8}𝐒𝑆𝑺𝒮𝓢𝔖𝕊𝕾𝖲𝗦𝘚𝙎𝚂"),
+    ("T", "ΤТᎢ⊤⟙ⲦꓔＴ𐊗𐊱𐌕𑢼𖼊\u{1CCE9}𝐓𝑇𝑻𝒯𝓣𝔗𝕋𝕿𝖳𝗧𝘛𝙏𝚃𝚻𝛵𝜯𝝩𝞣🝨"),
+    ("T3", "Ꜩ"),
+    ("TEL", "℡"),
+    ("T\u{308}", "⍡"),
+    ("T\u{328}", "Ʈ"),
+    ("T\u{329}", "Ҭ"),
+    ("T\u{335}", "Ŧ"),
+    ("T\u{338}", "Ⱦ"),
+    ("T\u{20EB}", "₮"),
+    ("U", "Սሀᑌ∪⋃ꓴ𐓎𑢸𖽂\u{1CCEA}𝐔𝑈𝑼𝒰𝓤𝔘𝕌𝖀𝖴𝗨𝘜𝙐𝚄"),
+    ("U'", "ᑧ"),
+    ("U·", "ᑘ"),
+    ("U\u{335}", "ɄᏌ"),
+    ("V", "Ѵ\u{667}۷ᏙᐯⅤⴸꓦꛟ𐔝𑢠𖼈\u{1CCEB}𝈍𝐕𝑉𝑽𝒱𝓥𝔙𝕍𝖁𝖵𝗩𝘝𝙑𝚅"),
+    ("VB", "🝬"),
+    ("Vl", "Ⅵ"),
+    ("Vll", "Ⅶ"),
+    ("Vlll", "Ⅷ"),
+    ("V·", "ᐻ"),
+    ("V\u{335}", "𐆗"),
+    ("V\u{1DE4}", "🜈"),
+    ("W", "ԜᎳᏔꓪ𑣦𑣯\u{1CCEC}𝐖𝑊𝑾𝒲𝓦𝔚𝕎𝖂𝖶𝗪𝘞𝙒𝚆"),
+    ("W\u{335}", "₩"),
+    ("X", "ΧХ᙭ᚷⅩ╳ⲬⵝꓫꞳＸ𐊐𐊴𐌗𐌢𐔧𑣬\u{1CCED}𝐗𝑋𝑿𝒳𝓧𝔛𝕏𝖃𝖷𝗫𝘟𝙓𝚇𝚾𝛸𝜲𝝬𝞦"),
+    ("Xl", "Ⅺ"),
+    ("Xll", "Ⅻ"),
+    ("X\u{329}", "Ҳ"),
+    ("X\u{335}", "𐆖"),
+    ("Y", "ΥϒУҮᎩᎽⲨꓬＹ𐊲𑢤𖽃\u{1CCEE}𝐘𝑌𝒀𝒴𝓨𝔜𝕐𝖄𝖸𝗬𝘠𝙔𝚈𝚼𝛶𝜰𝝪𝞤"),
+    ("Y\u{335}", "¥ɎҰ"),
+    ("Z", "ΖᏃℤℨꓜＺ𐋵𑢩𑣥\u{1CCEF}𝐙𝑍𝒁𝒵𝓩𝖅𝖹𝗭𝘡𝙕𝚉𝚭𝛧𝜡𝝛𝞕"),
+    ("Z\u{326}", "Ȥ"),
+    ("Z\u{335}", "Ƶ"),
+    ("\\", "∖⟍⧵⧹⼂㇔丶﹨＼𝈏𝈻"),
+    ("\\\\", "⑊⳹"),
+    ("\\ᑕ", "⟈"),
+    ("^", "˄\u{2C6}"),
+    ("_", "\u{7FA}﹍﹎﹏"),
+    ("a", "ɑαа⍺ａ𝐚𝑎𝒂𝒶𝓪𝔞𝕒𝖆𝖺𝗮𝘢𝙖𝚊𝛂𝛼𝜶𝝰𝞪"),
+    ("a/c", "℀"),
+    ("a/s", "℁"),
+    ("aa", "ꜳ"),
+    ("ae", "æӕ"),
+    ("ao", "ꜵ"),
+    ("au", "ꜷ"),
+    ("av", "ꜹꜻ"),
+    ("ay", "ꜽ"),
+    ("a\u{332}", "⍶"),
+    ("b", "ƄЬᏏᑲᖯ𝐛𝑏𝒃𝒷𝓫𝔟𝕓𝖇𝖻𝗯𝘣𝙗𝚋"),
+    ("b'", "ᒈ"),
+    ("bl", "Ы"),
+    ("b·", "ᑿ"),
+    ("b\u{304}", "ƂƃБ"),
+    ("b\u{307}", "ᑳ"),
+    ("b\u{307}·", "ᒁ"),
+    ("b\u{314}", "ɓ"),
+    ("b\u{335}", "ƀѢѣҌҍ"),
+    ("c", "ϲсᴄⅽⲥꮯｃ𐐽𝐜𝑐𝒄𝒸𝓬𝔠𝕔𝖈𝖼𝗰𝘤𝙘𝚌"),
+    ("c/o", "℅"),
+    ("c/u", "℆"),
+    ("c\u{326}", "çҫ"),
+    ("c\u{338}", "¢ȼ"),
+    ("d", "ԁᏧᑯⅆⅾꓒ𝐝𝑑𝒅𝒹𝓭𝔡𝕕𝖉𝖽𝗱𝘥𝙙𝚍"),
+    ("d'", "ᒇ"),
+    ("dz", "ǳʣ"),
+    ("d·", "ᑻ"),
+    ("dž", "ǆ"),
+    ("dȝ", "ʤ"),
+    ("dʑ", "ʥ"),
+    ("d\u{304}", "ƌ"),
+    ("d\u{314}", "ɗ"),
+    ("d\u{328}", "ɖ"),
+    ("d\u{335}", "đ"),
+    ("d\u{335}\u{331}", "₫"),
+    ("e", "еҽ℮ℯⅇꬲｅ𝐞𝑒𝒆𝓮𝔢𝕖𝖊𝖾𝗲𝘦𝙚𝚎"),
+    ("e\u{328}", "ҿ"),
+    ("e\u{338}", "ɇ"),
+    ("f", "ſքẝꞙꬵ𝐟𝑓𝒇𝒻𝓯𝔣𝕗𝖋𝖿𝗳𝘧𝙛𝚏"),
+    ("ff", "ﬀ"),
+    ("ffi", "ﬃ"),
+    ("ffl", "ﬄ"),
+    ("fi", "ﬁ"),
+    ("fl", "ﬂ"),
+    ("fŋ", "ʩ"),
+    ("f\u{326}", "ƒ"),
+    ("f\u{334}", "ᵮ"),
+    ("g", "ƍɡցᶃℊｇ𝐠𝑔𝒈𝓰𝔤𝕘𝖌𝗀𝗴𝘨𝙜𝚐"),
+    ("g\u{314}", "ɠ"),
+    ("g\u{335}", "ǥ"),
+    ("h", "һհᏂℎｈ𝐡𝒉𝒽𝓱𝔥𝕙𝖍𝗁𝗵𝘩𝙝𝚑"),
+    ("h\u{314}", "ɦᏲꚕ"),
+    ("h\u{335}", "ħћℏ"),
+    ("i", "ıɩɪ˛\u{37A}ιіӏᎥ\u{1FBE}ℹⅈⅰ⍳ꙇꭵｉ𑣃𝐢𝑖𝒊𝒾𝓲𝔦𝕚𝖎𝗂𝗶𝘪𝙞𝚒𝚤𝛊𝜄𝜾𝝸𝞲"),
+    ("ii", "ⅱ"),
+    ("iii", "ⅲ"),
+    ("ij", "ĳ"),
+    ("iv", "ⅳ"),
+    ("ix", "ⅸ"),
+    ("i\u{332}", "⍸"),
+    ("i\u{335}", "ɨᵻᵼ"),
+    ("j", "ϳјⅉｊ𝐣𝑗𝒋𝒿𝓳𝔧𝕛𝖏𝗃𝗷𝘫𝙟𝚓"),
+    ("j\u{335}", "ɉ"),
+    ("k", "𝐤𝑘𝒌𝓀𝓴𝔨𝕜𝖐𝗄𝗸𝘬𝙠𝚔"),
+    ("k\u{314}", "ƙ"),
+    (
+        "l",
+        "1I|ƖǀΙІӀ\u{5C0}\u{5D5}\u{5DF}\u{627}\u{661}۱\u{7CA}ᛁℐℑℓⅠⅼ∣⏽Ⲓⵏꓲ\u{FE8D}\u{FE8E}Ｉｌ￨𐊊𐌉𐌠𖼨\u{1CCDE}\u{1CCF1}𝐈𝐥𝐼𝑙𝑰𝒍𝓁𝓘𝓵𝔩𝕀𝕝𝕴𝖑𝖨𝗅𝗜𝗹𝘐𝘭𝙄𝙡𝙸𝚕𝚰𝛪𝜤𝝞𝞘𝟏𝟙𝟣𝟭𝟷\u{1E8C7}\u{1EE00}\u{1EE80}🯱",
+    ),
+    ("l'", "\u{5F1}"),
+    ("l,", "🄂"),
+    ("l.", "⒈"),
+    ("l2.", "⒓"),
+    ("l2日", "㏫"),
+    ("l2月", "㋋"),
+    ("l2点", "㍤"),
+    ("l3.", "⒔"),
+    ("l3日", "㏬"),
+    ("l3点", "㍥"),
+    ("l4.", "⒕"),
+    ("l4日", "㏭"),
+    ("l4点", "㍦"),
+    ("l5.", "⒖"),
+    ("l5日", "㏮"),
+    ("l5点", "㍧"),
+    ("l6.", "⒗"),
+    ("l6日", "㏯"),
+    ("l6点", "㍨"),
+    ("l7.", "⒘"),
+    ("l7日", "㏰"),
+    ("l7点", "㍩"),
+    ("l8.", "⒙"),
+    ("l8日", "㏱"),
+    ("l8点", "㍪"),
+    ("l9.", "⒚"),
+    ("l9日", "㏲"),
+    ("l9点", "㍫"),
+    ("lJ", "Ĳ"),
+    ("lO", "Ю"),
+    ("lO.", "⒑"),
+    ("lO日", "㏩"),
+    ("lO月", "㋉"),
+    ("lO点", "㍢"),
+    ("lV", "Ⅳ"),
+    ("lX", "Ⅸ"),
+    ("lj", "ǉ"),
+    ("ll", "ǁ\u{5F0}‖Ⅱ∥"),
+    ("ll.", "⒒"),
+    ("lll", "Ⅲ"),
+    ("ll日", "㏪"),
+    ("ll月", "㋊"),
+    ("ll点", "㍣"),
+    ("ls", "ʪ"),
+    ("lt", "₶"),
+    ("lz", "ʫ"),
+    ("l·", "Ŀŀᒷ"),
+    ("lȝ", "ɮ"),
+    ("l\u{30B}", "\u{FD3C}\u{FD3D}"),
+    ("l\u{328}", "ɭ"),
+    ("l\u{334}", "ɫ"),
+    ("l\u{335}", "Ɨƚ"),
+    ("l\u{335}l\u{335}", "𐆙"),
+    ("l\u{335}l\u{335}S\u{335}", "𐆘"),
+    ("l\u{338}", "ł"),
+    ("l\u{643}\u{628}\u{631}", "\u{FDF3}"),
+    ("l\u{644}\u{644}\u{651}\u{670}o", "\u{FDF2}"),
+    ("l\u{655}", "\u{625}\u{673}\u{FE87}\u{FE88}"),
+    ("l\u{674}", "\u{623}\u{672}\u{675}\u{FE83}\u{FE84}"),
+    ("l日", "㏠"),
+    ("l月", "㋀"),
+    ("l点", "㍙"),
+    ("n", "ոռ𝐧𝑛𝒏𝓃𝓷𝔫𝕟𝖓𝗇𝗻𝘯𝙣𝚗"),
+    ("nj", "ǌ"),
+    ("n\u{328}", "ɳ"),
+    ("n\u{329}", "ƞη𝛈𝜂𝜼𝝶𝞰"),
+    ("n\u{334}", "ᵰ"),
+    (
+        "o",
+        "οσоօ\u{5E1}\u{647}\u{665}\u{6BE}\u{6C1}\u{6D5}۵०੦૦௦\u{C02}౦\u{C82}೦\u{D02}ഠ൦\u{D82}๐໐ဝ၀ჿᴏᴑℴⲟꬽ\u{FBA6}\u{FBA7}\u{FBA8}\u{FBA9}\u{FBAA}\u{FBAB}\u{FBAC}\u{FBAD}\u{FEE9}\u{FEEA}\u{FEEB}\u{FEEC}ｏ𐐬𐓪𑣈𑣗𝐨𝑜𝒐𝓸𝔬𝕠𝖔𝗈𝗼𝘰𝙤𝚘𝛐𝛔𝜊𝜎𝝄𝝈𝝾𝞂𝞸𝞼\u{1EE24}\u{1EE64}\u{1EE84}",
+    ),
+    ("o'", "ơ"),
+    ("oe", "œ"),
+    ("oo", "∞ꚙꝏ"),
+    ("o\u{302}", "\u{6FF}"),
+    ("o\u{31B}", "ꭴ"),
+    ("o\u{335}", "ɵѳөꝋꮎꮻ"),
+    ("o\u{338}", "øꬾ"),
+    ("o\u{62C}", "\u{FC51}\u{FCD7}"),
+    ("o\u{645}", "\u{FC52}\u{FCD8}"),
+    ("o\u{645}\u{62C}", "\u{FD93}"),
+    ("o\u{645}\u{645}", "\u{FD94}"),
+    ("o\u{649}", "\u{FC53}\u{FC54}"),
+    ("o\u{670}", "\u{FCD9}"),
+    ("oരo", "ൟ"),
+    ("o\u{102C}", "တ"),
+    ("oᴇ", "ɶ"),
+    ("p", "ρϱр⍴ⲣｐ𝐩𝑝𝒑𝓅𝓹𝔭𝕡𝖕𝗉𝗽𝘱𝙥𝚙𝛒𝛠𝜌𝜚𝝆𝝔𝞀𝞎𝞺𝟈"),
+    ("p·", "ᑷ"),
+    ("p\u{314}", "ƥ"),
+    ("p\u{335}", "ᵽ"),
+    ("q", "ԛգզ𝐪𝑞𝒒𝓆𝓺𝔮𝕢𝖖𝗊𝗾𝘲𝙦𝚚"),
+    ("q\u{314}", "ʠ"),
+    ("r", "гᴦⲅꭇꭈꮁ𝐫𝑟𝒓𝓇𝓻𝔯𝕣𝖗𝗋𝗿𝘳𝙧𝚛"),
+    ("r'", "ґ"),
+    ("rn", "mⅿ𑜀𑣣𝐦𝑚𝒎𝓂𝓶𝔪𝕞𝖒𝗆𝗺𝘮𝙢𝚖"),
+    ("rn\u{326}", "ɱ"),
+    ("rn\u{334}", "ᵯ"),
+    ("rn\u{338}", "₥"),
+    ("r\u{328}", "ɽ"),
+    ("r\u{329}", "ɼ"),
+    ("r\u{334}", "ᵲ"),
+    ("r\u{335}", "ɍғ"),
+    ("s", "ƽѕꜱꮪｓ𐑈𑣁𝐬𝑠𝒔𝓈𝓼𝔰𝕤𝖘𝗌𝘀𝘴𝙨𝚜"),
+    ("sss", "🝜"),
+    ("st", "ﬆ"),
+    ("s\u{328}", "ʂ"),
+    ("s\u{334}", "ᵴ"),
+    ("t", "𝐭𝑡𝒕𝓉𝓽𝔱𝕥𝖙𝗍𝘁𝘵𝙩𝚝"),
+    ("tf", "ꝷ"),
+    ("ts", "ʦ"),
+    ("tȝ", "ꜩ"),
+    ("tɕ", "ʨ"),
+    ("tʃ", "ʧ"),
+    ("t\u{314}", "ƭ"),
+    ("t\u{334}", "ᵵ"),
+    ("t\u{335}", "ŧ"),
+    ("u", "ʋυսᴜꞟꭎꭒ𐓶𑣘𝐮𝑢𝒖𝓊𝓾𝔲𝕦𝖚𝗎𝘂𝘶𝙪𝚞𝛖𝜐𝝊𝞄𝞾"),
+    ("ue", "ᵫ"),
+    ("uo", "ꭣ"),
+    ("u\u{335}", "ᵾꮜ"),
+    ("v", "νѵ\u{5D8}ᴠⅴ∨⋁ꮩｖ𑜆𑣀𝐯𝑣𝒗𝓋𝓿𝔳𝕧𝖛𝗏𝘃𝘷𝙫𝚟𝛎𝜈𝝂𝝼𝞶"),
+    ("vi", "ⅵ"),
+    ("vii", "ⅶ"),
+    ("viii", "ⅷ"),
+    ("w", "ɯѡԝաᴡꮃ𑜊𑜎𑜏𝐰𝑤𝒘𝓌𝔀𝔴𝕨𝖜𝗐𝘄𝘸𝙬𝚠"),
+    ("w\u{307}", "𑓅"),
+    ("w\u{326}", "ꝡ"),
+    ("w\u{486}\u{487}", "ѽ"),
+    ("x", "×хᕁᕽ᙮ⅹ⤫⤬⨯ｘ𝐱𝑥𝒙𝓍𝔁𝔵𝕩𝖝𝗑𝘅𝘹𝙭𝚡"),
+    ("xi", "ⅺ"),
+    ("xii", "ⅻ"),
+    ("x\u{307}", "⨰"),
+    ("y", "ɣʏγуүყᶌỿℽꭚｙ𑣜𝐲𝑦𝒚𝓎𝔂𝔶𝕪𝖞𝗒𝘆𝘺𝙮𝚢𝛄𝛾𝜸𝝲𝞬"),
+    ("y\u{314}", "ƴ"),
+    ("y\u{335}", "ɏұ"),
+    ("z", "ᴢꮓ𑣄𝐳𝑧𝒛𝓏𝔃𝔷𝕫𝖟𝗓𝘇𝘻𝙯𝚣"),
+    ("z\u{326}", "ȥ"),
+    ("z\u{328}", "ʐ"),
+    ("z\u{334}", "ᵶ"),
+    ("z\u{335}", "ƶ"),
+    ("{", "❴𝄔"),
+    ("}", "❵"),
+    ("~", "˜῀⁓∼"),
+    ("~\u{307}", "⩪⸞"),
+    ("~\u{308}", "⍨"),
+    ("~\u{323}", "⸟"),
+    ("£", "₤"),
+    ("©", "Ⓒ"),
+    ("®", "Ⓡ"),
+    ("°", "˚∘○◦⸰"),
+    ("°C", "℃"),
+    ("°F", "℉"),
+    ("°\u{308}", "⍤"),
+    ("°\u{332}", "⍜"),
+    ("¶", "⸿"),
+    ("·", "\u{387}ᐧ᛫•‧∙⋅⸱・ꞏ･𐄁"),
+    ("·4", "ᔯ"),
+    ("·<", "ᑄ"),
+    ("·>", "ᐷᑀ⋗"),
+    ("·J", "ᒘ"),
+    ("·L", "ᒶ"),
+    ("·P", "ᑶ"),
+    ("·U", "ᑗ"),
+    ("·V", "ᐺ"),
+    ("·b", "ᑾ"),
+    ("·b\u{307}", "ᒀ"),
+    ("·d", "ᑺ"),
+    ("···", "⋯ⵈ"),
+    ("·Ʌ", "ᐼ"),
+    ("·Γ", "ᒮ"),
+    ("·Δ", "ᐎ"),
+    ("·Ո", "ᑙ"),
+    ("·ᐁ", "ᐌ"),
+    ("·ᐄ", "ᐐ"),
+    ("·ᐅ", "ᐒ"),
+    ("·ᐆ", "ᐔ"),
+    ("·ᐊ", "ᐗ"),
+    ("·ᐋ", "ᐙ"),
+    ("·ᐲ", "ᐾ"),
+    ("·ᐴ", "ᑂ"),
+    ("·ᐹ", "ᑆ"),
+    ("·ᑏ", "ᑛ"),
+    ("·ᑐ", "ᑔᑝ"),
+    ("·ᑑ", "ᑟ"),
+    ("·ᑕ", "ᑡ"),
+    ("·ᑖ", "ᑣ"),
+    ("·ᑫ", "ᑴ"),
+    ("·ᑮ", "ᑸ"),
+    ("·ᑰ", "ᑼ"),
+    ("·ᒉ", "ᒒ"),
+    ("·ᒋ", "ᒔ"),
+    ("·ᒌ", "ᒖ"),
+    ("·ᒎ", "ᒚ"),
+    ("·ᒐ", "ᒜ"),
+    ("·ᒑ", "ᒞ"),
+    ("·ᒣ", "ᒬ"),
+    ("·ᒦ", "ᒰ"),
+    ("·ᒧ", "ᒲ"),
+    ("·ᒨ", "ᒴ"),
+    ("·ᒫ", "ᒸ"),
+    ("·ᓀ", "ᓉ"),
+    ("·ᓂ", "ᣆ"),
+    ("·ᓃ", "ᣈ"),
+    ("·ᓄ", "ᣊ"),
+    ("·ᓅ", "ᣌ"),
+    ("·ᓇ", "ᓋ"),
+    ("·ᓈ", "ᓍ"),
+    ("·ᓓ", "ᓜ"),
+    ("·ᓕ", "ᓞ"),
+    ("·ᓖ", "ᓠ"),
+    ("·ᓗ", "ᓢ"),
+    ("·ᓘ", "ᓤ"),
+    ("·ᓚ", "ᓦ"),
+    ("·ᓛ", "ᓨ"),
+    ("·ᓭ", "ᓶ"),
+    ("·ᓯ", "ᓸ"),
+    ("·ᓰ", "ᓺ"),
+    ("·ᓱ", "ᓼ"),
+    ("·ᓲ", "ᓾ"),
+    ("·ᓴ", "ᔀ"),
+    ("·ᓵ", "ᔂ"),
+    ("·ᔐ", "ᔗ"),
+    ("·ᔑ", "ᔙ"),
+    ("·ᔒ", "ᔛ"),
+    ("·ᔓ", "ᔝ"),
+    ("·ᔔ", "ᔟ"),
+    ("·ᔕ", "ᔡ"),
+    ("·ᔖ", "ᔣ"),
+    ("·ᔨ", "ᔱ"),
+    ("·ᔩ", "ᔳ"),
+    ("·ᔪ", "ᔵ"),
+    ("·ᔫ", "ᔷ"),
+    ("·ᔭ", "ᔹ"),
+    ("·ᔮ", "ᔻ"),
+    ("·ᕃ", "ᣎ"),
+    ("·ᕆ", "ᣏ"),
+    ("·ᕇ", "ᣐ"),
+    ("·ᕈ", "ᣑ"),
+    ("·ᕉ", "ᣒ"),
+    ("·ᕋ", "ᣓ"),
+    ("·ᕌ", "ᕎ"),
+    ("·ᕚ", "ᕛ"),
+    ("·ᕧ", "ᕨ"),
+    ("·ᢱ", "ᢳ"),
+    ("·ᢴ", "ᢶ"),
+    ("·ᢸ", "ᢹ"),
+    ("·ᣀ", "ᣂ"),
+    ("º", "\u{1D52}⁰"),
+    ("º/₀", "%٪⁒"),
+    ("º/₀₀", "؉‰"),
+    ("º/₀₀₀", "؊‱"),
+    ("Å", "Ȧ"),
+    ("Ö", "Ő"),
+    ("Þ", "Ϸ𐓄"),
+    ("ß", "βϐᏰẞꞵꟖ𝛃𝛽𝜷𝝱𝞫"),
+    ("å", "ȧ"),
+    ("÷", "➗"),
+    ("þ", "ƿϸ"),
+    ("Ă", "Ǎ"),
+    ("ă", "ǎ"),
+    ("Ĕ", "Ě"),
+    ("ĕ", "ě"),
+    ("Ğ", "Ǧ"),
+    ("ğ", "ǧ"),
+    ("ģ", "ǵ"),
+    ("Ĭ", "Ǐ"),
+    ("ĭ", "ǐ"),
+    ("ĸ", "κϰкᴋⲕꮶ𝛋𝛞𝜅𝜘𝜿𝝒𝝹𝞌𝞳𝟆"),
+    ("ĸ\u{329}", "қ"),
+    ("ĸ\u{335}", "ҟ"),
+    ("Ŏ", "Ǒ"),
+    ("ŏ", "ǒ"),
+    ("Ţ", "Ț"),
+    ("Ŭ", "Ǔ"),
+    ("ŭ", "ǔ"),
+    ("ƅ", "ьꮟ"),
+    ("ƅi", "ы"),
+    ("Ɔ", "ϽↃꓛ𐐣"),
+    ("Ǝ", "∃ⴺꓱ"),
+    ("Ə", "Ә"),
+    ("Ɛ", "ԐᏋℇ𐐁𖼭𝈡"),
+    ("ƨ", "ϩᴤꙅ"),
+    ("Ʃ", "Σ⅀∑ⵉ𝚺𝛴𝜮𝝨𝞢"),
+    ("ƫ", "ţțᎿ"),
+    ("Ʊ", "ᘮᘴ℧"),
+    ("ǝ", "əә"),
+    ("ǝo", "ᴔ"),
+    ("ǝo\u{335}", "ꭂ"),
+    ("ǝo\u{338}", "ꭁ"),
+    ("ǝ˞", "ɚ"),
+    ("Ƕ", "Ԋ"),
+    ("ȝ", "ʒӡჳⳍꝫ"),
+    ("ȷ", "յ𝚥"),
+    ("ɂ", "ꭾ"),
+    ("Ʌ", "ΛЛ\u{668}۸ᐱⴷꓥꛎ\u{A7DA}𐊍𐒰𖼽𝚲𝛬𝜦𝝠𝞚"),
+    ("Ʌ·", "ᐽ"),
+    ("Ʌ\u{326}", "Ӆ"),
+    ("Ʌ\u{338}", "\u{A7DC}"),
+    ("ɋ", "ᶐ"),
+    ("ɔ", "ͻᴐↄ𐑋"),
+    ("ɔe", "ꭢ"),
+    ("ɔ\u{338}", "ꬿ"),
+    ("ɜ", "зᴈ"),
+    ("ɜ\u{326}", "ҙ"),
+    ("ɞ", "𐑂"),
+    ("ɢ", "ԍᏻꮐ"),
+    ("ɰ", "պሣ"),
+    ("ɲ", "ņ"),
+    ("ɷ", "𐐿"),
+    ("ɸ", "φϕфⲫ𝛗𝛟𝜑𝜙𝝋𝝓𝞅𝞍𝞿𝟇"),
+    ("ɾ\u{334}", "ᵳ"),
+    ("ɿ", "℩"),
+    ("ʀ", "ꭱꮢ"),
+    ("ʃ", "∫ꭍ"),
+    ("ʃʃ", "∬"),
+    ("ʃʃʃ", "∭"),
+    ("ʃʃʃʃ", "⨌"),
+    ("ʊ\u{335}", "ᵿ"),
+    ("ʌ", "ᴧ𐓘"),
+    ("ʍ", "мᴍꮇ"),
+    ("ʍ\u{326}", "ӎ"),
+    ("ʘ", "⊙☉⨀ⵙꙨ𐓃"),
+    ("ʙ", "вᏼ"),
+    ("ʚ", "ꞝ𐐪"),
+    ("ʜ", "нꮋ"),
+    ("ʜ\u{326}", "ӈӊ"),
+    ("ʜ\u{329}", "ң"),
+    ("ʟ", "ⳑꮮ𐑃"),
+    ("ʡ", "ꛍ"),
+    ("\u{2C1}", "\u{2E4}"),
+    ("\u{2C7}", "˘꙾"),
+    ("\u{2C9}", "¯‾▔﹉﹊﹋﹌￣"),
+    ("\u{2C9}b", "ъ"),
+    ("\u{2C9}bi", "ꙑ"),
+    ("\u{2CF}", "͵"),
+    ("˪", "˻꜖"),
+    ("˫", "꜔"),
+    ("˳", "。"),
+    ("\u{300}", "\u{340}\u{953}"),
+    (
+        "\u{301}",
+        "\u{341}\u{59C}\u{59D}\u{618}\u{64E}\u{747}\u{954}",
+    ),
+    ("\u{302}", "\u{311}\u{65B}\u{7EE}\u{1CD0}\u{A6F0}"),
+    ("\u{303}", "\u{342}\u{653}"),
+    ("\u{304}", "\u{305}\u{659}\u{7EB}\u{1CD2}\u{A6F1}"),
+    ("\u{306}", "\u{30C}\u{36E}\u{658}\u{65A}\u{A67C}"),
+    (
+        "\u{306}\u{307}",
+        "\u{310}\u{6E8}\u{901}\u{981}\u{A81}\u{B01}\u{C00}\u{C81}\u{D01}\u{114BF}",
+    ),
+    (
+        "\u{307}",
+        "\u{358}\u{5B9}\u{5BA}\u{5C1}\u{5C2}\u{5C4}\u{6EC}\u{740}\u{741}\u{7ED}\u{8EA}\u{902}\u{A02}\u{A82}\u{BCD}",
+    ),
+    ("\u{308}", "\u{7F3}\u{8EB}"),
+    ("\u{309}", "\u{302C}"),
+    (
+        "\u{30A}",
+        "\u{366}\u{5AF}\u{652}\u{6DF}\u{B82}\u{E4D}\u{ECD}\u{1036}\u{17C6}\u{17D3}\u{2DEA}\u{309A}\u{11300}",
+    ),
+    ("\u{30A}า", "ำ"),
+    ("\u{30A}າ", "ຳ"),
+    ("\u{30B}", "\u{64B}\u{8F0}"),
+    ("\u{30E}", "\u{1CDA}"),
+    ("\u{312}", "\u{657}"),
+    ("\u{313}", "\u{315}\u{343}\u{619}\u{64F}\u{8F3}"),
+    ("\u{314}", "\u{65D}"),
+    ("\u{316}", "\u{1CED}"),
+    (
+        "\u{323}",
+        "\u{5B4}\u{5C5}\u{65C}\u{8ED}\u{93C}\u{9BC}\u{A3C}\u{ABC}\u{B3C}\u{1CDD}\u{10A3A}\u{111CA}\u{114C3}",
+    ),
+    ("\u{324}", "\u{8EE}\u{1CDE}"),
+    ("\u{325}", "\u{F37}\u{302D}"),
+    ("\u{326}", "\u{321}\u{327}\u{339}"),
+    ("\u{328}", "\u{322}\u{345}\u{1AB7}"),
+    ("\u{329}", "\u{656}\u{1CDC}"),
+    ("\u{32B}", "\u{1CD5}"),
+    ("\u{32D}", "\u{1CD9}"),
+    ("\u{32E}", "\u{1CD8}"),
+    ("\u{331}", "\u{320}\u{952}"),
+    ("\u{333}", "\u{347}"),
+    ("\u{335}", "\u{336}"),
+    ("\u{338}", "\u{337}"),
+    ("\u{350}", "\u{357}\u{8F8}\u{8FF}"),
+    ("\u{352}", "\u{900}"),
+    ("\u{354}", "\u{8F9}"),
+    ("\u{355}", "\u{8FA}"),
+    ("\u{363}", "\u{2DF6}"),
+    ("\u{364}", "\u{2DF7}"),
+    ("\u{368}", "\u{2DED}"),
+    ("\u{36F}", "\u{2DEF}"),
+    ("Γ", "ГᎱᒥℾⲄ𖼇𝚪𝛤𝜞𝝘𝞒"),
+    ("Γ'", "Ґ"),
+    ("Γ·", "ᒯ"),
+    ("Γ\u{335}", "Ғ"),
+    ("Δ", "ᐃ∆△Ⲇⵠ𐊅𐊣𖼚𝚫𝛥𝜟𝝙𝞓🜂"),
+    ("Δ·", "ᐏ"),
+    ("Δ\u{332}", "⍙"),
+    ("Δᐠ", "ᐬ"),
+    ("Ξ", "𝚵𝛯𝜩𝝣𝞝"),
+    ("Π", "Пℿ∏Ⲡꛛ𝚷𝛱𝜫𝝥𝞟"),
+    ("Φ", "ФՓቀᛰⲪ𐊳𝚽𝛷𝜱𝝫𝞥"),
+    ("Ψ", "ѰᛘⲮ𐊵𐓑𝚿𝛹𝜳𝝭𝞧"),
+    ("Ω", "ᘯᘵ\u{2126}𐊶𝛀𝛺𝜴𝝮𝞨"),
+    ("ζ", "𝛇𝜁𝜻𝝵𝞯"),
+    ("λ", "Ⲗ\u{A7DB}𐓛𝛌𝜆𝝀𝝺𝞴"),
+    ("λ\u{338}", "ƛ"),
+    ("μ", "µ𝛍𝜇𝝁𝝻𝞵"),
+    ("ξ", "𝛏𝜉𝝃𝝽𝞷"),
+    ("π", "ϖпᴨℼ𝛑𝛡𝜋𝜛𝝅𝝕𝝿𝞏𝞹𝟉"),
+    ("ς", "ϛ𝛓𝜍𝝇𝞁𝞻"),
+    ("χ", "ⲭꭓꭕ𝛘𝜒𝝌𝞆𝟀"),
+    ("ψ", "ѱ𐓹𝛙𝜓𝝍𝞇𝟁"),
+    ("ω", "⍵ⲱꙍꞷ𝛚𝜔𝝎𝞈𝟂"),
+    ("ω\u{332}", "⍹"),
+    ("ϗ", "ⳤ"),
+    ("Ϙ", "𐊭𐌒"),
+    ("ϝ", "𝟋"),
+    ("Ϭ", "Ⳝ"),
+    ("Ћ", "𐓍"),
+    ("Ѝ", "Й"),
+    ("Ѝ\u{326}", "Ҋ"),
+    ("Ж\u{329}", "Җ"),
+    ("И", "Ͷꚡ𐐥𝈋"),
+    ("Ш", "Ⲽ"),
+    ("Ъl", "Ꙑ"),
+    ("Э", "℈"),
+    ("ж\u{329}", "җ"),
+    ("й", "ѝ"),
+    ("й\u{326}", "ҋ"),
+    ("л", "ᴫ"),
+    ("л\u{326}", "ӆ"),
+    ("ш", "ⲽ"),
+    ("љ", "ꭠ"),
+    ("Ѡ", "Ꮗᗯ𝈢"),
+    ("Ѡ·", "ᣭ"),
+    ("Ѡ\u{486}\u{487}", "Ѽ"),
+    ("Ҷ", "Ӌ"),
+    ("ҷ", "ӌ"),
+    ("Ҽ\u{328}", "Ҿ"),
+    ("Ӄ", "𐒼"),
+    ("Ӿ", "𝈂"),
+    ("Ո", "በᑎ∩⋂ꓵ𝉅"),
+    ("Ո'", "ᑨ"),
+    ("Ո·", "ᑚ"),
+    ("Ք", "₽"),
+    ("\u{559}", "\u{2BF}˓"),
+    ("եւ", "և"),
+    ("ձ", "ኔ"),
+    ("մե", "ﬔ"),
+    ("մի", "ﬕ"),
+    ("մխ", "ﬗ"),
+    ("մն", "ﬓ"),
+    ("վն", "ﬖ"),
+    ("\u{596}", "\u{5AD}"),
+    ("\u{598}", "\u{5AE}"),
+    ("\u{599}", "\u{5A8}"),
+    ("\u{59A}", "\u{5A4}"),
+    ("\u{5D0}", "ℵ\u{FB21}"),
+    ("\u{5D0}\u{5DC}", "\u{FB4F}"),
+    ("\u{5D1}", "ℶ"),
+    ("\u{5D2}", "ℷ"),
+    ("\u{5D3}", "ℸ\u{FB22}"),
+    ("\u{5D4}", "\u{FB23}"),
+    ("\u{5DB}", "\u{FB24}"),
+    ("\u{5DC}", "\u{FB25}"),
+    ("\u{5DD}", "\u{FB26}"),
+    ("\u{5E2}", "\u{FB20}"),
+    ("\u{5E8}", "\u{FB27}"),
+    ("\u{5EA}", "\u{FB28}"),
+    ("،", "\u{66C}⸲"),
+    ("\u{61B}", "⸵"),
+    ("\u{61F}", "⸮"),
+    ("\u{621}", "\u{FE80}"),
+    ("\u{621}\u{348}", "\u{6FD}"),
+    ("\u{622}", "\u{FE81}\u{FE82}"),
+    (
+        "\u{628}",
+        "\u{FE8F}\u{FE90}\u{FE91}\u{FE92}\u{1EE01}\u{1EE21}\u{1EE61}\u{1EE81}\u{1EEA1}",
+    ),
+    ("\u{628}o", "\u{FCA0}\u{FCE2}"),
+    ("\u{628}\u{62C}", "\u{FC05}\u{FC9C}"),
+    ("\u{628}\u{62D}", "\u{FC06}\u{FC9D}"),
+    ("\u{628}\u{62D}\u{649}", "\u{FDC2}"),
+    ("\u{628}\u{62E}", "\u{FC07}\u{FC4B}\u{FC9E}\u{FCD2}"),
+    ("\u{628}\u{62E}\u{649}", "\u{FD9E}"),
+    ("\u{628}\u{631}", "\u{FC6A}"),
+    ("\u{628}\u{632}", "\u{FC6B}"),
+    ("\u{628}\u{645}", "\u{FC08}\u{FC6C}\u{FC9F}\u{FCE1}"),
+    ("\u{628}\u{646}", "\u{FC6D}"),
+    ("\u{628}\u{649}", "\u{FC09}\u{FC0A}\u{FC6E}\u{FC6F}"),
+    ("\u{628}\u{654}", "\u{8A1}"),
+    ("\u{628}\u{6DB}", "\u{751}"),
+    ("\u{628}\u{6E2}", "\u{8B6}"),
+    ("\u{629}", "ö\u{6C3}⍥\u{FE93}\u{FE94}"),
+    (
+        "\u{62A}",
+        "\u{FE95}\u{FE96}\u{FE97}\u{FE98}\u{1EE15}\u{1EE35}\u{1EE75}\u{1EE95}\u{1EEB5}",
+    ),
+    ("\u{62A}o", "\u{FCA5}\u{FCE4}"),
+    ("\u{62A}\u{62C}", "\u{FC0B}\u{FCA1}"),
+    ("\u{62A}\u{62C}\u{645}", "\u{FD50}"),
+    ("\u{62A}\u{62C}\u{649}", "\u{FD9F}\u{FDA0}"),
+    ("\u{62A}\u{62D}", "\u{FC0C}\u{FCA2}"),
+    ("\u{62A}\u{62D}\u{62C}", "\u{FD51}\u{FD52}"),
+    ("\u{62A}\u{62D}\u{645}", "\u{FD53}"),
+    ("\u{62A}\u{62E}", "\u{FC0D}\u{FCA3}"),
+    ("\u{62A}\u{62E}\u{645}", "\u{FD54}"),
+    ("\u{62A}\u{62E}\u{649}", "\u{FDA1}\u{FDA2}"),
+    ("\u{62A}\u{631}", "\u{FC70}"),
+    ("\u{62A}\u{632}", "\u{FC71}"),
+    ("\u{62A}\u{645}", "\u{FC0E}\u{FC72}\u{FCA4}\u{FCE3}"),
+    ("\u{62A}\u{645}\u{62C}", "\u{FD55}"),
+    ("\u{62A}\u{645}\u{62D}", "\u{FD56}"),
+    ("\u{62A}\u{645}\u{62E}", "\u{FD57}"),
+    ("\u{62A}\u{645}\u{649}", "\u{FDA3}\u{FDA4}"),
+    ("\u{62A}\u{646}", "\u{FC73}"),
+    ("\u{62A}\u{649}", "\u{FC0F}\u{FC10}\u{FC74}\u{FC75}"),
+    (
+        "\u{62C}",
+        "\u{FE9D}\u{FE9E}\u{FE9F}\u{FEA0}\u{1EE02}\u{1EE22}\u{1EE42}\u{1EE62}\u{1EE82}\u{1EEA2}",
+    ),
+    ("\u{62C}\u{62D}", "\u{FC15}\u{FCA7}"),
+    ("\u{62C}\u{62D}\u{649}", "\u{FDA6}\u{FDBE}"),
+    ("\u{62C}\u{644} \u{62C}\u{644}l\u{644}o", "\u{FDFB}"),
+    ("\u{62C}\u{645}", "\u{FC16}\u{FCA8}"),
+    ("\u{62C}\u{645}\u{62D}", "\u{FD58}\u{FD59}"),
+    ("\u{62C}\u{645}\u{649}", "\u{FDA5}\u{FDA7}"),
+    ("\u{62C}\u{649}", "\u{FD01}\u{FD02}\u{FD1D}\u{FD1E}"),
+    (
+        "\u{62D}",
+        "\u{FEA1}\u{FEA2}\u{FEA3}\u{FEA4}\u{1EE07}\u{1EE27}\u{1EE47}\u{1EE67}\u{1EE87}\u{1EEA7}",
+    ),
+    ("\u{62D}\u{62C}", "\u{FC17}\u{FCA9}"),
+    ("\u{62D}\u{62C}\u{649}", "\u{FDBF}"),
+    ("\u{62D}\u{645}", "\u{FC18}\u{FCAA}"),
+    ("\u{62D}\u{645}\u{649}", "\u{FD5A}\u{FD5B}"),
+    ("\u{62D}\u{649}", "\u{FCFF}\u{FD00}\u{FD1B}\u{FD1C}"),
+    ("\u{62D}\u{654}", "\u{681}\u{772}"),
+    ("\u{62D}\u{6DB}", "\u{685}"),
+    (
+        "\u{62E}",
+        "\u{FEA5}\u{FEA6}\u{FEA7}\u{FEA8}\u{1EE17}\u{1EE37}\u{1EE57}\u{1EE77}\u{1EE97}\u{1EEB7}",
+    ),
+    ("\u{62E}\u{62C}", "\u{FC19}\u{FCAB}"),
+    ("\u{62E}\u{62D}", "\u{FC1A}"),
+    ("\u{62E}\u{645}", "\u{FC1B}\u{FCAC}"),
+    ("\u{62E}\u{649}", "\u{FD03}\u{FD04}\u{FD1F}\u{FD20}"),
+    ("\u{62F}", "\u{FEA9}\u{FEAA}𐋡\u{1EE03}\u{1EE83}\u{1EEA3}"),
+    ("\u{62F}\u{302}", "\u{6EE}"),
+    ("\u{62F}\u{324}\u{323}", "\u{8AE}"),
+    ("\u{62F}\u{615}", "\u{688}\u{FB88}\u{FB89}"),
+    ("\u{62F}\u{6DB}", "\u{68E}\u{FB86}\u{FB87}"),
+    ("\u{630}", "\u{FEAB}\u{FEAC}\u{1EE18}\u{1EE98}\u{1EEB8}"),
+    ("\u{630}\u{670}", "\u{FC5B}"),
+    ("\u{631}", "\u{FEAD}\u{FEAE}\u{1EE13}\u{1EE93}\u{1EEB3}"),
+    ("\u{631}\u{302}", "\u{6EF}"),
+    ("\u{631}\u{306}", "\u{692}"),
+    ("\u{631}\u{306}\u{307}", "\u{8B9}"),
+    ("\u{631}\u{615}", "\u{691}\u{FB8C}\u{FB8D}"),
+    ("\u{631}\u{633}\u{648}\u{644}", "\u{FDF6}"),
+    ("\u{631}\u{649}l\u{644}", "\u{FDFC}"),
+    ("\u{631}\u{654}", "\u{76C}"),
+    ("\u{631}\u{670}", "\u{FC5C}"),
+    ("\u{631}\u{6DB}", "\u{698}\u{FB8A}\u{FB8B}"),
+    ("\u{632}", "\u{FEAF}\u{FEB0}\u{1EE06}\u{1EE86}\u{1EEA6}"),
+    ("\u{632}\u{302}", "\u{8B2}"),
+    (
+        "\u{633}",
+        "\u{FEB1}\u{FEB2}\u{FEB3}\u{FEB4}\u{1EE0E}\u{1EE2E}\u{1EE4E}\u{1EE6E}\u{1EE8E}\u{1EEAE}",
+    ),
+    ("\u{633}o", "\u{FCE8}\u{FD31}"),
+    ("\u{633}\u{302}", "\u{77E}"),
+    ("\u{633}\u{62C}", "\u{FC1C}\u{FCAD}\u{FD34}"),
+    ("\u{633}\u{62C}\u{62D}", "\u{FD5D}"),
+    ("\u{633}\u{62C}\u{649}", "\u{FD5E}"),
+    ("\u{633}\u{62D}", "\u{FC1D}\u{FCAE}\u{FD35}"),
+    ("\u{633}\u{62D}\u{62C}", "\u{FD5C}"),
+    ("\u{633}\u{62E}", "\u{FC1E}\u{FCAF}\u{FD36}"),
+    ("\u{633}\u{62E}\u{649}", "\u{FDA8}\u{FDC6}"),
+    ("\u{633}\u{631}", "\u{FD0E}\u{FD2A}"),
+    ("\u{633}\u{645}", "\u{FC1F}\u{FCB0}\u{FCE7}"),
+    ("\u{633}\u{645}\u{62C}", "\u{FD61}"),
+    ("\u{633}\u{645}\u{62D}", "\u{FD5F}\u{FD60}"),
+    ("\u{633}\u{645}\u{645}", "\u{FD62}\u{FD63}"),
+    ("\u{633}\u{649}", "\u{FCFB}\u{FCFC}\u{FD17}\u{FD18}"),
+    (
+        "\u{633}\u{6DB}",
+        "\u{634}\u{FEB5}\u{FEB6}\u{FEB7}\u{FEB8}\u{1EE14}\u{1EE34}\u{1EE54}\u{1EE74}\u{1EE94}\u{1EEB4}",
+    ),
+    ("\u{633}\u{6DB}o", "\u{FCEA}\u{FD32}"),
+    ("\u{633}\u{6DB}\u{62C}", "\u{FD09}\u{FD25}\u{FD2D}\u{FD37}"),
+    ("\u{633}\u{6DB}\u{62C}\u{649}", "\u{FD69}"),
+    ("\u{633}\u{6DB}\u{62D}", "\u{FD0A}\u{FD26}\u{FD2E}\u{FD38}"),
+    ("\u{633}\u{6DB}\u{62D}\u{645}", "\u{FD67}\u{FD68}"),
+    ("\u{633}\u{6DB}\u{62D}\u{649}", "\u{FDAA}"),
+    ("\u{633}\u{6DB}\u{62E}", "\u{FD0B}\u{FD27}\u{FD2F}\u{FD39}"),
+    ("\u{633}\u{6DB}\u{631}", "\u{FD0D}\u{FD29}"),
+    ("\u{633}\u{6DB}\u{645}", "\u{FCE9}\u{FD0C}\u{FD28}\u{FD30}"),
+    ("\u{633}\u{6DB}\u{645}\u{62E}", "\u{FD6A}\u{FD6B}"),
+    ("\u{633}\u{6DB}\u{645}\u{645}", "\u{FD6C}\u{FD6D}"),
+    ("\u{633}\u{6DB}\u{649}", "\u{FCFD}\u{FCFE}\u{FD19}\u{FD1A}"),
+    (
+        "\u{635}",
+        "\u{FEB9}\u{FEBA}\u{FEBB}\u{FEBC}𐋲\u{1EE11}\u{1EE31}\u{1EE51}\u{1EE71}\u{1EE91}\u{1EEB1}",
+    ),
+    ("\u{635}\u{324}\u{323}", "\u{8AF}"),
+    ("\u{635}\u{62D}", "\u{FC20}\u{FCB1}"),
+    ("\u{635}\u{62D}\u{62D}", "\u{FD64}\u{FD65}"),
+    ("\u{635}\u{62D}\u{649}", "\u{FDA9}"),
+    ("\u{635}\u{62E}", "\u{FCB2}"),
+    ("\u{635}\u{631}", "\u{FD0F}\u{FD2B}"),
+    ("\u{635}\u{644}\u{639}\u{645}", "\u{FDF5}"),
+    ("\u{635}\u{644}\u{649}", "\u{FDF0}\u{FDF9}"),
+    (
+        "\u{635}\u{644}\u{649} l\u{644}\u{644}o \u{639}\u{644}\u{649}o \u{648}\u{633}\u{644}\u{645}",
+        "\u{FDFA}",
+    ),
+    ("\u{635}\u{645}", "\u{FC21}\u{FCB3}"),
+    ("\u{635}\u{645}\u{645}", "\u{FD66}\u{FDC5}"),
+    ("\u{635}\u{649}", "\u{FD05}\u{FD06}\u{FD21}\u{FD22}"),
+    ("\u{635}\u{6DB}", "\u{69E}"),
+    (
+        "\u{636}",
+        "\u{FEBD}\u{FEBE}\u{FEBF}\u{FEC0}\u{1EE19}\u{1EE39}\u{1EE59}\u{1EE79}\u{1EE99}\u{1EEB9}",
+    ),
+    ("\u{636}\u{62C}", "\u{FC22}\u{FCB4}"),
+    ("\u{636}\u{62D}", "\u{FC23}\u{FCB5}"),
+    ("\u{636}\u{62D}\u{649}", "\u{FD6E}\u{FDAB}"),
+    ("\u{636}\u{62E}", "\u{FC24}\u{FCB6}"),
+    ("\u{636}\u{62E}\u{645}", "\u{FD6F}\u{FD70}"),
+    ("\u{636}\u{631}", "\u{FD10}\u{FD2C}"),
+    ("\u{636}\u{645}", "\u{FC25}\u{FCB7}"),
+    ("\u{636}\u{649}", "\u{FD07}\u{FD08}\u{FD23}\u{FD24}"),
+    (
+        "\u{637}",
+        "\u{FEC1}\u{FEC2}\u{FEC3}\u{FEC4}𐋨\u{1EE08}\u{1EE68}\u{1EE88}\u{1EEA8}",
+    ),
+    ("\u{637}\u{62D}", "\u{FC26}\u{FCB8}"),
+    ("\u{637}\u{645}", "\u{FC27}\u{FD33}\u{FD3A}"),
+    ("\u{637}\u{645}\u{62D}", "\u{FD71}\u{FD72}"),
+    ("\u{637}\u{645}\u{645}", "\u{FD73}"),
+    ("\u{637}\u{645}\u{649}", "\u{FD74}"),
+    ("\u{637}\u{649}", "\u{FCF5}\u{FCF6}\u{FD11}\u{FD12}"),
+    ("\u{637}\u{6DB}", "\u{69F}"),
+    (
+        "\u{638}",
+        "\u{FEC5}\u{FEC6}\u{FEC7}\u{FEC8}\u{1EE1A}\u{1EE7A}\u{1EE9A}\u{1EEBA}",
+    ),
+    ("\u{638}\u{645}", "\u{FC28}\u{FCB9}\u{FD3B}"),
+    (
+        "\u{639}",
+        "؏\u{FEC9}\u{FECA}\u{FECB}\u{FECC}\u{1EE0F}\u{1EE2F}\u{1EE4F}\u{1EE6F}\u{1EE8F}\u{1EEAF}",
+    ),
+    ("\u{639}\u{62C}", "\u{FC29}\u{FCBA}"),
+    ("\u{639}\u{62C}\u{645}", "\u{FD75}\u{FDC4}"),
+    ("\u{639}\u{644}\u{649}o", "\u{FDF7}"),
+    ("\u{639}\u{645}", "\u{FC2A}\u{FCBB}"),
+    ("\u{639}\u{645}\u{645}", "\u{FD76}\u{FD77}"),
+    ("\u{639}\u{645}\u{649}", "\u{FD78}\u{FDB6}"),
+    ("\u{639}\u{649}", "\u{FCF7}\u{FCF8}\u{FD13}\u{FD14}"),
+    (
+        "\u{63A}",
+        "\u{FECD}\u{FECE}\u{FECF}\u{FED0}\u{1EE1B}\u{1EE3B}\u{1EE5B}\u{1EE7B}\u{1EE9B}\u{1EEBB}",
+    ),
+    ("\u{63A}\u{62C}", "\u{FC2B}\u{FCBC}"),
+    ("\u{63A}\u{645}", "\u{FC2C}\u{FCBD}"),
+    ("\u{63A}\u{645}\u{645}", "\u{FD79}"),
+    ("\u{63A}\u{645}\u{649}", "\u{FD7A}\u{FD7B}"),
+    ("\u{63A}\u{649}", "\u{FCF9}\u{FCFA}\u{FD15}\u{FD16}"),
+    (
+        "\u{641}",
+        "\u{6A7}\u{FED1}\u{FED2}\u{FED3}\u{FED4}\u{1EE10}\u{1EE30}\u{1EE70}\u{1EE90}\u{1EEB0}",
+    ),
+    ("\u{641}\u{62C}", "\u{FC2D}\u{FCBE}"),
+    ("\u{641}\u{62D}", "\u{FC2E}\u{FCBF}"),
+    ("\u{641}\u{62E}", "\u{FC2F}\u{FCC0}"),
+    ("\u{641}\u{62E}\u{645}", "\u{FD7C}\u{FD7D}"),
+    ("\u{641}\u{645}", "\u{FC30}\u{FCC1}"),
+    ("\u{641}\u{645}\u{649}", "\u{FDC1}"),
+    ("\u{641}\u{649}", "\u{FC31}\u{FC32}\u{FC7C}\u{FC7D}"),
+    (
+        "\u{642}",
+        "\u{FED5}\u{FED6}\u{FED7}\u{FED8}\u{1EE12}\u{1EE32}\u{1EE52}\u{1EE72}\u{1EE92}\u{1EEB2}",
+    ),
+    ("\u{642}\u{62D}", "\u{FC33}\u{FCC2}"),
+    ("\u{642}\u{644}\u{649}", "\u{FDF1}"),
+    ("\u{642}\u{645}", "\u{FC34}\u{FCC3}"),
+    ("\u{642}\u{645}\u{62D}", "\u{FD7E}\u{FDB4}"),
+    ("\u{642}\u{645}\u{645}", "\u{FD7F}"),
+    ("\u{642}\u{645}\u{649}", "\u{FDB2}"),
+    ("\u{642}\u{649}", "\u{FC35}\u{FC36}\u{FC7E}\u{FC7F}"),
+    (
+        "\u{643}",
+        "\u{6A9}\u{6AA}\u{FB8E}\u{FB8F}\u{FB90}\u{FB91}\u{FED9}\u{FEDA}\u{FEDB}\u{FEDC}\u{1EE0A}\u{1EE2A}\u{1EE6A}",
+    ),
+    ("\u{643}l", "\u{FC37}\u{FC80}"),
+    ("\u{643}\u{62C}", "\u{FC38}\u{FCC4}"),
+    ("\u{643}\u{62D}", "\u{FC39}\u{FCC5}"),
+    ("\u{643}\u{62E}", "\u{FC3A}\u{FCC6}"),
+    ("\u{643}\u{644}", "\u{FC3B}\u{FC81}\u{FCC7}\u{FCEB}"),
+    ("\u{643}\u{645}", "\u{FC3C}\u{FC82}\u{FCC8}\u{FCEC}"),
+    ("\u{643}\u{645}\u{645}", "\u{FDBB}\u{FDC3}"),
+    ("\u{643}\u{645}\u{649}", "\u{FDB7}"),
+    ("\u{643}\u{649}", "\u{FC3D}\u{FC3E}\u{FC83}\u{FC84}"),
+    (
+        "\u{643}\u{6DB}",
+        "\u{6AD}\u{763}\u{FBD3}\u{FBD4}\u{FBD5}\u{FBD6}",
+    ),
+    (
+        "\u{644}",
+        "\u{FEDD}\u{FEDE}\u{FEDF}\u{FEE0}\u{1EE0B}\u{1EE2B}\u{1EE4B}\u{1EE8B}\u{1EEAB}",
+    ),
+    ("\u{644}l", "\u{FEFB}\u{FEFC}"),
+    ("\u{644}l\u{655}", "\u{FEF9}\u{FEFA}"),
+    ("\u{644}l\u{674}", "\u{FEF7}\u{FEF8}"),
+    ("\u{644}o", "\u{FCCD}"),
+    ("\u{644}\u{306}", "\u{6B5}"),
+    ("\u{644}\u{622}", "\u{FEF5}\u{FEF6}"),
+    ("\u{644}\u{62C}", "\u{FC3F}\u{FCC9}"),
+    ("\u{644}\u{62C}\u{62C}", "\u{FD83}\u{FD84}"),
+    ("\u{644}\u{62C}\u{645}", "\u{FDBA}\u{FDBC}"),
+    ("\u{644}\u{62C}\u{649}", "\u{FDAC}"),
+    ("\u{644}\u{62D}", "\u{FC40}\u{FCCA}"),
+    ("\u{644}\u{62D}\u{645}", "\u{FD80}\u{FDB5}"),
+    ("\u{644}\u{62D}\u{649}", "\u{FD81}\u{FD82}"),
+    ("\u{644}\u{62E}", "\u{FC41}\u{FCCB}"),
+    ("\u{644}\u{62E}\u{645}", "\u{FD85}\u{FD86}"),
+    ("\u{644}\u{645}", "\u{FC42}\u{FC85}\u{FCCC}\u{FCED}"),
+    ("\u{644}\u{645}\u{62D}", "\u{FD87}\u{FD88}"),
+    ("\u{644}\u{645}\u{649}", "\u{FDAD}"),
+    ("\u{644}\u{649}", "\u{FC43}\u{FC44}\u{FC86}\u{FC87}"),
+    ("\u{644}\u{6DB}", "\u{6B7}"),
+    (
+        "\u{645}",
+        "\u{FEE1}\u{FEE2}\u{FEE3}\u{FEE4}\u{1EE0C}\u{1EE2C}\u{1EE6C}\u{1EE8C}\u{1EEAC}",
+    ),
+    ("\u{645}l", "\u{FC88}"),
+    ("\u{645}\u{348}", "\u{6FE}"),
+    ("\u{645}\u{62C}", "\u{FC45}\u{FCCE}"),
+    ("\u{645}\u{62C}\u{62D}", "\u{FD8C}"),
+    ("\u{645}\u{62C}\u{62E}", "\u{FD92}"),
+    ("\u{645}\u{62C}\u{645}", "\u{FD8D}"),
+    ("\u{645}\u{62C}\u{649}", "\u{FDC0}"),
+    ("\u{645}\u{62D}", "\u{FC46}\u{FCCF}"),
+    ("\u{645}\u{62D}\u{62C}", "\u{FD89}"),
+    ("\u{645}\u{62D}\u{645}", "\u{FD8A}"),
+    ("\u{645}\u{62D}\u{645}\u{62F}", "\u{FDF4}"),
+    ("\u{645}\u{62D}\u{649}", "\u{FD8B}"),
+    ("\u{645}\u{62E}", "\u{FC47}\u{FCD0}"),
+    ("\u{645}\u{62E}\u{62C}", "\u{FD8E}"),
+    ("\u{645}\u{62E}\u{645}", "\u{FD8F}"),
+    ("\u{645}\u{62E}\u{649}", "\u{FDB9}"),
+    ("\u{645}\u{645}", "\u{FC48}\u{FC89}\u{FCD1}"),
+    ("\u{645}\u{645}\u{649}", "\u{FDB1}"),
+    ("\u{645}\u{649}", "\u{FC49}\u{FC4A}"),
+    ("\u{645}\u{6DB}", "\u{8A7}"),
+    (
+        "\u{646}",
+        "\u{FEE5}\u{FEE6}\u{FEE7}\u{FEE8}\u{1EE0D}\u{1EE2D}\u{1EE4D}\u{1EE6D}\u{1EE8D}\u{1EEAD}",
+    ),
+    ("\u{646}o", "\u{FCD6}\u{FCEF}"),
+    ("\u{646}\u{306}", "\u{769}"),
+    ("\u{646}\u{615}", "\u{768}"),
+    ("\u{646}\u{62C}\u{62D}", "\u{FDB8}\u{FDBD}"),
+    ("\u{646}\u{62C}\u{645}", "\u{FD97}\u{FD98}"),
+    ("\u{646}\u{62C}\u{649}", "\u{FD99}\u{FDC7}"),
+    ("\u{646}\u{62D}", "\u{FC4C}\u{FCD3}"),
+    ("\u{646}\u{62D}\u{645}", "\u{FD95}"),
+    ("\u{646}\u{62D}\u{649}", "\u{FD96}\u{FDB3}"),
+    ("\u{646}\u{62E}", "\u{FC4D}\u{FCD4}"),
+    ("\u{646}\u{631}", "\u{FC8A}"),
+    ("\u{646}\u{632}", "\u{FC8B}"),
+    ("\u{646}\u{645}", "\u{FC4E}\u{FC8C}\u{FCD5}\u{FCEE}"),
+    ("\u{646}\u{645}\u{649}", "\u{FD9A}\u{FD9B}"),
+    ("\u{646}\u{646}", "\u{FC8D}"),
+    ("\u{646}\u{649}", "\u{FC4F}\u{FC50}\u{FC8E}\u{FC8F}"),
+    (
+        "\u{648}",
+        "\u{8B1}\u{FEED}\u{FEEE}𐋤\u{1EE05}\u{1EE85}\u{1EEA5}",
+    ),
+    ("\u{648}\u{302}", "\u{6C9}\u{FBE2}\u{FBE3}"),
+    ("\u{648}\u{306}", "\u{6C6}\u{FBD9}\u{FBDA}"),
+    ("\u{648}\u{313}", "\u{6C7}\u{FBD7}\u{FBD8}"),
+    ("\u{648}\u{313}\u{674}", "\u{677}\u{FBDD}"),
+    ("\u{648}\u{633}\u{644}\u{645}", "\u{FDF8}"),
+    ("\u{648}\u{670}", "\u{6C8}\u{FBDB}\u{FBDC}"),
+    ("\u{648}\u{674}", "\u{624}\u{676}\u{FE85}\u{FE86}"),
+    ("\u{648}\u{6DB}", "\u{6CB}\u{FBDE}\u{FBDF}"),
+    (
+        "\u{649}",
+        "\u{64A}\u{66E}\u{6BA}\u{6CC}\u{6D2}\u{8BD}\u{FB9E}\u{FB9F}\u{FBAE}\u{FBAF}\u{FBE8}\u{FBE9}\u{FBFC}\u{FBFD}\u{FBFE}\u{FBFF}\u{FEEF}\u{FEF0}\u{FEF1}\u{FEF2}\u{FEF3}\u{FEF4}\u{1EE09}\u{1EE1C}\u{1EE1D}\u{1EE29}\u{1EE49}\u{1EE5D}\u{1EE69}\u{1EE7C}\u{1EE89}\u{1EEA9}",
+    ),
+    ("\u{649}o", "\u{FCDE}\u{FCF1}"),
+    ("\u{649}\u{302}", "\u{63D}"),
+    ("\u{649}\u{306}", "\u{6CE}\u{756}"),
+    ("\u{649}\u{306}\u{307}", "\u{8BA}"),
+    (
+        "\u{649}\u{615}",
+        "\u{679}\u{6BB}\u{FB66}\u{FB67}\u{FB68}\u{FB69}\u{FBA0}\u{FBA1}\u{FBA2}\u{FBA3}",
+    ),
+    ("\u{649}\u{62C}", "\u{FC55}\u{FCDA}"),
+    ("\u{649}\u{62C}\u{649}", "\u{FDAF}"),
+    ("\u{649}\u{62D}", "\u{FC56}\u{FCDB}"),
+    ("\u{649}\u{62D}\u{649}", "\u{FDAE}"),
+    ("\u{649}\u{62E}", "\u{FC57}\u{FCDC}"),
+    ("\u{649}\u{631}", "\u{FC91}"),
+    ("\u{649}\u{632}", "\u{FC92}"),
+    ("\u{649}\u{645}", "\u{FC58}\u{FC93}\u{FCDD}\u{FCF0}"),
+    ("\u{649}\u{645}\u{645}", "\u{FD9C}\u{FD9D}"),
+    ("\u{649}\u{645}\u{649}", "\u{FDB0}"),
+    ("\u{649}\u{646}", "\u{FC94}"),
+    ("\u{649}\u{649}", "\u{FC59}\u{FC5A}\u{FC95}\u{FC96}"),
+    ("\u{649}\u{654}", "\u{8A8}"),
+    ("\u{649}\u{670}", "\u{FC5D}\u{FC90}"),
+    (
+        "\u{649}\u{674}",
+        "\u{626}\u{678}\u{FE89}\u{FE8A}\u{FE8B}\u{FE8C}",
+    ),
+    ("\u{649}\u{674}l", "\u{FBEA}\u{FBEB}"),
+    ("\u{649}\u{674}o", "\u{FBEC}\u{FBED}\u{FC9B}\u{FCE0}"),
+    ("\u{649}\u{674}\u{62C}", "\u{FC00}\u{FC97}"),
+    ("\u{649}\u{674}\u{62D}", "\u{FC01}\u{FC98}"),
+    ("\u{649}\u{674}\u{62E}", "\u{FC99}"),
+    ("\u{649}\u{674}\u{631}", "\u{FC64}"),
+    ("\u{649}\u{674}\u{632}", "\u{FC65}"),
+    ("\u{649}\u{674}\u{645}", "\u{FC02}\u{FC66}\u{FC9A}\u{FCDF}"),
+    ("\u{649}\u{674}\u{646}", "\u{FC67}"),
+    ("\u{649}\u{674}\u{648}", "\u{FBEE}\u{FBEF}"),
+    ("\u{649}\u{674}\u{648}\u{306}", "\u{FBF2}\u{FBF3}"),
+    ("\u{649}\u{674}\u{648}\u{313}", "\u{FBF0}\u{FBF1}"),
+    ("\u{649}\u{674}\u{648}\u{670}", "\u{FBF4}\u{FBF5}"),
+    (
+        "\u{649}\u{674}\u{649}",
+        "\u{FBF9}\u{FBFA}\u{FBFB}\u{FC03}\u{FC04}\u{FC68}\u{FC69}",
+    ),
+    ("\u{649}\u{674}\u{67B}", "\u{FBF6}\u{FBF7}\u{FBF8}"),
+    (
+        "\u{649}\u{6DB}",
+        "\u{62B}\u{63F}\u{67E}\u{6BD}\u{6D1}\u{FB56}\u{FB57}\u{FB58}\u{FB59}\u{FE99}\u{FE9A}\u{FE9B}\u{FE9C}\u{1EE16}\u{1EE36}\u{1EE76}\u{1EE96}\u{1EEB6}",
+    ),
+    ("\u{649}\u{6DB}o", "\u{FCE6}"),
+    ("\u{649}\u{6DB}\u{62C}", "\u{FC11}"),
+    ("\u{649}\u{6DB}\u{631}", "\u{FC76}"),
+    ("\u{649}\u{6DB}\u{632}", "\u{FC77}"),
+    ("\u{649}\u{6DB}\u{645}", "\u{FC12}\u{FC78}\u{FCA6}\u{FCE5}"),
+    ("\u{649}\u{6DB}\u{646}", "\u{FC79}"),
+    ("\u{649}\u{6DB}\u{649}", "\u{FC13}\u{FC14}\u{FC7A}\u{FC7B}"),
+    ("\u{649}\u{6DB}\u{6E2}", "\u{8B7}"),
+    ("\u{64C}", "\u{8E5}\u{8E8}\u{8F1}"),
+    ("\u{64D}", "\u{8F2}"),
+    ("\u{650}", "\u{317}\u{61A}"),
+    ("\u{655}", "\u{65F}"),
+    ("\u{662}", "۲\u{A9CF}"),
+    ("\u{663}", "۳\u{1E8C9}"),
+    ("\u{664}", "۴"),
+    ("\u{666}", "۶"),
+    ("\u{669}", "۹१𑣤"),
+    ("\u{670}", "\u{30D}"),
+    ("\u{671}", "\u{FB50}\u{FB51}"),
+    ("\u{67A}", "\u{FB5E}\u{FB5F}\u{FB60}\u{FB61}"),
+    (
+        "\u{67B}",
+        "\u{6D0}\u{FB52}\u{FB53}\u{FB54}\u{FB55}\u{FBE4}\u{FBE5}\u{FBE6}\u{FBE7}",
+    ),
+    ("\u{67F}", "\u{FB62}\u{FB63}\u{FB64}\u{FB65}"),
+    ("\u{680}", "\u{FB5A}\u{FB5B}\u{FB5C}\u{FB5D}"),
+    ("\u{683}", "\u{FB76}\u{FB77}\u{FB78}\u{FB79}"),
+    ("\u{684}", "\u{FB72}\u{FB73}\u{FB74}\u{FB75}"),
+    ("\u{686}", "\u{FB7A}\u{FB7B}\u{FB7C}\u{FB7D}"),
+    ("\u{687}", "\u{FB7E}\u{FB7F}\u{FB80}\u{FB81}"),
+    ("\u{68A}\u{615}", "\u{68B}"),
+    ("\u{68C}", "\u{FB84}\u{FB85}"),
+    ("\u{68D}", "\u{FB82}\u{FB83}"),
+    ("\u{697}\u{615}", "\u{771}"),
+    (
+        "\u{6A1}",
+        "\u{66F}\u{8BB}\u{8BC}\u{1EE1E}\u{1EE1F}\u{1EE5F}\u{1EE7E}",
+    ),
+    (
+        "\u{6A1}\u{6DB}",
+        "\u{6A4}\u{6A8}\u{FB6A}\u{FB6B}\u{FB6C}\u{FB6D}",
+    ),
+    ("\u{6A2}\u{6DB}", "\u{8A4}"),
+    ("\u{6A6}", "\u{FB6E}\u{FB6F}\u{FB70}\u{FB71}"),
+    ("\u{6AC}", "\u{762}"),
+    ("\u{6AF}", "\u{8B0}\u{FB92}\u{FB93}\u{FB94}\u{FB95}"),
+    ("\u{6AF}\u{6DB}", "\u{6B4}"),
+    ("\u{6B1}", "\u{FB9A}\u{FB9B}\u{FB9C}\u{FB9D}"),
+    ("\u{6B3}", "\u{FB96}\u{FB97}\u{FB98}\u{FB99}"),
+    ("\u{6C0}", "\u{6C2}\u{FBA4}\u{FBA5}"),
+    ("\u{6C5}", "\u{FBE0}\u{FBE1}"),
+    ("\u{6D3}", "\u{FBB0}\u{FBB1}"),
+    ("\u{6DB}", "\u{1AB4}\u{20DB}"),
+    ("\u{73C}", "\u{742}"),
+    ("\u{754}", "\u{767}\u{8A9}"),
+    ("अ\u{93E}", "आ"),
+    ("अ\u{93E}\u{946}", "ऒ"),
+    ("अ\u{93E}\u{947}", "ओ"),
+    ("अ\u{93E}\u{948}", "औ"),
+    ("अ\u{946}", "ऄ"),
+    ("अ\u{949}", "ऑ"),
+    ("ए\u{945}", "ऍ"),
+    ("ए\u{946}", "ऎ"),
+    ("ए\u{947}", "ऐ"),
+    ("र\u{94D}इ", "ई"),
+    ("\u{93A}", "\u{111CB}"),
+    ("ऽ", "ઽ"),
+    ("\u{941}", "\u{AC1}"),
+    ("\u{942}", "\u{AC2}"),
+    ("\u{946}", "\u{A4B}"),
+    ("\u{94D}", "\u{A4D}\u{ACD}"),
+    ("।", "꠰"),
+    ("।।", "॥"),
+    ("२", "૨"),
+    ("३", "૩"),
+    ("४", "૪"),
+    ("८", "૮"),
+    ("॰", "૰⚬𑂻𑇇"),
+    ("\u{971}", "˙ൎ"),
+    (
+        "\u{983}",
+        "\u{A03}\u{C03}\u{C83}\u{D03}\u{D83}\u{1038}\u{114C1}",
+    ),
+    ("অ\u{9BE}", "আ"),
+    ("ঋ\u{9C3}", "ৠৡ"),
+    ("ঘ", "𑒒"),
+    ("চ", "𑒔"),
+    ("জ", "𑒖"),
+    ("ঞ", "𑒘"),
+    ("ট", "𑒙"),
+    ("ড", "𑒛"),
+    ("ণ", "𑒪"),
+    ("ত", "𑒞"),
+    ("থ", "𑒟"),
+    ("দ", "𑒠"),
+    ("ধ", "𑒡"),
+    ("ন", "𑒢"),
+    ("প", "𑒣"),
+    ("ব", "𑒩"),
+    ("ম", "𑒧"),
+    ("য", "𑒨"),
+    ("র", "𑒫"),
+    ("ল", "𑒝"),
+    ("ষ", "𑒭"),
+    ("স", "𑒮"),
+    ("ঽ", "𑓄"),
+    ("\u{9BE}", "\u{114B0}"),
+    ("\u{9BF}", "\u{114B1}"),
+    ("\u{9C7}", "\u{114B9}"),
+    ("\u{9CB}", "\u{114BC}"),
+    ("\u{9CC}", "\u{114BE}"),
+    ("\u{9CD}", "\u{114C2}"),
+    ("\u{9D7}", "\u{114BD}"),
+    ("১", "𑓑"),
+    ("২", "𑓒"),
+    ("৬", "𑓖"),
+    ("ਅ\u{A3E}", "ਆ"),
+    ("ਅ\u{A48}", "ਐ"),
+    ("ਅ\u{A4C}", "ਔ"),
+    ("ੲ\u{A3F}", "ਇ"),
+    ("ੲ\u{A40}", "ਈ"),
+    ("ੲ\u{A47}", "ਏ"),
+    ("ੳ\u{A41}", "ਉ"),
+    ("ੳ\u{A42}", "ਊ"),
+    ("અ\u{ABE}", "આ"),
+    ("અ\u{ABE}\u{AC5}", "ઑ"),
+    ("અ\u{ABE}\u{AC7}", "ઓ"),
+    ("અ\u{ABE}\u{AC8}", "ઔ"),
+    ("અ\u{AC5}", "ઍ"),
+    ("અ\u{AC7}", "એ"),
+    ("અ\u{AC8}", "ઐ"),
+    ("ଅ\u{B3E}", "ଆ"),
+    ("அ", "௮"),
+    ("ஈ", "ர\u{BBE}"),
+    ("ஈ\u{BC1}", "௫"),
+    ("உ", "௨ഉ"),
+    ("உள", "ஊ"),
+    ("உ\u{D57}", "ഊ"),
+    ("எ", "௭"),
+    ("எவ", "௷"),
+    ("ஐ", "ஜജ"),
+    ("க", "௧"),
+    ("ச", "௪"),
+    ("ச\u{BC1}", "௬"),
+    ("ச\u{BC2}", "௲"),
+    ("ட\u{BBF}", "ഺ"),
+    ("ண", "ണ"),
+    ("ந\u{BC0}", "௺"),
+    ("ன", "\u{BC8}"),
+    ("ம\u{BC0}", "௴"),
+    ("ய", "௰"),
+    ("ள", "\u{BD7}"),
+    ("ழ", "ഴ"),
+    ("ஶ", "ശ"),
+    ("ஷ", "௸"),
+    ("\u{BBF}", "\u{D3F}\u{D40}"),
+    ("\u{BC6}ஈ", "\u{BCA}"),
+    ("\u{BC6}ள", "\u{BCC}"),
+    ("\u{BC7}ஈ", "\u{BCB}"),
+    ("௳", "௵"),
+    ("అ", "ಅ"),
+    ("ఆ", "ಆ"),
+    ("ఇ", "ಇ"),
+    ("ఋ\u{C3E}", "ౠ"),
+    ("ఌ\u{C3E}", "ౡ"),
+    ("ఒ", "ಒ"),
+    ("ఒ\u{C4C}", "ఔಔ"),
+    ("ఒ\u{C55}", "ఓಓ"),
+    ("జ", "ಜ"),
+    ("ఞ", "ಞ"),
+    ("డ\u{323}", "ఢ"),
+    ("ణ", "ಣ"),
+    ("ధ\u{5BC}", "థ"),
+    ("బ\u{323}", "భ"),
+    ("య", "ಯ"),
+    ("ర\u{5BC}", "ఠ"),
+    ("ఱ", "ಱ"),
+    ("ల", "ಲ"),
+    ("వ\u{323}", "ష"),
+    ("వ\u{C3E}", "హ"),
+    ("వ\u{C41}", "మ"),
+    ("\u{C41}\u{C3E}", "\u{C42}"),
+    ("\u{C43}\u{C3E}", "\u{C44}"),
+    ("౧", "೧"),
+    ("౨", "೨"),
+    ("౯", "೯"),
+    ("ಌ\u{CBE}", "ೡ"),
+    ("ഇ\u{D57}", "ഈ"),
+    ("എ\u{D46}", "ഐ"),
+    ("ഒ\u{D3E}", "ഓ"),
+    ("ഒ\u{D57}", "ഔ"),
+    ("ഞ", "ൡ"),
+    ("ദ\u{D4D}ര", "൫"),
+    ("ന\u{D41}", "ഌങ൹"),
+    ("ന\u{D4D}", "൯ൻ"),
+    ("ന\u{D4D}ന", "൬"),
+    ("ന\u{D4D}മ", "൚"),
+    ("ര", "റ"),
+    ("ര\u{D4D}", "൪ർ"),
+    ("വ\u{D4D}ര", "൮"),
+    ("ഹ\u{D4D}മ", "൶"),
+    ("\u{D41}", "\u{D42}\u{D43}"),
+    ("\u{D46}\u{D46}", "\u{D48}"),
+    ("ජ", "෪"),
+    ("ද", "෫"),
+    ("෨\u{DCF}", "෩"),
+    ("෨\u{DD3}", "෯"),
+    ("ข", "ฃ"),
+    ("ค", "ดต"),
+    ("ฆ", "ม"),
+    ("จ", "ຈ"),
+    ("ช", "ซ"),
+    ("ฎ", "ฏ"),
+    ("ฑ", "ท"),
+    ("บ", "ບ"),
+    ("ป", "ປ"),
+    ("ฝ", "ຝ"),
+    ("พ", "ພ"),
+    ("ฟ", "ຟ"),
+    ("ภ", "ฦ"),
+    ("ย", "ຍ"),
+    ("ฯ", "។"),
+    ("า", "ๅ"),
+    ("\u{E34}", "\u{17B7}"),
+    ("\u{E35}", "\u{17B8}"),
+    ("\u{E36}", "\u{17B9}"),
+    ("\u{E37}", "\u{17BA}"),
+    ("\u{E38}", "\u{EB8}"),
+    ("\u{E39}", "\u{EB9}"),
+    ("เเ", "แ"),
+    ("\u{E48}", "\u{EC8}\u{17CB}"),
+    ("\u{E49}", "\u{EC9}"),
+    ("\u{E4A}", "\u{ECA}"),
+    ("\u{E4B}", "\u{ECB}"),
+    ("๏", "៙"),
+    ("๚", "៕"),
+    ("๛", "៚"),
+    ("ຫນ", "ໜ"),
+    ("ຫມ", "ໝ"),
+    ("་", "༌"),
+    ("།།", "༎"),
+    ("༚༚", "༛"),
+    ("༚༝", "༟"),
+    ("༝༚", "࿎"),
+    ("༝༝", "༞"),
+    ("འ\u{F74}\u{F82}༔", "༃"),
+    ("འ\u{F74}\u{F82}\u{F7F}", "༂"),
+    ("ར", "ཪ"),
+    ("ཨ\u{F7C}\u{F7E}", "ༀ"),
+    ("\u{FB2}\u{F71}\u{F80}", "\u{F77}"),
+    ("\u{FB3}\u{F71}\u{F80}", "\u{F79}"),
+    ("ဂ\u{102C}", "က"),
+    ("ဂ\u{103E}", "ႁ"),
+    ("ဃ\u{103E}", "ၰ"),
+    ("ပ\u{102C}", "ဟ"),
+    ("ပ\u{102C}\u{103E}", "ၯ"),
+    ("ပ\u{103E}", "ၦ"),
+    ("သ\u{103C}", "ဩ"),
+    ("သ\u{103C}\u{1031}\u{102C}\u{103A}", "ဪ"),
+    ("၁", "ၥ"),
+    ("၊၊", "။"),
+    ("ၽ\u{103E}", "ၾ"),
+    ("\u{1083}\u{30A}", "႞"),
+    ("ᄀ", "ᆨㄱ"),
+    ("ᄀᄀ", "ᄁᆩㄲ"),
+    ("ᄀᄂ", "ᇺ"),
+    ("ᄀᄃ", "ᅚ"),
+    ("ᄀᄅ", "ᇃ"),
+    ("ᄀᄇ", "ᇻ"),
+    ("ᄀᄉ", "ᆪㄳ"),
+    ("ᄀᄉᄀ", "ᇄ"),
+    ("ᄀᄎ", "ᇼ"),
+    ("ᄀᄏ", "ᇽ"),
+    ("ᄀᄒ", "ᇾ"),
+    ("ᄂ", "ᆫㄴ"),
+    ("ᄂᄀ", "ᄓᇅ"),
+    ("ᄂᄂ", "ᄔᇿㅥ"),
+    ("ᄂᄃ", "ᄕᇆㅦ"),
+    ("ᄂᄅ", "ퟋ"),
+    ("ᄂᄇ", "ᄖ"),
+    ("ᄂᄉ", "ᅛᇇㅧ"),
+    ("ᄂᄌ", "ᅜᆬㄵ"),
+    ("ᄂᄎ", "ퟌ"),
+    ("ᄂᄐ", "ᇉ"),
+    ("ᄂᄒ", "ᅝᆭㄶ"),
+    ("ᄂᅀ", "ᇈㅨ"),
+    ("ᄃ", "ᆮㄷ"),
+    ("ᄃᄀ", "ᄗᇊ"),
+    ("ᄃᄃ", "ᄄㄸퟍ"),
+    ("ᄃᄃᄇ", "ퟎ"),
+    ("ᄃᄅ", "ᅞᇋ"),
+    ("ᄃᄆ", "ꥠ"),
+    ("ᄃᄇ", "ꥡퟏ"),
+    ("ᄃᄉ", "ꥢퟐ"),
+    ("ᄃᄉᄀ", "ퟑ"),
+    ("ᄃᄌ", "ꥣퟒ"),
+    ("ᄃᄎ", "ퟓ"),
+    ("ᄃᄐ", "ퟔ"),
+    ("ᄅ", "ᆯㄹ"),
+    ("ᄅᄀ", "ᆰㄺꥤ"),
+    ("ᄅᄀᄀ", "ꥥퟕ"),
+    ("ᄅᄀᄉ", "ᇌㅩ"),
+    ("ᄅᄀᄒ", "ퟖ"),
+    ("ᄅᄂ", "ᄘᇍ"),
+    ("ᄅᄃ", "ᇎㅪꥦ"),
+    ("ᄅᄃᄃ", "ꥧ"),
+    ("ᄅᄃᄒ", "ᇏ"),
+    ("ᄅᄅ", "ᄙᇐ"),
+    ("ᄅᄅᄏ", "ퟗ"),
+    ("ᄅᄆ", "ᆱㄻꥨ"),
+    ("ᄅᄆᄀ", "ᇑ"),
+    ("ᄅᄆᄉ", "ᇒ"),
+    ("ᄅᄆᄒ", "ퟘ"),
+    ("ᄅᄇ", "ᆲㄼꥩ"),
+    ("ᄅᄇᄃ", "ퟙ"),
+    ("ᄅᄇᄇ", "ꥪ"),
+    ("ᄅᄇᄉ", "ᇓㅫ"),
+    ("ᄅᄇᄋ", "ᇕꥫ"),
+    ("ᄅᄇᄑ", "ퟚ"),
+    ("ᄅᄇᄒ", "ᇔ"),
+    ("ᄅᄉ", "ᆳㄽꥬ"),
+    ("ᄅᄉᄉ", "ᇖ"),
+    ("ᄅᄋ", "ᄛퟝ"),
+    ("ᄅᄌ", "ꥭ"),
+    ("ᄅᄏ", "ᇘꥮ"),
+    ("ᄅᄐ", "ᆴㄾ"),
+    ("ᄅᄑ", "ᆵㄿ"),
+    ("ᄅᄒ", "ᄚᄻᆶㅀퟲ"),
+    ("ᄅᅀ", "ᇗㅬ"),
+    ("ᄅᅌ", "ퟛ"),
+    ("ᄅᅙ", "ᇙㅭ"),
+    ("ᄅᅙᄒ", "ퟜ"),
+    ("ᄆ", "ᆷㅁ"),
+    ("ᄆᄀ", "ᇚꥯ"),
+    ("ᄆᄂ", "ퟞ"),
+    ("ᄆᄂᄂ", "ퟟ"),
+    ("ᄆᄃ", "ꥰ"),
+    ("ᄆᄅ", "ᇛ"),
+    ("ᄆᄆ", "ퟠ"),
+    ("ᄆᄇ", "ᄜᇜㅮ"),
+    ("ᄆᄇᄉ", "ퟡ"),
+    ("ᄆᄉ", "ᇝㅯꥱ"),
+    ("ᄆᄉᄉ", "ᇞ"),
+    ("ᄆᄋ", "ᄝᇢㅱ"),
+    ("ᄆᄌ", "ퟢ"),
+    ("ᄆᄎ", "ᇠ"),
+    ("ᄆᄒ", "ᇡ"),
+    ("ᄆᅀ", "ᇟㅰ"),
+    ("ᄇ", "ᆸㅂ"),
+    ("ᄇᄀ", "ᄞㅲ"),
+    ("ᄇᄂ", "ᄟ"),
+    ("ᄇᄃ", "ᄠㅳퟣ"),
+    ("ᄇᄅ", "ᇣ"),
+    ("ᄇᄅᄑ", "ퟤ"),
+    ("ᄇᄆ", "ퟥ"),
+    ("ᄇᄇ", "ᄈㅃퟦ"),
+    ("ᄇᄇᄋ", "ᄬㅹ"),
+    ("ᄇᄉ", "ᄡᆹㅄ"),
+    ("ᄇᄉᄀ", "ᄢㅴ"),
+    ("ᄇᄉᄃ", "ᄣㅵퟧ"),
+    ("ᄇᄉᄇ", "ᄤ"),
+    ("ᄇᄉᄉ", "ᄥ"),
+    ("ᄇᄉᄌ", "ᄦ"),
+    ("ᄇᄉᄐ", "ꥲ"),
+    ("ᄇᄋ", "ᄫᇦㅸ"),
+    ("ᄇᄌ", "ᄧㅶퟨ"),
+    ("ᄇᄎ", "ᄨퟩ"),
+    ("ᄇᄏ", "ꥳ"),
+    ("ᄇᄐ", "ᄩㅷ"),
+    ("ᄇᄑ", "ᄪᇤ"),
+    ("ᄇᄒ", "ᇥꥴ"),
+    ("ᄉ", "ᆺㅅ"),
+    ("ᄉᄀ", "ᄭᇧㅺ"),
+    ("ᄉᄂ", "ᄮㅻ"),
+    ("ᄉᄃ", "ᄯᇨㅼ"),
+    ("ᄉᄅ", "ᄰᇩ"),
+    ("ᄉᄆ", "ᄱퟪ"),
+    ("ᄉᄇ", "ᄲᇪㅽ"),
+    ("ᄉᄇᄀ", "ᄳ"),
+    ("ᄉᄇᄋ", "ퟫ"),
+    ("ᄉᄉ", "ᄊᆻㅆ"),
+    ("ᄉᄉᄀ", "ퟬ"),
+    ("ᄉᄉᄃ", "ퟭ"),
+    ("ᄉᄉᄇ", "ꥵ"),
+    ("ᄉᄉᄉ", "ᄴ"),
+    ("ᄉᄋ", "ᄵ"),
+    ("ᄉᄌ", "ᄶㅾퟯ"),
+    ("ᄉᄎ", "ᄷퟰ"),
+    ("ᄉᄏ", "ᄸ"),
+    ("ᄉᄐ", "ᄹퟱ"),
+    ("ᄉᄑ", "ᄺ"),
+    ("ᄉᅀ", "ퟮ"),
+    ("ᄋ", "ᆼㅇ"),
+    ("ᄋᄀ", "ᅁᇬ"),
+    ("ᄋᄀᄀ", "ᇭ"),
+    ("ᄋᄃ", "ᅂ"),
+    ("ᄋᄅ", "ꥶ"),
+    ("ᄋᄆ", "ᅃ"),
+    ("ᄋᄇ", "ᅄ"),
+    ("ᄋᄉ", "ᅅᇱㆂ"),
+    ("ᄋᄋ", "ᅇᇮㆀ"),
+    ("ᄋᄌ", "ᅈ"),
+    ("ᄋᄎ", "ᅉ"),
+    ("ᄋᄏ", "ᇯ"),
+    ("ᄋᄐ", "ᅊ"),
+    ("ᄋᄑ", "ᅋ"),
+    ("ᄋᄒ", "ꥷ"),
+    ("ᄋᅀ", "ᅆᇲㆃ"),
+    ("ᄌ", "ᆽㅈ"),
+    ("ᄌᄇ", "ퟷ"),
+    ("ᄌᄇᄇ", "ퟸ"),
+    ("ᄌᄋ", "ᅍ"),
+    ("ᄌᄌ", "ᄍㅉퟹ"),
+    ("ᄌᄌᄒ", "ꥸ"),
+    ("ᄎ", "ᆾㅊ"),
+    ("ᄎᄏ", "ᅒ"),
+    ("ᄎᄒ", "ᅓ"),
+    ("ᄏ", "ᆿㅋ"),
+    ("ᄐ", "ᇀㅌ"),
+    ("ᄐᄐ", "ꥹ"),
+    ("ᄑ", "ᇁㅍ"),
+    ("ᄑᄇ", "ᅖᇳ"),
+    ("ᄑᄉ", "ퟺ"),
+    ("ᄑᄋ", "ᅗᇴㆄ"),
+    ("ᄑᄐ", "ퟻ"),
+    ("ᄑᄒ", "ꥺ"),
+    ("ᄒ", "ᇂㅎ"),
+    ("ᄒᄂ", "ᇵ"),
+    ("ᄒᄅ", "ᇶ"),
+    ("ᄒᄆ", "ᇷ"),
+    ("ᄒᄇ", "ᇸ"),
+    ("ᄒᄉ", "ꥻ"),
+    ("ᄒᄒ", "ᅘㆅ"),
+    ("ᄼᄼ", "ᄽ"),
+    ("ᄾᄾ", "ᄿ"),
+    ("ᅀ", "ᇫㅿ"),
+    ("ᅀᄇ", "ퟳ"),
+    ("ᅀᄇᄋ", "ퟴ"),
+    ("ᅌ", "ᇰㆁ"),
+    ("ᅌᄆ", "ퟵ"),
+    ("ᅌᄒ", "ퟶ"),
+    ("ᅎᅎ", "ᅏ"),
+    ("ᅐᅐ", "ᅑ"),
+    ("ᅙ", "ᇹㆆ"),
+    ("ᅙᅙ", "ꥼ"),
+    ("ᅠ", "ㅤ"),
+    ("ᅡ", "ㅏ"),
+    ("ᅡᅩ", "ᅶ"),
+    ("ᅡᅮ", "ᅷ"),
+    ("ᅡ\u{30FC}", "ᆣ"),
+    ("ᅡ丨", "ᅢㅐ"),
+    ("ᅣ", "ㅑ"),
+    ("ᅣᅩ", "ᅸ"),
+    ("ᅣᅭ", "ᅹ"),
+    ("ᅣᅮ", "ᆤ"),
+    ("ᅣ丨", "ᅤㅒ"),
+    ("ᅥ", "ㅓ"),
+    ("ᅥᅩ", "ᅺ"),
+    ("ᅥᅮ", "ᅻ"),
+    ("ᅥ\u{30FC}", "ᅼ"),
+    ("ᅥ丨", "ᅦㅔ"),
+    ("ᅧ", "ㅕ"),
+    ("ᅧᅣ", "ᆥ"),
+    ("ᅧᅩ", "ᅽ"),
+    ("ᅧᅮ", "ᅾ"),
+    ("ᅧ丨", "ᅨㅖ"),
+    ("ᅩ", "ㅗ"),
+    ("ᅩᅡ", "ᅪㅘ"),
+    ("ᅩᅡ丨", "ᅫㅙ"),
+    ("ᅩᅣ", "ᆦ"),
+    ("ᅩᅣ丨", "ᆧ"),
+    ("ᅩᅥ", "ᅿ"),
+    ("ᅩᅥ丨", "ᆀ"),
+    ("ᅩᅧ", "ힰ"),
+    ("ᅩᅧ丨", "ᆁ"),
+    ("ᅩᅩ", "ᆂ"),
+    ("ᅩᅩ丨", "ힱ"),
+    ("ᅩᅮ", "ᆃ"),
+    ("ᅩ丨", "ᅬㅚ"),
+    ("ᅭ", "ㅛ"),
+    ("ᅭᅡ", "ힲ"),
+    ("ᅭᅡ丨", "ힳ"),
+    ("ᅭᅣ", "ᆄᆆㆇ"),
+    ("ᅭᅣ丨", "ᆅㆈ"),
+    ("ᅭᅥ", "ힴ"),
+    ("ᅭᅩ", "ᆇ"),
+    ("ᅭ丨", "ᆈㆉ"),
+    ("ᅮ", "ㅜ"),
+    ("ᅮᅡ", "ᆉ"),
+    ("ᅮᅡ丨", "ᆊ"),
+    ("ᅮᅥ", "ᅯㅝ"),
+    ("ᅮᅥ\u{30FC}", "ᆋ"),
+    ("ᅮᅥ丨", "ᅰㅞ"),
+    ("ᅮᅧ", "ힵ"),
+    ("ᅮᅧ丨", "ᆌ"),
+    ("ᅮᅮ", "ᆍ"),
+    ("ᅮ丨", "ᅱㅟ"),
+    ("ᅮ丨丨", "ힶ"),
+    ("ᅲ", "ㅠ"),
+    ("ᅲᅡ", "ᆎ"),
+    ("ᅲᅡ丨", "ힷ"),
+    ("ᅲᅥ", "ᆏ"),
+    ("ᅲᅥ丨", "ᆐ"),
+    ("ᅲᅧ", "ᆑㆊ"),
+    ("ᅲᅧ丨", "ᆒㆋ"),
+    ("ᅲᅩ", "ힸ"),
+    ("ᅲᅮ", "ᆓ"),
+    ("ᅲ丨", "ᆔㆌ"),
+    ("ᆞ", "ㆍ"),
+    ("ᆞᅡ", "ퟅ"),
+    ("ᆞᅥ", "ᆟ"),
+    ("ᆞᅥ丨", "ퟆ"),
+    ("ᆞᅮ", "ᆠ"),
+    ("ᆞᆞ", "ᆢ"),
+    ("ᆞ丨", "ᆡㆎ"),
+    ("ሆ", "Մ"),
+    ("ቡ", "Ռ"),
+    ("ኮ", "Ի"),
+    ("ጣ", "Պ"),
+    ("Ꮿ", "ῶ"),
+    ("ᐁ·", "ᐍ"),
+    ("ᐁᐠ", "ᐫ"),
+    ("ᐄ·", "ᐑ"),
+    ("ᐅ·", "ᐓ"),
+    ("ᐅᐠ", "ᐭ"),
+    ("ᐆ·", "ᐕ"),
+    ("ᐊ·", "ᐘ"),
+    ("ᐊᐠ", "ᐮ"),
+    ("ᐋ·", "ᐚ"),
+    ("ᐞᣟ", "ᣝ"),
+    ("ᐡ", "ᓑ"),
+    ("ᐩ", "ᕀ"),
+    ("ᐲ·", "ᐿ"),
+    ("ᐴ·", "ᑃ"),
+    ("ᐵ", "⍩"),
+    ("ᐹ·", "ᑇ"),
+    ("ᑏ·", "ᑜ"),
+    ("ᑐ", "⊃⸧"),
+    ("ᑐ'", "ᑩ"),
+    ("ᑐ/", "⟉"),
+    ("ᑐ·", "ᑞ"),
+    ("ᑐᑕ", "⫗"),
+    ("ᑑ·", "ᑠ"),
+    ("ᑕ", "⊂⸦"),
+    ("ᑕ'", "ᑪ"),
+    ("ᑕ·", "ᑢ"),
+    ("ᑖ·", "ᑤ"),
+    ("ᑫ'", "ᒅ"),
+    ("ᑫ·", "ᑵ"),
+    ("ᑮ·", "ᑹ"),
+    ("ᑰ·", "ᑽ"),
+    ("ᒉ", "ᘃ"),
+    ("ᒉ·", "ᒓ"),
+    ("ᒋ·", "ᒕ"),
+    ("ᒌ·", "ᒗ"),
+    ("ᒎ·", "ᒛ"),
+    ("ᒐ", "ᘂ"),
+    ("ᒐ·", "ᒝ"),
+    ("ᒑ·", "ᒟ"),
+    ("ᒣ·", "ᒭ"),
+    ("ᒦ·", "ᒱ"),
+    ("ᒧ·", "ᒳ"),
+    ("ᒨ·", "ᒵ"),
+    ("ᒫ·", "ᒹ"),
+    ("ᓀ·", "ᓊ"),
+    ("ᓂ·", "ᣇ"),
+    ("ᓃ·", "ᣉ"),
+    ("ᓄ·", "ᣋ"),
+    ("ᓅ·", "ᣍ"),
+    ("ᓇ·", "ᓌ"),
+    ("ᓈ·", "ᓎ"),
+    ("ᓓ", "ᘄ"),
+    ("ᓓ·", "ᓝ"),
+    ("ᓕ·", "ᓟ"),
+    ("ᓖ·", "ᓡ"),
+    ("ᓗ·", "ᓣ"),
+    ("ᓘ·", "ᓥ"),
+    ("ᓚ", "ᘇ"),
+    ("ᓚ·", "ᓧ"),
+    ("ᓛ·", "ᓩ"),
+    ("ᓭ·", "ᓷ"),
+    ("ᓯ·", "ᓹ"),
+    ("ᓰ·", "ᓻ"),
+    ("ᓱ·", "ᓽ"),
+    ("ᓲ·", "ᓿ"),
+    ("ᓴ·", "ᔁ"),
+    ("ᓵ·", "ᔃ"),
+    ("ᔋ<", "ᔌ"),
+    ("ᔋb", "ᔎ"),
+    ("ᔋᑕ", "ᔍ"),
+    ("ᔋᒐ", "ᔏ"),
+    ("ᔐ·", "ᔘ"),
+    ("ᔑ·", "ᔚ"),
+    ("ᔒ·", "ᔜ"),
+    ("ᔓ·", "ᔞ"),
+    ("ᔔ·", "ᔠ"),
+    ("ᔕ·", "ᔢ"),
+    ("ᔖ·", "ᔤ"),
+    ("ᔨ·", "ᔲ"),
+    ("ᔩ·", "ᔴ"),
+    ("ᔪ·", "ᔶ"),
+    ("ᔫ·", "ᔸ"),
+    ("ᔭ·", "ᔺ"),
+    ("ᔮ·", "ᔼ"),
+    ("ᕃ", "ᘢ"),
+    ("ᕃ·", "ᣠ"),
+    ("ᕆ", "ᘣ"),
+    ("ᕊ", "ᘤ"),
+    ("ᕌ·", "ᕏ"),
+    ("ᕐP", "ᕿ"),
+    ("ᕐb", "ᖃ"),
+    ("ᕐb\u{307}", "ᖄ"),
+    ("ᕐd", "ᖁ"),
+    ("ᕐᑫ", "ᙯ"),
+    ("ᕐᑬ", "ᕾ"),
+    ("ᕐᑮ", "ᖀ"),
+    ("ᕐᑰ", "ᖂ"),
+    ("ᕐᒃ", "ᖅ"),
+    ("ᕚ·", "ᕜ"),
+    ("ᕞ·", "ᣣ"),
+    ("ᕦ·", "ᣤ"),
+    ("ᕧ·", "ᕩ"),
+    ("ᕫ·", "ᣥ"),
+    ("ᖆ·", "ᣨ"),
+    ("ᖕJ", "ᖑ"),
+    ("ᖕᒉ", "ᙰ"),
+    ("ᖕᒊ", "ᖎ"),
+    ("ᖕᒋ", "ᖏ"),
+    ("ᖕᒌ", "ᖐ"),
+    ("ᖕᒎ", "ᖒ"),
+    ("ᖕᒐ", "ᖓ"),
+    ("ᖕᒑ", "ᖔ"),
+    ("ᖖJ", "ᙳ"),
+    ("ᖖᒋ", "ᙱ"),
+    ("ᖖᒌ", "ᙲ"),
+    ("ᖖᒎ", "ᙴ"),
+    ("ᖖᒐ", "ᙵ"),
+    ("ᖖᒑ", "ᙶ"),
+    ("ᖗ·", "ᣪ"),
+    ("ᖧ·", "ᙷ"),
+    ("ᖨ·", "ᙸ"),
+    ("ᖩ·", "ᙹ"),
+    ("ᖪ·", "ᙺ"),
+    ("ᖫ·", "ᙻ"),
+    ("ᖬ·", "ᙼ"),
+    ("ᖭ·", "ᙽ"),
+    ("ᗒ", "⪫"),
+    ("ᗕ", "⪪"),
+    ("ᗡ", "ꓷ"),
+    ("ᗴ·", "ᣰ"),
+    ("ᘛ·", "ᣲ"),
+    ("ᙆ", "\u{1DBB}"),
+    ("ᙠ", "ꓭ"),
+    ("ᚹ", "ꚰ"),
+    ("ᚼ", "ᛡ"),
+    ("ᚽ", "ᛂ⍿"),
+    ("ᛋ", "𝈿"),
+    ("ᛏ", "↑"),
+    ("ᛐ", "↿"),
+    ("ᛐᛚ", "⥣"),
+    ("ᛐ⇂", "⥮"),
+    ("ᛚ", "↾⨡"),
+    ("ᛜ", "⋄◇◊♢𐊔𑢷🝔"),
+    ("ᛜ\u{332}", "⍚"),
+    ("ᛞ", "⋈⨝"),
+    ("ᛦ", "𐓐"),
+    ("ᛨ", "↕"),
+    ("ᛯ", "ⵣ"),
+    ("\u{1715}", "\u{1734}"),
+    ("អ", "ឣ"),
+    ("ᠵ", "ᡕ"),
+    ("ᡜ", "ᢖ"),
+    ("ᣔ", "\u{1DBA}"),
+    ("ᣖ", "\u{1D3E}"),
+    ("ᣟᐞ", "ᣜ"),
+    ("ᣳ", "\u{2E1}"),
+    ("ᣴ", "\u{2B3}"),
+    ("ᣵ", "\u{2E2}ᣛ"),
+    ("ᦞ", "᧐"),
+    ("ᦱ", "᧑"),
+    ("ᩅ", "᪀᪐"),
+    ("᪨᪨", "᪩"),
+    ("᪪᪨", "᪫"),
+    ("ᬍ", "᭒"),
+    ("ᬑ", "᭓"),
+    ("ᬨ", "᭘"),
+    ("᭐", "᭜"),
+    ("᭞᭞", "᭟"),
+    ("᰻᰻", "᰼"),
+    ("᱾᱾", "᱿"),
+    ("ᴀ", "ꭺ"),
+    ("ᴅ", "ꭰ"),
+    ("ᴇ", "ꭼ"),
+    ("ᴊ", "ꭻ"),
+    ("ᴎ", "ͷи𐑍"),
+    ("ᴘ", "ᴩꮲ"),
+    ("ᴙ", "я"),
+    ("ᴛ", "τтꭲ𝛕𝜏𝝉𝞃𝞽"),
+    ("ᴛ\u{329}", "ҭ"),
+    ("\u{1D34}", "\u{1D78}"),
+    ("\u{1D4B}", "\u{1D9F}"),
+    ("\u{1D4D}", "\u{1DA2}"),
+    ("\u{1DDF}", "\u{2DE8}"),
+    ("ẟ", "δծᕷ⸹𝛅𝛿𝜹𝝳𝞭"),
+    ("ả", "ẚ"),
+    ("ῴ", "\u{1F7D}"),
+    ("₁₀", "⏨"),
+    ("₸", "〒〶"),
+    ("\u{20E9}", "\u{A66F}"),
+    ("℗", "Ⓟ"),
+    ("Ⅎ", "ᖵꓞ"),
+    ("⅄", "𝈛"),
+    ("↞", "⯬"),
+    ("↟", "⯭"),
+    ("↠", "⯮"),
+    ("↡", "⯯"),
+    ("↲", "↵"),
+    ("⇃ᛚ", "⥯"),
+    ("⇃⇂", "⥥"),
+    ("∂", "𝛛𝜕𝝏𝞉𝟃\u{1E8CC}"),
+    ("∂\u{335}", "ð\u{1E8CD}"),
+    ("∅", "⌀"),
+    ("∇", "𑢨𝛁𝛻𝜵𝝯𝞩"),
+    ("∇\u{308}", "⍢"),
+    ("∇\u{334}", "⍫"),
+    ("∎", "█■"),
+    ("∐", "⨿"),
+    ("∠", "\u{1E8C8}"),
+    ("∧", "⋀"),
+    ("∮∮", "∯"),
+    ("∮∮∮", "∰"),
+    ("∴", "⸫"),
+    ("∵", "⸪"),
+    ("∷", "⸬"),
+    ("≈", "𑇞"),
+    ("≏", "♎🝞"),
+    ("≡", "≣"),
+    ("⊍", "⨃"),
+    ("⊎", "⨄"),
+    ("⊏", "𝈸"),
+    ("⊐", "𝈹"),
+    ("⊓", "⨅"),
+    ("⊔", "⨆"),
+    ("⊗", "⨂"),
+    ("⊛", "⍟"),
+    ("⊠", "🝱"),
+    ("⊡", "🝕"),
+    ("⊲", "◁"),
+    ("⊳", "▷"),
+    ("⋆\u{308}", "⍣"),
+    ("⌇", "︴"),
+    ("⌒", "◠"),
+    ("⌙", "⨽"),
+    ("⌤", "⌥"),
+    ("⌻", "⧇"),
+    ("⌾", "◎⦾"),
+    ("⍂", "⧅"),
+    ("⍉", "⦰"),
+    ("⍋", "⏃"),
+    ("⍎", "⏂"),
+    ("⍕", "⏁"),
+    ("⍭", "⏆"),
+    ("⎈", "☸"),
+    ("⏜", "︵"),
+    ("⏝", "︶"),
+    ("⏞", "︷"),
+    ("⏟", "︸"),
+    ("⏠", "︹"),
+    ("⏡", "︺"),
+    ("⏥", "▱"),
+    ("⏻", "⏼"),
+    ("Ⓘ", "ⓛ"),
+    ("│", "┃︱｜"),
+    ("┌", "┏"),
+    ("├", "┣"),
+    ("▌", "▐"),
+    ("▖", "▗"),
+    ("▘", "▝"),
+    ("□", "☐"),
+    ("▪", "￭"),
+    ("▶", "▸►"),
+    ("☧", "⳩"),
+    ("☩", "🜊"),
+    ("☽", "🌒🌙"),
+    ("☾", "⏾🌘"),
+    ("❬", "\u{2329}⟨〈く㇛𡿨"),
+    ("❭", "\u{232A}⟩〉"),
+    ("➀", "①"),
+    ("➁", "②"),
+    ("➂", "③"),
+    ("➃", "④"),
+    ("➄", "⑤"),
+    ("➅", "⑥"),
+    ("➆", "⑦"),
+    ("➇", "⑧"),
+    ("➈", "⑨"),
+    ("➉", "⑩"),
+    ("⟦", "〚"),
+    ("⟧", "〛"),
+    ("⦚", "⧙"),
+    ("⧟", "🜺"),
+    ("⨟", "⨾"),
+    ("ⰿ", "ꦒ"),
+    ("Ɐ", "ᗄ∀ꓯ𝈗"),
+    ("Ɒ", "𐐟"),
+    ("Ⱶ", "ͰᎨᎰꚱ"),
+    ("ⱶ", "ꮀ"),
+    ("Ⲷ", "☰"),
+    ("⳨", "𐆠"),
+    ("ⵀ", "𐊸"),
+    ("ⵂ", "⁞⦙⸽"),
+    ("ⵗ", "⁝⋮︙"),
+    ("\u{2DEC}", "\u{1DEE}"),
+    ("〜", "～"),
+    ("〼", "⍁⧄"),
+    ("へ", "ヘ"),
+    ("\u{30FC}", "ᅳ—―─━⼀ㅡ㇐一ꟷ－"),
+    ("\u{30FC}ᅡ", "ힹ"),
+    ("\u{30FC}ᅥ", "ힺ"),
+    ("\u{30FC}ᅥ丨", "ힻ"),
+    ("\u{30FC}ᅩ", "ힼ"),
+    ("\u{30FC}ᅮ", "ᆕ"),
+    ("\u{30FC}\u{30FC}", "ᆖ"),
+    ("\u{30FC}丨", "ᅴㅢ"),
+    ("\u{30FC}丨ᅮ", "ᆗ"),
+    ("㏄\u{9}\u{20DD}", "🅭"),
+    ("㒞", "\u{2F80C}"),
+    ("㒹", "\u{2F813}"),
+    ("㒻", "\u{2F9CA}"),
+    ("㓟", "\u{2F81F}"),
+    ("㔕", "\u{2F824}"),
+    ("㔾", "⺋"),
+    ("㖈", "䎛"),
+    ("㘽", "㦳"),
+    ("㛮", "\u{2F867}"),
+    ("㛼", "\u{2F868}"),
+    ("㞁", "\u{2F876}"),
+    ("㠯", "\u{2F883}"),
+    ("㡢", "\u{2F888}"),
+    ("㡼", "\u{2F88A}"),
+    ("㣇", "\u{2F896}"),
+    ("㣣", "\u{2F89B}"),
+    ("㣺", "⺗"),
+    ("㤜", "\u{2F8A2}"),
+    ("㤺", "\u{2F8A1}"),
+    ("㨮", "\u{2F8C2}"),
+    ("㩁", "搉"),
+    ("㩬", "\u{2F8C7}"),
+    ("㫚", "曶"),
+    ("㫤", "\u{2F8D1}"),
+    ("㬈", "\u{2F8D0}"),
+    ("㬙", "\u{2F8CE}"),
+    ("㬵", "胶"),
+    ("㬺", "幐"),
+    ("㬻", "䐠"),
+    ("㭉", "\u{2F8DE}"),
+    ("㮝", "\u{FAD2}\u{2F8E7}"),
+    ("㮣", "槩"),
+    ("㰘", "\u{2F8EE}"),
+    ("㱎", "\u{2F8F2}"),
+    ("㴳", "\u{2F90A}"),
+    ("㶖", "\u{2F916}"),
+    ("㺬", "\u{2F92A}"),
+    ("㺸", "\u{2F92C}\u{2F92D}"),
+    ("㼛", "\u{2F933}"),
+    ("㿼", "\u{2F93E}"),
+    ("䀈", "\u{2F93F}"),
+    ("䀘", "\u{FAD3}"),
+    ("䀹", "鿃\u{FAD4}\u{2F949}"),
+    ("䀿", "晣"),
+    ("䁆", "\u{2F94B}"),
+    ("䂖", "\u{2F94C}"),
+    ("䃣", "\u{2F951}"),
+    ("䄯", "\u{2F958}"),
+    ("䈂", "\u{2F960}"),
+    ("䈧", "\u{2F964}"),
+    ("䊠", "\u{2F967}"),
+    ("䌁", "\u{2F96D}"),
+    ("䌴", "\u{2F971}"),
+    ("䍙", "\u{2F974}"),
+    ("䏕", "\u{2F981}"),
+    ("䏙", "\u{2F8D7}"),
+    ("䐋", "\u{2F984}"),
+    ("䑃", "朦"),
+    ("䑫", "\u{2F98E}"),
+    ("䔫", "\u{2F9A7}"),
+    ("䕝", "\u{2F9AE}"),
+    ("䕡", "\u{2F9AF}"),
+    ("䕫", "\u{2F9B2}"),
+    ("䗗", "\u{2F9BF}"),
+    ("䗹", "\u{2F9C2}"),
+    ("䘵", "\u{2F9C8}"),
+    ("䚶", "訞"),
+    ("䚾", "\u{2F9CD}"),
+    ("䛇", "\u{2F9CE}"),
+    ("䦕", "\u{2F9EF}"),
+    ("䧦", "\u{2F9F2}"),
+    ("䩮", "\u{2F9F8}"),
+    ("䩶", "\u{2F9F9}"),
+    ("䪲", "\u{2F9FC}"),
+    ("䬳", "\u{2FA03}"),
+    ("䯎", "\u{2FA08}"),
+    ("䳎", "\u{2FA0D}"),
+    ("䳭", "\u{2FA0E}"),
+    ("䳸", "\u{2FA11}"),
+    ("䵖", "\u{2FA16}"),
+    ("不", "\u{F967}"),
+    ("並", "\u{FA70}"),
+    ("丨", "ᅵ⎜⎟⎢⎥⎪⎮⼁ㅣ㇑"),
+    ("丨ᅡ", "ᆘ"),
+    ("丨ᅣ", "ᆙ"),
+    ("丨ᅣᅩ", "ힽ"),
+    ("丨ᅣ丨", "ힾ"),
+    ("丨ᅧ", "ힿ"),
+    ("丨ᅧ丨", "ퟀ"),
+    ("丨ᅩ", "ᆚ"),
+    ("丨ᅩ丨", "ퟁ"),
+    ("丨ᅭ", "ퟂ"),
+    ("丨ᅮ", "ᆛ"),
+    ("丨ᅲ", "ퟃ"),
+    ("丨ᆞ", "ᆝ"),
+    ("丨\u{30FC}", "ᆜ"),
+    ("丨丨", "ퟄ"),
+    ("丬", "⺦"),
+    ("串", "\u{F905}"),
+    ("丸", "\u{2F801}"),
+    ("丹", "\u{F95E}"),
+    ("丽", "\u{2F800}"),
+    ("乁", "\u{2F802}"),
+    ("乙", "⼄㇠"),
+    ("乚", "⺃㇟"),
+    ("乛", "⺂㇖"),
+    ("亀", "⻲"),
+    ("亂", "\u{F91B}"),
+    ("亅", "⼅㇚"),
+    ("了", "\u{F9BA}"),
+    ("二", "⼆ニ"),
+    ("亠", "⼇"),
+    ("亮", "\u{F977}"),
+    ("人", "⼈"),
+    ("亻", "⺅イ"),
+    ("什", "\u{F9FD}"),
+    ("仌", "\u{2F819}"),
+    ("令", "\u{F9A8}"),
+    ("你", "\u{2F804}"),
+    ("併", "倂\u{2F807}"),
+    ("侀", "\u{FA73}"),
+    ("來", "\u{F92D}"),
+    ("例", "\u{F9B5}"),
+    ("侮", "\u{FA30}\u{2F805}"),
+    ("侻", "\u{2F806}"),
+    ("便", "\u{F965}"),
+    ("値", "值"),
+    ("倫", "\u{F9D4}"),
+    ("偺", "\u{2F808}"),
+    ("備", "\u{2F809}"),
+    ("像", "\u{2F80B}"),
+    ("僚", "\u{F9BB}"),
+    ("僧", "\u{FA31}\u{2F80A}"),
+    ("儿", "⼉ㄦ"),
+    ("兀", "⺎\u{FA0C}"),
+    ("充", "\u{FA74}"),
+    ("免", "\u{FA32}\u{2F80E}"),
+    ("兔", "\u{2F80F}"),
+    ("兤", "\u{2F810}"),
+    ("入", "⼊"),
+    ("內", "\u{2F814}"),
+    ("全", "\u{FA72}"),
+    ("兩", "\u{F978}"),
+    ("八", "⼋ハ"),
+    ("六", "\u{F9D1}"),
+    ("具", "\u{2F811}"),
+    ("冀", "\u{FA75}"),
+    ("冂", "⼌"),
+    ("再", "\u{2F815}"),
+    ("冒", "\u{2F8D2}"),
+    ("冕", "\u{2F8D3}"),
+    ("冖", "⼍"),
+    ("冗", "\u{2F817}"),
+    ("冤", "\u{2F818}"),
+    ("冫", "⼎"),
+    ("冬", "\u{2F81A}"),
+    ("况", "\u{FA71}\u{2F81B}"),
+    ("冷", "\u{F92E}"),
+    ("凉", "\u{F979}"),
+    ("凌", "\u{F955}"),
+    ("凜", "\u{F954}"),
+    ("凞", "\u{FA15}"),
+    ("几", "⼏"),
+    ("凵", "⼐\u{2F81D}"),
+    ("刀", "⼑"),
+    ("刂", "⺉"),
+    ("刃", "\u{2F81E}"),
+    ("切", "\u{FA00}\u{2F850}"),
+    ("列", "\u{F99C}"),
+    ("利", "\u{F9DD}"),
+    ("刺", "\u{F9FF}"),
+    ("刻", "\u{2F820}"),
+    ("剆", "\u{2F821}"),
+    ("割", "\u{2F822}"),
+    ("剷", "\u{2F823}"),
+    ("劉", "\u{F9C7}"),
+    ("力", "⼒カ\u{F98A}"),
+    ("劣", "\u{F99D}"),
+    ("劳", "\u{2F992}"),
+    ("勇", "\u{FA76}\u{2F825}"),
+    ("勉", "\u{FA33}\u{2F826}"),
+    ("勒", "\u{F952}"),
+    ("勞", "\u{F92F}"),
+    ("勤", "\u{FA34}\u{2F827}"),
+    ("勵", "\u{F97F}"),
+    ("勹", "⼓"),
+    ("勺", "\u{FA77}\u{2F828}"),
+    ("包", "\u{2F829}"),
+    ("匆", "\u{2F82A}"),
+    ("匕", "⼔"),
+    ("北", "\u{F963}\u{2F82B}"),
+    ("匚", "⼕"),
+    ("匸", "⼖"),
+    ("匿", "\u{F9EB}"),
+    ("十", "⼗〸"),
+    ("卄", "〹"),
+    ("卅", "〺"),
+    ("卉", "\u{2F82C}"),
+    ("卍", "࿖"),
+    ("卐", "࿕"),
+    ("卑", "\u{FA35}\u{2F82D}"),
+    ("博", "\u{2F82E}"),
+    ("卜", "⼘ト"),
+    ("卩", "⼙"),
+    ("即", "\u{2F82F}"),
+    ("卵", "\u{F91C}"),
+    ("卽", "\u{2F830}"),
+    ("卿", "\u{2F831}\u{2F832}\u{2F833}"),
+    ("厂", "⼚"),
+    ("厶", "⼛"),
+    ("參", "\u{F96B}"),
+    ("又", "⼜"),
+    ("及", "\u{2F836}"),
+    ("叟", "\u{2F837}"),
+    ("口", "⼝⼞ロ囗"),
+    ("句", "\u{F906}"),
+    ("叫", "\u{2F839}"),
+    ("叱", "\u{2F83A}"),
+    ("吆", "\u{2F83B}"),
+    ("吏", "\u{F9DE}"),
+    ("吝", "\u{F9ED}"),
+    ("吸", "\u{2F83D}"),
+    ("呂", "\u{F980}"),
+    ("呈", "\u{2F83E}"),
+    ("周", "\u{2F83F}"),
+    ("咞", "\u{2F83C}"),
+    ("咢", "\u{2F840}"),
+    ("咽", "\u{F99E}"),
+    ("哶", "\u{2F841}"),
+    ("唐", "\u{2F842}"),
+    ("啓", "啟\u{2F843}"),
+    ("啕", "\u{FA79}"),
+    ("啣", "\u{2F844}"),
+    ("善", "\u{2F845}\u{2F846}"),
+    ("喇", "\u{F90B}"),
+    ("喙", "\u{FA7A}\u{2F847}"),
+    ("喝", "\u{FA36}\u{FA78}"),
+    ("喫", "\u{2F848}"),
+    ("喳", "\u{2F849}"),
+    ("嗀", "\u{FA0D}"),
+    ("嗂", "\u{2F84A}"),
+    ("嗢", "\u{FA7B}"),
+    ("嘆", "\u{FA37}\u{2F84C}"),
+    ("噑", "\u{2F84E}"),
+    ("器", "\u{FA38}"),
+    ("噴", "\u{2F84F}"),
+    ("囹", "\u{F9A9}"),
+    ("圖", "\u{2F84B}"),
+    ("圗", "\u{2F84D}"),
+    ("土", "⼟⼠士"),
+    ("型", "\u{2F855}"),
+    ("城", "\u{2F852}"),
+    ("埴", "\u{2F853}"),
+    ("堍", "\u{2F854}"),
+    ("報", "\u{2F857}"),
+    ("堲", "\u{2F856}"),
+    ("塀", "\u{FA39}"),
+    ("塚", "\u{FA10}\u{FA7C}"),
+    ("塞", "\u{F96C}"),
+    ("塡", "填"),
+    ("墨", "\u{FA3A}"),
+    ("墫", "壿"),
+    ("墬", "\u{2F858}"),
+    ("墳", "\u{FA7D}"),
+    ("壘", "\u{F94A}"),
+    ("壟", "\u{F942}"),
+    ("壮", "\u{2F851}"),
+    ("売", "\u{2F85A}"),
+    ("壷", "\u{2F85B}"),
+    ("夂", "⼡"),
+    ("夆", "\u{2F85C}"),
+    ("夊", "⼢"),
+    ("夕", "⼣タ"),
+    ("多", "\u{2F85D}"),
+    ("夢", "\u{2F85E}"),
+    ("大", "⼤"),
+    ("奄", "\u{FA7E}"),
+    ("奈", "\u{F90C}"),
+    ("契", "\u{F909}"),
+    ("奔", "\u{FA7F}"),
+    ("奢", "\u{2F85F}"),
+    ("女", "⼥\u{F981}"),
+    ("姘", "\u{2F865}"),
+    ("姬", "\u{2F862}"),
+    ("娛", "\u{2F863}"),
+    ("娧", "\u{2F864}"),
+    ("婢", "\u{FA80}"),
+    ("婦", "\u{2F866}"),
+    ("媯", "嬀"),
+    ("媵", "\u{2F986}"),
+    ("嬈", "\u{2F869}"),
+    ("嬨", "\u{FA81}"),
+    ("嬾", "\u{2F86A}\u{2F86B}"),
+    ("子", "⼦"),
+    ("宀", "⼧"),
+    ("宅", "\u{FA04}"),
+    ("寃", "\u{2F86D}"),
+    ("寘", "\u{2F86E}"),
+    ("寧", "\u{F95F}\u{F9AA}\u{2F86F}"),
+    ("寮", "\u{F9BC}"),
+    ("寳", "\u{2F870}"),
+    ("寸", "⼨"),
+    ("寿", "\u{2F872}"),
+    ("将", "\u{2F873}"),
+    ("小", "⼩"),
+    ("尢", "⺐⼪\u{2F875}"),
+    ("尣", "⺏"),
+    ("尸", "⼫"),
+    ("尿", "\u{F9BD}"),
+    ("屠", "\u{2F877}"),
+    ("屢", "\u{F94B}"),
+    ("層", "\u{FA3B}"),
+    ("履", "\u{F9DF}"),
+    ("屮", "⼬\u{FA3C}\u{2F878}"),
+    ("山", "⼭"),
+    ("岍", "\u{2F87A}"),
+    ("峀", "\u{2F879}"),
+    ("崙", "\u{F9D5}"),
+    ("嵃", "\u{2F87C}"),
+    ("嵐", "\u{F921}"),
+    ("嵫", "\u{2F87F}"),
+    ("嵮", "\u{2F87E}"),
+    ("嵼", "\u{2F880}"),
+    ("嶲", "\u{2F9F4}"),
+    ("嶺", "\u{F9AB}"),
+    ("巛", "⼮"),
+    ("巡", "\u{2F881}"),
+    ("巢", "\u{2F882}"),
+    ("工", "⼯エ"),
+    ("己", "⼰"),
+    ("巳", "⺒"),
+    ("巽", "\u{2F884}"),
+    ("巾", "⼱"),
+    ("帡", "帲"),
+    ("帨", "\u{2F885}"),
+    ("帽", "\u{2F886}"),
+    ("幩", "\u{2F887}"),
+    ("干", "⼲"),
+    ("年", "\u{F98E}"),
+    ("幺", "⺓⼳"),
+    ("广", "⼴"),
+    ("度", "\u{FA01}"),
+    ("庰", "\u{2F88B}"),
+    ("庳", "\u{2F88C}"),
+    ("庶", "\u{2F88D}"),
+    ("廉", "\u{F9A2}"),
+    ("廊", "\u{F928}\u{2F88E}"),
+    ("廒", "\u{FA82}"),
+    ("廓", "\u{FA0B}"),
+    ("廙", "\u{FA83}"),
+    ("廬", "\u{F982}"),
+    ("廴", "⼵"),
+    ("廾", "⼶\u{2F890}"),
+    ("弄", "\u{F943}"),
+    ("弋", "⼷"),
+    ("弓", "⼸"),
+    ("弢", "\u{2F894}\u{2F895}"),
+    ("彐", "⼹"),
+    ("彑", "⺔"),
+    ("当", "\u{2F874}"),
+    ("彡", "⼺"),
+    ("形", "\u{2F899}"),
+    ("彩", "\u{FA84}"),
+    ("彫", "\u{2F89A}"),
+    ("彳", "⼻"),
+    ("律", "\u{F9D8}"),
+    ("徚", "\u{2F89C}"),
+    ("復", "\u{F966}"),
+    ("徭", "\u{FA85}"),
+    ("心", "⼼"),
+    ("忄", "⺖"),
+    ("忍", "\u{2F89D}"),
+    ("志", "\u{2F89E}"),
+    ("念", "\u{F9A3}"),
+    ("忹", "\u{2F89F}"),
+    ("怒", "\u{F960}"),
+    ("怜", "\u{F9AC}"),
+    ("恵", "\u{FA6B}"),
+    ("悁", "\u{2F8A0}"),
+    ("悔", "\u{FA3D}\u{2F8A3}"),
+    ("惇", "\u{2F8A5}"),
+    ("惘", "\u{FA86}"),
+    ("惡", "\u{F9B9}"),
+    ("愈", "\u{FA88}"),
+    ("慄", "\u{F9D9}"),
+    ("慈", "\u{2F8A6}"),
+    ("慌", "\u{2F8A7}\u{2F8A9}"),
+    ("慎", "\u{FA87}\u{2F8A8}"),
+    ("慠", "\u{FA8A}"),
+    ("慨", "\u{FA3E}"),
+    ("慺", "\u{2F8AA}"),
+    ("憎", "\u{FA3F}\u{FA89}\u{2F8AB}"),
+    ("憐", "\u{F98F}"),
+    ("憤", "\u{2F8AD}"),
+    ("憯", "\u{2F8AE}"),
+    ("憲", "\u{2F8AC}"),
+    ("懞", "\u{2F8AF}"),
+    ("懲", "\u{FA40}\u{FA8B}\u{2F8B0}"),
+    ("懶", "\u{F90D}\u{2F8B1}"),
+    ("戀", "\u{F990}"),
+    ("戈", "⼽"),
+    ("成", "\u{2F8B2}"),
+    ("戛", "\u{2F8B3}"),
+    ("戮", "\u{F9D2}"),
+    ("戴", "\u{FA8C}"),
+    ("戶", "⼾戸"),
+    ("手", "⼿"),
+    ("扌", "⺘"),
+    ("扝", "\u{2F8B4}"),
+    ("抱", "\u{2F8B5}"),
+    ("拉", "\u{F925}"),
+    ("拏", "\u{F95B}"),
+    ("拓", "\u{FA02}"),
+    ("拔", "\u{2F8B6}"),
+    ("拼", "\u{2F8BA}"),
+    ("拾", "\u{F973}"),
+    ("挽", "\u{2F8B9}"),
+    ("捐", "\u{2F8B7}"),
+    ("捨", "\u{2F8BB}"),
+    ("捻", "\u{F9A4}"),
+    ("掃", "\u{2F8BC}"),
+    ("掠", "\u{F975}"),
+    ("掩", "\u{2F8C1}"),
+    ("揄", "\u{FA8D}"),
+    ("揅", "\u{2F8C0}"),
+    ("揤", "\u{2F8BD}"),
+    ("搜", "\u{FA8E}"),
+    ("搢", "\u{2F8BF}"),
+    ("摒", "\u{FA8F}"),
+    ("摩", "\u{2F8C3}"),
+    ("摷", "\u{2F8C6}"),
+    ("摾", "\u{2F8C4}"),
+    ("撚", "\u{F991}"),
+    ("撝", "\u{2F8C5}"),
+    ("擄", "\u{F930}"),
+    ("支", "⽀"),
+    ("攴", "⽁"),
+    ("攵", "⺙"),
+    ("敏", "\u{FA41}\u{2F8C8}"),
+    ("敖", "\u{FA90}"),
+    ("敬", "\u{2F8C9}"),
+    ("數", "\u{F969}"),
+    ("文", "⽂"),
+    ("斉", "⻫"),
+    ("斗", "⽃"),
+    ("料", "\u{F9BE}"),
+    ("斤", "⽄"),
+    ("方", "⽅"),
+    ("旅", "\u{F983}"),
+    ("无", "⽆"),
+    ("旡", "⺛"),
+    ("既", "\u{FA42}"),
+    ("旣", "\u{2F8CB}"),
+    ("日", "⽇"),
+    ("易", "\u{F9E0}"),
+    ("晉", "\u{2F8CD}"),
+    ("晚", "晩"),
+    ("晴", "\u{FA12}\u{FA91}"),
+    ("暈", "\u{F9C5}"),
+    ("暑", "\u{FA43}\u{2F8CF}"),
+    ("暜", "\u{2F8D5}"),
+    ("暴", "\u{FA06}"),
+    ("曆", "\u{F98B}"),
+    ("曰", "⽈"),
+    ("更", "\u{F901}"),
+    ("書", "\u{2F8CC}"),
+    ("最", "\u{2F8D4}"),
+    ("月", "⽉"),
+    ("朌", "肦"),
+    ("朏", "胐"),
+    ("朐", "胊"),
+    ("朓", "脁"),
+    ("朗", "\u{F929}\u{FA92}\u{2F8D8}"),
+    ("朘", "脧"),
+    ("望", "\u{FA93}\u{2F8D9}"),
+    ("朡", "\u{2F8DA}"),
+    ("朣", "膧"),
+    ("木", "⽊"),
+    ("李", "\u{F9E1}"),
+    ("杓", "\u{2F8DC}"),
+    ("杖", "\u{FA94}"),
+    ("杞", "\u{2F8DB}"),
+    ("杮", "柿"),
+    ("杻", "\u{F9C8}"),
+    ("枅", "\u{2F8E0}"),
+    ("林", "\u{F9F4}"),
+    ("柳", "\u{F9C9}"),
+    ("柺", "\u{2F8DF}"),
+    ("栗", "\u{F9DA}"),
+    ("栟", "\u{2F8E5}"),
+    ("桒", "\u{2F8E1}"),
+    ("梁", "\u{F97A}"),
+    ("梅", "\u{FA44}\u{2F8E2}"),
+    ("梎", "\u{2F8E4}"),
+    ("梨", "\u{F9E2}"),
+    ("椔", "\u{2F8E6}"),
+    ("楂", "\u{2F8E8}"),
+    ("榝", "樧"),
+    ("榣", "\u{2F8E9}"),
+    ("槪", "\u{2F8EA}"),
+    ("樂", "\u{F914}\u{F95C}\u{F9BF}"),
+    ("樓", "\u{F94C}"),
+    ("檨", "\u{2F8EB}"),
+    ("櫓", "\u{F931}"),
+    ("櫛", "\u{2F8ED}"),
+    ("欄", "\u{F91D}"),
+    ("欠", "⽋"),
+    ("次", "\u{2F8EF}"),
+    ("歔", "\u{2F8F1}"),
+    ("止", "⽌"),
+    ("歯", "⻭"),
+    ("歲", "\u{2F8F3}"),
+    ("歷", "\u{F98C}"),
+    ("歹", "⽍\u{FA95}"),
+    ("歺", "⺞"),
+    ("殟", "\u{2F8F4}"),
+    ("殮", "\u{F9A5}"),
+    ("殳", "⽎"),
+    ("殺", "\u{F970}\u{FA96}\u{2F8F5}"),
+    ("殻", "\u{2F8F6}"),
+    ("毋", "⽏"),
+    ("母", "⺟"),
+    ("比", "⽐"),
+    ("毛", "⽑"),
+    ("氏", "⽒"),
+    ("民", "⺠"),
+    ("气", "⽓"),
+    ("水", "⽔"),
+    ("氵", "⺡"),
+    ("氺", "⺢"),
+    ("汎", "\u{2F8FA}"),
+    ("汧", "\u{2F8FE}"),
+    ("沈", "\u{F972}"),
+    ("沿", "\u{2F8FC}"),
+    ("泌", "\u{F968}"),
+    ("泍", "\u{2F8FD}"),
+    ("泥", "\u{F9E3}"),
+    ("洖", "\u{2F8FF}"),
+    ("洛", "\u{F915}"),
+    ("洞", "\u{FA05}"),
+    ("洴", "\u{2F907}"),
+    ("派", "\u{2F900}"),
+    ("流", "\u{F9CA}\u{FA97}\u{2F902}"),
+    ("浩", "\u{2F903}"),
+    ("浪", "\u{F92A}"),
+    ("海", "\u{FA45}\u{2F901}"),
+    ("浸", "\u{2F904}"),
+    ("涅", "\u{2F905}"),
+    ("淋", "\u{F9F5}"),
+    ("淚", "\u{F94D}"),
+    ("淪", "\u{F9D6}"),
+    ("淹", "\u{2F90E}"),
+    ("渚", "\u{FA46}"),
+    ("港", "\u{2F908}"),
+    ("湮", "\u{2F909}"),
+    ("溈", "潙"),
+    ("溜", "\u{F9CB}"),
+    ("溺", "\u{F9EC}"),
+    ("滇", "\u{2F90C}"),
+    ("滋", "\u{FA99}\u{2F90B}"),
+    ("滑", "\u{F904}"),
+    ("滛", "\u{FA98}"),
+    ("漏", "\u{F94E}"),
+    ("漢", "\u{FA47}\u{FA9A}"),
+    ("漣", "\u{F992}"),
+    ("潮", "\u{2F90F}"),
+    ("濆", "\u{2F912}"),
+    ("濫", "\u{F922}"),
+    ("濾", "\u{F984}"),
+    ("瀛", "\u{2F915}"),
+    ("瀞", "\u{FA9B}\u{2F914}"),
+    ("瀹", "\u{2F913}"),
+    ("灊", "\u{2F917}"),
+    ("火", "⽕"),
+    ("灬", "⺣"),
+    ("灰", "\u{2F835}"),
+    ("灷", "\u{2F919}"),
+    ("災", "\u{2F918}"),
+    ("炙", "\u{F9FB}"),
+    ("炭", "\u{2F91A}"),
+    ("烈", "\u{F99F}"),
+    ("烙", "\u{F916}"),
+    ("煅", "\u{2F91C}"),
+    ("煉", "\u{F993}"),
+    ("煮", "\u{FA48}\u{FA9C}"),
+    ("熜", "\u{2F91E}"),
+    ("燎", "\u{F9C0}"),
+    ("燐", "\u{F9EE}"),
+    ("爐", "\u{F932}"),
+    ("爛", "\u{F91E}"),
+    ("爨", "\u{2F920}"),
+    ("爪", "⽖"),
+    ("爫", "⺤\u{FA49}"),
+    ("爵", "\u{FA9E}\u{2F921}"),
+    ("父", "⽗"),
+    ("爻", "⽘"),
+    ("爿", "⽙"),
+    ("片", "⽚"),
+    ("牐", "\u{2F922}"),
+    ("牙", "⽛"),
+    ("牛", "⽜"),
+    ("牢", "\u{F946}"),
+    ("犀", "\u{2F924}"),
+    ("犕", "\u{2F925}"),
+    ("犬", "⽝"),
+    ("犭", "⺨"),
+    ("犯", "\u{FA9F}"),
+    ("狀", "\u{F9FA}"),
+    ("狼", "\u{F92B}"),
+    ("猪", "\u{FA16}\u{FAA0}"),
+    ("獵", "\u{F9A7}"),
+    ("獺", "\u{2F928}"),
+    ("玄", "⽞"),
+    ("率", "\u{F961}\u{F9DB}"),
+    ("玉", "⽟"),
+    ("王", "\u{2F929}"),
+    ("玥", "\u{2F92B}"),
+    ("玲", "\u{F9AD}"),
+    ("珞", "\u{F917}"),
+    ("理", "\u{F9E4}"),
+    ("琉", "\u{F9CC}"),
+    ("琢", "\u{FA4A}"),
+    ("瑇", "\u{2F92E}"),
+    ("瑜", "\u{2F92F}"),
+    ("瑩", "\u{F9AE}"),
+    ("瑱", "\u{FAA1}\u{2F930}"),
+    ("璅", "\u{2F931}"),
+    ("璉", "\u{F994}"),
+    ("璘", "\u{F9EF}"),
+    ("瓊", "\u{2F932}"),
+    ("瓜", "⽠"),
+    ("瓦", "⽡"),
+    ("甆", "\u{FAA2}"),
+    ("甘", "⽢"),
+    ("生", "⽣"),
+    ("甤", "\u{2F934}"),
+    ("用", "⽤"),
+    ("田", "⽥"),
+    ("画", "\u{FAA3}"),
+    ("甾", "\u{2F936}"),
+    ("留", "\u{F9CD}"),
+    ("略", "\u{F976}"),
+    ("異", "\u{F962}\u{2F938}"),
+    ("疋", "⽦"),
+    ("疒", "⽧"),
+    ("痢", "\u{F9E5}"),
+    ("瘐", "\u{2F93A}"),
+    ("瘝", "\u{FAA4}"),
+    ("瘟", "\u{FAA5}"),
+    ("療", "\u{F9C1}"),
+    ("癩", "\u{F90E}"),
+    ("癶", "⽨"),
+    ("白", "⽩"),
+    ("皮", "⽪"),
+    ("皿", "⽫"),
+    ("益", "\u{FA17}\u{FAA6}"),
+    ("盛", "\u{FAA7}"),
+    ("盧", "\u{F933}"),
+    ("目", "⽬"),
+    ("直", "\u{FAA8}\u{2F940}"),
+    ("省", "\u{F96D}"),
+    ("眞", "\u{2F945}"),
+    ("真", "\u{2F946}\u{2F947}"),
+    ("着", "\u{FAAA}"),
+    ("睊", "\u{FAA9}\u{2F948}"),
+    ("瞋", "\u{2F94A}"),
+    ("瞧", "\u{FA9D}"),
+    ("矛", "⽭"),
+    ("矢", "⽮"),
+    ("石", "⽯"),
+    ("研", "硏"),
+    ("硎", "\u{2F94E}"),
+    ("硫", "\u{F9CE}"),
+    ("碌", "\u{F93B}\u{2F94F}"),
+    ("碑", "\u{FA4B}"),
+    ("磊", "\u{F947}"),
+    ("磌", "\u{FAAB}\u{2F950}"),
+    ("磻", "\u{F964}"),
+    ("礪", "\u{F985}"),
+    ("示", "⽰"),
+    ("礻", "⺭"),
+    ("礼", "\u{FA18}"),
+    ("社", "\u{FA4C}"),
+    ("祈", "\u{FA4E}"),
+    ("祉", "\u{FA4D}"),
+    ("祐", "\u{FA4F}"),
+    ("祖", "\u{FA50}\u{2F953}"),
+    ("祝", "\u{FA51}"),
+    ("神", "\u{FA19}"),
+    ("祥", "\u{FA1A}"),
+    ("祿", "\u{F93C}"),
+    ("禍", "\u{FA52}"),
+    ("禎", "\u{FA53}"),
+    ("福", "\u{FA1B}\u{2F956}"),
+    ("禮", "\u{F9B6}"),
+    ("禸", "⽱"),
+    ("禾", "⽲"),
+    ("秊", "\u{F995}"),
+    ("秫", "\u{2F957}"),
+    ("稜", "\u{F956}"),
+    ("穀", "\u{FA54}\u{2F959}"),
+    ("穊", "\u{2F95A}"),
+    ("穏", "\u{2F95B}"),
+    ("穴", "⽳"),
+    ("突", "\u{FA55}"),
+    ("窱", "\u{FAAC}"),
+    ("立", "⽴\u{F9F7}"),
+    ("竜", "⻯"),
+    ("竮", "\u{2F95F}"),
+    ("竹", "⽵"),
+    ("笠", "\u{F9F8}"),
+    ("節", "\u{FA56}\u{FAAD}"),
+    ("篆", "\u{2F962}"),
+    ("築", "\u{2F963}"),
+    ("簾", "\u{F9A6}"),
+    ("籠", "\u{F944}"),
+    ("米", "⽶"),
+    ("类", "\u{FAAE}"),
+    ("粒", "\u{F9F9}"),
+    ("精", "\u{FA1D}"),
+    ("糒", "\u{2F966}"),
+    ("糖", "\u{FA03}"),
+    ("糣", "\u{2F969}"),
+    ("糧", "\u{F97B}"),
+    ("糨", "\u{2F968}"),
+    ("糸", "⽷"),
+    ("糹", "⺯"),
+    ("紀", "\u{2F96A}"),
+    ("紐", "\u{F9CF}"),
+    ("索", "\u{F96A}"),
+    ("累", "\u{F94F}"),
+    ("絕", "絶"),
+    ("絛", "\u{FAAF}"),
+    ("絣", "\u{2F96C}"),
+    ("綠", "\u{F93D}"),
+    ("綾", "\u{F957}"),
+    ("緇", "\u{2F96E}"),
+    ("練", "\u{F996}\u{FA57}\u{FAB0}"),
+    ("縂", "\u{2F96F}"),
+    ("縉", "\u{FA58}"),
+    ("縷", "\u{F950}"),
+    ("繁", "\u{FA59}"),
+    ("繅", "\u{2F970}"),
+    ("缶", "⽸"),
+    ("缾", "\u{FAB1}"),
+    ("网", "⽹"),
+    ("罒", "⺫⺲"),
+    ("罓", "⺱"),
+    ("署", "\u{FA5A}"),
+    ("罹", "\u{F9E6}"),
+    ("罺", "\u{2F976}"),
+    ("羅", "\u{F90F}"),
+    ("羊", "⽺"),
+    ("羕", "\u{2F978}"),
+    ("羚", "\u{F9AF}"),
+    ("羽", "⽻\u{FA1E}"),
+    ("翺", "\u{2F979}"),
+    ("老", "⽼\u{F934}"),
+    ("耂", "⺹"),
+    ("者", "\u{FA5B}\u{FAB2}\u{2F97A}"),
+    ("而", "⽽"),
+    ("耒", "⽾"),
+    ("耳", "⽿"),
+    ("聆", "\u{F9B0}"),
+    ("聠", "\u{2F97D}"),
+    ("聯", "\u{F997}"),
+    ("聰", "\u{2F97F}"),
+    ("聾", "\u{F945}"),
+    ("聿", "⾀"),
+    ("肀", "⺺"),
+    ("肉", "⾁"),
+    ("肋", "\u{F953}"),
+    ("肭", "\u{2F8D6}"),
+    ("育", "\u{2F982}"),
+    ("胼", "腁"),
+    ("脃", "\u{2F983}"),
+    ("脾", "\u{2F985}"),
+    ("臘", "\u{F926}"),
+    ("臣", "⾂"),
+    ("臨", "\u{F9F6}"),
+    ("自", "⾃"),
+    ("臭", "\u{FA5C}"),
+    ("至", "⾄"),
+    ("臼", "⾅"),
+    ("舁", "\u{2F893}\u{2F98B}"),
+    ("舄", "\u{2F98C}"),
+    ("舌", "⾆"),
+    ("舘", "\u{FA6D}"),
+    ("舛", "⾇"),
+    ("舟", "⾈"),
+    ("艮", "⾉"),
+    ("良", "\u{F97C}"),
+    ("色", "⾊"),
+    ("艸", "⾋"),
+    ("艹", "⺾⺿⻀\u{FA5D}\u{FA5E}"),
+    ("芋", "\u{2F990}"),
+    ("芑", "\u{2F98F}"),
+    ("芝", "\u{2F991}"),
+    ("花", "\u{2F993}"),
+    ("芳", "\u{2F994}"),
+    ("芽", "\u{2F995}"),
+    ("若", "\u{F974}\u{2F998}"),
+    ("苦", "\u{2F996}"),
+    ("茝", "\u{2F999}"),
+    ("茣", "\u{2F99C}"),
+    ("茶", "\u{F9FE}"),
+    ("荒", "\u{FAB3}"),
+    ("荓", "\u{2F9A0}"),
+    ("荣", "\u{2F99A}"),
+    ("莭", "\u{2F99B}"),
+    ("莽", "\u{2F99D}"),
+    ("菉", "\u{F93E}"),
+    ("菊", "\u{2F9A1}"),
+    ("菌", "\u{2F9A2}"),
+    ("菜", "\u{2F9A3}"),
+    ("菧", "\u{2F99E}"),
+    ("華", "\u{FAB4}"),
+    ("菱", "\u{F958}"),
+    ("落", "\u{F918}"),
+    ("葉", "\u{F96E}"),
+    ("著", "\u{FA5F}\u{2F99F}"),
+    ("蒍", "蔿"),
+    ("蓮", "\u{F999}"),
+    ("蓱", "\u{2F9A8}"),
+    ("蓳", "\u{2F9A9}"),
+    ("蓼", "\u{F9C2}"),
+    ("蔖", "\u{2F9AA}"),
+    ("蕤", "\u{2F9AC}"),
+    ("藍", "\u{F923}"),
+    ("藺", "\u{F9F0}"),
+    ("蘆", "\u{F935}"),
+    ("蘒", "\u{FA20}"),
+    ("蘭", "\u{F91F}"),
+    ("蘷", "虁"),
+    ("蘿", "\u{F910}"),
+    ("虍", "⾌"),
+    ("虎", "⻁"),
+    ("虐", "\u{2F9B3}"),
+    ("虜", "\u{F936}\u{2F9B4}"),
+    ("虧", "\u{2F9B5}"),
+    ("虩", "\u{2F9B6}"),
+    ("虫", "⾍"),
+    ("蚈", "\u{2F9B8}"),
+    ("蚩", "\u{2F9B7}"),
+    ("蛢", "\u{2F9BA}"),
+    ("蜎", "\u{2F9B9}"),
+    ("蜨", "\u{2F9BC}"),
+    ("蝫", "\u{2F9BD}"),
+    ("蝹", "\u{FAB5}\u{2F9BB}"),
+    ("螆", "\u{2F9BE}"),
+    ("螺", "\u{F911}"),
+    ("蟡", "\u{2F9C0}"),
+    ("蠁", "\u{2F9C1}"),
+    ("蠟", "\u{F927}"),
+    ("血", "⾎"),
+    ("行", "⾏\u{FA08}"),
+    ("衠", "\u{2F9C3}"),
+    ("衣", "⾐\u{2F9C4}"),
+    ("衤", "⻂"),
+    ("裂", "\u{F9A0}"),
+    ("裏", "\u{F9E7}"),
+    ("裗", "\u{2F9C6}"),
+    ("裞", "\u{2F9C7}"),
+    ("裡", "\u{F9E8}"),
+    ("裸", "\u{F912}"),
+    ("裺", "\u{2F9C9}"),
+    ("褐", "\u{FA60}"),
+    ("襁", "\u{FAB6}"),
+    ("襤", "\u{F924}"),
+    ("襾", "⾑"),
+    ("西", "⻄"),
+    ("覀", "⻃"),
+    ("覆", "\u{FAB7}"),
+    ("見", "⾒\u{FA0A}"),
+    ("視", "\u{FA61}\u{FAB8}"),
+    ("见", "⻅"),
+    ("角", "⾓"),
+    ("言", "⾔"),
+    ("訮", "詽"),
+    ("誠", "\u{2F9CF}"),
+    ("說", "\u{F96F}\u{F9A1}"),
+    ("調", "\u{FAB9}"),
+    ("請", "\u{FABB}"),
+    ("諒", "\u{F97D}"),
+    ("論", "\u{F941}"),
+    ("諭", "\u{FABE}\u{2F9D0}"),
+    ("諸", "\u{FA22}\u{FABA}"),
+    ("諾", "\u{F95D}\u{FABD}"),
+    ("謁", "\u{FA62}\u{FABC}"),
+    ("謹", "\u{FA63}\u{FABF}"),
+    ("識", "\u{F9FC}"),
+    ("讀", "\u{F95A}"),
+    ("讆", "讏"),
+    ("變", "\u{FAC0}\u{2F9D1}"),
+    ("讠", "⻈"),
+    ("谷", "⾕"),
+    ("豆", "⾖"),
+    ("豈", "\u{F900}"),
+    ("豕", "⾗\u{2F9D2}"),
+    ("豜", "豣"),
+    ("豸", "⾘"),
+    ("貝", "⾙"),
+    ("貫", "\u{2F9D4}"),
+    ("賁", "\u{2F9D5}"),
+    ("賂", "\u{F948}"),
+    ("賈", "\u{F903}"),
+    ("賓", "\u{FA64}"),
+    ("贈", "\u{FA65}\u{FAC1}"),
+    ("贛", "\u{2F9D6}"),
+    ("贝", "⻉"),
+    ("赤", "⾚"),
+    ("走", "⾛"),
+    ("起", "\u{2F9D7}"),
+    ("赿", "趆"),
+    ("足", "⾜"),
+    ("趼", "\u{2F9DB}"),
+    ("跋", "\u{2F9DA}"),
+    ("跥", "跺"),
+    ("路", "\u{F937}"),
+    ("跰", "\u{2F9DC}"),
+    ("躗", "躛"),
+    ("身", "⾝"),
+    ("車", "⾞\u{F902}"),
+    ("軔", "\u{2F9DE}"),
+    ("軿", "輧"),
+    ("輦", "\u{F998}"),
+    ("輪", "\u{F9D7}"),
+    ("輸", "\u{FAC2}\u{2F9DF}"),
+    ("輻", "\u{FA07}"),
+    ("轢", "\u{F98D}"),
+    ("车", "⻋"),
+    ("辛", "⾟"),
+    ("辞", "\u{2F98D}"),
+    ("辰", "⾠\u{F971}"),
+    ("辵", "⾡"),
+    ("辶", "⻌⻍\u{FA66}"),
+    ("連", "\u{F99A}"),
+    ("逸", "\u{FA25}\u{FA67}"),
+    ("遲", "\u{FAC3}"),
+    ("遼", "\u{F9C3}"),
+    ("邏", "\u{F913}"),
+    ("邑", "⾢"),
+    ("邔", "\u{2F9E2}"),
+    ("郎", "郞\u{F92C}\u{FA2E}"),
+    ("郱", "\u{2F9E3}"),
+    ("都", "\u{FA26}"),
+    ("鄑", "\u{2F9E4}"),
+    ("鄛", "\u{2F9E6}"),
+    ("酉", "⾣"),
+    ("酪", "\u{F919}"),
+    ("醙", "\u{FAC4}"),
+    ("醴", "\u{F9B7}"),
+    ("釆", "⾤"),
+    ("里", "⾥\u{F9E9}"),
+    ("量", "\u{F97E}"),
+    ("金", "⾦\u{F90A}"),
+    ("鈴", "\u{F9B1}"),
+    ("鈸", "\u{2F9E7}"),
+    ("鉶", "\u{FAC5}"),
+    ("鉼", "\u{2F9EA}"),
+    ("鋗", "\u{2F9E8}"),
+    ("鋘", "\u{2F9E9}"),
+    ("錄", "\u{F93F}"),
+    ("鍊", "\u{F99B}"),
+    ("鎭", "鎮"),
+    ("鏹", "\u{2F9EB}"),
+    ("鐕", "\u{2F9EC}"),
+    ("钅", "⻐"),
+    ("長", "⻑⾧"),
+    ("镸", "⻒"),
+    ("长", "⻓"),
+    ("門", "⾨"),
+    ("開", "\u{2F9EE}"),
+    ("閭", "\u{F986}"),
+    ("閷", "\u{2F9F0}"),
+    ("门", "⻔"),
+    ("阜", "⾩"),
+    ("阝", "⻏⻖"),
+    ("阮", "\u{F9C6}"),
+    ("陋", "\u{F951}"),
+    ("降", "\u{FA09}"),
+    ("陵", "\u{F959}"),
+    ("陸", "\u{F9D3}"),
+    ("陼", "\u{FAC6}"),
+    ("隆", "\u{F9DC}"),
+    ("隣", "\u{F9F1}"),
+    ("隶", "⾪"),
+    ("隷", "隸\u{F9B8}\u{FA2F}"),
+    ("隹", "⾫"),
+    ("雃", "\u{2F9F3}"),
+    ("離", "\u{F9EA}"),
+    ("難", "\u{FA68}\u{FAC7}"),
+    ("雨", "⾬"),
+    ("零", "\u{F9B2}"),
+    ("雷", "\u{F949}"),
+    ("霣", "\u{2F9F5}"),
+    ("露", "\u{F938}"),
+    ("靈", "\u{F9B3}"),
+    ("靑", "⾭"),
+    ("青", "⻘"),
+    ("靖", "\u{FA1C}\u{FAC8}"),
+    ("非", "⾮"),
+    ("面", "⾯"),
+    ("革", "⾰"),
+    ("韋", "⾱"),
+    ("韛", "\u{FAC9}"),
+    ("韠", "\u{2F9FA}"),
+    ("韦", "⻙"),
+    ("韭", "⾲"),
+    ("音", "⾳"),
+    ("響", "\u{FA69}\u{FACA}"),
+    ("頁", "⾴"),
+    ("頋", "\u{FACB}\u{2F9FE}\u{2F9FF}"),
+    ("領", "\u{F9B4}"),
+    ("頩", "\u{2FA00}"),
+    ("頻", "\u{FA6A}\u{FACC}"),
+    ("類", "\u{F9D0}"),
+    ("页", "⻚"),
+    ("風", "⾵"),
+    ("风", "⻛"),
+    ("飛", "⾶"),
+    ("飞", "⻜"),
+    ("食", "⻝⾷"),
+    ("飠", "⻟"),
+    ("飢", "\u{2FA02}"),
+    ("飯", "\u{FA2A}"),
+    ("飼", "\u{FA2B}"),
+    ("館", "\u{FA2C}"),
+    ("餩", "\u{2FA04}"),
+    ("饣", "⻠"),
+    ("首", "⾸"),
+    ("香", "⾹"),
+    ("馧", "\u{2FA05}"),
+    ("馬", "⾺"),
+    ("駂", "\u{2FA06}"),
+    ("駱", "\u{F91A}"),
+    ("駾", "\u{2FA07}"),
+    ("驪", "\u{F987}"),
+    ("马", "⻢"),
+    ("骨", "⾻"),
+    ("高", "⾼"),
+    ("髟", "⾽"),
+    ("鬒", "\u{FACD}\u{2FA0A}"),
+    ("鬥", "⾾"),
+    ("鬯", "⾿"),
+    ("鬲", "⿀"),
+    ("鬼", "⻤⿁"),
+    ("魚", "⿂"),
+    ("魯", "\u{F939}"),
+    ("鱀", "\u{2FA0B}"),
+    ("鱗", "\u{F9F2}"),
+    ("鱼", "⻥"),
+    ("鳥", "⿃"),
+    ("鳽", "\u{2FA0C}"),
+    ("鵧", "\u{2FA0F}"),
+    ("鶴", "\u{FA2D}"),
+    ("鷺", "\u{F93A}"),
+    ("鸞", "\u{F920}"),
+    ("鹂", "鹃"),
+    ("鹵", "⿄"),
+    ("鹿", "⿅\u{F940}"),
+    ("麗", "\u{F988}"),
+    ("麟", "\u{F9F3}"),
+    ("麥", "⿆"),
+    ("麦", "⻨"),
+    ("麻", "⿇\u{2FA15}"),
+    ("黃", "⿈"),
+    ("黄", "⻩"),
+    ("黍", "⿉"),
+    ("黎", "\u{F989}"),
+    ("黑", "⿊黒"),
+    ("黹", "⿋\u{2FA17}"),
+    ("黽", "⿌"),
+    ("黾", "\u{2FA18}"),
+    ("鼅", "\u{2FA19}"),
+    ("鼎", "⿍"),
+    ("鼏", "\u{2FA1A}"),
+    ("鼓", "⿎"),
+    ("鼖", "\u{2FA1B}"),
+    ("鼠", "⿏"),
+    ("鼻", "⿐\u{2FA1C}"),
+    ("齃", "\u{FAD8}"),
+    ("齊", "⿑"),
+    ("齐", "⻬"),
+    ("齒", "⿒"),
+    ("齿", "⻮"),
+    ("龍", "⿓\u{F9C4}"),
+    ("龎", "\u{FAD9}"),
+    ("龙", "⻰"),
+    ("龜", "⿔\u{F907}\u{F908}\u{FACE}"),
+    ("龟", "⻳"),
+    ("龠", "⿕"),
+    ("ꁊ", "꒞"),
+    ("ꁐ", "꒬"),
+    ("ꃀ", "꒜"),
+    ("ꄲ", "꒨"),
+    ("ꉙ", "꒿"),
+    ("ꊱ", "꒾"),
+    ("ꋍ", "꒔"),
+    ("ꎫ", "꓀"),
+    ("ꎵ", "꓂"),
+    ("ꎿ", "꒺"),
+    ("ꏂ", "꒰"),
+    ("ꑘ", "꒧"),
+    ("ꓕ", "⊥⟂Ʇ𝈜"),
+    ("ꓤ", "Ꞟ"),
+    ("ꓨ", "⅁"),
+    ("ꓶ", "⅂𐐑𖼦𝈕𝈫"),
+    ("Ꙍ", "Ꞷ"),
+    ("Ꙙ", "𖼜🜁"),
+    ("ꙩ", "𐓫"),
+    ("꛳꛳", "꛴"),
+    ("ꜧ", "ꞕ"),
+    ("Ꜿ", "Ͽ"),
+    ("ꜿ", "ͽ"),
+    ("\u{A770}", "⁹"),
+    ("Ꝺ", "ꝺ"),
+    ("Ꞇ", "Ⴀ"),
+    ("Ꞓ", "Є€Ⲉ"),
+    ("ꞓ", "ɛεϵєԑ⋴ⲉꮛ𐐩𑣎𝛆𝛜𝜀𝜖𝜺𝝐𝝴𝞊𝞮𝟄"),
+    ("ꞓ\u{332}", "⍷"),
+    ("ꟻ", "ᖷ𝈰"),
+    ("ꣻ", "𑇜"),
+    ("꣼", "𑇛"),
+    ("ꦝ", "ꦣ"),
+    ("꧐", "꧆"),
+    ("ꨁ", "꩓"),
+    ("ꨣ", "꩖"),
+    ("ꭑ", "ṃ"),
+    ("\u{FB1D}", "\u{FB39}"),
+    ("\u{FB2A}", "\u{FB2B}\u{FB49}"),
+    ("\u{FB2C}", "\u{FB2D}"),
+    ("\u{FB2E}", "\u{FB2F}\u{FB30}"),
+    ("︿", "＾"),
+    ("\u{FE72}\u{651}", "\u{FC5E}"),
+    ("\u{FE74}\u{651}", "\u{FC5F}"),
+    ("\u{FE76}\u{651}", "\u{FC60}"),
+    ("\u{FE77}\u{651}", "\u{FCF2}"),
+    ("\u{FE78}\u{651}", "\u{FC61}"),
+    ("\u{FE79}\u{651}", "\u{FCF3}"),
+    ("\u{FE7A}\u{651}", "\u{FC62}"),
+    ("\u{FE7B}\u{651}", "\u{FCF4}"),
+    ("\u{FE7C}\u{670}", "\u{FC63}"),
+    ("\u{FF9E}", "゛"),
+    ("\u{FF9F}", "゜"),
+    ("𐊨", "⊕⨁Ꚛ🜨"),
+    ("𐊼", "▽𝈔🜄"),
+    ("𐋀", "⧖"),
+    ("𐎂", "𐏑"),
+    ("𐎓", "𐏓"),
+    ("𐎚", "𒀸"),
+    ("𐐒", "Ꞛ"),
+    ("𐐺", "ꞛ"),
+    ("𐒆", "𐒠"),
+    ("\u{1099E}", "☥𓋹"),
+    ("\u{10A56}\u{10A56}", "\u{10A57}"),
+    ("\u{10C82}", "\u{10CFC}"),
+    ("\u{10CA5}", "\u{10CFA}"),
+    ("𑐴\u{11442}𑐒", "𑐓"),
+    ("𑐴\u{11442}𑐘", "𑐙"),
+    ("𑐴\u{11442}𑐣", "𑐤"),
+    ("𑐴\u{11442}𑐩", "𑐪"),
+    ("𑐴\u{11442}𑐬", "𑐭"),
+    ("𑐴\u{11442}𑐮", "𑐯"),
+    ("𑑋𑑋", "𑑌"),
+    ("𑖂", "𑗘𑗙"),
+    ("𑖃", "𑗚"),
+    ("𑖄", "𑗛"),
+    ("\u{115B2}", "\u{115DC}"),
+    ("\u{115B3}", "\u{115DD}"),
+    ("𑙁𑙁", "𑙂"),
+    ("𑫥𑫥", "𑫨"),
+    ("𑫥𑫥𑫯", "𑫩"),
+    ("𑫥𑫥𑫰", "𑫪"),
+    ("𑫥𑫯", "𑫦"),
+    ("𑫥𑫰", "𑫧"),
+    ("𑫫𑫫", "𑫭"),
+    ("𑫫𑫫𑫯", "𑫮"),
+    ("𑫫𑫯", "𑫬"),
+    ("𑫳𑫯", "𑫴"),
+    ("𑫳𑫰", "𑫵"),
+    ("𑫳𑫳", "𑫶"),
+    ("𑫳𑫳𑫯", "𑫷"),
+    ("𑫳𑫳𑫰", "𑫸"),
+    ("𑱁𑱁", "𑱂"),
+    ("\u{11CAA}", "\u{11CB2}"),
+    ("𖼀", "⅃"),
+    ("𝅘\u{1D165}", "♩"),
+    ("𝅘\u{1D165}\u{1D16E}", "♪"),
+    ("🄍", "⓪"),
+    ("🄎", "↺"),
+    ("𠄢", "\u{2F803}"),
+    ("𠔜", "\u{2F812}"),
+    ("𠔥", "\u{2F91B}"),
+    ("𠕋", "\u{2F816}"),
+    ("𠘺", "\u{2F80D}"),
+    ("𠠄", "\u{2F9D9}"),
+    ("𠣞", "\u{2F9DD}"),
+    ("𠨬", "\u{2F834}"),
+    ("𠭣", "\u{2F838}"),
+    ("𡓤", "\u{2F859}"),
+    ("𡚨", "\u{2F860}"),
+    ("𡛪", "\u{2F861}"),
+    ("𡧈", "\u{2F86C}"),
+    ("𡬘", "\u{2F871}"),
+    ("𡴋", "\u{2F8F8}"),
+    ("𡷤", "\u{2F87B}"),
+    ("𡷦", "\u{2F87D}"),
+    ("𢆃", "\u{2F889}"),
+    ("𢆟", "\u{2F939}"),
+    ("𢌱", "\u{2F891}\u{2F892}"),
+    ("𢛔", "\u{2F8A4}"),
+    ("𢡄", "\u{FAD0}"),
+    ("𢡊", "\u{FACF}"),
+    ("𢬌", "\u{2F8B8}"),
+    ("𢯱", "\u{2F8BE}"),
+    ("𣀊", "\u{2F8CA}"),
+    ("𣊸", "\u{2F897}"),
+    ("𣍟", "\u{2F980}"),
+    ("𣎓", "\u{2F989}"),
+    ("𣎜", "\u{2F98A}"),
+    ("𣏃", "\u{2F8DD}"),
+    ("𣏕", "\u{FAD1}"),
+    ("𣑭", "\u{2F8E3}"),
+    ("𣚣", "\u{2F8EC}"),
+    ("𣢧", "\u{2F8F0}"),
+    ("𣪍", "\u{2F8F7}"),
+    ("𣫺", "\u{2F8F9}"),
+    ("𣲼", "\u{2F8FB}"),
+    ("𣴞", "\u{2F906}"),
+    ("𣻑", "\u{2F90D}"),
+    ("𣽞", "\u{2F910}"),
+    ("𣾎", "\u{2F911}"),
+    ("𤉣", "\u{2F91D}"),
+    ("𤋮", "\u{FA6C}"),
+    ("𤎫", "\u{2F91F}"),
+    ("𤘈", "\u{2F923}"),
+    ("𤜵", "\u{2F926}"),
+    ("𤠔", "\u{2F927}"),
+    ("𤰶", "\u{2F935}"),
+    ("𤲒", "\u{2F937}"),
+    ("𤾡", "\u{2F93B}"),
+    ("𤾸", "\u{2F93C}"),
+    ("𥁄", "\u{2F93D}"),
+    ("𥃲", "\u{2F942}"),
+    ("𥃳", "\u{2F941}"),
+    ("𥄙", "\u{2F943}"),
+    ("𥄳", "\u{2F944}"),
+    ("𥉉", "\u{FAD5}"),
+    ("𥐝", "\u{2F94D}"),
+    ("𥘦", "\u{2F952}"),
+    ("𥚚", "\u{2F954}"),
+    ("𥛅", "\u{2F955}"),
+    ("𥥼", "\u{2F95C}"),
+    ("𥪧", "\u{2F95D}\u{2F95E}"),
+    ("𥮫", "\u{2F961}"),
+    ("𥲀", "\u{2F965}"),
+    ("𥳐", "\u{FAD6}"),
+    ("𥾆", "\u{2F96B}"),
+    ("𦇚", "\u{2F898}"),
+    ("𦈨", "\u{2F972}"),
+    ("𦉇", "\u{2F973}"),
+    ("𦋙", "\u{2F975}"),
+    ("𦌾", "\u{2F977}"),
+    ("𦓚", "\u{2F97B}"),
+    ("𦔣", "\u{2F97C}"),
+    ("𦖨", "\u{2F97E}"),
+    ("𦞧", "\u{2F987}"),
+    ("𦞵", "\u{2F988}"),
+    ("𦬼", "\u{2F997}"),
+    ("𦰶", "\u{2F9A4}"),
+    ("𦳕", "\u{2F9A6}"),
+    ("𦵫", "\u{2F9A5}"),
+    ("𦼬", "\u{2F9AD}"),
+    ("𦾱", "\u{2F9B0}"),
+    ("𧃒", "\u{2F9B1}"),
+    ("𧏊", "\u{2F9AB}"),
+    ("𧙧", "\u{2F9C5}"),
+    ("𧢮", "\u{2F9CB}"),
+    ("𧥦", "\u{2F9CC}"),
+    ("𧲨", "\u{2F9D3}"),
+    ("𧻓", "\u{FAD7}"),
+    ("𧼯", "\u{2F9D8}"),
+    ("𨗒", "\u{2F9E0}"),
+    ("𨗭", "\u{2F9E1}"),
+    ("𨜮", "\u{2F9E5}"),
+    ("𨯺", "\u{2F9ED}"),
+    ("𨵷", "\u{2F9F1}"),
+    ("𩅅", "\u{2F9F6}"),
+    ("𩇟", "\u{2F81C}"),
+    ("𩈚", "\u{2F9F7}"),
+    ("𩐊", "\u{2F9FB}"),
+    ("𩒖", "\u{2F9FD}"),
+    ("𩖶", "\u{2FA01}"),
+    ("𩬰", "\u{2FA09}"),
+    ("𪃎", "\u{2FA10}"),
+    ("𪄅", "\u{2FA12}"),
+    ("𪈎", "\u{2FA13}"),
+    ("𪊑", "\u{2FA14}"),
+    ("𪎒", "\u{2F88F}"),
+    ("𪘀", "\u{2FA1D}"),
+];
+
+/// Maps every character that has a prototype to the position of its group in [`GROUPS`], sorted
+/// by the character.
+pub static SOURCES: &[(char, u16)] = &[
+    ('"', 7),
+    ('%', 703),
+    ('0', 335),
+    ('1', 456),
+    ('I', 456),
+    ('`', 6),
+    ('m', 549),
+    ('|', 456),
+    ('\u{A0}', 0),
+    ('¢', 413),
+    ('¥', 381),
+    ('¯', 786),
+    ('´', 6),
+    ('µ', 849),
+    ('¸', 173),
+    ('Æ', 285),
+    ('Ç', 294),
+    ('Ð', 302),
+    ('×', 583),
+    ('Ø', 342),
+    ('æ', 394),
+    ('ç', 412),
+    ('ð', 1910),
+    ('ö', 933),
+    ('ø', 531),
+    ('Đ', 302),
+    ('đ', 424),
+    ('Ě', 715),
+    ('ě', 716),
+    ('Ħ', 314),
+    ('ħ', 443),
+    ('ı', 444),
+    ('Ĳ', 485),
+    ('ĳ', 447),
+    ('Ŀ', 503),
+    ('ŀ', 503),
+    ('Ł', 325),
+    ('ł', 511),
+    ('ņ', 762),
+    ('ŉ', 15),
+    ('Ő', 707),
+    ('Œ', 339),
+    ('œ', 526),
+    ('ţ', 738),
+    ('Ŧ', 358),
+    ('ŧ', 570),
+    ('ſ', 429),
+    ('ƀ', 408),
+    ('Ɓ', 10),
+    ('Ƃ', 404),
+    ('ƃ', 404),
+    ('Ƅ', 400),
+    ('Ƈ', 293),
+    ('Ɖ', 302),
+    ('Ɗ', 11),
+    ('ƌ', 421),
+    ('ƍ', 438),
+    ('Ƒ', 307),
+    ('ƒ', 436),
+    ('Ɠ', 309),
+    ('Ɩ', 456),
+    ('Ɨ', 508),
+    ('Ƙ', 319),
+    ('ƙ', 455),
+    ('ƚ', 508),
+    ('ƛ', 848),
+    ('Ɲ', 334),
+    ('ƞ', 522),
+    ('Ɵ', 341),
+    ('Ơ', 336),
+    ('ơ', 525),
+    ('Ƥ', 12),
+    ('ƥ', 543),
+    ('Ʀ', 349),
+    ('Ƨ', 188),
+    ('Ƭ', 13),
+    ('ƭ', 568),
+    ('Ʈ', 356),
+    ('Ƴ', 14),
+    ('ƴ', 588),
+    ('Ƶ', 384),
+    ('ƶ', 594),
+    ('Ʒ', 211),
+    ('ƻ', 207),
+    ('Ƽ', 227),
+    ('ƽ', 557),
+    ('ƿ', 712),
+    ('ǀ', 456),
+    ('ǁ', 494),
+    ('ǃ', 1),
+    ('Ǆ', 300),
+    ('ǅ', 301),
+    ('ǆ', 418),
+    ('Ǉ', 323),
+    ('ǈ', 324),
+    ('ǉ', 493),
+    ('Ǌ', 330),
+    ('ǋ', 331),
+    ('ǌ', 520),
+    ('Ǎ', 713),
+    ('ǎ', 714),
+    ('Ǐ', 720),
+    ('ǐ', 721),
+    ('Ǒ', 725),
+    ('ǒ', 726),
+    ('Ǔ', 728),
+    ('ǔ', 729),
+    ('Ǥ', 310),
+    ('ǥ', 440),
+    ('Ǧ', 717),
+    ('ǧ', 718),
+    ('Ǳ', 298),
+    ('ǲ', 299),
+    ('ǳ', 416),
+    ('ǵ', 719),
+    ('Ǿ', 343),
+    ('Ț', 727),
+    ('ț', 738),
+    ('Ȝ', 211),
+    ('Ȣ', 245),
+    ('ȣ', 245),
+    ('Ȥ', 383),
+    ('ȥ', 591),
+    ('Ȧ', 706),
+    ('ȧ', 710),
+    ('ȼ', 413),
+    ('Ⱦ', 359),
+    ('Ɂ', 280),
+    ('Ʉ', 364),
+    ('Ɇ', 304),
+    ('ɇ', 428),
+    ('Ɉ', 317),
+    ('ɉ', 453),
+    ('ɍ', 556),
+    ('Ɏ', 381),
+    ('ɏ', 589),
+    ('ɑ', 390),
+    ('ɓ', 407),
+    ('ɖ', 423),
+    ('ɗ', 422),
+    ('ə', 740),
+    ('ɚ', 744),
+    ('ɛ', 3135),
+    ('ɠ', 439),
+    ('ɡ', 438),
+    ('ɣ', 587),
+    ('ɦ', 442),
+    ('ɨ', 451),
+    ('ɩ', 444),
+    ('ɪ', 444),
+    ('ɫ', 507),
+    ('ɭ', 506),
+    ('ɮ', 504),
+    ('ɯ', 579),
+    ('ɱ', 550),
+    ('ɳ', 521),
+    ('ɵ', 530),
+    ('ɶ', 540),
+    ('ɼ', 554),
+    ('ɽ', 553),
+    ('ʂ', 560),
+    ('ʋ', 571),
+    ('ʏ', 587),
+    ('ʐ', 592),
+    ('ʒ', 746),
+    ('ʔ', 280),
+    ('ʠ', 546),
+    ('ʣ', 416),
+    ('ʤ', 419),
+    ('ʥ', 420),
+    ('ʦ', 564),
+    ('ʧ', 567),
+    ('ʨ', 566),
+    ('ʩ', 435),
+    ('ʪ', 500),
+    ('ʫ', 502),
+    ('\u{2B3}', 1866),
+    ('\u{2B9}', 6),
+    ('\u{2BA}', 7),
+    ('\u{2BB}', 6),
+    ('\u{2BC}', 6),
+    ('\u{2BD}', 6),
+    ('\u{2BE}', 6),
+    ('\u{2BF}', 888),
+    ('˂', 261),
+    ('˃', 275),
+    ('˄', 388),
+    ('\u{2C6}', 388),
+    ('\u{2C8}', 6),
+    ('\u{2CA}', 6),
+    ('\u{2CB}', 6),
+    ('\u{2D0}', 257),
+    ('˓', 888),
+    ('˗', 174),
+    ('˘', 785),
+    ('˙', 1271),
+    ('˚', 604),
+    ('˛', 444),
+    ('˜', 597),
+    ('˝', 7),
+    ('\u{2E1}', 1865),
+    ('\u{2E2}', 1867),
+    ('\u{2E4}', 784),
+    ('\u{2EE}', 7),
+    ('˴', 6),
+    ('˶', 7),
+    ('˸', 257),
+    ('˻', 790),
+    ('\u{305}', 797),
+    ('\u{30C}', 798),
+    ('\u{30D}', 1219),
+    ('\u{310}', 799),
+    ('\u{311}', 795),
+    ('\u{315}', 809),
+    ('\u{317}', 1212),
+    ('\u{320}', 821),
+    ('\u{321}', 815),
+    ('\u{322}', 816),
+    ('\u{327}', 815),
+    ('\u{336}', 823),
+    ('\u{337}', 824),
+    ('\u{339}', 815),
+    ('\u{340}', 793),
+    ('\u{341}', 794),
+    ('\u{342}', 796),
+    ('\u{343}', 809),
+    ('\u{345}', 816),
+    ('\u{347}', 822),
+    ('\u{357}', 825),
+    ('\u{358}', 800),
+    ('\u{366}', 803),
+    ('\u{36E}', 798),
+    ('Ͱ', 1995),
+    ('\u{374}', 6),
+    ('͵', 789),
+    ('Ͷ', 865),
+    ('ͷ', 1884),
+    ('\u{37A}', 444),
+    ('ͻ', 754),
+    ('ͽ', 3130),
+    ('\u{37E}', 260),
+    ('Ϳ', 315),
+    ('΄', 6),
+    ('\u{387}', 610),
+    ('Α', 283),
+    ('Β', 291),
+    ('Ε', 303),
+    ('Ζ', 382),
+    ('Η', 311),
+    ('Θ', 341),
+    ('Ι', 456),
+    ('Κ', 318),
+    ('Λ', 749),
+    ('Μ', 326),
+    ('Ν', 329),
+    ('Ο', 335),
+    ('Ρ', 345),
+    ('Σ', 737),
+    ('Τ', 352),
+    ('Υ', 380),
+    ('Χ', 375),
+    ('α', 390),
+    ('β', 709),
+    ('γ', 587),
+    ('δ', 1893),
+    ('ε', 3135),
+    ('η', 522),
+    ('θ', 341),
+    ('ι', 444),
+    ('κ', 722),
+    ('ν', 575),
+    ('ο', 524),
+    ('ρ', 541),
+    ('σ', 524),
+    ('τ', 1887),
+    ('υ', 571),
+    ('φ', 764),
+    ('ϐ', 709),
+    ('ϑ', 341),
+    ('ϒ', 380),
+    ('ϕ', 764),
+    ('ϖ', 851),
+    ('ϛ', 852),
+    ('Ϝ', 305),
+    ('Ϩ', 188),
+    ('ϩ', 736),
+    ('ϰ', 722),
+    ('ϱ', 541),
+    ('ϲ', 409),
+    ('ϳ', 452),
+    ('ϴ', 341),
+    ('ϵ', 3135),
+    ('Ϸ', 708),
+    ('ϸ', 712),
+    ('Ϲ', 292),
+    ('Ϻ', 326),
+    ('Ͻ', 732),
+    ('Ͽ', 3129),
+    ('Є', 3134),
+    ('Ѕ', 351),
+    ('І', 456),
+    ('Ј', 315),
+    ('А', 283),
+    ('Б', 404),
+    ('В', 291),
+    ('Г', 833),
+    ('Е', 303),
+    ('З', 211),
+    ('Й', 862),
+    ('К', 318),
+    ('Л', 749),
+    ('М', 326),
+    ('Н', 311),
+    ('О', 335),
+    ('П', 842),
+    ('Р', 345),
+    ('С', 292),
+    ('Т', 352),
+    ('У', 380),
+    ('Ф', 843),
+    ('Х', 375),
+    ('Ы', 402),
+    ('Ь', 400),
+    ('Ю', 486),
+    ('а', 390),
+    ('б', 233),
+    ('в', 777),
+    ('г', 547),
+    ('е', 426),
+    ('з', 757),
+    ('и', 1884),
+    ('к', 722),
+    ('м', 774),
+    ('н', 779),
+    ('о', 524),
+    ('п', 851),
+    ('р', 541),
+    ('с', 409),
+    ('т', 1887),
+    ('у', 587),
+    ('ф', 764),
+    ('х', 583),
+    ('ъ', 787),
+    ('ы', 731),
+    ('ь', 730),
+    ('я', 1886),
+    ('є', 3135),
+    ('ѕ', 557),
+    ('і', 444),
+    ('ј', 452),
+    ('ћ', 443),
+    ('ѝ', 870),
+    ('ѡ', 579),
+    ('Ѣ', 408),
+    ('ѣ', 408),
+    ('Ѱ', 844),
+    ('ѱ', 854),
+    ('Ѳ', 341),
+    ('ѳ', 530),
+    ('Ѵ', 365),
+    ('ѵ', 575),
+    ('Ѽ', 878),
+    ('ѽ', 582),
+    ('Ҋ', 863),
+    ('ҋ', 871),
+    ('Ҍ', 408),
+    ('ҍ', 408),
+    ('Ґ', 834),
+    ('ґ', 548),
+    ('Ғ', 836),
+    ('ғ', 556),
+    ('Җ', 864),
+    ('җ', 869),
+    ('Ҙ', 216),
+    ('ҙ', 758),
+    ('Қ', 320),
+    ('қ', 723),
+    ('Ҟ', 321),
+    ('ҟ', 724),
+    ('Ң', 313),
+    ('ң', 781),
+    ('Ҫ', 294),
+    ('ҫ', 412),
+    ('Ҭ', 357),
+    ('ҭ', 1888),
+    ('Ү', 380),
+    ('ү', 587),
+    ('Ұ', 381),
+    ('ұ', 589),
+    ('Ҳ', 378),
+    ('һ', 441),
+    ('ҽ', 426),
+    ('Ҿ', 881),
+    ('ҿ', 427),
+    ('Ӏ', 456),
+    ('Ӆ', 751),
+    ('ӆ', 873),
+    ('Ӈ', 312),
+    ('ӈ', 780),
+    ('Ӊ', 312),
+    ('ӊ', 780),
+    ('Ӌ', 879),
+    ('ӌ', 880),
+    ('Ӎ', 328),
+    ('ӎ', 775),
+    ('ӏ', 444),
+    ('Ӕ', 285),
+    ('ӕ', 394),
+    ('Ә', 734),
+    ('ә', 740),
+    ('Ӡ', 211),
+    ('ӡ', 746),
+    ('Ө', 341),
+    ('ө', 530),
+    ('ԁ', 414),
+    ('Ԋ', 745),
+    ('Ԍ', 308),
+    ('ԍ', 760),
+    ('Ԑ', 735),
+    ('ԑ', 3135),
+    ('ԛ', 545),
+    ('Ԝ', 373),
+    ('ԝ', 579),
+    ('Ի', 1707),
+    ('Մ', 1705),
+    ('Պ', 1708),
+    ('Ռ', 1706),
+    ('Ս', 361),
+    ('Տ', 351),
+    ('Փ', 843),
+    ('Օ', 335),
+    ('՚', 6),
+    ('՝', 6),
+    ('ա', 579),
+    ('գ', 545),
+    ('զ', 545),
+    ('ծ', 1893),
+    ('հ', 441),
+    ('յ', 747),
+    ('ո', 519),
+    ('պ', 761),
+    ('ռ', 519),
+    ('ս', 571),
+    ('ց', 438),
+    ('ք', 429),
+    ('օ', 524),
+    ('և', 889),
+    ('։', 257),
+    ('\u{59C}', 794),
+    ('\u{59D}', 794),
+    ('\u{5A4}', 899),
+    ('\u{5A8}', 898),
+    ('\u{5AD}', 896),
+    ('\u{5AE}', 897),
+    ('\u{5AF}', 803),
+    ('\u{5B4}', 812),
+    ('\u{5B9}', 800),
+    ('\u{5BA}', 800),
+    ('\u{5C0}', 456),
+    ('\u{5C1}', 800),
+    ('\u{5C2}', 800),
+    ('\u{5C3}', 257),
+    ('\u{5C4}', 800),
+    ('\u{5C5}', 812),
+    ('\u{5D5}', 456),
+    ('\u{5D8}', 575),
+    ('\u{5D9}', 6),
+    ('\u{5DF}', 456),
+    ('\u{5E1}', 524),
+    ('\u{5F0}', 494),
+    ('\u{5F1}', 457),
+    ('\u{5F2}', 7),
+    ('\u{5F3}', 6),
+    ('\u{5F4}', 7),
+    ('؉', 704),
+    ('؊', 705),
+    ('\u{60D}', 173),
+    ('؏', 1056),
+    ('\u{618}', 794),
+    ('\u{619}', 809),
+    ('\u{61A}', 1212),
+    ('\u{623}', 515),
+    ('\u{624}', 1163),
+    ('\u{625}', 514),
+    ('\u{626}', 1185),
+    ('\u{627}', 456),
+    ('\u{62B}', 1201),
+    ('\u{634}', 1010),
+    ('\u{63D}', 1167),
+    ('\u{63F}', 1201),
+    ('\u{647}', 524),
+    ('\u{64A}', 1165),
+    ('\u{64B}', 806),
+    ('\u{64E}', 794),
+    ('\u{64F}', 809),
+    ('\u{652}', 803),
+    ('\u{653}', 796),
+    ('\u{656}', 817),
+    ('\u{657}', 808),
+    ('\u{658}', 798),
+    ('\u{659}', 797),
+    ('\u{65A}', 798),
+    ('\u{65B}', 795),
+    ('\u{65C}', 812),
+    ('\u{65D}', 810),
+    ('\u{65F}', 1213),
+    ('\u{660}', 180),
+    ('\u{661}', 456),
+    ('\u{665}', 524),
+    ('\u{667}', 365),
+    ('\u{668}', 749),
+    ('٪', 703),
+    ('\u{66B}', 173),
+    ('\u{66C}', 912),
+    ('\u{66D}', 164),
+    ('\u{66E}', 1165),
+    ('\u{66F}', 1233),
+    ('\u{672}', 515),
+    ('\u{673}', 514),
+    ('\u{675}', 515),
+    ('\u{676}', 1163),
+    ('\u{677}', 1160),
+    ('\u{678}', 1185),
+    ('\u{679}', 1170),
+    ('\u{67E}', 1201),
+    ('\u{681}', 968),
+    ('\u{685}', 969),
+    ('\u{688}', 978),
+    ('\u{68B}', 1229),
+    ('\u{68E}', 979),
+    ('\u{691}', 986),
+    ('\u{692}', 984),
+    ('\u{698}', 991),
+    ('\u{69E}', 1036),
+    ('\u{69F}', 1053),
+    ('\u{6A4}', 1234),
+    ('\u{6A7}', 1070),
+    ('\u{6A8}', 1234),
+    ('\u{6A9}', 1086),
+    ('\u{6AA}', 1086),
+    ('\u{6AD}', 1096),
+    ('\u{6B4}', 1239),
+    ('\u{6B5}', 1102),
+    ('\u{6B7}', 1117),
+    ('\u{6BA}', 1165),
+    ('\u{6BB}', 1170),
+    ('\u{6BD}', 1201),
+    ('\u{6BE}', 524),
+    ('\u{6C1}', 524),
+    ('\u{6C2}', 1242),
+    ('\u{6C3}', 933),
+    ('\u{6C6}', 1158),
+    ('\u{6C7}', 1159),
+    ('\u{6C8}', 1162),
+    ('\u{6C9}', 1157),
+    ('\u{6CB}', 1164),
+    ('\u{6CC}', 1165),
+    ('\u{6CE}', 1168),
+    ('\u{6D0}', 1222),
+    ('\u{6D1}', 1201),
+    ('\u{6D2}', 1165),
+    ('\u{6D4}', 174),
+    ('\u{6D5}', 524),
+    ('\u{6DF}', 803),
+    ('\u{6E8}', 799),
+    ('\u{6EC}', 800),
+    ('\u{6EE}', 976),
+    ('\u{6EF}', 983),
+    ('۰', 180),
+    ('۱', 456),
+    ('۲', 1214),
+    ('۳', 1215),
+    ('۴', 1216),
+    ('۵', 524),
+    ('۶', 1217),
+    ('۷', 365),
+    ('۸', 749),
+    ('۹', 1218),
+    ('\u{6FD}', 916),
+    ('\u{6FE}', 1120),
+    ('\u{6FF}', 528),
+    ('\u{701}', 180),
+    ('\u{702}', 180),
+    ('\u{703}', 257),
+    ('\u{704}', 257),
+    ('\u{740}', 800),
+    ('\u{741}', 800),
+    ('\u{742}', 1246),
+    ('\u{747}', 794),
+    ('\u{751}', 931),
+    ('\u{756}', 1168),
+    ('\u{762}', 1237),
+    ('\u{763}', 1096),
+    ('\u{767}', 1247),
+    ('\u{768}', 1142),
+    ('\u{769}', 1141),
+    ('\u{76C}', 989),
+    ('\u{771}', 1232),
+    ('\u{772}', 968),
+    ('\u{77E}', 996),
+    ('\u{7C0}', 335),
+    ('\u{7CA}', 456),
+    ('\u{7EB}', 797),
+    ('\u{7ED}', 800),
+    ('\u{7EE}', 795),
+    ('\u{7F3}', 801),
+    ('\u{7F4}', 6),
+    ('\u{7F5}', 6),
+    ('\u{7FA}', 389),
+    ('\u{8A1}', 930),
+    ('\u{8A4}', 1235),
+    ('\u{8A7}', 1138),
+    ('\u{8A8}', 1183),
+    ('\u{8A9}', 1247),
+    ('\u{8AE}', 977),
+    ('\u{8AF}', 1024),
+    ('\u{8B0}', 1238),
+    ('\u{8B1}', 1156),
+    ('\u{8B2}', 993),
+    ('\u{8B6}', 932),
+    ('\u{8B7}', 1209),
+    ('\u{8B9}', 985),
+    ('\u{8BA}', 1169),
+    ('\u{8BB}', 1233),
+    ('\u{8BC}', 1233),
+    ('\u{8BD}', 1165),
+    ('\u{8E5}', 1210),
+    ('\u{8E8}', 1210),
+    ('\u{8EA}', 800),
+    ('\u{8EB}', 801),
+    ('\u{8ED}', 812),
+    ('\u{8EE}', 813),
+    ('\u{8F0}', 806),
+    ('\u{8F1}', 1210),
+    ('\u{8F2}', 1211),
+    ('\u{8F3}', 809),
+    ('\u{8F8}', 825),
+    ('\u{8F9}', 827),
+    ('\u{8FA}', 828),
+    ('\u{8FF}', 825),
+    ('\u{900}', 826),
+    ('\u{901}', 799),
+    ('\u{902}', 800),
+    ('\u{903}', 257),
+    ('ऄ', 1252),
+    ('आ', 1248),
+    ('ई', 1257),
+    ('ऍ', 1254),
+    ('ऎ', 1255),
+    ('ऐ', 1256),
+    ('ऑ', 1253),
+    ('ऒ', 1249),
+    ('ओ', 1250),
+    ('औ', 1251),
+    ('\u{93C}', 812),
+    ('\u{952}', 821),
+    ('\u{953}', 793),
+    ('\u{954}', 794),
+    ('॥', 1265),
+    ('०', 524),
+    ('१', 1218),
+    ('ॽ', 280),
+    ('\u{981}', 799),
+    ('আ', 1273),
+    ('\u{9BC}', 812),
+    ('ৠ', 1274),
+    ('ৡ', 1274),
+    ('০', 335),
+    ('৪', 245),
+    ('৭', 251),
+    ('\u{A02}', 800),
+    ('\u{A03}', 1272),
+    ('ਆ', 1306),
+    ('ਇ', 1309),
+    ('ਈ', 1310),
+    ('ਉ', 1312),
+    ('ਊ', 1313),
+    ('ਏ', 1311),
+    ('ਐ', 1307),
+    ('ਔ', 1308),
+    ('\u{A3C}', 812),
+    ('\u{A4B}', 1262),
+    ('\u{A4D}', 1263),
+    ('੦', 524),
+    ('੧', 251),
+    ('੪', 245),
+    ('\u{A81}', 799),
+    ('\u{A82}', 800),
+    ('\u{A83}', 257),
+    ('આ', 1314),
+    ('ઍ', 1318),
+    ('એ', 1319),
+    ('ઐ', 1320),
+    ('ઑ', 1315),
+    ('ઓ', 1316),
+    ('ઔ', 1317),
+    ('\u{ABC}', 812),
+    ('ઽ', 1259),
+    ('\u{AC1}', 1260),
+    ('\u{AC2}', 1261),
+    ('\u{ACD}', 1263),
+    ('૦', 524),
+    ('૨', 1266),
+    ('૩', 1267),
+    ('૪', 1268),
+    ('૮', 1269),
+    ('૰', 1270),
+    ('\u{B01}', 799),
+    ('\u{B03}', 245),
+    ('ଆ', 1321),
+    ('ଠ', 335),
+    ('\u{B3C}', 812),
+    ('୦', 335),
+    ('୨', 251),
+    ('\u{B82}', 803),
+    ('ஊ', 1326),
+    ('ஜ', 1330),
+    ('ர', 1323),
+    ('\u{BBE}', 1323),
+    ('\u{BC8}', 1338),
+    ('\u{BCA}', 1346),
+    ('\u{BCB}', 1348),
+    ('\u{BCC}', 1347),
+    ('\u{BCD}', 800),
+    ('\u{BD7}', 1341),
+    ('௦', 524),
+    ('௧', 1331),
+    ('௨', 1325),
+    ('௪', 1332),
+    ('௫', 1324),
+    ('௬', 1333),
+    ('௭', 1328),
+    ('௮', 1322),
+    ('௰', 1340),
+    ('௲', 1334),
+    ('௴', 1339),
+    ('௵', 1349),
+    ('௷', 1329),
+    ('௸', 1344),
+    ('௺', 1337),
+    ('\u{C00}', 799),
+    ('\u{C02}', 524),
+    ('\u{C03}', 1272),
+    ('ఓ', 1357),
+    ('ఔ', 1356),
+    ('ఠ', 1365),
+    ('ఢ', 1360),
+    ('థ', 1362),
+    ('భ', 1363),
+    ('మ', 1370),
+    ('ష', 1368),
+    ('హ', 1369),
+    ('\u{C42}', 1371),
+    ('\u{C44}', 1372),
+    ('ౠ', 1353),
+    ('ౡ', 1354),
+    ('౦', 524),
+    ('\u{C81}', 799),
+    ('\u{C82}', 524),
+    ('\u{C83}', 1272),
+    ('ಅ', 1350),
+    ('ಆ', 1351),
+    ('ಇ', 1352),
+    ('ಒ', 1355),
+    ('ಓ', 1357),
+    ('ಔ', 1356),
+    ('ಜ', 1358),
+    ('ಞ', 1359),
+    ('ಣ', 1361),
+    ('ಯ', 1364),
+    ('ಱ', 1366),
+    ('ಲ', 1367),
+    ('ೡ', 1376),
+    ('೦', 524),
+    ('೧', 1373),
+    ('೨', 1374),
+    ('೯', 1375),
+    ('\u{D01}', 799),
+    ('\u{D02}', 524),
+    ('\u{D03}', 1272),
+    ('ഈ', 1377),
+    ('ഉ', 1325),
+    ('ഊ', 1327),
+    ('ഌ', 1383),
+    ('ഐ', 1378),
+    ('ഓ', 1379),
+    ('ഔ', 1380),
+    ('ങ', 1383),
+    ('ജ', 1330),
+    ('ഠ', 524),
+    ('ണ', 1336),
+    ('റ', 1387),
+    ('ഴ', 1342),
+    ('ശ', 1343),
+    ('ഺ', 1335),
+    ('\u{D3F}', 1345),
+    ('\u{D40}', 1345),
+    ('\u{D42}', 1391),
+    ('\u{D43}', 1391),
+    ('\u{D48}', 1392),
+    ('ൎ', 1271),
+    ('൚', 1386),
+    ('ൟ', 538),
+    ('ൡ', 1381),
+    ('൦', 524),
+    ('൪', 1388),
+    ('൫', 1382),
+    ('൬', 1385),
+    ('൭', 251),
+    ('൮', 1389),
+    ('൯', 1384),
+    ('൶', 1390),
+    ('൹', 1383),
+    ('ൻ', 1384),
+    ('ർ', 1388),
+    ('\u{D82}', 524),
+    ('\u{D83}', 1272),
+    ('෩', 1395),
+    ('෪', 1393),
+    ('෫', 1394),
+    ('෯', 1396),
+    ('ฃ', 1397),
+    ('ซ', 1401),
+    ('ฏ', 1402),
+    ('ด', 1398),
+    ('ต', 1398),
+    ('ท', 1403),
+    ('ม', 1399),
+    ('ฦ', 1409),
+    ('ำ', 804),
+    ('แ', 1419),
+    ('ๅ', 1412),
+    ('\u{E4D}', 803),
+    ('๐', 524),
+    ('ຈ', 1400),
+    ('ຍ', 1410),
+    ('ບ', 1404),
+    ('ປ', 1405),
+    ('ຝ', 1406),
+    ('ພ', 1407),
+    ('ຟ', 1408),
+    ('ຳ', 805),
+    ('\u{EB8}', 1417),
+    ('\u{EB9}', 1418),
+    ('\u{EC8}', 1420),
+    ('\u{EC9}', 1421),
+    ('\u{ECA}', 1422),
+    ('\u{ECB}', 1423),
+    ('\u{ECD}', 803),
+    ('໐', 524),
+    ('ໜ', 1427),
+    ('ໝ', 1428),
+    ('ༀ', 1438),
+    ('༂', 1436),
+    ('༃', 1435),
+    ('༌', 1429),
+    ('༎', 1430),
+    ('༛', 1431),
+    ('༞', 1434),
+    ('༟', 1432),
+    ('\u{F37}', 814),
+    ('ཪ', 1437),
+    ('\u{F77}', 1439),
+    ('\u{F79}', 1440),
+    ('࿎', 1433),
+    ('࿕', 2215),
+    ('࿖', 2214),
+    ('က', 1441),
+    ('တ', 539),
+    ('ဝ', 524),
+    ('ဟ', 1444),
+    ('ဩ', 1447),
+    ('ဪ', 1448),
+    ('\u{1036}', 803),
+    ('\u{1038}', 1272),
+    ('၀', 524),
+    ('။', 1450),
+    ('ၥ', 1449),
+    ('ၦ', 1446),
+    ('ၯ', 1445),
+    ('ၰ', 1443),
+    ('ၾ', 1451),
+    ('ႁ', 1442),
+    ('႞', 1452),
+    ('Ⴀ', 3133),
+    ('ყ', 587),
+    ('ჳ', 746),
+    ('ჿ', 524),
+    ('ᄁ', 1454),
+    ('ᄄ', 1478),
+    ('ᄈ', 1545),
+    ('ᄊ', 1570),
+    ('ᄍ', 1602),
+    ('ᄓ', 1465),
+    ('ᄔ', 1466),
+    ('ᄕ', 1467),
+    ('ᄖ', 1469),
+    ('ᄗ', 1477),
+    ('ᄘ', 1493),
+    ('ᄙ', 1497),
+    ('ᄚ', 1517),
+    ('ᄛ', 1512),
+    ('ᄜ', 1529),
+    ('ᄝ', 1533),
+    ('ᄞ', 1539),
+    ('ᄟ', 1540),
+    ('ᄠ', 1541),
+    ('ᄡ', 1547),
+    ('ᄢ', 1548),
+    ('ᄣ', 1549),
+    ('ᄤ', 1550),
+    ('ᄥ', 1551),
+    ('ᄦ', 1552),
+    ('ᄧ', 1555),
+    ('ᄨ', 1556),
+    ('ᄩ', 1558),
+    ('ᄪ', 1559),
+    ('ᄫ', 1554),
+    ('ᄬ', 1546),
+    ('ᄭ', 1562),
+    ('ᄮ', 1563),
+    ('ᄯ', 1564),
+    ('ᄰ', 1565),
+    ('ᄱ', 1566),
+    ('ᄲ', 1567),
+    ('ᄳ', 1568),
+    ('ᄴ', 1574),
+    ('ᄵ', 1575),
+    ('ᄶ', 1576),
+    ('ᄷ', 1577),
+    ('ᄸ', 1578),
+    ('ᄹ', 1579),
+    ('ᄺ', 1580),
+    ('ᄻ', 1517),
+    ('ᄽ', 1623),
+    ('ᄿ', 1624),
+    ('ᅁ', 1583),
+    ('ᅂ', 1585),
+    ('ᅃ', 1587),
+    ('ᅄ', 1588),
+    ('ᅅ', 1589),
+    ('ᅆ', 1597),
+    ('ᅇ', 1590),
+    ('ᅈ', 1591),
+    ('ᅉ', 1592),
+    ('ᅊ', 1594),
+    ('ᅋ', 1595),
+    ('ᅍ', 1601),
+    ('ᅏ', 1631),
+    ('ᅑ', 1632),
+    ('ᅒ', 1605),
+    ('ᅓ', 1606),
+    ('ᅖ', 1611),
+    ('ᅗ', 1613),
+    ('ᅘ', 1622),
+    ('ᅚ', 1456),
+    ('ᅛ', 1470),
+    ('ᅜ', 1471),
+    ('ᅝ', 1474),
+    ('ᅞ', 1480),
+    ('ᅢ', 1640),
+    ('ᅤ', 1645),
+    ('ᅦ', 1650),
+    ('ᅨ', 1655),
+    ('ᅪ', 1657),
+    ('ᅫ', 1658),
+    ('ᅬ', 1668),
+    ('ᅯ', 1680),
+    ('ᅰ', 1682),
+    ('ᅱ', 1686),
+    ('ᅳ', 2006),
+    ('ᅴ', 2013),
+    ('ᅵ', 2098),
+    ('ᅶ', 1637),
+    ('ᅷ', 1638),
+    ('ᅸ', 1642),
+    ('ᅹ', 1643),
+    ('ᅺ', 1647),
+    ('ᅻ', 1648),
+    ('ᅼ', 1649),
+    ('ᅽ', 1653),
+    ('ᅾ', 1654),
+    ('ᅿ', 1661),
+    ('ᆀ', 1662),
+    ('ᆁ', 1664),
+    ('ᆂ', 1665),
+    ('ᆃ', 1667),
+    ('ᆄ', 1672),
+    ('ᆅ', 1673),
+    ('ᆆ', 1672),
+    ('ᆇ', 1675),
+    ('ᆈ', 1676),
+    ('ᆉ', 1678),
+    ('ᆊ', 1679),
+    ('ᆋ', 1681),
+    ('ᆌ', 1684),
+    ('ᆍ', 1685),
+    ('ᆎ', 1689),
+    ('ᆏ', 1691),
+    ('ᆐ', 1692),
+    ('ᆑ', 1693),
+    ('ᆒ', 1694),
+    ('ᆓ', 1696),
+    ('ᆔ', 1697),
+    ('ᆕ', 2011),
+    ('ᆖ', 2012),
+    ('ᆗ', 2014),
+    ('ᆘ', 2099),
+    ('ᆙ', 2100),
+    ('ᆚ', 2105),
+    ('ᆛ', 2108),
+    ('ᆜ', 2111),
+    ('ᆝ', 2110),
+    ('ᆟ', 1700),
+    ('ᆠ', 1702),
+    ('ᆡ', 1704),
+    ('ᆢ', 1703),
+    ('ᆣ', 1639),
+    ('ᆤ', 1644),
+    ('ᆥ', 1652),
+    ('ᆦ', 1659),
+    ('ᆧ', 1660),
+    ('ᆨ', 1453),
+    ('ᆩ', 1454),
+    ('ᆪ', 1459),
+    ('ᆫ', 1464),
+    ('ᆬ', 1471),
+    ('ᆭ', 1474),
+    ('ᆮ', 1476),
+    ('ᆯ', 1488),
+    ('ᆰ', 1489),
+    ('ᆱ', 1499),
+    ('ᆲ', 1503),
+    ('ᆳ', 1510),
+    ('ᆴ', 1515),
+    ('ᆵ', 1516),
+    ('ᆶ', 1517),
+    ('ᆷ', 1522),
+    ('ᆸ', 1538),
+    ('ᆹ', 1547),
+    ('ᆺ', 1561),
+    ('ᆻ', 1570),
+    ('ᆼ', 1582),
+    ('ᆽ', 1598),
+    ('ᆾ', 1604),
+    ('ᆿ', 1607),
+    ('ᇀ', 1608),
+    ('ᇁ', 1610),
+    ('ᇂ', 1616),
+    ('ᇃ', 1457),
+    ('ᇄ', 1460),
+    ('ᇅ', 1465),
+    ('ᇆ', 1467),
+    ('ᇇ', 1470),
+    ('ᇈ', 1475),
+    ('ᇉ', 1473),
+    ('ᇊ', 1477),
+    ('ᇋ', 1480),
+    ('ᇌ', 1491),
+    ('ᇍ', 1493),
+    ('ᇎ', 1494),
+    ('ᇏ', 1496),
+    ('ᇐ', 1497),
+    ('ᇑ', 1500),
+    ('ᇒ', 1501),
+    ('ᇓ', 1506),
+    ('ᇔ', 1509),
+    ('ᇕ', 1507),
+    ('ᇖ', 1511),
+    ('ᇗ', 1518),
+    ('ᇘ', 1514),
+    ('ᇙ', 1520),
+    ('ᇚ', 1523),
+    ('ᇛ', 1527),
+    ('ᇜ', 1529),
+    ('ᇝ', 1531),
+    ('ᇞ', 1532),
+    ('ᇟ', 1537),
+    ('ᇠ', 1535),
+    ('ᇡ', 1536),
+    ('ᇢ', 1533),
+    ('ᇣ', 1542),
+    ('ᇤ', 1559),
+    ('ᇥ', 1560),
+    ('ᇦ', 1554),
+    ('ᇧ', 1562),
+    ('ᇨ', 1564),
+    ('ᇩ', 1565),
+    ('ᇪ', 1567),
+    ('ᇫ', 1625),
+    ('ᇬ', 1583),
+    ('ᇭ', 1584),
+    ('ᇮ', 1590),
+    ('ᇯ', 1593),
+    ('ᇰ', 1628),
+    ('ᇱ', 1589),
+    ('ᇲ', 1597),
+    ('ᇳ', 1611),
+    ('ᇴ', 1613),
+    ('ᇵ', 1617),
+    ('ᇶ', 1618),
+    ('ᇷ', 1619),
+    ('ᇸ', 1620),
+    ('ᇹ', 1633),
+    ('ᇺ', 1455),
+    ('ᇻ', 1458),
+    ('ᇼ', 1461),
+    ('ᇽ', 1462),
+    ('ᇾ', 1463),
+    ('ᇿ', 1466),
+    ('ሀ', 361),
+    ('ሣ', 761),
+    ('ቀ', 843),
+    ('በ', 884),
+    ('ኔ', 890),
+    ('ዐ', 335),
+    ('Ꭰ', 297),
+    ('Ꭱ', 349),
+    ('Ꭲ', 352),
+    ('Ꭴ', 336),
+    ('Ꭵ', 444),
+    ('Ꭸ', 1995),
+    ('Ꭹ', 380),
+    ('Ꭺ', 283),
+    ('Ꭻ', 315),
+    ('Ꭼ', 303),
+    ('Ꭾ', 280),
+    ('Ꮀ', 1995),
+    ('Ꮁ', 833),
+    ('Ꮃ', 373),
+    ('Ꮇ', 326),
+    ('Ꮋ', 311),
+    ('Ꮍ', 380),
+    ('Ꮎ', 341),
+    ('Ꮏ', 738),
+    ('Ꮐ', 308),
+    ('Ꮒ', 441),
+    ('Ꮓ', 382),
+    ('Ꮗ', 876),
+    ('Ꮛ', 735),
+    ('Ꮜ', 364),
+    ('Ꮞ', 220),
+    ('Ꮟ', 400),
+    ('Ꮢ', 349),
+    ('Ꮤ', 373),
+    ('Ꮥ', 351),
+    ('Ꮩ', 365),
+    ('Ꮪ', 351),
+    ('Ꮮ', 322),
+    ('Ꮯ', 292),
+    ('Ꮲ', 345),
+    ('Ꮶ', 318),
+    ('Ꮷ', 414),
+    ('Ꮻ', 341),
+    ('Ꮾ', 233),
+    ('Ᏸ', 709),
+    ('Ᏺ', 442),
+    ('Ᏻ', 308),
+    ('Ᏼ', 291),
+    ('ᏻ', 760),
+    ('ᏼ', 777),
+    ('᐀', 265),
+    ('ᐃ', 837),
+    ('ᐌ', 627),
+    ('ᐍ', 1710),
+    ('ᐎ', 625),
+    ('ᐏ', 838),
+    ('ᐐ', 628),
+    ('ᐑ', 1712),
+    ('ᐒ', 629),
+    ('ᐓ', 1713),
+    ('ᐔ', 630),
+    ('ᐕ', 1715),
+    ('ᐗ', 631),
+    ('ᐘ', 1716),
+    ('ᐙ', 632),
+    ('ᐚ', 1718),
+    ('ᐧ', 610),
+    ('ᐫ', 1711),
+    ('ᐬ', 840),
+    ('ᐭ', 1714),
+    ('ᐮ', 1717),
+    ('ᐯ', 365),
+    ('ᐱ', 749),
+    ('ᐳ', 275),
+    ('ᐷ', 613),
+    ('ᐸ', 261),
+    ('ᐺ', 618),
+    ('ᐻ', 370),
+    ('ᐼ', 623),
+    ('ᐽ', 750),
+    ('ᐾ', 633),
+    ('ᐿ', 1722),
+    ('ᑀ', 613),
+    ('ᑁ', 279),
+    ('ᑂ', 634),
+    ('ᑃ', 1723),
+    ('ᑄ', 612),
+    ('ᑅ', 264),
+    ('ᑆ', 635),
+    ('ᑇ', 1725),
+    ('ᑊ', 6),
+    ('ᑌ', 361),
+    ('ᑎ', 884),
+    ('ᑔ', 637),
+    ('ᑗ', 617),
+    ('ᑘ', 363),
+    ('ᑙ', 626),
+    ('ᑚ', 886),
+    ('ᑛ', 636),
+    ('ᑜ', 1726),
+    ('ᑝ', 637),
+    ('ᑞ', 1730),
+    ('ᑟ', 638),
+    ('ᑠ', 1732),
+    ('ᑡ', 639),
+    ('ᑢ', 1735),
+    ('ᑣ', 640),
+    ('ᑤ', 1736),
+    ('ᑧ', 362),
+    ('ᑨ', 885),
+    ('ᑩ', 1728),
+    ('ᑪ', 1734),
+    ('ᑭ', 345),
+    ('ᑯ', 414),
+    ('ᑲ', 400),
+    ('ᑳ', 405),
+    ('ᑴ', 641),
+    ('ᑵ', 1738),
+    ('ᑶ', 616),
+    ('ᑷ', 542),
+    ('ᑸ', 642),
+    ('ᑹ', 1739),
+    ('ᑺ', 621),
+    ('ᑻ', 417),
+    ('ᑼ', 643),
+    ('ᑽ', 1740),
+    ('ᑾ', 619),
+    ('ᑿ', 403),
+    ('ᒀ', 620),
+    ('ᒁ', 406),
+    ('ᒅ', 1737),
+    ('ᒆ', 346),
+    ('ᒇ', 415),
+    ('ᒈ', 401),
+    ('ᒍ', 315),
+    ('ᒒ', 644),
+    ('ᒓ', 1742),
+    ('ᒔ', 645),
+    ('ᒕ', 1743),
+    ('ᒖ', 646),
+    ('ᒗ', 1744),
+    ('ᒘ', 614),
+    ('ᒙ', 316),
+    ('ᒚ', 647),
+    ('ᒛ', 1745),
+    ('ᒜ', 648),
+    ('ᒝ', 1747),
+    ('ᒞ', 649),
+    ('ᒟ', 1748),
+    ('ᒥ', 833),
+    ('ᒪ', 322),
+    ('ᒬ', 650),
+    ('ᒭ', 1749),
+    ('ᒮ', 624),
+    ('ᒯ', 835),
+    ('ᒰ', 651),
+    ('ᒱ', 1750),
+    ('ᒲ', 652),
+    ('ᒳ', 1751),
+    ('ᒴ', 653),
+    ('ᒵ', 1752),
+    ('ᒶ', 615),
+    ('ᒷ', 503),
+    ('ᒸ', 654),
+    ('ᒹ', 1753),
+    ('ᒿ', 188),
+    ('ᓉ', 655),
+    ('ᓊ', 1754),
+    ('ᓋ', 660),
+    ('ᓌ', 1759),
+    ('ᓍ', 661),
+    ('ᓎ', 1760),
+    ('ᓑ', 1720),
+    ('ᓜ', 662),
+    ('ᓝ', 1762),
+    ('ᓞ', 663),
+    ('ᓟ', 1763),
+    ('ᓠ', 664),
+    ('ᓡ', 1764),
+    ('ᓢ', 665),
+    ('ᓣ', 1765),
+    ('ᓤ', 666),
+    ('ᓥ', 1766),
+    ('ᓦ', 667),
+    ('ᓧ', 1768),
+    ('ᓨ', 668),
+    ('ᓩ', 1769),
+    ('ᓶ', 669),
+    ('ᓷ', 1770),
+    ('ᓸ', 670),
+    ('ᓹ', 1771),
+    ('ᓺ', 671),
+    ('ᓻ', 1772),
+    ('ᓼ', 672),
+    ('ᓽ', 1773),
+    ('ᓾ', 673),
+    ('ᓿ', 1774),
+    ('ᔀ', 674),
+    ('ᔁ', 1775),
+    ('ᔂ', 675),
+    ('ᔃ', 1776),
+    ('ᔌ', 1777),
+    ('ᔍ', 1779),
+    ('ᔎ', 1778),
+    ('ᔏ', 1780),
+    ('ᔗ', 676),
+    ('ᔘ', 1781),
+    ('ᔙ', 677),
+    ('ᔚ', 1782),
+    ('ᔛ', 678),
+    ('ᔜ', 1783),
+    ('ᔝ', 679),
+    ('ᔞ', 1784),
+    ('ᔟ', 680),
+    ('ᔠ', 1785),
+    ('ᔡ', 681),
+    ('ᔢ', 1786),
+    ('ᔣ', 682),
+    ('ᔤ', 1787),
+    ('ᔯ', 611),
+    ('ᔰ', 223),
+    ('ᔱ', 683),
+    ('ᔲ', 1788),
+    ('ᔳ', 684),
+    ('ᔴ', 1789),
+    ('ᔵ', 685),
+    ('ᔶ', 1790),
+    ('ᔷ', 686),
+    ('ᔸ', 1791),
+    ('ᔹ', 687),
+    ('ᔺ', 1792),
+    ('ᔻ', 688),
+    ('ᔼ', 1793),
+    ('ᕀ', 1721),
+    ('ᕁ', 583),
+    ('ᕎ', 695),
+    ('ᕏ', 1798),
+    ('ᕛ', 696),
+    ('ᕜ', 1808),
+    ('ᕨ', 697),
+    ('ᕩ', 1811),
+    ('ᕷ', 1893),
+    ('ᕼ', 311),
+    ('ᕽ', 583),
+    ('ᕾ', 1804),
+    ('ᕿ', 1799),
+    ('ᖀ', 1805),
+    ('ᖁ', 1802),
+    ('ᖂ', 1806),
+    ('ᖃ', 1800),
+    ('ᖄ', 1801),
+    ('ᖅ', 1807),
+    ('ᖇ', 349),
+    ('ᖎ', 1816),
+    ('ᖏ', 1817),
+    ('ᖐ', 1818),
+    ('ᖑ', 1814),
+    ('ᖒ', 1819),
+    ('ᖓ', 1820),
+    ('ᖔ', 1821),
+    ('ᖯ', 400),
+    ('ᖴ', 305),
+    ('ᖵ', 1900),
+    ('ᖷ', 3137),
+    ('ᗄ', 1993),
+    ('ᗅ', 283),
+    ('ᗞ', 297),
+    ('ᗪ', 297),
+    ('ᗯ', 876),
+    ('ᗰ', 326),
+    ('ᗷ', 291),
+    ('ᘂ', 1746),
+    ('ᘃ', 1741),
+    ('ᘄ', 1761),
+    ('ᘇ', 1767),
+    ('ᘢ', 1794),
+    ('ᘣ', 1796),
+    ('ᘤ', 1797),
+    ('ᘮ', 739),
+    ('ᘯ', 845),
+    ('ᘴ', 739),
+    ('ᘵ', 845),
+    ('᙭', 375),
+    ('᙮', 583),
+    ('ᙯ', 1803),
+    ('ᙰ', 1815),
+    ('ᙱ', 1823),
+    ('ᙲ', 1824),
+    ('ᙳ', 1822),
+    ('ᙴ', 1825),
+    ('ᙵ', 1826),
+    ('ᙶ', 1827),
+    ('ᙷ', 1829),
+    ('ᙸ', 1830),
+    ('ᙹ', 1831),
+    ('ᙺ', 1832),
+    ('ᙻ', 1833),
+    ('ᙼ', 1834),
+    ('ᙽ', 1835),
+    ('\u{1680}', 0),
+    ('ᚲ', 261),
+    ('ᚷ', 375),
+    ('ᛁ', 456),
+    ('ᛂ', 1845),
+    ('ᛌ', 6),
+    ('ᛕ', 318),
+    ('ᛖ', 326),
+    ('ᛘ', 844),
+    ('ᛡ', 1844),
+    ('᛫', 610),
+    ('᛬', 257),
+    ('᛭', 165),
+    ('ᛰ', 843),
+    ('\u{1734}', 1858),
+    ('᜵', 184),
+    ('ឣ', 1859),
+    ('\u{17B7}', 1413),
+    ('\u{17B8}', 1414),
+    ('\u{17B9}', 1415),
+    ('\u{17BA}', 1416),
+    ('\u{17C6}', 803),
+    ('\u{17CB}', 1420),
+    ('\u{17D3}', 803),
+    ('។', 1411),
+    ('៕', 1425),
+    ('៙', 1424),
+    ('៚', 1426),
+    ('᠃', 257),
+    ('᠉', 257),
+    ('ᡕ', 1860),
+    ('ᢖ', 1861),
+    ('ᢳ', 698),
+    ('ᢶ', 699),
+    ('ᢹ', 700),
+    ('ᣂ', 701),
+    ('ᣆ', 656),
+    ('ᣇ', 1755),
+    ('ᣈ', 657),
+    ('ᣉ', 1756),
+    ('ᣊ', 658),
+    ('ᣋ', 1757),
+    ('ᣌ', 659),
+    ('ᣍ', 1758),
+    ('ᣎ', 689),
+    ('ᣏ', 690),
+    ('ᣐ', 691),
+    ('ᣑ', 692),
+    ('ᣒ', 693),
+    ('ᣓ', 694),
+    ('ᣛ', 1867),
+    ('ᣜ', 1864),
+    ('ᣝ', 1719),
+    ('ᣠ', 1795),
+    ('ᣣ', 1809),
+    ('ᣤ', 1810),
+    ('ᣥ', 1812),
+    ('ᣨ', 1813),
+    ('ᣪ', 1828),
+    ('ᣭ', 877),
+    ('ᣰ', 1839),
+    ('ᣲ', 1840),
+    ('᧐', 1868),
+    ('᧑', 1869),
+    ('᪀', 1870),
+    ('᪐', 1870),
+    ('᪩', 1871),
+    ('᪫', 1872),
+    ('\u{1AB4}', 1245),
+    ('\u{1AB7}', 816),
+    ('᭒', 1873),
+    ('᭓', 1874),
+    ('᭘', 1875),
+    ('᭜', 1876),
+    ('᭟', 1877),
+    ('᰼', 1878),
+    ('᱿', 1879),
+    ('\u{1CD0}', 795),
+    ('\u{1CD2}', 797),
+    ('᳓', 7),
+    ('\u{1CD5}', 818),
+    ('\u{1CD8}', 820),
+    ('\u{1CD9}', 819),
+    ('\u{1CDA}', 807),
+    ('\u{1CDC}', 817),
+    ('\u{1CDD}', 812),
+    ('\u{1CDE}', 813),
+    ('\u{1CED}', 811),
+    ('ᴄ', 409),
+    ('ᴈ', 757),
+    ('ᴋ', 722),
+    ('ᴍ', 774),
+    ('ᴏ', 524),
+    ('ᴐ', 754),
+    ('ᴑ', 524),
+    ('ᴔ', 741),
+    ('ᴜ', 571),
+    ('ᴠ', 575),
+    ('ᴡ', 579),
+    ('ᴢ', 590),
+    ('ᴤ', 736),
+    ('ᴦ', 547),
+    ('ᴧ', 773),
+    ('ᴨ', 851),
+    ('ᴩ', 1885),
+    ('ᴫ', 872),
+    ('\u{1D3E}', 1863),
+    ('\u{1D52}', 702),
+    ('ᵫ', 572),
+    ('ᵮ', 437),
+    ('ᵯ', 551),
+    ('ᵰ', 523),
+    ('ᵲ', 555),
+    ('ᵳ', 765),
+    ('ᵴ', 561),
+    ('ᵵ', 569),
+    ('ᵶ', 593),
+    ('\u{1D78}', 1889),
+    ('ᵻ', 451),
+    ('ᵼ', 451),
+    ('ᵽ', 544),
+    ('ᵾ', 574),
+    ('ᵿ', 772),
+    ('ᶃ', 438),
+    ('ᶌ', 587),
+    ('ᶐ', 753),
+    ('\u{1D9F}', 1890),
+    ('\u{1DA2}', 1891),
+    ('\u{1DBA}', 1862),
+    ('\u{1DBB}', 1841),
+    ('\u{1DEE}', 2002),
+    ('ṃ', 3144),
+    ('ẚ', 1894),
+    ('ẝ', 429),
+    ('ẞ', 709),
+    ('ỿ', 587),
+    ('\u{1F7D}', 1895),
+    ('᾽', 6),
+    ('\u{1FBE}', 444),
+    ('᾿', 6),
+    ('῀', 597),
+    ('\u{1FEF}', 6),
+    ('ῶ', 1709),
+    ('\u{1FFD}', 6),
+    ('῾', 6),
+    ('\u{2000}', 0),
+    ('\u{2001}', 0),
+    ('\u{2002}', 0),
+    ('\u{2003}', 0),
+    ('\u{2004}', 0),
+    ('\u{2005}', 0),
+    ('\u{2006}', 0),
+    ('\u{2007}', 0),
+    ('\u{2008}', 0),
+    ('\u{2009}', 0),
+    ('\u{200A}', 0),
+    ('‐', 174),
+    ('‑', 174),
+    ('‒', 174),
+    ('–', 174),
+    ('—', 2006),
+    ('―', 2006),
+    ('‖', 494),
+    ('‘', 6),
+    ('’', 6),
+    ('‚', 173),
+    ('‛', 6),
+    ('“', 7),
+    ('”', 7),
+    ('‟', 7),
+    ('•', 610),
+    ('․', 180),
+    ('‥', 182),
+    ('…', 183),
+    ('‧', 610),
+    ('\u{2028}', 0),
+    ('\u{2029}', 0),
+    ('\u{202F}', 0),
+    ('‰', 704),
+    ('‱', 705),
+    ('′', 6),
+    ('″', 7),
+    ('‴', 8),
+    ('‵', 6),
+    ('‶', 7),
+    ('‷', 8),
+    ('‹', 261),
+    ('›', 275),
+    ('‼', 2),
+    ('‾', 786),
+    ('⁁', 184),
+    ('⁃', 174),
+    ('⁄', 184),
+    ('⁇', 282),
+    ('⁈', 281),
+    ('⁉', 3),
+    ('⁎', 164),
+    ('⁒', 703),
+    ('⁓', 597),
+    ('⁗', 9),
+    ('⁚', 257),
+    ('⁝', 2001),
+    ('⁞', 2000),
+    ('\u{205F}', 0),
+    ('⁰', 702),
+    ('⁹', 3131),
+    ('₡', 296),
+    ('₤', 601),
+    ('₥', 552),
+    ('₨', 350),
+    ('₩', 374),
+    ('₫', 425),
+    ('€', 3134),
+    ('₭', 321),
+    ('₮', 360),
+    ('₶', 501),
+    ('₽', 887),
+    ('\u{20DB}', 1245),
+    ('℀', 391),
+    ('℁', 392),
+    ('ℂ', 292),
+    ('℃', 605),
+    ('℅', 410),
+    ('℆', 411),
+    ('ℇ', 735),
+    ('℈', 868),
+    ('℉', 606),
+    ('ℊ', 438),
+    ('ℋ', 311),
+    ('ℌ', 311),
+    ('ℍ', 311),
+    ('ℎ', 441),
+    ('ℏ', 443),
+    ('ℐ', 456),
+    ('ℑ', 456),
+    ('ℒ', 322),
+    ('ℓ', 456),
+    ('ℕ', 329),
+    ('№', 332),
+    ('ℙ', 345),
+    ('ℚ', 347),
+    ('ℛ', 349),
+    ('ℜ', 349),
+    ('ℝ', 349),
+    ('℡', 354),
+    ('ℤ', 382),
+    ('\u{2126}', 845),
+    ('℧', 739),
+    ('ℨ', 382),
+    ('℩', 766),
+    ('\u{212A}', 318),
+    ('ℬ', 291),
+    ('ℭ', 292),
+    ('℮', 426),
+    ('ℯ', 426),
+    ('ℰ', 303),
+    ('ℱ', 305),
+    ('ℳ', 326),
+    ('ℴ', 524),
+    ('ℵ', 900),
+    ('ℶ', 902),
+    ('ℷ', 903),
+    ('ℸ', 904),
+    ('ℹ', 444),
+    ('℻', 306),
+    ('ℼ', 851),
+    ('ℽ', 587),
+    ('ℾ', 833),
+    ('ℿ', 842),
+    ('⅀', 737),
+    ('⅁', 3122),
+    ('⅂', 3123),
+    ('⅃', 3202),
+    ('ⅅ', 297),
+    ('ⅆ', 414),
+    ('ⅇ', 426),
+    ('ⅈ', 444),
+    ('ⅉ', 452),
+    ('Ⅰ', 456),
+    ('Ⅱ', 494),
+    ('Ⅲ', 496),
+    ('Ⅳ', 491),
+    ('Ⅴ', 365),
+    ('Ⅵ', 367),
+    ('Ⅶ', 368),
+    ('Ⅷ', 369),
+    ('Ⅸ', 492),
+    ('Ⅹ', 375),
+    ('Ⅺ', 376),
+    ('Ⅻ', 377),
+    ('Ⅼ', 322),
+    ('Ⅽ', 292),
+    ('Ⅾ', 297),
+    ('Ⅿ', 326),
+    ('ⅰ', 444),
+    ('ⅱ', 445),
+    ('ⅲ', 446),
+    ('ⅳ', 448),
+    ('ⅴ', 575),
+    ('ⅵ', 576),
+    ('ⅶ', 577),
+    ('ⅷ', 578),
+    ('ⅸ', 449),
+    ('ⅹ', 583),
+    ('ⅺ', 584),
+    ('ⅻ', 585),
+    ('ⅼ', 456),
+    ('ⅽ', 409),
+    ('ⅾ', 414),
+    ('ⅿ', 549),
+    ('Ↄ', 732),
+    ('ↄ', 754),
+    ('↑', 1847),
+    ('↕', 1856),
+    ('↵', 1906),
+    ('↺', 3206),
+    ('↾', 1851),
+    ('↿', 1848),
+    ('∀', 1993),
+    ('∃', 733),
+    ('∆', 837),
+    ('∏', 842),
+    ('∑', 737),
+    ('−', 174),
+    ('∔', 168),
+    ('∕', 184),
+    ('∖', 385),
+    ('∗', 164),
+    ('∘', 604),
+    ('∙', 610),
+    ('∞', 527),
+    ('∣', 456),
+    ('∥', 494),
+    ('∨', 575),
+    ('∩', 884),
+    ('∪', 361),
+    ('∫', 768),
+    ('∬', 769),
+    ('∭', 770),
+    ('∯', 1919),
+    ('∰', 1920),
+    ('∶', 257),
+    ('∸', 176),
+    ('∼', 597),
+    ('≐', 270),
+    ('≑', 271),
+    ('≗', 272),
+    ('≙', 268),
+    ('≚', 269),
+    ('≞', 273),
+    ('≣', 1926),
+    ('≪', 262),
+    ('≫', 277),
+    ('⊂', 1733),
+    ('⊃', 1727),
+    ('⊕', 3161),
+    ('⊖', 341),
+    ('⊙', 776),
+    ('⊝', 341),
+    ('⊤', 352),
+    ('⊥', 3120),
+    ('⋀', 1918),
+    ('⋁', 575),
+    ('⋂', 884),
+    ('⋃', 361),
+    ('⋄', 1852),
+    ('⋅', 610),
+    ('⋈', 1854),
+    ('⋖', 264),
+    ('⋗', 613),
+    ('⋘', 263),
+    ('⋙', 278),
+    ('⋮', 2001),
+    ('⋯', 622),
+    ('⋴', 3135),
+    ('⋿', 303),
+    ('⌀', 1911),
+    ('⌥', 1943),
+    ('\u{2329}', 1975),
+    ('\u{232A}', 1976),
+    ('⍁', 2004),
+    ('⍙', 839),
+    ('⍚', 1853),
+    ('⍜', 608),
+    ('⍟', 1934),
+    ('⍡', 355),
+    ('⍢', 1913),
+    ('⍣', 1939),
+    ('⍤', 607),
+    ('⍥', 933),
+    ('⍨', 599),
+    ('⍩', 1724),
+    ('⍫', 1914),
+    ('⍬', 341),
+    ('⍳', 444),
+    ('⍴', 541),
+    ('⍵', 855),
+    ('⍶', 399),
+    ('⍷', 3136),
+    ('⍸', 450),
+    ('⍹', 856),
+    ('⍺', 390),
+    ('⍿', 1845),
+    ('⎜', 2098),
+    ('⎟', 2098),
+    ('⎢', 2098),
+    ('⎥', 2098),
+    ('⎪', 2098),
+    ('⎮', 2098),
+    ('⏁', 1950),
+    ('⏂', 1949),
+    ('⏃', 1948),
+    ('⏆', 1951),
+    ('⏨', 1896),
+    ('⏼', 1960),
+    ('⏽', 456),
+    ('⏾', 1974),
+    ('⑊', 386),
+    ('①', 1977),
+    ('②', 1978),
+    ('③', 1979),
+    ('④', 1980),
+    ('⑤', 1981),
+    ('⑥', 1982),
+    ('⑦', 1983),
+    ('⑧', 1984),
+    ('⑨', 1985),
+    ('⑩', 1986),
+    ('⑴', 63),
+    ('⑵', 18),
+    ('⑶', 20),
+    ('⑷', 21),
+    ('⑸', 22),
+    ('⑹', 23),
+    ('⑺', 24),
+    ('⑻', 25),
+    ('⑼', 26),
+    ('⑽', 72),
+    ('⑾', 73),
+    ('⑿', 64),
+    ('⒀', 65),
+    ('⒁', 66),
+    ('⒂', 67),
+    ('⒃', 68),
+    ('⒄', 69),
+    ('⒅', 70),
+    ('⒆', 71),
+    ('⒇', 19),
+    ('⒈', 459),
+    ('⒉', 190),
+    ('⒊', 213),
+    ('⒋', 222),
+    ('⒌', 229),
+    ('⒍', 235),
+    ('⒎', 241),
+    ('⒏', 247),
+    ('⒐', 253),
+    ('⒑', 487),
+    ('⒒', 495),
+    ('⒓', 460),
+    ('⒔', 464),
+    ('⒕', 467),
+    ('⒖', 470),
+    ('⒗', 473),
+    ('⒘', 476),
+    ('⒙', 479),
+    ('⒚', 482),
+    ('⒛', 202),
+    ('⒜', 52),
+    ('⒝', 53),
+    ('⒞', 54),
+    ('⒟', 55),
+    ('⒠', 56),
+    ('⒡', 57),
+    ('⒢', 58),
+    ('⒣', 59),
+    ('⒤', 60),
+    ('⒥', 61),
+    ('⒦', 62),
+    ('⒧', 63),
+    ('⒨', 79),
+    ('⒩', 74),
+    ('⒪', 75),
+    ('⒫', 76),
+    ('⒬', 77),
+    ('⒭', 78),
+    ('⒮', 80),
+    ('⒯', 81),
+    ('⒰', 82),
+    ('⒱', 83),
+    ('⒲', 84),
+    ('⒳', 85),
+    ('⒴', 86),
+    ('⒵', 87),
+    ('Ⓒ', 602),
+    ('Ⓟ', 1899),
+    ('Ⓡ', 603),
+    ('ⓛ', 1961),
+    ('⓪', 3205),
+    ('─', 2006),
+    ('━', 2006),
+    ('┃', 1962),
+    ('┏', 1963),
+    ('┣', 1964),
+    ('╱', 184),
+    ('╳', 375),
+    ('█', 1915),
+    ('▐', 1965),
+    ('▔', 786),
+    ('▗', 1966),
+    ('▝', 1967),
+    ('■', 1915),
+    ('▱', 1959),
+    ('△', 837),
+    ('▷', 1938),
+    ('▸', 1970),
+    ('►', 1970),
+    ('▽', 3162),
+    ('◁', 1937),
+    ('◇', 1852),
+    ('◊', 1852),
+    ('○', 604),
+    ('◎', 1945),
+    ('◠', 1941),
+    ('◦', 604),
+    ('☉', 776),
+    ('☐', 1968),
+    ('☥', 3170),
+    ('☰', 1997),
+    ('☸', 1952),
+    ('♎', 1925),
+    ('♢', 1852),
+    ('♩', 3203),
+    ('♪', 3204),
+    ('⚬', 1270),
+    ('❨', 16),
+    ('❩', 162),
+    ('❮', 261),
+    ('❯', 275),
+    ('❲', 16),
+    ('❳', 162),
+    ('❴', 595),
+    ('❵', 596),
+    ('➕', 165),
+    ('➖', 174),
+    ('➗', 711),
+    ('⟂', 3120),
+    ('⟈', 387),
+    ('⟉', 1729),
+    ('⟋', 184),
+    ('⟍', 385),
+    ('⟙', 352),
+    ('⟨', 1975),
+    ('⟩', 1976),
+    ('⤫', 583),
+    ('⤬', 583),
+    ('⥣', 1849),
+    ('⥥', 1908),
+    ('⥮', 1850),
+    ('⥯', 1907),
+    ('⦙', 2000),
+    ('⦰', 1947),
+    ('⦾', 1945),
+    ('⧄', 2004),
+    ('⧅', 1946),
+    ('⧇', 1944),
+    ('⧖', 3163),
+    ('⧙', 1989),
+    ('⧴', 259),
+    ('⧵', 385),
+    ('⧶', 187),
+    ('⧸', 184),
+    ('⧹', 385),
+    ('⨀', 776),
+    ('⨁', 3161),
+    ('⨂', 1933),
+    ('⨃', 1927),
+    ('⨄', 1928),
+    ('⨅', 1931),
+    ('⨆', 1932),
+    ('⨌', 771),
+    ('⨝', 1854),
+    ('⨠', 277),
+    ('⨡', 1851),
+    ('⨢', 169),
+    ('⨣', 166),
+    ('⨤', 167),
+    ('⨥', 170),
+    ('⨦', 171),
+    ('⨧', 172),
+    ('⨩', 178),
+    ('⨪', 179),
+    ('⨯', 583),
+    ('⨰', 586),
+    ('⨽', 1942),
+    ('⨾', 1991),
+    ('⨿', 1916),
+    ('⩪', 598),
+    ('⩮', 274),
+    ('⩴', 258),
+    ('⩵', 266),
+    ('⩶', 267),
+    ('⪥', 276),
+    ('⪪', 1837),
+    ('⪫', 1836),
+    ('⫗', 1731),
+    ('⫻', 186),
+    ('⫽', 185),
+    ('⯬', 1902),
+    ('⯭', 1903),
+    ('⯮', 1904),
+    ('⯯', 1905),
+    ('Ⱨ', 313),
+    ('Ⱪ', 320),
+    ('Ⲅ', 833),
+    ('ⲅ', 547),
+    ('Ⲇ', 837),
+    ('Ⲉ', 3134),
+    ('ⲉ', 3135),
+    ('Ⲏ', 311),
+    ('Ⲓ', 456),
+    ('Ⲕ', 318),
+    ('ⲕ', 722),
+    ('Ⲗ', 847),
+    ('Ⲙ', 326),
+    ('Ⲛ', 329),
+    ('Ⲟ', 335),
+    ('ⲟ', 524),
+    ('Ⲡ', 842),
+    ('Ⲣ', 345),
+    ('ⲣ', 541),
+    ('Ⲥ', 292),
+    ('ⲥ', 409),
+    ('Ⲧ', 352),
+    ('Ⲩ', 380),
+    ('Ⲫ', 843),
+    ('ⲫ', 764),
+    ('Ⲭ', 375),
+    ('ⲭ', 853),
+    ('Ⲯ', 844),
+    ('ⲱ', 855),
+    ('Ⲵ', 264),
+    ('Ⲻ', 174),
+    ('Ⲽ', 866),
+    ('ⲽ', 874),
+    ('Ⳇ', 184),
+    ('Ⳋ', 251),
+    ('Ⳍ', 211),
+    ('ⳍ', 746),
+    ('Ⳑ', 322),
+    ('ⳑ', 782),
+    ('Ⳓ', 233),
+    ('Ⳝ', 860),
+    ('ⳤ', 857),
+    ('⳩', 1971),
+    ('⳹', 386),
+    ('ⴱ', 341),
+    ('ⴷ', 749),
+    ('ⴸ', 365),
+    ('ⴹ', 303),
+    ('ⴺ', 733),
+    ('ⵁ', 342),
+    ('ⵈ', 622),
+    ('ⵉ', 737),
+    ('ⵏ', 456),
+    ('ⵑ', 1),
+    ('ⵔ', 335),
+    ('ⵕ', 347),
+    ('ⵙ', 776),
+    ('ⵝ', 375),
+    ('ⵠ', 837),
+    ('ⵣ', 1857),
+    ('\u{2DE8}', 1892),
+    ('\u{2DEA}', 803),
+    ('\u{2DED}', 831),
+    ('\u{2DEF}', 832),
+    ('\u{2DF6}', 829),
+    ('\u{2DF7}', 830),
+    ('⸚', 177),
+    ('⸞', 598),
+    ('⸟', 600),
+    ('⸦', 1733),
+    ('⸧', 1727),
+    ('⸨', 17),
+    ('⸩', 163),
+    ('⸪', 1922),
+    ('⸫', 1921),
+    ('⸬', 1923),
+    ('⸮', 914),
+    ('⸰', 604),
+    ('⸱', 610),
+    ('⸲', 912),
+    ('⸵', 913),
+    ('⸹', 1893),
+    ('⸽', 2000),
+    ('⸿', 609),
+    ('⹀', 265),
+    ('⺂', 2121),
+    ('⺃', 2120),
+    ('⺅', 2130),
+    ('⺉', 2181),
+    ('⺋', 2021),
+    ('⺎', 2150),
+    ('⺏', 2322),
+    ('⺐', 2321),
+    ('⺒', 2346),
+    ('⺓', 2355),
+    ('⺔', 2374),
+    ('⺖', 2386),
+    ('⺗', 2032),
+    ('⺘', 2423),
+    ('⺙', 2453),
+    ('⺛', 2466),
+    ('⺞', 2530),
+    ('⺟', 2537),
+    ('⺠', 2541),
+    ('⺡', 2544),
+    ('⺢', 2545),
+    ('⺣', 2590),
+    ('⺤', 2608),
+    ('⺦', 2113),
+    ('⺨', 2621),
+    ('⺫', 2762),
+    ('⺭', 2696),
+    ('⺯', 2742),
+    ('⺱', 2763),
+    ('⺲', 2762),
+    ('⺹', 2774),
+    ('⺺', 2785),
+    ('⺾', 2810),
+    ('⺿', 2810),
+    ('⻀', 2810),
+    ('⻁', 2852),
+    ('⻂', 2874),
+    ('⻃', 2887),
+    ('⻄', 2886),
+    ('⻅', 2891),
+    ('⻈', 2910),
+    ('⻉', 2925),
+    ('⻋', 2946),
+    ('⻌', 2951),
+    ('⻍', 2951),
+    ('⻏', 2993),
+    ('⻐', 2983),
+    ('⻑', 2984),
+    ('⻒', 2985),
+    ('⻓', 2986),
+    ('⻔', 2991),
+    ('⻖', 2993),
+    ('⻘', 3015),
+    ('⻙', 3023),
+    ('⻚', 3033),
+    ('⻛', 3035),
+    ('⻜', 3037),
+    ('⻝', 3038),
+    ('⻟', 3039),
+    ('⻠', 3045),
+    ('⻢', 3054),
+    ('⻤', 3062),
+    ('⻥', 3067),
+    ('⻨', 3080),
+    ('⻩', 3083),
+    ('⻫', 2459),
+    ('⻬', 3099),
+    ('⻭', 2526),
+    ('⻮', 3101),
+    ('⻯', 2723),
+    ('⻰', 3104),
+    ('⻲', 2122),
+    ('⻳', 3106),
+    ('⼀', 2006),
+    ('⼁', 2098),
+    ('⼂', 385),
+    ('⼃', 184),
+    ('⼄', 2119),
+    ('⼅', 2124),
+    ('⼆', 2126),
+    ('⼇', 2127),
+    ('⼈', 2129),
+    ('⼉', 2149),
+    ('⼊', 2155),
+    ('⼋', 2159),
+    ('⼌', 2163),
+    ('⼍', 2167),
+    ('⼎', 2170),
+    ('⼏', 2178),
+    ('⼐', 2179),
+    ('⼑', 2180),
+    ('⼒', 2192),
+    ('⼓', 2201),
+    ('⼔', 2205),
+    ('⼕', 2207),
+    ('⼖', 2208),
+    ('⼗', 2210),
+    ('⼘', 2218),
+    ('⼙', 2219),
+    ('⼚', 2224),
+    ('⼛', 2225),
+    ('⼜', 2227),
+    ('⼝', 2230),
+    ('⼞', 2230),
+    ('⼟', 2265),
+    ('⼠', 2265),
+    ('⼡', 2285),
+    ('⼢', 2287),
+    ('⼣', 2288),
+    ('⼤', 2291),
+    ('⼥', 2297),
+    ('⼦', 2309),
+    ('⼧', 2310),
+    ('⼨', 2317),
+    ('⼩', 2320),
+    ('⼪', 2321),
+    ('⼫', 2323),
+    ('⼬', 2329),
+    ('⼭', 2330),
+    ('⼮', 2341),
+    ('⼯', 2344),
+    ('⼰', 2345),
+    ('⼱', 2348),
+    ('⼲', 2353),
+    ('⼳', 2355),
+    ('⼴', 2356),
+    ('⼵', 2367),
+    ('⼶', 2368),
+    ('⼷', 2370),
+    ('⼸', 2371),
+    ('⼹', 2373),
+    ('⼺', 2376),
+    ('⼻', 2380),
+    ('⼼', 2385),
+    ('⼽', 2416),
+    ('⼾', 2421),
+    ('⼿', 2422),
+    ('⽀', 2451),
+    ('⽁', 2452),
+    ('⽂', 2458),
+    ('⽃', 2460),
+    ('⽄', 2462),
+    ('⽅', 2463),
+    ('⽆', 2465),
+    ('⽇', 2469),
+    ('⽈', 2479),
+    ('⽉', 2483),
+    ('⽊', 2493),
+    ('⽋', 2522),
+    ('⽌', 2525),
+    ('⽍', 2529),
+    ('⽎', 2533),
+    ('⽏', 2536),
+    ('⽐', 2538),
+    ('⽑', 2539),
+    ('⽒', 2540),
+    ('⽓', 2542),
+    ('⽔', 2543),
+    ('⽕', 2589),
+    ('⽖', 2607),
+    ('⽗', 2610),
+    ('⽘', 2611),
+    ('⽙', 2612),
+    ('⽚', 2613),
+    ('⽛', 2615),
+    ('⽜', 2616),
+    ('⽝', 2620),
+    ('⽞', 2628),
+    ('⽟', 2630),
+    ('⽠', 2646),
+    ('⽡', 2647),
+    ('⽢', 2649),
+    ('⽣', 2650),
+    ('⽤', 2652),
+    ('⽥', 2653),
+    ('⽦', 2659),
+    ('⽧', 2660),
+    ('⽨', 2667),
+    ('⽩', 2668),
+    ('⽪', 2669),
+    ('⽫', 2670),
+    ('⽬', 2674),
+    ('⽭', 2683),
+    ('⽮', 2684),
+    ('⽯', 2685),
+    ('⽰', 2695),
+    ('⽱', 2711),
+    ('⽲', 2712),
+    ('⽳', 2719),
+    ('⽴', 2722),
+    ('⽵', 2725),
+    ('⽶', 2732),
+    ('⽷', 2741),
+    ('⽸', 2759),
+    ('⽹', 2761),
+    ('⽺', 2768),
+    ('⽻', 2771),
+    ('⽼', 2773),
+    ('⽽', 2776),
+    ('⽾', 2777),
+    ('⽿', 2778),
+    ('⾀', 2784),
+    ('⾁', 2786),
+    ('⾂', 2794),
+    ('⾃', 2796),
+    ('⾄', 2798),
+    ('⾅', 2799),
+    ('⾆', 2802),
+    ('⾇', 2804),
+    ('⾈', 2805),
+    ('⾉', 2806),
+    ('⾊', 2808),
+    ('⾋', 2809),
+    ('⾌', 2851),
+    ('⾍', 2857),
+    ('⾎', 2870),
+    ('⾏', 2871),
+    ('⾐', 2873),
+    ('⾑', 2885),
+    ('⾒', 2889),
+    ('⾓', 2892),
+    ('⾔', 2893),
+    ('⾕', 2911),
+    ('⾖', 2912),
+    ('⾗', 2914),
+    ('⾘', 2916),
+    ('⾙', 2917),
+    ('⾚', 2926),
+    ('⾛', 2927),
+    ('⾜', 2930),
+    ('⾝', 2937),
+    ('⾞', 2938),
+    ('⾟', 2947),
+    ('⾠', 2949),
+    ('⾡', 2950),
+    ('⾢', 2957),
+    ('⾣', 2964),
+    ('⾤', 2968),
+    ('⾥', 2969),
+    ('⾦', 2971),
+    ('⾧', 2984),
+    ('⾨', 2987),
+    ('⾩', 2992),
+    ('⾪', 3002),
+    ('⾫', 3004),
+    ('⾬', 3008),
+    ('⾭', 3014),
+    ('⾮', 3017),
+    ('⾯', 3018),
+    ('⾰', 3019),
+    ('⾱', 3020),
+    ('⾲', 3024),
+    ('⾳', 3025),
+    ('⾴', 3027),
+    ('⾵', 3034),
+    ('⾶', 3036),
+    ('⾷', 3038),
+    ('⾸', 3046),
+    ('⾹', 3047),
+    ('⾺', 3049),
+    ('⾻', 3055),
+    ('⾼', 3056),
+    ('⾽', 3057),
+    ('⾾', 3059),
+    ('⾿', 3060),
+    ('⿀', 3061),
+    ('⿁', 3062),
+    ('⿂', 3063),
+    ('⿃', 3068),
+    ('⿄', 3075),
+    ('⿅', 3076),
+    ('⿆', 3079),
+    ('⿇', 3081),
+    ('⿈', 3082),
+    ('⿉', 3084),
+    ('⿊', 3086),
+    ('⿋', 3087),
+    ('⿌', 3088),
+    ('⿍', 3091),
+    ('⿎', 3093),
+    ('⿏', 3095),
+    ('⿐', 3096),
+    ('⿑', 3098),
+    ('⿒', 3100),
+    ('⿓', 3102),
+    ('⿔', 3105),
+    ('⿕', 3107),
+    ('。', 792),
+    ('〃', 7),
+    ('〇', 335),
+    ('〈', 1975),
+    ('〉', 1976),
+    ('〒', 1897),
+    ('〔', 16),
+    ('〕', 162),
+    ('〚', 1987),
+    ('〛', 1988),
+    ('\u{302C}', 802),
+    ('\u{302D}', 814),
+    ('\u{3033}', 184),
+    ('〶', 1897),
+    ('〸', 2210),
+    ('〹', 2211),
+    ('〺', 2212),
+    ('く', 1975),
+    ('\u{309A}', 803),
+    ('゛', 3159),
+    ('゜', 3160),
+    ('゠', 265),
+    ('イ', 2130),
+    ('エ', 2344),
+    ('カ', 2192),
+    ('タ', 2288),
+    ('ト', 2218),
+    ('ニ', 2126),
+    ('ノ', 184),
+    ('ハ', 2159),
+    ('ヘ', 2005),
+    ('ロ', 2230),
+    ('・', 610),
+    ('ㄦ', 2149),
+    ('ㄱ', 1453),
+    ('ㄲ', 1454),
+    ('ㄳ', 1459),
+    ('ㄴ', 1464),
+    ('ㄵ', 1471),
+    ('ㄶ', 1474),
+    ('ㄷ', 1476),
+    ('ㄸ', 1478),
+    ('ㄹ', 1488),
+    ('ㄺ', 1489),
+    ('ㄻ', 1499),
+    ('ㄼ', 1503),
+    ('ㄽ', 1510),
+    ('ㄾ', 1515),
+    ('ㄿ', 1516),
+    ('ㅀ', 1517),
+    ('ㅁ', 1522),
+    ('ㅂ', 1538),
+    ('ㅃ', 1545),
+    ('ㅄ', 1547),
+    ('ㅅ', 1561),
+    ('ㅆ', 1570),
+    ('ㅇ', 1582),
+    ('ㅈ', 1598),
+    ('ㅉ', 1602),
+    ('ㅊ', 1604),
+    ('ㅋ', 1607),
+    ('ㅌ', 1608),
+    ('ㅍ', 1610),
+    ('ㅎ', 1616),
+    ('ㅏ', 1636),
+    ('ㅐ', 1640),
+    ('ㅑ', 1641),
+    ('ㅒ', 1645),
+    ('ㅓ', 1646),
+    ('ㅔ', 1650),
+    ('ㅕ', 1651),
+    ('ㅖ', 1655),
+    ('ㅗ', 1656),
+    ('ㅘ', 1657),
+    ('ㅙ', 1658),
+    ('ㅚ', 1668),
+    ('ㅛ', 1669),
+    ('ㅜ', 1677),
+    ('ㅝ', 1680),
+    ('ㅞ', 1682),
+    ('ㅟ', 1686),
+    ('ㅠ', 1688),
+    ('ㅡ', 2006),
+    ('ㅢ', 2013),
+    ('ㅣ', 2098),
+    ('ㅤ', 1635),
+    ('ㅥ', 1466),
+    ('ㅦ', 1467),
+    ('ㅧ', 1470),
+    ('ㅨ', 1475),
+    ('ㅩ', 1491),
+    ('ㅪ', 1494),
+    ('ㅫ', 1506),
+    ('ㅬ', 1518),
+    ('ㅭ', 1520),
+    ('ㅮ', 1529),
+    ('ㅯ', 1531),
+    ('ㅰ', 1537),
+    ('ㅱ', 1533),
+    ('ㅲ', 1539),
+    ('ㅳ', 1541),
+    ('ㅴ', 1548),
+    ('ㅵ', 1549),
+    ('ㅶ', 1555),
+    ('ㅷ', 1558),
+    ('ㅸ', 1554),
+    ('ㅹ', 1546),
+    ('ㅺ', 1562),
+    ('ㅻ', 1563),
+    ('ㅼ', 1564),
+    ('ㅽ', 1567),
+    ('ㅾ', 1576),
+    ('ㅿ', 1625),
+    ('ㆀ', 1590),
+    ('ㆁ', 1628),
+    ('ㆂ', 1589),
+    ('ㆃ', 1597),
+    ('ㆄ', 1613),
+    ('ㆅ', 1622),
+    ('ㆆ', 1633),
+    ('ㆇ', 1672),
+    ('ㆈ', 1673),
+    ('ㆉ', 1676),
+    ('ㆊ', 1693),
+    ('ㆋ', 1694),
+    ('ㆌ', 1697),
+    ('ㆍ', 1698),
+    ('ㆎ', 1704),
+    ('㇐', 2006),
+    ('㇑', 2098),
+    ('㇓', 184),
+    ('㇔', 385),
+    ('㇖', 2121),
+    ('㇚', 2124),
+    ('㇛', 1975),
+    ('㇟', 2120),
+    ('㇠', 2119),
+    ('㈀', 88),
+    ('㈁', 89),
+    ('㈂', 90),
+    ('㈃', 91),
+    ('㈄', 92),
+    ('㈅', 93),
+    ('㈆', 94),
+    ('㈇', 95),
+    ('㈈', 96),
+    ('㈉', 97),
+    ('㈊', 98),
+    ('㈋', 99),
+    ('㈌', 100),
+    ('㈍', 101),
+    ('㈎', 145),
+    ('㈏', 146),
+    ('㈐', 147),
+    ('㈑', 148),
+    ('㈒', 149),
+    ('㈓', 150),
+    ('㈔', 151),
+    ('㈕', 152),
+    ('㈖', 155),
+    ('㈗', 157),
+    ('㈘', 158),
+    ('㈙', 159),
+    ('㈚', 160),
+    ('㈛', 161),
+    ('㈜', 156),
+    ('㈝', 153),
+    ('㈞', 154),
+    ('㈠', 102),
+    ('㈡', 106),
+    ('㈢', 104),
+    ('㈣', 119),
+    ('㈤', 107),
+    ('㈥', 112),
+    ('㈦', 103),
+    ('㈧', 111),
+    ('㈨', 105),
+    ('㈩', 115),
+    ('㈪', 126),
+    ('㈫', 132),
+    ('㈬', 131),
+    ('㈭', 128),
+    ('㈮', 144),
+    ('㈯', 120),
+    ('㈰', 125),
+    ('㈱', 130),
+    ('㈲', 127),
+    ('㈳', 137),
+    ('㈴', 117),
+    ('㈵', 134),
+    ('㈶', 142),
+    ('㈷', 138),
+    ('㈸', 113),
+    ('㈹', 108),
+    ('㈺', 118),
+    ('㈻', 121),
+    ('㈼', 136),
+    ('㈽', 109),
+    ('㈾', 143),
+    ('㈿', 116),
+    ('㉀', 139),
+    ('㉁', 110),
+    ('㉂', 140),
+    ('㉃', 141),
+    ('㋀', 517),
+    ('㋁', 209),
+    ('㋂', 218),
+    ('㋃', 225),
+    ('㋄', 231),
+    ('㋅', 237),
+    ('㋆', 243),
+    ('㋇', 249),
+    ('㋈', 255),
+    ('㋉', 489),
+    ('㋊', 498),
+    ('㋋', 462),
+    ('㍘', 344),
+    ('㍙', 518),
+    ('㍚', 210),
+    ('㍛', 219),
+    ('㍜', 226),
+    ('㍝', 232),
+    ('㍞', 238),
+    ('㍟', 244),
+    ('㍠', 250),
+    ('㍡', 256),
+    ('㍢', 490),
+    ('㍣', 499),
+    ('㍤', 463),
+    ('㍥', 466),
+    ('㍦', 469),
+    ('㍧', 472),
+    ('㍨', 475),
+    ('㍩', 478),
+    ('㍪', 481),
+    ('㍫', 484),
+    ('㍬', 204),
+    ('㍭', 206),
+    ('㍮', 192),
+    ('㍯', 194),
+    ('㍰', 196),
+    ('㏠', 516),
+    ('㏡', 208),
+    ('㏢', 217),
+    ('㏣', 224),
+    ('㏤', 230),
+    ('㏥', 236),
+    ('㏦', 242),
+    ('㏧', 248),
+    ('㏨', 254),
+    ('㏩', 488),
+    ('㏪', 497),
+    ('㏫', 461),
+    ('㏬', 465),
+    ('㏭', 468),
+    ('㏮', 471),
+    ('㏯', 474),
+    ('㏰', 477),
+    ('㏱', 480),
+    ('㏲', 483),
+    ('㏳', 203),
+    ('㏴', 205),
+    ('㏵', 191),
+    ('㏶', 193),
+    ('㏷', 195),
+    ('㏸', 197),
+    ('㏹', 198),
+    ('㏺', 199),
+    ('㏻', 200),
+    ('㏼', 201),
+    ('㏽', 214),
+    ('㏾', 215),
+    ('㦳', 2023),
+    ('䎛', 2022),
+    ('䐠', 2044),
+    ('一', 2006),
+    ('丶', 385),
+    ('丿', 184),
+    ('倂', 2135),
+    ('值', 2142),
+    ('啟', 2246),
+    ('囗', 2230),
+    ('填', 2275),
+    ('士', 2265),
+    ('壿', 2277),
+    ('嬀', 2304),
+    ('帲', 2349),
+    ('幐', 2043),
+    ('戸', 2421),
+    ('搉', 2036),
+    ('晣', 2059),
+    ('晩', 2472),
+    ('曶', 2038),
+    ('朦', 2073),
+    ('柿', 2498),
+    ('槩', 2047),
+    ('樧', 2513),
+    ('潙', 2571),
+    ('硏', 2686),
+    ('絶', 2747),
+    ('肦', 2484),
+    ('胊', 2486),
+    ('胐', 2485),
+    ('胶', 2042),
+    ('脁', 2487),
+    ('脧', 2489),
+    ('腁', 2790),
+    ('膧', 2492),
+    ('蔿', 2837),
+    ('虁', 2849),
+    ('訞', 2082),
+    ('詽', 2894),
+    ('讏', 2908),
+    ('豣', 2915),
+    ('趆', 2929),
+    ('跺', 2933),
+    ('躛', 2936),
+    ('輧', 2940),
+    ('郞', 2959),
+    ('鎮', 2980),
+    ('隸', 3003),
+    ('鹃', 3074),
+    ('黒', 3086),
+    ('鿃', 2058),
+    ('꒔', 3114),
+    ('꒜', 3110),
+    ('꒞', 3108),
+    ('꒧', 3119),
+    ('꒨', 3111),
+    ('꒬', 3109),
+    ('꒰', 3118),
+    ('꒺', 3117),
+    ('꒾', 3113),
+    ('꒿', 3112),
+    ('꓀', 3115),
+    ('꓂', 3116),
+    ('ꓐ', 291),
+    ('ꓑ', 345),
+    ('ꓒ', 414),
+    ('ꓓ', 297),
+    ('ꓔ', 352),
+    ('ꓖ', 308),
+    ('ꓗ', 318),
+    ('ꓙ', 315),
+    ('ꓚ', 292),
+    ('ꓛ', 732),
+    ('ꓜ', 382),
+    ('ꓝ', 305),
+    ('ꓞ', 1900),
+    ('ꓟ', 326),
+    ('ꓠ', 329),
+    ('ꓡ', 322),
+    ('ꓢ', 351),
+    ('ꓣ', 349),
+    ('ꓥ', 749),
+    ('ꓦ', 365),
+    ('ꓧ', 311),
+    ('ꓪ', 373),
+    ('ꓫ', 375),
+    ('ꓬ', 380),
+    ('ꓭ', 1842),
+    ('ꓮ', 283),
+    ('ꓯ', 1993),
+    ('ꓰ', 303),
+    ('ꓱ', 733),
+    ('ꓲ', 456),
+    ('ꓳ', 335),
+    ('ꓴ', 361),
+    ('ꓵ', 884),
+    ('ꓷ', 1838),
+    ('\u{A4F8}', 180),
+    ('\u{A4F9}', 173),
+    ('\u{A4FA}', 182),
+    ('\u{A4FB}', 181),
+    ('\u{A4FD}', 257),
+    ('꓾', 175),
+    ('꓿', 265),
+    ('꘎', 180),
+    ('Ꙅ', 188),
+    ('ꙅ', 736),
+    ('ꙇ', 444),
+    ('ꙍ', 855),
+    ('Ꙑ', 867),
+    ('ꙑ', 788),
+    ('Ꙩ', 776),
+    ('\u{A66F}', 1898),
+    ('\u{A67C}', 798),
+    ('꙾', 785),
+    ('ꚕ', 442),
+    ('Ꚙ', 340),
+    ('ꚙ', 527),
+    ('Ꚛ', 3161),
+    ('ꚡ', 865),
+    ('ꚰ', 1843),
+    ('ꚱ', 1995),
+    ('ꛍ', 783),
+    ('ꛎ', 749),
+    ('ꛛ', 842),
+    ('ꛟ', 365),
+    ('ꛫ', 280),
+    ('ꛯ', 188),
+    ('\u{A6F0}', 795),
+    ('\u{A6F1}', 797),
+    ('꛴', 3127),
+    ('꜔', 791),
+    ('꜖', 790),
+    ('Ꜩ', 353),
+    ('ꜩ', 565),
+    ('ꜱ', 557),
+    ('Ꜳ', 284),
+    ('ꜳ', 393),
+    ('Ꜵ', 286),
+    ('ꜵ', 395),
+    ('Ꜷ', 288),
+    ('ꜷ', 396),
+    ('Ꜹ', 289),
+    ('ꜹ', 397),
+    ('Ꜻ', 289),
+    ('ꜻ', 397),
+    ('Ꜽ', 290),
+    ('ꜽ', 398),
+    ('Ꝁ', 321),
+    ('Ꝋ', 341),
+    ('ꝋ', 530),
+    ('Ꝏ', 340),
+    ('ꝏ', 527),
+    ('Ꝛ', 188),
+    ('ꝡ', 581),
+    ('Ꝫ', 211),
+    ('ꝫ', 746),
+    ('Ꝯ', 251),
+    ('ꝷ', 563),
+    ('ꝸ', 5),
+    ('ꝺ', 3132),
+    ('꞉', 257),
+    ('ꞌ', 6),
+    ('ꞏ', 610),
+    ('ꞕ', 3128),
+    ('Ꞙ', 305),
+    ('ꞙ', 429),
+    ('Ꞛ', 3167),
+    ('ꞛ', 3168),
+    ('ꞝ', 778),
+    ('Ꞟ', 3121),
+    ('ꞟ', 571),
+    ('Ɜ', 211),
+    ('Ʇ', 3120),
+    ('Ʝ', 315),
+    ('Ꭓ', 375),
+    ('Ꞵ', 291),
+    ('ꞵ', 709),
+    ('Ꞷ', 3124),
+    ('ꞷ', 855),
+    ('Ꟗ', 709),
+    ('\u{A7DA}', 749),
+    ('\u{A7DB}', 847),
+    ('\u{A7DC}', 752),
+    ('ꟷ', 2006),
+    ('꠰', 1264),
+    ('ꥠ', 1481),
+    ('ꥡ', 1482),
+    ('ꥢ', 1483),
+    ('ꥣ', 1485),
+    ('ꥤ', 1489),
+    ('ꥥ', 1490),
+    ('ꥦ', 1494),
+    ('ꥧ', 1495),
+    ('ꥨ', 1499),
+    ('ꥩ', 1503),
+    ('ꥪ', 1505),
+    ('ꥫ', 1507),
+    ('ꥬ', 1510),
+    ('ꥭ', 1513),
+    ('ꥮ', 1514),
+    ('ꥯ', 1523),
+    ('ꥰ', 1526),
+    ('ꥱ', 1531),
+    ('ꥲ', 1553),
+    ('ꥳ', 1557),
+    ('ꥴ', 1560),
+    ('ꥵ', 1573),
+    ('ꥶ', 1586),
+    ('ꥷ', 1596),
+    ('ꥸ', 1603),
+    ('ꥹ', 1609),
+    ('ꥺ', 1615),
+    ('ꥻ', 1621),
+    ('ꥼ', 1634),
+    ('ꦒ', 1992),
+    ('ꦣ', 3140),
+    ('꧆', 3141),
+    ('\u{A9CF}', 1214),
+    ('꩓', 3142),
+    ('꩖', 3143),
+    ('ꬲ', 426),
+    ('ꬵ', 429),
+    ('ꬽ', 524),
+    ('ꬾ', 531),
+    ('ꬿ', 756),
+    ('ꭁ', 743),
+    ('ꭂ', 742),
+    ('ꭇ', 547),
+    ('ꭈ', 547),
+    ('ꭍ', 768),
+    ('ꭎ', 571),
+    ('ꭒ', 571),
+    ('ꭓ', 853),
+    ('ꭕ', 853),
+    ('ꭚ', 587),
+    ('ꭠ', 875),
+    ('ꭢ', 755),
+    ('ꭣ', 573),
+    ('ꭰ', 1881),
+    ('ꭱ', 767),
+    ('ꭲ', 1887),
+    ('ꭴ', 529),
+    ('ꭵ', 444),
+    ('ꭺ', 1880),
+    ('ꭻ', 1883),
+    ('ꭼ', 1882),
+    ('ꭾ', 748),
+    ('ꮀ', 1996),
+    ('ꮁ', 547),
+    ('ꮃ', 579),
+    ('ꮇ', 774),
+    ('ꮋ', 779),
+    ('ꮎ', 530),
+    ('ꮐ', 760),
+    ('ꮓ', 590),
+    ('ꮛ', 3135),
+    ('ꮜ', 574),
+    ('ꮟ', 730),
+    ('ꮢ', 767),
+    ('ꮩ', 575),
+    ('ꮪ', 557),
+    ('ꮮ', 782),
+    ('ꮯ', 409),
+    ('ꮲ', 1885),
+    ('ꮶ', 722),
+    ('ꮻ', 530),
+    ('ힰ', 1663),
+    ('ힱ', 1666),
+    ('ힲ', 1670),
+    ('ힳ', 1671),
+    ('ힴ', 1674),
+    ('ힵ', 1683),
+    ('ힶ', 1687),
+    ('ힷ', 1690),
+    ('ힸ', 1695),
+    ('ힹ', 2007),
+    ('ힺ', 2008),
+    ('ힻ', 2009),
+    ('ힼ', 2010),
+    ('ힽ', 2101),
+    ('ힾ', 2102),
+    ('ힿ', 2103),
+    ('ퟀ', 2104),
+    ('ퟁ', 2106),
+    ('ퟂ', 2107),
+    ('ퟃ', 2109),
+    ('ퟄ', 2112),
+    ('ퟅ', 1699),
+    ('ퟆ', 1701),
+    ('ퟋ', 1468),
+    ('ퟌ', 1472),
+    ('ퟍ', 1478),
+    ('ퟎ', 1479),
+    ('ퟏ', 1482),
+    ('ퟐ', 1483),
+    ('ퟑ', 1484),
+    ('ퟒ', 1485),
+    ('ퟓ', 1486),
+    ('ퟔ', 1487),
+    ('ퟕ', 1490),
+    ('ퟖ', 1492),
+    ('ퟗ', 1498),
+    ('ퟘ', 1502),
+    ('ퟙ', 1504),
+    ('ퟚ', 1508),
+    ('ퟛ', 1519),
+    ('ퟜ', 1521),
+    ('ퟝ', 1512),
+    ('ퟞ', 1524),
+    ('ퟟ', 1525),
+    ('ퟠ', 1528),
+    ('ퟡ', 1530),
+    ('ퟢ', 1534),
+    ('ퟣ', 1541),
+    ('ퟤ', 1543),
+    ('ퟥ', 1544),
+    ('ퟦ', 1545),
+    ('ퟧ', 1549),
+    ('ퟨ', 1555),
+    ('ퟩ', 1556),
+    ('ퟪ', 1566),
+    ('ퟫ', 1569),
+    ('ퟬ', 1571),
+    ('ퟭ', 1572),
+    ('ퟮ', 1581),
+    ('ퟯ', 1576),
+    ('ퟰ', 1577),
+    ('ퟱ', 1579),
+    ('ퟲ', 1517),
+    ('ퟳ', 1626),
+    ('ퟴ', 1627),
+    ('ퟵ', 1629),
+    ('ퟶ', 1630),
+    ('ퟷ', 1599),
+    ('ퟸ', 1600),
+    ('ퟹ', 1602),
+    ('ퟺ', 1612),
+    ('ퟻ', 1614),
+    ('\u{F900}', 2913),
+    ('\u{F901}', 2480),
+    ('\u{F902}', 2938),
+    ('\u{F903}', 2921),
+    ('\u{F904}', 2576),
+    ('\u{F905}', 2114),
+    ('\u{F906}', 2231),
+    ('\u{F907}', 3105),
+    ('\u{F908}', 3105),
+    ('\u{F909}', 2294),
+    ('\u{F90A}', 2971),
+    ('\u{F90B}', 2250),
+    ('\u{F90C}', 2293),
+    ('\u{F90D}', 2414),
+    ('\u{F90E}', 2666),
+    ('\u{F90F}', 2767),
+    ('\u{F910}', 2850),
+    ('\u{F911}', 2866),
+    ('\u{F912}', 2880),
+    ('\u{F913}', 2956),
+    ('\u{F914}', 2516),
+    ('\u{F915}', 2554),
+    ('\u{F916}', 2597),
+    ('\u{F917}', 2634),
+    ('\u{F918}', 2834),
+    ('\u{F919}', 2965),
+    ('\u{F91A}', 3051),
+    ('\u{F91B}', 2123),
+    ('\u{F91C}', 2221),
+    ('\u{F91D}', 2521),
+    ('\u{F91E}', 2605),
+    ('\u{F91F}', 2848),
+    ('\u{F920}', 3073),
+    ('\u{F921}', 2335),
+    ('\u{F922}', 2583),
+    ('\u{F923}', 2844),
+    ('\u{F924}', 2884),
+    ('\u{F925}', 2426),
+    ('\u{F926}', 2793),
+    ('\u{F927}', 2869),
+    ('\u{F928}', 2362),
+    ('\u{F929}', 2488),
+    ('\u{F92A}', 2560),
+    ('\u{F92B}', 2624),
+    ('\u{F92C}', 2959),
+    ('\u{F92D}', 2137),
+    ('\u{F92E}', 2173),
+    ('\u{F92F}', 2198),
+    ('\u{F930}', 2450),
+    ('\u{F931}', 2519),
+    ('\u{F932}', 2604),
+    ('\u{F933}', 2673),
+    ('\u{F934}', 2773),
+    ('\u{F935}', 2846),
+    ('\u{F936}', 2854),
+    ('\u{F937}', 2934),
+    ('\u{F938}', 3012),
+    ('\u{F939}', 3064),
+    ('\u{F93A}', 3072),
+    ('\u{F93B}', 2689),
+    ('\u{F93C}', 2706),
+    ('\u{F93D}', 2750),
+    ('\u{F93E}', 2827),
+    ('\u{F93F}', 2978),
+    ('\u{F940}', 3076),
+    ('\u{F941}', 2900),
+    ('\u{F942}', 2281),
+    ('\u{F943}', 2369),
+    ('\u{F944}', 2731),
+    ('\u{F945}', 2783),
+    ('\u{F946}', 2617),
+    ('\u{F947}', 2691),
+    ('\u{F948}', 2920),
+    ('\u{F949}', 3010),
+    ('\u{F94A}', 2280),
+    ('\u{F94B}', 2326),
+    ('\u{F94C}', 2517),
+    ('\u{F94D}', 2565),
+    ('\u{F94E}', 2578),
+    ('\u{F94F}', 2746),
+    ('\u{F950}', 2756),
+    ('\u{F951}', 2995),
+    ('\u{F952}', 2197),
+    ('\u{F953}', 2787),
+    ('\u{F954}', 2176),
+    ('\u{F955}', 2175),
+    ('\u{F956}', 2715),
+    ('\u{F957}', 2751),
+    ('\u{F958}', 2833),
+    ('\u{F959}', 2997),
+    ('\u{F95A}', 2907),
+    ('\u{F95B}', 2427),
+    ('\u{F95C}', 2516),
+    ('\u{F95D}', 2903),
+    ('\u{F95E}', 2116),
+    ('\u{F95F}', 2314),
+    ('\u{F960}', 2391),
+    ('\u{F961}', 2629),
+    ('\u{F962}', 2658),
+    ('\u{F963}', 2206),
+    ('\u{F964}', 2693),
+    ('\u{F965}', 2141),
+    ('\u{F966}', 2383),
+    ('\u{F967}', 2096),
+    ('\u{F968}', 2550),
+    ('\u{F969}', 2457),
+    ('\u{F96A}', 2745),
+    ('\u{F96B}', 2226),
+    ('\u{F96C}', 2274),
+    ('\u{F96D}', 2676),
+    ('\u{F96E}', 2835),
+    ('\u{F96F}', 2896),
+    ('\u{F970}', 2534),
+    ('\u{F971}', 2949),
+    ('\u{F972}', 2548),
+    ('\u{F973}', 2431),
+    ('\u{F974}', 2817),
+    ('\u{F975}', 2437),
+    ('\u{F976}', 2657),
+    ('\u{F977}', 2128),
+    ('\u{F978}', 2158),
+    ('\u{F979}', 2174),
+    ('\u{F97A}', 2507),
+    ('\u{F97B}', 2739),
+    ('\u{F97C}', 2807),
+    ('\u{F97D}', 2899),
+    ('\u{F97E}', 2970),
+    ('\u{F97F}', 2200),
+    ('\u{F980}', 2238),
+    ('\u{F981}', 2297),
+    ('\u{F982}', 2366),
+    ('\u{F983}', 2464),
+    ('\u{F984}', 2584),
+    ('\u{F985}', 2694),
+    ('\u{F986}', 2989),
+    ('\u{F987}', 3053),
+    ('\u{F988}', 3077),
+    ('\u{F989}', 3085),
+    ('\u{F98A}', 2192),
+    ('\u{F98B}', 2478),
+    ('\u{F98C}', 2528),
+    ('\u{F98D}', 2945),
+    ('\u{F98E}', 2354),
+    ('\u{F98F}', 2408),
+    ('\u{F990}', 2415),
+    ('\u{F991}', 2448),
+    ('\u{F992}', 2580),
+    ('\u{F993}', 2599),
+    ('\u{F994}', 2643),
+    ('\u{F995}', 2713),
+    ('\u{F996}', 2753),
+    ('\u{F997}', 2781),
+    ('\u{F998}', 2941),
+    ('\u{F999}', 2838),
+    ('\u{F99A}', 2952),
+    ('\u{F99B}', 2979),
+    ('\u{F99C}', 2184),
+    ('\u{F99D}', 2193),
+    ('\u{F99E}', 2243),
+    ('\u{F99F}', 2596),
+    ('\u{F9A0}', 2875),
+    ('\u{F9A1}', 2896),
+    ('\u{F9A2}', 2361),
+    ('\u{F9A3}', 2389),
+    ('\u{F9A4}', 2435),
+    ('\u{F9A5}', 2532),
+    ('\u{F9A6}', 2730),
+    ('\u{F9A7}', 2626),
+    ('\u{F9A8}', 2133),
+    ('\u{F9A9}', 2262),
+    ('\u{F9AA}', 2314),
+    ('\u{F9AB}', 2340),
+    ('\u{F9AC}', 2392),
+    ('\u{F9AD}', 2633),
+    ('\u{F9AE}', 2640),
+    ('\u{F9AF}', 2770),
+    ('\u{F9B0}', 2779),
+    ('\u{F9B1}', 2972),
+    ('\u{F9B2}', 3009),
+    ('\u{F9B3}', 3013),
+    ('\u{F9B4}', 3029),
+    ('\u{F9B5}', 2138),
+    ('\u{F9B6}', 2710),
+    ('\u{F9B7}', 2967),
+    ('\u{F9B8}', 3003),
+    ('\u{F9B9}', 2398),
+    ('\u{F9BA}', 2125),
+    ('\u{F9BB}', 2147),
+    ('\u{F9BC}', 2315),
+    ('\u{F9BD}', 2324),
+    ('\u{F9BE}', 2461),
+    ('\u{F9BF}', 2516),
+    ('\u{F9C0}', 2602),
+    ('\u{F9C1}', 2665),
+    ('\u{F9C2}', 2841),
+    ('\u{F9C3}', 2955),
+    ('\u{F9C4}', 3102),
+    ('\u{F9C5}', 2474),
+    ('\u{F9C6}', 2994),
+    ('\u{F9C7}', 2191),
+    ('\u{F9C8}', 2499),
+    ('\u{F9C9}', 2502),
+    ('\u{F9CA}', 2558),
+    ('\u{F9CB}', 2572),
+    ('\u{F9CC}', 2636),
+    ('\u{F9CD}', 2656),
+    ('\u{F9CE}', 2688),
+    ('\u{F9CF}', 2744),
+    ('\u{F9D0}', 3032),
+    ('\u{F9D1}', 2160),
+    ('\u{F9D2}', 2419),
+    ('\u{F9D3}', 2998),
+    ('\u{F9D4}', 2143),
+    ('\u{F9D5}', 2333),
+    ('\u{F9D6}', 2566),
+    ('\u{F9D7}', 2942),
+    ('\u{F9D8}', 2381),
+    ('\u{F9D9}', 2400),
+    ('\u{F9DA}', 2504),
+    ('\u{F9DB}', 2629),
+    ('\u{F9DC}', 3000),
+    ('\u{F9DD}', 2185),
+    ('\u{F9DE}', 2235),
+    ('\u{F9DF}', 2328),
+    ('\u{F9E0}', 2470),
+    ('\u{F9E1}', 2494),
+    ('\u{F9E2}', 2510),
+    ('\u{F9E3}', 2552),
+    ('\u{F9E4}', 2635),
+    ('\u{F9E5}', 2661),
+    ('\u{F9E6}', 2765),
+    ('\u{F9E7}', 2876),
+    ('\u{F9E8}', 2879),
+    ('\u{F9E9}', 2969),
+    ('\u{F9EA}', 3006),
+    ('\u{F9EB}', 2209),
+    ('\u{F9EC}', 2573),
+    ('\u{F9ED}', 2236),
+    ('\u{F9EE}', 2603),
+    ('\u{F9EF}', 2644),
+    ('\u{F9F0}', 2845),
+    ('\u{F9F1}', 3001),
+    ('\u{F9F2}', 3066),
+    ('\u{F9F3}', 3078),
+    ('\u{F9F4}', 2501),
+    ('\u{F9F5}', 2564),
+    ('\u{F9F6}', 2795),
+    ('\u{F9F7}', 2722),
+    ('\u{F9F8}', 2726),
+    ('\u{F9F9}', 2734),
+    ('\u{F9FA}', 2623),
+    ('\u{F9FB}', 2594),
+    ('\u{F9FC}', 2906),
+    ('\u{F9FD}', 2131),
+    ('\u{F9FE}', 2821),
+    ('\u{F9FF}', 2186),
+    ('\u{FA00}', 2183),
+    ('\u{FA01}', 2357),
+    ('\u{FA02}', 2428),
+    ('\u{FA03}', 2737),
+    ('\u{FA04}', 2311),
+    ('\u{FA05}', 2555),
+    ('\u{FA06}', 2477),
+    ('\u{FA07}', 2944),
+    ('\u{FA08}', 2871),
+    ('\u{FA09}', 2996),
+    ('\u{FA0A}', 2889),
+    ('\u{FA0B}', 2364),
+    ('\u{FA0C}', 2150),
+    ('\u{FA0D}', 2255),
+    ('\u{FA10}', 2273),
+    ('\u{FA12}', 2473),
+    ('\u{FA15}', 2177),
+    ('\u{FA16}', 2625),
+    ('\u{FA17}', 2671),
+    ('\u{FA18}', 2697),
+    ('\u{FA19}', 2704),
+    ('\u{FA1A}', 2705),
+    ('\u{FA1B}', 2709),
+    ('\u{FA1C}', 3016),
+    ('\u{FA1D}', 2735),
+    ('\u{FA1E}', 2771),
+    ('\u{FA20}', 2847),
+    ('\u{FA22}', 2902),
+    ('\u{FA25}', 2953),
+    ('\u{FA26}', 2961),
+    ('\u{FA2A}', 3041),
+    ('\u{FA2B}', 3042),
+    ('\u{FA2C}', 3043),
+    ('\u{FA2D}', 3071),
+    ('\u{FA2E}', 2959),
+    ('\u{FA2F}', 3003),
+    ('\u{FA30}', 2139),
+    ('\u{FA31}', 2148),
+    ('\u{FA32}', 2152),
+    ('\u{FA33}', 2196),
+    ('\u{FA34}', 2199),
+    ('\u{FA35}', 2216),
+    ('\u{FA36}', 2252),
+    ('\u{FA37}', 2258),
+    ('\u{FA38}', 2260),
+    ('\u{FA39}', 2272),
+    ('\u{FA3A}', 2276),
+    ('\u{FA3B}', 2327),
+    ('\u{FA3C}', 2329),
+    ('\u{FA3D}', 2395),
+    ('\u{FA3E}', 2405),
+    ('\u{FA3F}', 2407),
+    ('\u{FA40}', 2413),
+    ('\u{FA41}', 2454),
+    ('\u{FA42}', 2467),
+    ('\u{FA43}', 2475),
+    ('\u{FA44}', 2508),
+    ('\u{FA45}', 2561),
+    ('\u{FA46}', 2568),
+    ('\u{FA47}', 2579),
+    ('\u{FA48}', 2600),
+    ('\u{FA49}', 2608),
+    ('\u{FA4A}', 2637),
+    ('\u{FA4B}', 2690),
+    ('\u{FA4C}', 2698),
+    ('\u{FA4D}', 2700),
+    ('\u{FA4E}', 2699),
+    ('\u{FA4F}', 2701),
+    ('\u{FA50}', 2702),
+    ('\u{FA51}', 2703),
+    ('\u{FA52}', 2707),
+    ('\u{FA53}', 2708),
+    ('\u{FA54}', 2716),
+    ('\u{FA55}', 2720),
+    ('\u{FA56}', 2727),
+    ('\u{FA57}', 2753),
+    ('\u{FA58}', 2755),
+    ('\u{FA59}', 2757),
+    ('\u{FA5A}', 2764),
+    ('\u{FA5B}', 2775),
+    ('\u{FA5C}', 2797),
+    ('\u{FA5D}', 2810),
+    ('\u{FA5E}', 2810),
+    ('\u{FA5F}', 2836),
+    ('\u{FA60}', 2882),
+    ('\u{FA61}', 2890),
+    ('\u{FA62}', 2904),
+    ('\u{FA63}', 2905),
+    ('\u{FA64}', 2922),
+    ('\u{FA65}', 2923),
+    ('\u{FA66}', 2951),
+    ('\u{FA67}', 2953),
+    ('\u{FA68}', 3007),
+    ('\u{FA69}', 3026),
+    ('\u{FA6A}', 3031),
+    ('\u{FA6B}', 2393),
+    ('\u{FA6C}', 3250),
+    ('\u{FA6D}', 2803),
+    ('\u{FA70}', 2097),
+    ('\u{FA71}', 2172),
+    ('\u{FA72}', 2157),
+    ('\u{FA73}', 2136),
+    ('\u{FA74}', 2151),
+    ('\u{FA75}', 2162),
+    ('\u{FA76}', 2195),
+    ('\u{FA77}', 2202),
+    ('\u{FA78}', 2252),
+    ('\u{FA79}', 2247),
+    ('\u{FA7A}', 2251),
+    ('\u{FA7B}', 2257),
+    ('\u{FA7C}', 2273),
+    ('\u{FA7D}', 2279),
+    ('\u{FA7E}', 2292),
+    ('\u{FA7F}', 2295),
+    ('\u{FA80}', 2302),
+    ('\u{FA81}', 2307),
+    ('\u{FA82}', 2363),
+    ('\u{FA83}', 2365),
+    ('\u{FA84}', 2378),
+    ('\u{FA85}', 2384),
+    ('\u{FA86}', 2397),
+    ('\u{FA87}', 2403),
+    ('\u{FA88}', 2399),
+    ('\u{FA89}', 2407),
+    ('\u{FA8A}', 2404),
+    ('\u{FA8B}', 2413),
+    ('\u{FA8C}', 2420),
+    ('\u{FA8D}', 2439),
+    ('\u{FA8E}', 2442),
+    ('\u{FA8F}', 2444),
+    ('\u{FA90}', 2455),
+    ('\u{FA91}', 2473),
+    ('\u{FA92}', 2488),
+    ('\u{FA93}', 2490),
+    ('\u{FA94}', 2496),
+    ('\u{FA95}', 2529),
+    ('\u{FA96}', 2534),
+    ('\u{FA97}', 2558),
+    ('\u{FA98}', 2577),
+    ('\u{FA99}', 2575),
+    ('\u{FA9A}', 2579),
+    ('\u{FA9B}', 2586),
+    ('\u{FA9C}', 2600),
+    ('\u{FA9D}', 2682),
+    ('\u{FA9E}', 2609),
+    ('\u{FA9F}', 2622),
+    ('\u{FAA0}', 2625),
+    ('\u{FAA1}', 2641),
+    ('\u{FAA2}', 2648),
+    ('\u{FAA3}', 2654),
+    ('\u{FAA4}', 2663),
+    ('\u{FAA5}', 2664),
+    ('\u{FAA6}', 2671),
+    ('\u{FAA7}', 2672),
+    ('\u{FAA8}', 2675),
+    ('\u{FAA9}', 2680),
+    ('\u{FAAA}', 2679),
+    ('\u{FAAB}', 2692),
+    ('\u{FAAC}', 2721),
+    ('\u{FAAD}', 2727),
+    ('\u{FAAE}', 2733),
+    ('\u{FAAF}', 2748),
+    ('\u{FAB0}', 2753),
+    ('\u{FAB1}', 2760),
+    ('\u{FAB2}', 2775),
+    ('\u{FAB3}', 2822),
+    ('\u{FAB4}', 2832),
+    ('\u{FAB5}', 2864),
+    ('\u{FAB6}', 2883),
+    ('\u{FAB7}', 2888),
+    ('\u{FAB8}', 2890),
+    ('\u{FAB9}', 2897),
+    ('\u{FABA}', 2902),
+    ('\u{FABB}', 2898),
+    ('\u{FABC}', 2904),
+    ('\u{FABD}', 2903),
+    ('\u{FABE}', 2901),
+    ('\u{FABF}', 2905),
+    ('\u{FAC0}', 2909),
+    ('\u{FAC1}', 2923),
+    ('\u{FAC2}', 2943),
+    ('\u{FAC3}', 2954),
+    ('\u{FAC4}', 2966),
+    ('\u{FAC5}', 2974),
+    ('\u{FAC6}', 2999),
+    ('\u{FAC7}', 3007),
+    ('\u{FAC8}', 3016),
+    ('\u{FAC9}', 3021),
+    ('\u{FACA}', 3026),
+    ('\u{FACB}', 3028),
+    ('\u{FACC}', 3031),
+    ('\u{FACD}', 3058),
+    ('\u{FACE}', 3105),
+    ('\u{FACF}', 3229),
+    ('\u{FAD0}', 3228),
+    ('\u{FAD1}', 3238),
+    ('\u{FAD2}', 2046),
+    ('\u{FAD3}', 2057),
+    ('\u{FAD4}', 2058),
+    ('\u{FAD5}', 3264),
+    ('\u{FAD6}', 3273),
+    ('\u{FAD7}', 3297),
+    ('\u{FAD8}', 3097),
+    ('\u{FAD9}', 3103),
+    ('ﬀ', 430),
+    ('ﬁ', 433),
+    ('ﬂ', 434),
+    ('ﬃ', 431),
+    ('ﬄ', 432),
+    ('ﬆ', 559),
+    ('ﬓ', 894),
+    ('ﬔ', 891),
+    ('ﬕ', 892),
+    ('ﬖ', 895),
+    ('ﬗ', 893),
+    ('\u{FB20}', 909),
+    ('\u{FB21}', 900),
+    ('\u{FB22}', 904),
+    ('\u{FB23}', 905),
+    ('\u{FB24}', 906),
+    ('\u{FB25}', 907),
+    ('\u{FB26}', 908),
+    ('\u{FB27}', 910),
+    ('\u{FB28}', 911),
+    ('﬩', 176),
+    ('\u{FB2B}', 3146),
+    ('\u{FB2D}', 3147),
+    ('\u{FB2F}', 3148),
+    ('\u{FB30}', 3148),
+    ('\u{FB39}', 3145),
+    ('\u{FB49}', 3146),
+    ('\u{FB4F}', 901),
+    ('\u{FB50}', 1220),
+    ('\u{FB51}', 1220),
+    ('\u{FB52}', 1222),
+    ('\u{FB53}', 1222),
+    ('\u{FB54}', 1222),
+    ('\u{FB55}', 1222),
+    ('\u{FB56}', 1201),
+    ('\u{FB57}', 1201),
+    ('\u{FB58}', 1201),
+    ('\u{FB59}', 1201),
+    ('\u{FB5A}', 1224),
+    ('\u{FB5B}', 1224),
+    ('\u{FB5C}', 1224),
+    ('\u{FB5D}', 1224),
+    ('\u{FB5E}', 1221),
+    ('\u{FB5F}', 1221),
+    ('\u{FB60}', 1221),
+    ('\u{FB61}', 1221),
+    ('\u{FB62}', 1223),
+    ('\u{FB63}', 1223),
+    ('\u{FB64}', 1223),
+    ('\u{FB65}', 1223),
+    ('\u{FB66}', 1170),
+    ('\u{FB67}', 1170),
+    ('\u{FB68}', 1170),
+    ('\u{FB69}', 1170),
+    ('\u{FB6A}', 1234),
+    ('\u{FB6B}', 1234),
+    ('\u{FB6C}', 1234),
+    ('\u{FB6D}', 1234),
+    ('\u{FB6E}', 1236),
+    ('\u{FB6F}', 1236),
+    ('\u{FB70}', 1236),
+    ('\u{FB71}', 1236),
+    ('\u{FB72}', 1226),
+    ('\u{FB73}', 1226),
+    ('\u{FB74}', 1226),
+    ('\u{FB75}', 1226),
+    ('\u{FB76}', 1225),
+    ('\u{FB77}', 1225),
+    ('\u{FB78}', 1225),
+    ('\u{FB79}', 1225),
+    ('\u{FB7A}', 1227),
+    ('\u{FB7B}', 1227),
+    ('\u{FB7C}', 1227),
+    ('\u{FB7D}', 1227),
+    ('\u{FB7E}', 1228),
+    ('\u{FB7F}', 1228),
+    ('\u{FB80}', 1228),
+    ('\u{FB81}', 1228),
+    ('\u{FB82}', 1231),
+    ('\u{FB83}', 1231),
+    ('\u{FB84}', 1230),
+    ('\u{FB85}', 1230),
+    ('\u{FB86}', 979),
+    ('\u{FB87}', 979),
+    ('\u{FB88}', 978),
+    ('\u{FB89}', 978),
+    ('\u{FB8A}', 991),
+    ('\u{FB8B}', 991),
+    ('\u{FB8C}', 986),
+    ('\u{FB8D}', 986),
+    ('\u{FB8E}', 1086),
+    ('\u{FB8F}', 1086),
+    ('\u{FB90}', 1086),
+    ('\u{FB91}', 1086),
+    ('\u{FB92}', 1238),
+    ('\u{FB93}', 1238),
+    ('\u{FB94}', 1238),
+    ('\u{FB95}', 1238),
+    ('\u{FB96}', 1241),
+    ('\u{FB97}', 1241),
+    ('\u{FB98}', 1241),
+    ('\u{FB99}', 1241),
+    ('\u{FB9A}', 1240),
+    ('\u{FB9B}', 1240),
+    ('\u{FB9C}', 1240),
+    ('\u{FB9D}', 1240),
+    ('\u{FB9E}', 1165),
+    ('\u{FB9F}', 1165),
+    ('\u{FBA0}', 1170),
+    ('\u{FBA1}', 1170),
+    ('\u{FBA2}', 1170),
+    ('\u{FBA3}', 1170),
+    ('\u{FBA4}', 1242),
+    ('\u{FBA5}', 1242),
+    ('\u{FBA6}', 524),
+    ('\u{FBA7}', 524),
+    ('\u{FBA8}', 524),
+    ('\u{FBA9}', 524),
+    ('\u{FBAA}', 524),
+    ('\u{FBAB}', 524),
+    ('\u{FBAC}', 524),
+    ('\u{FBAD}', 524),
+    ('\u{FBAE}', 1165),
+    ('\u{FBAF}', 1165),
+    ('\u{FBB0}', 1244),
+    ('\u{FBB1}', 1244),
+    ('\u{FBD3}', 1096),
+    ('\u{FBD4}', 1096),
+    ('\u{FBD5}', 1096),
+    ('\u{FBD6}', 1096),
+    ('\u{FBD7}', 1159),
+    ('\u{FBD8}', 1159),
+    ('\u{FBD9}', 1158),
+    ('\u{FBDA}', 1158),
+    ('\u{FBDB}', 1162),
+    ('\u{FBDC}', 1162),
+    ('\u{FBDD}', 1160),
+    ('\u{FBDE}', 1164),
+    ('\u{FBDF}', 1164),
+    ('\u{FBE0}', 1243),
+    ('\u{FBE1}', 1243),
+    ('\u{FBE2}', 1157),
+    ('\u{FBE3}', 1157),
+    ('\u{FBE4}', 1222),
+    ('\u{FBE5}', 1222),
+    ('\u{FBE6}', 1222),
+    ('\u{FBE7}', 1222),
+    ('\u{FBE8}', 1165),
+    ('\u{FBE9}', 1165),
+    ('\u{FBEA}', 1186),
+    ('\u{FBEB}', 1186),
+    ('\u{FBEC}', 1187),
+    ('\u{FBED}', 1187),
+    ('\u{FBEE}', 1195),
+    ('\u{FBEF}', 1195),
+    ('\u{FBF0}', 1197),
+    ('\u{FBF1}', 1197),
+    ('\u{FBF2}', 1196),
+    ('\u{FBF3}', 1196),
+    ('\u{FBF4}', 1198),
+    ('\u{FBF5}', 1198),
+    ('\u{FBF6}', 1200),
+    ('\u{FBF7}', 1200),
+    ('\u{FBF8}', 1200),
+    ('\u{FBF9}', 1199),
+    ('\u{FBFA}', 1199),
+    ('\u{FBFB}', 1199),
+    ('\u{FBFC}', 1165),
+    ('\u{FBFD}', 1165),
+    ('\u{FBFE}', 1165),
+    ('\u{FBFF}', 1165),
+    ('\u{FC00}', 1188),
+    ('\u{FC01}', 1189),
+    ('\u{FC02}', 1193),
+    ('\u{FC03}', 1199),
+    ('\u{FC04}', 1199),
+    ('\u{FC05}', 920),
+    ('\u{FC06}', 921),
+    ('\u{FC07}', 923),
+    ('\u{FC08}', 927),
+    ('\u{FC09}', 929),
+    ('\u{FC0A}', 929),
+    ('\u{FC0B}', 936),
+    ('\u{FC0C}', 939),
+    ('\u{FC0D}', 942),
+    ('\u{FC0E}', 947),
+    ('\u{FC0F}', 953),
+    ('\u{FC10}', 953),
+    ('\u{FC11}', 1203),
+    ('\u{FC12}', 1206),
+    ('\u{FC13}', 1208),
+    ('\u{FC14}', 1208),
+    ('\u{FC15}', 955),
+    ('\u{FC16}', 958),
+    ('\u{FC17}', 963),
+    ('\u{FC18}', 965),
+    ('\u{FC19}', 971),
+    ('\u{FC1A}', 972),
+    ('\u{FC1B}', 973),
+    ('\u{FC1C}', 997),
+    ('\u{FC1D}', 1000),
+    ('\u{FC1E}', 1002),
+    ('\u{FC1F}', 1005),
+    ('\u{FC20}', 1025),
+    ('\u{FC21}', 1033),
+    ('\u{FC22}', 1038),
+    ('\u{FC23}', 1039),
+    ('\u{FC24}', 1041),
+    ('\u{FC25}', 1044),
+    ('\u{FC26}', 1047),
+    ('\u{FC27}', 1048),
+    ('\u{FC28}', 1055),
+    ('\u{FC29}', 1057),
+    ('\u{FC2A}', 1060),
+    ('\u{FC2B}', 1065),
+    ('\u{FC2C}', 1066),
+    ('\u{FC2D}', 1071),
+    ('\u{FC2E}', 1072),
+    ('\u{FC2F}', 1073),
+    ('\u{FC30}', 1075),
+    ('\u{FC31}', 1077),
+    ('\u{FC32}', 1077),
+    ('\u{FC33}', 1079),
+    ('\u{FC34}', 1081),
+    ('\u{FC35}', 1085),
+    ('\u{FC36}', 1085),
+    ('\u{FC37}', 1087),
+    ('\u{FC38}', 1088),
+    ('\u{FC39}', 1089),
+    ('\u{FC3A}', 1090),
+    ('\u{FC3B}', 1091),
+    ('\u{FC3C}', 1092),
+    ('\u{FC3D}', 1095),
+    ('\u{FC3E}', 1095),
+    ('\u{FC3F}', 1104),
+    ('\u{FC40}', 1108),
+    ('\u{FC41}', 1111),
+    ('\u{FC42}', 1113),
+    ('\u{FC43}', 1116),
+    ('\u{FC44}', 1116),
+    ('\u{FC45}', 1121),
+    ('\u{FC46}', 1126),
+    ('\u{FC47}', 1131),
+    ('\u{FC48}', 1135),
+    ('\u{FC49}', 1137),
+    ('\u{FC4A}', 1137),
+    ('\u{FC4B}', 923),
+    ('\u{FC4C}', 1146),
+    ('\u{FC4D}', 1149),
+    ('\u{FC4E}', 1152),
+    ('\u{FC4F}', 1155),
+    ('\u{FC50}', 1155),
+    ('\u{FC51}', 532),
+    ('\u{FC52}', 533),
+    ('\u{FC53}', 536),
+    ('\u{FC54}', 536),
+    ('\u{FC55}', 1171),
+    ('\u{FC56}', 1173),
+    ('\u{FC57}', 1175),
+    ('\u{FC58}', 1178),
+    ('\u{FC59}', 1182),
+    ('\u{FC5A}', 1182),
+    ('\u{FC5B}', 981),
+    ('\u{FC5C}', 990),
+    ('\u{FC5D}', 1184),
+    ('\u{FC5E}', 3150),
+    ('\u{FC5F}', 3151),
+    ('\u{FC60}', 3152),
+    ('\u{FC61}', 3154),
+    ('\u{FC62}', 3156),
+    ('\u{FC63}', 3158),
+    ('\u{FC64}', 1191),
+    ('\u{FC65}', 1192),
+    ('\u{FC66}', 1193),
+    ('\u{FC67}', 1194),
+    ('\u{FC68}', 1199),
+    ('\u{FC69}', 1199),
+    ('\u{FC6A}', 925),
+    ('\u{FC6B}', 926),
+    ('\u{FC6C}', 927),
+    ('\u{FC6D}', 928),
+    ('\u{FC6E}', 929),
+    ('\u{FC6F}', 929),
+    ('\u{FC70}', 945),
+    ('\u{FC71}', 946),
+    ('\u{FC72}', 947),
+    ('\u{FC73}', 952),
+    ('\u{FC74}', 953),
+    ('\u{FC75}', 953),
+    ('\u{FC76}', 1204),
+    ('\u{FC77}', 1205),
+    ('\u{FC78}', 1206),
+    ('\u{FC79}', 1207),
+    ('\u{FC7A}', 1208),
+    ('\u{FC7B}', 1208),
+    ('\u{FC7C}', 1077),
+    ('\u{FC7D}', 1077),
+    ('\u{FC7E}', 1085),
+    ('\u{FC7F}', 1085),
+    ('\u{FC80}', 1087),
+    ('\u{FC81}', 1091),
+    ('\u{FC82}', 1092),
+    ('\u{FC83}', 1095),
+    ('\u{FC84}', 1095),
+    ('\u{FC85}', 1113),
+    ('\u{FC86}', 1116),
+    ('\u{FC87}', 1116),
+    ('\u{FC88}', 1119),
+    ('\u{FC89}', 1135),
+    ('\u{FC8A}', 1150),
+    ('\u{FC8B}', 1151),
+    ('\u{FC8C}', 1152),
+    ('\u{FC8D}', 1154),
+    ('\u{FC8E}', 1155),
+    ('\u{FC8F}', 1155),
+    ('\u{FC90}', 1184),
+    ('\u{FC91}', 1176),
+    ('\u{FC92}', 1177),
+    ('\u{FC93}', 1178),
+    ('\u{FC94}', 1181),
+    ('\u{FC95}', 1182),
+    ('\u{FC96}', 1182),
+    ('\u{FC97}', 1188),
+    ('\u{FC98}', 1189),
+    ('\u{FC99}', 1190),
+    ('\u{FC9A}', 1193),
+    ('\u{FC9B}', 1187),
+    ('\u{FC9C}', 920),
+    ('\u{FC9D}', 921),
+    ('\u{FC9E}', 923),
+    ('\u{FC9F}', 927),
+    ('\u{FCA0}', 919),
+    ('\u{FCA1}', 936),
+    ('\u{FCA2}', 939),
+    ('\u{FCA3}', 942),
+    ('\u{FCA4}', 947),
+    ('\u{FCA5}', 935),
+    ('\u{FCA6}', 1206),
+    ('\u{FCA7}', 955),
+    ('\u{FCA8}', 958),
+    ('\u{FCA9}', 963),
+    ('\u{FCAA}', 965),
+    ('\u{FCAB}', 971),
+    ('\u{FCAC}', 973),
+    ('\u{FCAD}', 997),
+    ('\u{FCAE}', 1000),
+    ('\u{FCAF}', 1002),
+    ('\u{FCB0}', 1005),
+    ('\u{FCB1}', 1025),
+    ('\u{FCB2}', 1028),
+    ('\u{FCB3}', 1033),
+    ('\u{FCB4}', 1038),
+    ('\u{FCB5}', 1039),
+    ('\u{FCB6}', 1041),
+    ('\u{FCB7}', 1044),
+    ('\u{FCB8}', 1047),
+    ('\u{FCB9}', 1055),
+    ('\u{FCBA}', 1057),
+    ('\u{FCBB}', 1060),
+    ('\u{FCBC}', 1065),
+    ('\u{FCBD}', 1066),
+    ('\u{FCBE}', 1071),
+    ('\u{FCBF}', 1072),
+    ('\u{FCC0}', 1073),
+    ('\u{FCC1}', 1075),
+    ('\u{FCC2}', 1079),
+    ('\u{FCC3}', 1081),
+    ('\u{FCC4}', 1088),
+    ('\u{FCC5}', 1089),
+    ('\u{FCC6}', 1090),
+    ('\u{FCC7}', 1091),
+    ('\u{FCC8}', 1092),
+    ('\u{FCC9}', 1104),
+    ('\u{FCCA}', 1108),
+    ('\u{FCCB}', 1111),
+    ('\u{FCCC}', 1113),
+    ('\u{FCCD}', 1101),
+    ('\u{FCCE}', 1121),
+    ('\u{FCCF}', 1126),
+    ('\u{FCD0}', 1131),
+    ('\u{FCD1}', 1135),
+    ('\u{FCD2}', 923),
+    ('\u{FCD3}', 1146),
+    ('\u{FCD4}', 1149),
+    ('\u{FCD5}', 1152),
+    ('\u{FCD6}', 1140),
+    ('\u{FCD7}', 532),
+    ('\u{FCD8}', 533),
+    ('\u{FCD9}', 537),
+    ('\u{FCDA}', 1171),
+    ('\u{FCDB}', 1173),
+    ('\u{FCDC}', 1175),
+    ('\u{FCDD}', 1178),
+    ('\u{FCDE}', 1166),
+    ('\u{FCDF}', 1193),
+    ('\u{FCE0}', 1187),
+    ('\u{FCE1}', 927),
+    ('\u{FCE2}', 919),
+    ('\u{FCE3}', 947),
+    ('\u{FCE4}', 935),
+    ('\u{FCE5}', 1206),
+    ('\u{FCE6}', 1202),
+    ('\u{FCE7}', 1005),
+    ('\u{FCE8}', 995),
+    ('\u{FCE9}', 1019),
+    ('\u{FCEA}', 1011),
+    ('\u{FCEB}', 1091),
+    ('\u{FCEC}', 1092),
+    ('\u{FCED}', 1113),
+    ('\u{FCEE}', 1152),
+    ('\u{FCEF}', 1140),
+    ('\u{FCF0}', 1178),
+    ('\u{FCF1}', 1166),
+    ('\u{FCF2}', 3153),
+    ('\u{FCF3}', 3155),
+    ('\u{FCF4}', 3157),
+    ('\u{FCF5}', 1052),
+    ('\u{FCF6}', 1052),
+    ('\u{FCF7}', 1063),
+    ('\u{FCF8}', 1063),
+    ('\u{FCF9}', 1069),
+    ('\u{FCFA}', 1069),
+    ('\u{FCFB}', 1009),
+    ('\u{FCFC}', 1009),
+    ('\u{FCFD}', 1022),
+    ('\u{FCFE}', 1022),
+    ('\u{FCFF}', 967),
+    ('\u{FD00}', 967),
+    ('\u{FD01}', 961),
+    ('\u{FD02}', 961),
+    ('\u{FD03}', 974),
+    ('\u{FD04}', 974),
+    ('\u{FD05}', 1035),
+    ('\u{FD06}', 1035),
+    ('\u{FD07}', 1045),
+    ('\u{FD08}', 1045),
+    ('\u{FD09}', 1012),
+    ('\u{FD0A}', 1014),
+    ('\u{FD0B}', 1017),
+    ('\u{FD0C}', 1019),
+    ('\u{FD0D}', 1018),
+    ('\u{FD0E}', 1004),
+    ('\u{FD0F}', 1029),
+    ('\u{FD10}', 1043),
+    ('\u{FD11}', 1052),
+    ('\u{FD12}', 1052),
+    ('\u{FD13}', 1063),
+    ('\u{FD14}', 1063),
+    ('\u{FD15}', 1069),
+    ('\u{FD16}', 1069),
+    ('\u{FD17}', 1009),
+    ('\u{FD18}', 1009),
+    ('\u{FD19}', 1022),
+    ('\u{FD1A}', 1022),
+    ('\u{FD1B}', 967),
+    ('\u{FD1C}', 967),
+    ('\u{FD1D}', 961),
+    ('\u{FD1E}', 961),
+    ('\u{FD1F}', 974),
+    ('\u{FD20}', 974),
+    ('\u{FD21}', 1035),
+    ('\u{FD22}', 1035),
+    ('\u{FD23}', 1045),
+    ('\u{FD24}', 1045),
+    ('\u{FD25}', 1012),
+    ('\u{FD26}', 1014),
+    ('\u{FD27}', 1017),
+    ('\u{FD28}', 1019),
+    ('\u{FD29}', 1018),
+    ('\u{FD2A}', 1004),
+    ('\u{FD2B}', 1029),
+    ('\u{FD2C}', 1043),
+    ('\u{FD2D}', 1012),
+    ('\u{FD2E}', 1014),
+    ('\u{FD2F}', 1017),
+    ('\u{FD30}', 1019),
+    ('\u{FD31}', 995),
+    ('\u{FD32}', 1011),
+    ('\u{FD33}', 1048),
+    ('\u{FD34}', 997),
+    ('\u{FD35}', 1000),
+    ('\u{FD36}', 1002),
+    ('\u{FD37}', 1012),
+    ('\u{FD38}', 1014),
+    ('\u{FD39}', 1017),
+    ('\u{FD3A}', 1048),
+    ('\u{FD3B}', 1055),
+    ('\u{FD3C}', 505),
+    ('\u{FD3D}', 505),
+    ('﴾', 16),
+    ('﴿', 162),
+    ('\u{FD50}', 937),
+    ('\u{FD51}', 940),
+    ('\u{FD52}', 940),
+    ('\u{FD53}', 941),
+    ('\u{FD54}', 943),
+    ('\u{FD55}', 948),
+    ('\u{FD56}', 949),
+    ('\u{FD57}', 950),
+    ('\u{FD58}', 959),
+    ('\u{FD59}', 959),
+    ('\u{FD5A}', 966),
+    ('\u{FD5B}', 966),
+    ('\u{FD5C}', 1001),
+    ('\u{FD5D}', 998),
+    ('\u{FD5E}', 999),
+    ('\u{FD5F}', 1007),
+    ('\u{FD60}', 1007),
+    ('\u{FD61}', 1006),
+    ('\u{FD62}', 1008),
+    ('\u{FD63}', 1008),
+    ('\u{FD64}', 1026),
+    ('\u{FD65}', 1026),
+    ('\u{FD66}', 1034),
+    ('\u{FD67}', 1015),
+    ('\u{FD68}', 1015),
+    ('\u{FD69}', 1013),
+    ('\u{FD6A}', 1020),
+    ('\u{FD6B}', 1020),
+    ('\u{FD6C}', 1021),
+    ('\u{FD6D}', 1021),
+    ('\u{FD6E}', 1040),
+    ('\u{FD6F}', 1042),
+    ('\u{FD70}', 1042),
+    ('\u{FD71}', 1049),
+    ('\u{FD72}', 1049),
+    ('\u{FD73}', 1050),
+    ('\u{FD74}', 1051),
+    ('\u{FD75}', 1058),
+    ('\u{FD76}', 1061),
+    ('\u{FD77}', 1061),
+    ('\u{FD78}', 1062),
+    ('\u{FD79}', 1067),
+    ('\u{FD7A}', 1068),
+    ('\u{FD7B}', 1068),
+    ('\u{FD7C}', 1074),
+    ('\u{FD7D}', 1074),
+    ('\u{FD7E}', 1082),
+    ('\u{FD7F}', 1083),
+    ('\u{FD80}', 1109),
+    ('\u{FD81}', 1110),
+    ('\u{FD82}', 1110),
+    ('\u{FD83}', 1105),
+    ('\u{FD84}', 1105),
+    ('\u{FD85}', 1112),
+    ('\u{FD86}', 1112),
+    ('\u{FD87}', 1114),
+    ('\u{FD88}', 1114),
+    ('\u{FD89}', 1127),
+    ('\u{FD8A}', 1128),
+    ('\u{FD8B}', 1130),
+    ('\u{FD8C}', 1122),
+    ('\u{FD8D}', 1124),
+    ('\u{FD8E}', 1132),
+    ('\u{FD8F}', 1133),
+    ('\u{FD92}', 1123),
+    ('\u{FD93}', 534),
+    ('\u{FD94}', 535),
+    ('\u{FD95}', 1147),
+    ('\u{FD96}', 1148),
+    ('\u{FD97}', 1144),
+    ('\u{FD98}', 1144),
+    ('\u{FD99}', 1145),
+    ('\u{FD9A}', 1153),
+    ('\u{FD9B}', 1153),
+    ('\u{FD9C}', 1179),
+    ('\u{FD9D}', 1179),
+    ('\u{FD9E}', 924),
+    ('\u{FD9F}', 938),
+    ('\u{FDA0}', 938),
+    ('\u{FDA1}', 944),
+    ('\u{FDA2}', 944),
+    ('\u{FDA3}', 951),
+    ('\u{FDA4}', 951),
+    ('\u{FDA5}', 960),
+    ('\u{FDA6}', 956),
+    ('\u{FDA7}', 960),
+    ('\u{FDA8}', 1003),
+    ('\u{FDA9}', 1027),
+    ('\u{FDAA}', 1016),
+    ('\u{FDAB}', 1040),
+    ('\u{FDAC}', 1107),
+    ('\u{FDAD}', 1115),
+    ('\u{FDAE}', 1174),
+    ('\u{FDAF}', 1172),
+    ('\u{FDB0}', 1180),
+    ('\u{FDB1}', 1136),
+    ('\u{FDB2}', 1084),
+    ('\u{FDB3}', 1148),
+    ('\u{FDB4}', 1082),
+    ('\u{FDB5}', 1109),
+    ('\u{FDB6}', 1062),
+    ('\u{FDB7}', 1094),
+    ('\u{FDB8}', 1143),
+    ('\u{FDB9}', 1134),
+    ('\u{FDBA}', 1106),
+    ('\u{FDBB}', 1093),
+    ('\u{FDBC}', 1106),
+    ('\u{FDBD}', 1143),
+    ('\u{FDBE}', 956),
+    ('\u{FDBF}', 964),
+    ('\u{FDC0}', 1125),
+    ('\u{FDC1}', 1076),
+    ('\u{FDC2}', 922),
+    ('\u{FDC3}', 1093),
+    ('\u{FDC4}', 1058),
+    ('\u{FDC5}', 1034),
+    ('\u{FDC6}', 1003),
+    ('\u{FDC7}', 1145),
+    ('\u{FDF0}', 1031),
+    ('\u{FDF1}', 1080),
+    ('\u{FDF2}', 513),
+    ('\u{FDF3}', 512),
+    ('\u{FDF4}', 1129),
+    ('\u{FDF5}', 1030),
+    ('\u{FDF6}', 987),
+    ('\u{FDF7}', 1059),
+    ('\u{FDF8}', 1161),
+    ('\u{FDF9}', 1031),
+    ('\u{FDFA}', 1032),
+    ('\u{FDFB}', 957),
+    ('\u{FDFC}', 988),
+    ('︙', 2001),
+    ('︰', 257),
+    ('︱', 1962),
+    ('︴', 1940),
+    ('︵', 1953),
+    ('︶', 1954),
+    ('︷', 1955),
+    ('︸', 1956),
+    ('︹', 1957),
+    ('︺', 1958),
+    ('﹉', 786),
+    ('﹊', 786),
+    ('﹋', 786),
+    ('﹌', 786),
+    ('﹍', 389),
+    ('﹎', 389),
+    ('﹏', 389),
+    ('﹘', 174),
+    ('﹨', 385),
+    ('\u{FE80}', 915),
+    ('\u{FE81}', 917),
+    ('\u{FE82}', 917),
+    ('\u{FE83}', 515),
+    ('\u{FE84}', 515),
+    ('\u{FE85}', 1163),
+    ('\u{FE86}', 1163),
+    ('\u{FE87}', 514),
+    ('\u{FE88}', 514),
+    ('\u{FE89}', 1185),
+    ('\u{FE8A}', 1185),
+    ('\u{FE8B}', 1185),
+    ('\u{FE8C}', 1185),
+    ('\u{FE8D}', 456),
+    ('\u{FE8E}', 456),
+    ('\u{FE8F}', 918),
+    ('\u{FE90}', 918),
+    ('\u{FE91}', 918),
+    ('\u{FE92}', 918),
+    ('\u{FE93}', 933),
+    ('\u{FE94}', 933),
+    ('\u{FE95}', 934),
+    ('\u{FE96}', 934),
+    ('\u{FE97}', 934),
+    ('\u{FE98}', 934),
+    ('\u{FE99}', 1201),
+    ('\u{FE9A}', 1201),
+    ('\u{FE9B}', 1201),
+    ('\u{FE9C}', 1201),
+    ('\u{FE9D}', 954),
+    ('\u{FE9E}', 954),
+    ('\u{FE9F}', 954),
+    ('\u{FEA0}', 954),
+    ('\u{FEA1}', 962),
+    ('\u{FEA2}', 962),
+    ('\u{FEA3}', 962),
+    ('\u{FEA4}', 962),
+    ('\u{FEA5}', 970),
+    ('\u{FEA6}', 970),
+    ('\u{FEA7}', 970),
+    ('\u{FEA8}', 970),
+    ('\u{FEA9}', 975),
+    ('\u{FEAA}', 975),
+    ('\u{FEAB}', 980),
+    ('\u{FEAC}', 980),
+    ('\u{FEAD}', 982),
+    ('\u{FEAE}', 982),
+    ('\u{FEAF}', 992),
+    ('\u{FEB0}', 992),
+    ('\u{FEB1}', 994),
+    ('\u{FEB2}', 994),
+    ('\u{FEB3}', 994),
+    ('\u{FEB4}', 994),
+    ('\u{FEB5}', 1010),
+    ('\u{FEB6}', 1010),
+    ('\u{FEB7}', 1010),
+    ('\u{FEB8}', 1010),
+    ('\u{FEB9}', 1023),
+    ('\u{FEBA}', 1023),
+    ('\u{FEBB}', 1023),
+    ('\u{FEBC}', 1023),
+    ('\u{FEBD}', 1037),
+    ('\u{FEBE}', 1037),
+    ('\u{FEBF}', 1037),
+    ('\u{FEC0}', 1037),
+    ('\u{FEC1}', 1046),
+    ('\u{FEC2}', 1046),
+    ('\u{FEC3}', 1046),
+    ('\u{FEC4}', 1046),
+    ('\u{FEC5}', 1054),
+    ('\u{FEC6}', 1054),
+    ('\u{FEC7}', 1054),
+    ('\u{FEC8}', 1054),
+    ('\u{FEC9}', 1056),
+    ('\u{FECA}', 1056),
+    ('\u{FECB}', 1056),
+    ('\u{FECC}', 1056),
+    ('\u{FECD}', 1064),
+    ('\u{FECE}', 1064),
+    ('\u{FECF}', 1064),
+    ('\u{FED0}', 1064),
+    ('\u{FED1}', 1070),
+    ('\u{FED2}', 1070),
+    ('\u{FED3}', 1070),
+    ('\u{FED4}', 1070),
+    ('\u{FED5}', 1078),
+    ('\u{FED6}', 1078),
+    ('\u{FED7}', 1078),
+    ('\u{FED8}', 1078),
+    ('\u{FED9}', 1086),
+    ('\u{FEDA}', 1086),
+    ('\u{FEDB}', 1086),
+    ('\u{FEDC}', 1086),
+    ('\u{FEDD}', 1097),
+    ('\u{FEDE}', 1097),
+    ('\u{FEDF}', 1097),
+    ('\u{FEE0}', 1097),
+    ('\u{FEE1}', 1118),
+    ('\u{FEE2}', 1118),
+    ('\u{FEE3}', 1118),
+    ('\u{FEE4}', 1118),
+    ('\u{FEE5}', 1139),
+    ('\u{FEE6}', 1139),
+    ('\u{FEE7}', 1139),
+    ('\u{FEE8}', 1139),
+    ('\u{FEE9}', 524),
+    ('\u{FEEA}', 524),
+    ('\u{FEEB}', 524),
+    ('\u{FEEC}', 524),
+    ('\u{FEED}', 1156),
+    ('\u{FEEE}', 1156),
+    ('\u{FEEF}', 1165),
+    ('\u{FEF0}', 1165),
+    ('\u{FEF1}', 1165),
+    ('\u{FEF2}', 1165),
+    ('\u{FEF3}', 1165),
+    ('\u{FEF4}', 1165),
+    ('\u{FEF5}', 1103),
+    ('\u{FEF6}', 1103),
+    ('\u{FEF7}', 1100),
+    ('\u{FEF8}', 1100),
+    ('\u{FEF9}', 1099),
+    ('\u{FEFA}', 1099),
+    ('\u{FEFB}', 1098),
+    ('\u{FEFC}', 1098),
+    ('！', 1),
+    ('＂', 7),
+    ('＇', 6),
+    ('－', 2006),
+    ('：', 257),
+    ('Ａ', 283),
+    ('Ｂ', 291),
+    ('Ｃ', 292),
+    ('Ｅ', 303),
+    ('Ｈ', 311),
+    ('Ｉ', 456),
+    ('Ｊ', 315),
+    ('Ｋ', 318),
+    ('Ｍ', 326),
+    ('Ｎ', 329),
+    ('Ｏ', 335),
+    ('Ｐ', 345),
+    ('Ｓ', 351),
+    ('Ｔ', 352),
+    ('Ｘ', 375),
+    ('Ｙ', 380),
+    ('Ｚ', 382),
+    ('［', 16),
+    ('＼', 385),
+    ('］', 162),
+    ('＾', 3149),
+    ('｀', 6),
+    ('ａ', 390),
+    ('ｃ', 409),
+    ('ｅ', 426),
+    ('ｇ', 438),
+    ('ｈ', 441),
+    ('ｉ', 444),
+    ('ｊ', 452),
+    ('ｌ', 456),
+    ('ｏ', 524),
+    ('ｐ', 541),
+    ('ｓ', 557),
+    ('ｖ', 575),
+    ('ｘ', 583),
+    ('ｙ', 587),
+    ('｜', 1962),
+    ('～', 2003),
+    ('･', 610),
+    ('￣', 786),
+    ('￨', 456),
+    ('￭', 1969),
+    ('𐄁', 610),
+    ('𐆎', 333),
+    ('𐆖', 379),
+    ('𐆗', 371),
+    ('𐆘', 510),
+    ('𐆙', 509),
+    ('𐆠', 1998),
+    ('𐊂', 291),
+    ('𐊅', 837),
+    ('𐊆', 303),
+    ('𐊇', 305),
+    ('𐊊', 456),
+    ('𐊍', 749),
+    ('𐊐', 375),
+    ('𐊒', 335),
+    ('𐊔', 1852),
+    ('𐊕', 345),
+    ('𐊖', 351),
+    ('𐊗', 352),
+    ('𐊛', 165),
+    ('𐊠', 283),
+    ('𐊡', 291),
+    ('𐊢', 292),
+    ('𐊣', 837),
+    ('𐊥', 305),
+    ('𐊫', 335),
+    ('𐊭', 858),
+    ('𐊰', 326),
+    ('𐊱', 352),
+    ('𐊲', 380),
+    ('𐊳', 843),
+    ('𐊴', 375),
+    ('𐊵', 844),
+    ('𐊶', 845),
+    ('𐊸', 1999),
+    ('𐋏', 311),
+    ('𐋡', 975),
+    ('𐋤', 1156),
+    ('𐋨', 1046),
+    ('𐋲', 1023),
+    ('𐋵', 382),
+    ('𐌁', 291),
+    ('𐌂', 292),
+    ('𐌉', 456),
+    ('𐌑', 326),
+    ('𐌒', 858),
+    ('𐌕', 352),
+    ('𐌗', 375),
+    ('𐌚', 245),
+    ('𐌟', 164),
+    ('𐌠', 456),
+    ('𐌢', 375),
+    ('𐏑', 3164),
+    ('𐏓', 3165),
+    ('𐐁', 735),
+    ('𐐄', 335),
+    ('𐐑', 3123),
+    ('𐐕', 292),
+    ('𐐛', 322),
+    ('𐐟', 1994),
+    ('𐐠', 351),
+    ('𐐣', 732),
+    ('𐐥', 865),
+    ('𐐩', 3135),
+    ('𐐪', 778),
+    ('𐐬', 524),
+    ('𐐽', 409),
+    ('𐐿', 763),
+    ('𐑂', 759),
+    ('𐑃', 782),
+    ('𐑈', 557),
+    ('𐑋', 754),
+    ('𐑍', 1884),
+    ('𐒠', 3169),
+    ('𐒰', 749),
+    ('𐒴', 349),
+    ('𐒼', 882),
+    ('𐓂', 335),
+    ('𐓃', 776),
+    ('𐓄', 708),
+    ('𐓍', 861),
+    ('𐓎', 361),
+    ('𐓐', 1855),
+    ('𐓑', 844),
+    ('𐓒', 239),
+    ('𐓘', 773),
+    ('𐓛', 847),
+    ('𐓪', 524),
+    ('𐓫', 3126),
+    ('𐓶', 571),
+    ('𐓹', 854),
+    ('𐔓', 329),
+    ('𐔖', 335),
+    ('𐔘', 318),
+    ('𐔜', 292),
+    ('𐔝', 365),
+    ('𐔥', 305),
+    ('𐔦', 322),
+    ('𐔧', 375),
+    ('\u{10A3A}', 812),
+    ('\u{10A50}', 180),
+    ('\u{10A57}', 3171),
+    ('\u{10CFA}', 3173),
+    ('\u{10CFC}', 3172),
+    ('𑂻', 1270),
+    ('𑇇', 1270),
+    ('\u{111CA}', 812),
+    ('\u{111CB}', 1258),
+    ('𑇛', 3139),
+    ('𑇜', 3138),
+    ('𑇞', 1924),
+    ('\u{11300}', 803),
+    ('𑐓', 3174),
+    ('𑐙', 3175),
+    ('𑐤', 3176),
+    ('𑐪', 3177),
+    ('𑐭', 3178),
+    ('𑐯', 3179),
+    ('𑑌', 3180),
+    ('𑒒', 1275),
+    ('𑒔', 1276),
+    ('𑒖', 1277),
+    ('𑒘', 1278),
+    ('𑒙', 1279),
+    ('𑒛', 1280),
+    ('𑒝', 1292),
+    ('𑒞', 1282),
+    ('𑒟', 1283),
+    ('𑒠', 1284),
+    ('𑒡', 1285),
+    ('𑒢', 1286),
+    ('𑒣', 1287),
+    ('𑒧', 1289),
+    ('𑒨', 1290),
+    ('𑒩', 1288),
+    ('𑒪', 1281),
+    ('𑒫', 1291),
+    ('𑒭', 1293),
+    ('𑒮', 1294),
+    ('\u{114B0}', 1296),
+    ('\u{114B1}', 1297),
+    ('\u{114B9}', 1298),
+    ('\u{114BC}', 1299),
+    ('\u{114BD}', 1302),
+    ('\u{114BE}', 1300),
+    ('\u{114BF}', 799),
+    ('\u{114C1}', 1272),
+    ('\u{114C2}', 1301),
+    ('\u{114C3}', 812),
+    ('𑓄', 1295),
+    ('𑓅', 580),
+    ('𑓐', 335),
+    ('𑓑', 1303),
+    ('𑓒', 1304),
+    ('𑓖', 1305),
+    ('𑗘', 3181),
+    ('𑗙', 3181),
+    ('𑗚', 3182),
+    ('𑗛', 3183),
+    ('\u{115DC}', 3184),
+    ('\u{115DD}', 3185),
+    ('𑙂', 3186),
+    ('𑜀', 549),
+    ('𑜆', 575),
+    ('𑜊', 579),
+    ('𑜎', 579),
+    ('𑜏', 579),
+    ('𑢠', 365),
+    ('𑢢', 305),
+    ('𑢣', 322),
+    ('𑢤', 380),
+    ('𑢦', 303),
+    ('𑢨', 1912),
+    ('𑢩', 382),
+    ('𑢬', 251),
+    ('𑢮', 303),
+    ('𑢯', 220),
+    ('𑢲', 322),
+    ('𑢵', 335),
+    ('𑢷', 1852),
+    ('𑢸', 361),
+    ('𑢻', 227),
+    ('𑢼', 352),
+    ('𑣀', 575),
+    ('𑣁', 557),
+    ('𑣂', 305),
+    ('𑣃', 444),
+    ('𑣄', 590),
+    ('𑣆', 239),
+    ('𑣈', 524),
+    ('𑣊', 211),
+    ('𑣌', 251),
+    ('𑣎', 3135),
+    ('𑣕', 233),
+    ('𑣖', 251),
+    ('𑣗', 524),
+    ('𑣘', 571),
+    ('𑣜', 587),
+    ('𑣠', 335),
+    ('𑣣', 549),
+    ('𑣤', 1218),
+    ('𑣥', 382),
+    ('𑣦', 373),
+    ('𑣩', 292),
+    ('𑣬', 375),
+    ('𑣯', 373),
+    ('𑣲', 292),
+    ('𑫦', 3190),
+    ('𑫧', 3191),
+    ('𑫨', 3187),
+    ('𑫩', 3188),
+    ('𑫪', 3189),
+    ('𑫬', 3194),
+    ('𑫭', 3192),
+    ('𑫮', 3193),
+    ('𑫴', 3195),
+    ('𑫵', 3196),
+    ('𑫶', 3197),
+    ('𑫷', 3198),
+    ('𑫸', 3199),
+    ('𑱂', 3200),
+    ('\u{11CB2}', 3201),
+    ('𒀸', 3166),
+    ('𓋹', 3170),
+    ('𖼇', 833),
+    ('𖼈', 365),
+    ('𖼊', 352),
+    ('𖼖', 322),
+    ('𖼚', 837),
+    ('𖼜', 3125),
+    ('𖼦', 3123),
+    ('𖼨', 456),
+    ('𖼭', 735),
+    ('𖼵', 349),
+    ('𖼺', 351),
+    ('𖼻', 211),
+    ('𖼽', 749),
+    ('𖼿', 275),
+    ('𖽀', 283),
+    ('𖽂', 361),
+    ('𖽃', 380),
+    ('\u{16F51}', 6),
+    ('\u{16F52}', 6),
+    ('\u{1CCD6}', 283),
+    ('\u{1CCD7}', 291),
+    ('\u{1CCD8}', 292),
+    ('\u{1CCD9}', 297),
+    ('\u{1CCDA}', 303),
+    ('\u{1CCDB}', 305),
+    ('\u{1CCDC}', 308),
+    ('\u{1CCDD}', 311),
+    ('\u{1CCDE}', 456),
+    ('\u{1CCDF}', 315),
+    ('\u{1CCE0}', 318),
+    ('\u{1CCE1}', 322),
+    ('\u{1CCE2}', 326),
+    ('\u{1CCE3}', 329),
+    ('\u{1CCE4}', 335),
+    ('\u{1CCE5}', 345),
+    ('\u{1CCE6}', 347),
+    ('\u{1CCE7}', 349),
+    ('\u{1CCE8}', 351),
+    ('\u{1CCE9}', 352),
+    ('\u{1CCEA}', 361),
+    ('\u{1CCEB}', 365),
+    ('\u{1CCEC}', 373),
+    ('\u{1CCED}', 375),
+    ('\u{1CCEE}', 380),
+    ('\u{1CCEF}', 382),
+    ('\u{1CCF0}', 335),
+    ('\u{1CCF1}', 456),
+    ('\u{1CCF2}', 188),
+    ('\u{1CCF3}', 211),
+    ('\u{1CCF4}', 220),
+    ('\u{1CCF5}', 227),
+    ('\u{1CCF6}', 233),
+    ('\u{1CCF7}', 239),
+    ('\u{1CCF8}', 245),
+    ('\u{1CCF9}', 251),
+    ('𝄔', 595),
+    ('\u{1D16D}', 180),
+    ('𝈂', 883),
+    ('𝈆', 211),
+    ('𝈋', 865),
+    ('𝈍', 365),
+    ('𝈏', 385),
+    ('𝈒', 239),
+    ('𝈓', 305),
+    ('𝈔', 3162),
+    ('𝈕', 3123),
+    ('𝈖', 349),
+    ('𝈗', 1993),
+    ('𝈚', 341),
+    ('𝈛', 1901),
+    ('𝈜', 3120),
+    ('𝈡', 735),
+    ('𝈢', 876),
+    ('𝈪', 322),
+    ('𝈫', 3123),
+    ('𝈰', 3137),
+    ('𝈶', 261),
+    ('𝈷', 275),
+    ('𝈸', 1929),
+    ('𝈹', 1930),
+    ('𝈺', 184),
+    ('𝈻', 385),
+    ('𝈿', 1846),
+    ('𝉅', 884),
+    ('𝐀', 283),
+    ('𝐁', 291),
+    ('𝐂', 292),
+    ('𝐃', 297),
+    ('𝐄', 303),
+    ('𝐅', 305),
+    ('𝐆', 308),
+    ('𝐇', 311),
+    ('𝐈', 456),
+    ('𝐉', 315),
+    ('𝐊', 318),
+    ('𝐋', 322),
+    ('𝐌', 326),
+    ('𝐍', 329),
+    ('𝐎', 335),
+    ('𝐏', 345),
+    ('𝐐', 347),
+    ('𝐑', 349),
+    ('𝐒', 351),
+    ('𝐓', 352),
+    ('𝐔', 361),
+    ('𝐕', 365),
+    ('𝐖', 373),
+    ('𝐗', 375),
+    ('𝐘', 380),
+    ('𝐙', 382),
+    ('𝐚', 390),
+    ('𝐛', 400),
+    ('𝐜', 409),
+    ('𝐝', 414),
+    ('𝐞', 426),
+    ('𝐟', 429),
+    ('𝐠', 438),
+    ('𝐡', 441),
+    ('𝐢', 444),
+    ('𝐣', 452),
+    ('𝐤', 454),
+    ('𝐥', 456),
+    ('𝐦', 549),
+    ('𝐧', 519),
+    ('𝐨', 524),
+    ('𝐩', 541),
+    ('𝐪', 545),
+    ('𝐫', 547),
+    ('𝐬', 557),
+    ('𝐭', 562),
+    ('𝐮', 571),
+    ('𝐯', 575),
+    ('𝐰', 579),
+    ('𝐱', 583),
+    ('𝐲', 587),
+    ('𝐳', 590),
+    ('𝐴', 283),
+    ('𝐵', 291),
+    ('𝐶', 292),
+    ('𝐷', 297),
+    ('𝐸', 303),
+    ('𝐹', 305),
+    ('𝐺', 308),
+    ('𝐻', 311),
+    ('𝐼', 456),
+    ('𝐽', 315),
+    ('𝐾', 318),
+    ('𝐿', 322),
+    ('𝑀', 326),
+    ('𝑁', 329),
+    ('𝑂', 335),
+    ('𝑃', 345),
+    ('𝑄', 347),
+    ('𝑅', 349),
+    ('𝑆', 351),
+    ('𝑇', 352),
+    ('𝑈', 361),
+    ('𝑉', 365),
+    ('𝑊', 373),
+    ('𝑋', 375),
+    ('𝑌', 380),
+    ('𝑍', 382),
+    ('𝑎', 390),
+    ('𝑏', 400),
+    ('𝑐', 409),
+    ('𝑑', 414),
+    ('𝑒', 426),
+    ('𝑓', 429),
+    ('𝑔', 438),
+    ('𝑖', 444),
+    ('𝑗', 452),
+    ('𝑘', 454),
+    ('𝑙', 456),
+    ('𝑚', 549),
+    ('𝑛', 519),
+    ('𝑜', 524),
+    ('𝑝', 541),
+    ('𝑞', 545),
+    ('𝑟', 547),
+    ('𝑠', 557),
+    ('𝑡', 562),
+    ('𝑢', 571),
+    ('𝑣', 575),
+    ('𝑤', 579),
+    ('𝑥', 583),
+    ('𝑦', 587),
+    ('𝑧', 590),
+    ('𝑨', 283),
+    ('𝑩', 291),
+    ('𝑪', 292),
+    ('𝑫', 297),
+    ('𝑬', 303),
+    ('𝑭', 305),
+    ('𝑮', 308),
+    ('𝑯', 311),
+    ('𝑰', 456),
+    ('𝑱', 315),
+    ('𝑲', 318),
+    ('𝑳', 322),
+    ('𝑴', 326),
+    ('𝑵', 329),
+    ('𝑶', 335),
+    ('𝑷', 345),
+    ('𝑸', 347),
+    ('𝑹', 349),
+    ('𝑺', 351),
+    ('𝑻', 352),
+    ('𝑼', 361),
+    ('𝑽', 365),
+    ('𝑾', 373),
+    ('𝑿', 375),
+    ('𝒀', 380),
+    ('𝒁', 382),
+    ('𝒂', 390),
+    ('𝒃', 400),
+    ('𝒄', 409),
+    ('𝒅', 414),
+    ('𝒆', 426),
+    ('𝒇', 429),
+    ('𝒈', 438),
+    ('𝒉', 441),
+    ('𝒊', 444),
+    ('𝒋', 452),
+    ('𝒌', 454),
+    ('𝒍', 456),
+    ('𝒎', 549),
+    ('𝒏', 519),
+    ('𝒐', 524),
+    ('𝒑', 541),
+    ('𝒒', 545),
+    ('𝒓', 547),
+    ('𝒔', 557),
+    ('𝒕', 562),
+    ('𝒖', 571),
+    ('𝒗', 575),
+    ('𝒘', 579),
+    ('𝒙', 583),
+    ('𝒚', 587),
+    ('𝒛', 590),
+    ('𝒜', 283),
+    ('𝒞', 292),
+    ('𝒟', 297),
+    ('𝒢', 308),
+    ('𝒥', 315),
+    ('𝒦', 318),
+    ('𝒩', 329),
+    ('𝒪', 335),
+    ('𝒫', 345),
+    ('𝒬', 347),
+    ('𝒮', 351),
+    ('𝒯', 352),
+    ('𝒰', 361),
+    ('𝒱', 365),
+    ('𝒲', 373),
+    ('𝒳', 375),
+    ('𝒴', 380),
+    ('𝒵', 382),
+    ('𝒶', 390),
+    ('𝒷', 400),
+    ('𝒸', 409),
+    ('𝒹', 414),
+    ('𝒻', 429),
+    ('𝒽', 441),
+    ('𝒾', 444),
+    ('𝒿', 452),
+    ('𝓀', 454),
+    ('𝓁', 456),
+    ('𝓂', 549),
+    ('𝓃', 519),
+    ('𝓅', 541),
+    ('𝓆', 545),
+    ('𝓇', 547),
+    ('𝓈', 557),
+    ('𝓉', 562),
+    ('𝓊', 571),
+    ('𝓋', 575),
+    ('𝓌', 579),
+    ('𝓍', 583),
+    ('𝓎', 587),
+    ('𝓏', 590),
+    ('𝓐', 283),
+    ('𝓑', 291),
+    ('𝓒', 292),
+    ('𝓓', 297),
+    ('𝓔', 303),
+    ('𝓕', 305),
+    ('𝓖', 308),
+    ('𝓗', 311),
+    ('𝓘', 456),
+    ('𝓙', 315),
+    ('𝓚', 318),
+    ('𝓛', 322),
+    ('𝓜', 326),
+    ('𝓝', 329),
+    ('𝓞', 335),
+    ('𝓟', 345),
+    ('𝓠', 347),
+    ('𝓡', 349),
+    ('𝓢', 351),
+    ('𝓣', 352),
+    ('𝓤', 361),
+    ('𝓥', 365),
+    ('𝓦', 373),
+    ('𝓧', 375),
+    ('𝓨', 380),
+    ('𝓩', 382),
+    ('𝓪', 390),
+    ('𝓫', 400),
+    ('𝓬', 409),
+    ('𝓭', 414),
+    ('𝓮', 426),
+    ('𝓯', 429),
+    ('𝓰', 438),
+    ('𝓱', 441),
+    ('𝓲', 444),
+    ('𝓳', 452),
+    ('𝓴', 454),
+    ('𝓵', 456),
+    ('𝓶', 549),
+    ('𝓷', 519),
+    ('𝓸', 524),
+    ('𝓹', 541),
+    ('𝓺', 545),
+    ('𝓻', 547),
+    ('𝓼', 557),
+    ('𝓽', 562),
+    ('𝓾', 571),
+    ('𝓿', 575),
+    ('𝔀', 579),
+    ('𝔁', 583),
+    ('𝔂', 587),
+    ('𝔃', 590),
+    ('𝔄', 283),
+    ('𝔅', 291),
+    ('𝔇', 297),
+    ('𝔈', 303),
+    ('𝔉', 305),
+    ('𝔊', 308),
+    ('𝔍', 315),
+    ('𝔎', 318),
+    ('𝔏', 322),
+    ('𝔐', 326),
+    ('𝔑', 329),
+    ('𝔒', 335),
+    ('𝔓', 345),
+    ('𝔔', 347),
+    ('𝔖', 351),
+    ('𝔗', 352),
+    ('𝔘', 361),
+    ('𝔙', 365),
+    ('𝔚', 373),
+    ('𝔛', 375),
+    ('𝔜', 380),
+    ('𝔞', 390),
+    ('𝔟', 400),
+    ('𝔠', 409),
+    ('𝔡', 414),
+    ('𝔢', 426),
+    ('𝔣', 429),
+    ('𝔤', 438),
+    ('𝔥', 441),
+    ('𝔦', 444),
+    ('𝔧', 452),
+    ('𝔨', 454),
+    ('𝔩', 456),
+    ('𝔪', 549),
+    ('𝔫', 519),
+    ('𝔬', 524),
+    ('𝔭', 541),
+    ('𝔮', 545),
+    ('𝔯', 547),
+    ('𝔰', 557),
+    ('𝔱', 562),
+    ('𝔲', 571),
+    ('𝔳', 575),
+    ('𝔴', 579),
+    ('𝔵', 583),
+    ('𝔶', 587),
+    ('𝔷', 590),
+    ('𝔸', 283),
+    ('𝔹', 291),
+    ('𝔻', 297),
+    ('𝔼', 303),
+    ('𝔽', 305),
+    ('𝔾', 308),
+    ('𝕀', 456),
+    ('𝕁', 315),
+    ('𝕂', 318),
+    ('𝕃', 322),
+    ('𝕄', 326),
+    ('𝕆', 335),
+    ('𝕊', 351),
+    ('𝕋', 352),
+    ('𝕌', 361),
+    ('𝕍', 365),
+    ('𝕎', 373),
+    ('𝕏', 375),
+    ('𝕐', 380),
+    ('𝕒', 390),
+    ('𝕓', 400),
+    ('𝕔', 409),
+    ('𝕕', 414),
+    ('𝕖', 426),
+    ('𝕗', 429),
+    ('𝕘', 438),
+    ('𝕙', 441),
+    ('𝕚', 444),
+    ('𝕛', 452),
+    ('𝕜', 454),
+    ('𝕝', 456),
+    ('𝕞', 549),
+    ('𝕟', 519),
+    ('𝕠', 524),
+    ('𝕡', 541),
+    ('𝕢', 545),
+    ('𝕣', 547),
+    ('𝕤', 557),
+    ('𝕥', 562),
+    ('𝕦', 571),
+    ('𝕧', 575),
+    ('𝕨', 579),
+    ('𝕩', 583),
+    ('𝕪', 587),
+    ('𝕫', 590),
+    ('𝕬', 283),
+    ('𝕭', 291),
+    ('𝕮', 292),
+    ('𝕯', 297),
+    ('𝕰', 303),
+    ('𝕱', 305),
+    ('𝕲', 308),
+    ('𝕳', 311),
+    ('𝕴', 456),
+    ('𝕵', 315),
+    ('𝕶', 318),
+    ('𝕷', 322),
+    ('𝕸', 326),
+    ('𝕹', 329),
+    ('𝕺', 335),
+    ('𝕻', 345),
+    ('𝕼', 347),
+    ('𝕽', 349),
+    ('𝕾', 351),
+    ('𝕿', 352),
+    ('𝖀', 361),
+    ('𝖁', 365),
+    ('𝖂', 373),
+    ('𝖃', 375),
+    ('𝖄', 380),
+    ('𝖅', 382),
+    ('𝖆', 390),
+    ('𝖇', 400),
+    ('𝖈', 409),
+    ('𝖉', 414),
+    ('𝖊', 426),
+    ('𝖋', 429),
+    ('𝖌', 438),
+    ('𝖍', 441),
+    ('𝖎', 444),
+    ('𝖏', 452),
+    ('𝖐', 454),
+    ('𝖑', 456),
+    ('𝖒', 549),
+    ('𝖓', 519),
+    ('𝖔', 524),
+    ('𝖕', 541),
+    ('𝖖', 545),
+    ('𝖗', 547),
+    ('𝖘', 557),
+    ('𝖙', 562),
+    ('𝖚', 571),
+    ('𝖛', 575),
+    ('𝖜', 579),
+    ('𝖝', 583),
+    ('𝖞', 587),
+    ('𝖟', 590),
+    ('𝖠', 283),
+    ('𝖡', 291),
+    ('𝖢', 292),
+    ('𝖣', 297),
+    ('𝖤', 303),
+    ('𝖥', 305),
+    ('𝖦', 308),
+    ('𝖧', 311),
+    ('𝖨', 456),
+    ('𝖩', 315),
+    ('𝖪', 318),
+    ('𝖫', 322),
+    ('𝖬', 326),
+    ('𝖭', 329),
+    ('𝖮', 335),
+    ('𝖯', 345),
+    ('𝖰', 347),
+    ('𝖱', 349),
+    ('𝖲', 351),
+    ('𝖳', 352),
+    ('𝖴', 361),
+    ('𝖵', 365),
+    ('𝖶', 373),
+    ('𝖷', 375),
+    ('𝖸', 380),
+    ('𝖹', 382),
+    ('𝖺', 390),
+    ('𝖻', 400),
+    ('𝖼', 409),
+    ('𝖽', 414),
+    ('𝖾', 426),
+    ('𝖿', 429),
+    ('𝗀', 438),
+    ('𝗁', 441),
+    ('𝗂', 444),
+    ('𝗃', 452),
+    ('𝗄', 454),
+    ('𝗅', 456),
+    ('𝗆', 549),
+    ('𝗇', 519),
+    ('𝗈', 524),
+    ('𝗉', 541),
+    ('𝗊', 545),
+    ('𝗋', 547),
+    ('𝗌', 557),
+    ('𝗍', 562),
+    ('𝗎', 571),
+    ('𝗏', 575),
+    ('𝗐', 579),
+    ('𝗑', 583),
+    ('𝗒', 587),
+    ('𝗓', 590),
+    ('𝗔', 283),
+    ('𝗕', 291),
+    ('𝗖', 292),
+    ('𝗗', 297),
+    ('𝗘', 303),
+    ('𝗙', 305),
+    ('𝗚', 308),
+    ('𝗛', 311),
+    ('𝗜', 456),
+    ('𝗝', 315),
+    ('𝗞', 318),
+    ('𝗟', 322),
+    ('𝗠', 326),
+    ('𝗡', 329),
+    ('𝗢', 335),
+    ('𝗣', 345),
+    ('𝗤', 347),
+    ('𝗥', 349),
+    ('𝗦', 351),
+    ('𝗧', 352),
+    ('𝗨', 361),
+    ('𝗩', 365),
+    ('𝗪', 373),
+    ('𝗫', 375),
+    ('𝗬', 380),
+    ('𝗭', 382),
+    ('𝗮', 390),
+    ('𝗯', 400),
+    ('𝗰', 409),
+    ('𝗱', 414),
+    ('𝗲', 426),
+    ('𝗳', 429),
+    ('𝗴', 438),
+    ('𝗵', 441),
+    ('𝗶', 444),
+    ('𝗷', 452),
+    ('𝗸', 454),
+    ('𝗹', 456),
+    ('𝗺', 549),
+    ('𝗻', 519),
+    ('𝗼', 524),
+    ('𝗽', 541),
+    ('𝗾', 545),
+    ('𝗿', 547),
+    ('𝘀', 557),
+    ('𝘁', 562),
+    ('𝘂', 571),
+    ('𝘃', 575),
+    ('𝘄', 579),
+    ('𝘅', 583),
+    ('𝘆', 587),
+    ('𝘇', 590),
+    ('𝘈', 283),
+    ('𝘉', 291),
+    ('𝘊', 292),
+    ('𝘋', 297),
+    ('𝘌', 303),
+    ('𝘍', 305),
+    ('𝘎', 308),
+    ('𝘏', 311),
+    ('𝘐', 456),
+    ('𝘑', 315),
+    ('𝘒', 318),
+    ('𝘓', 322),
+    ('𝘔', 326),
+    ('𝘕', 329),
+    ('𝘖', 335),
+    ('𝘗', 345),
+    ('𝘘', 347),
+    ('𝘙', 349),
+    ('𝘚', 351),
+    ('𝘛', 352),
+    ('𝘜', 361),
+    ('𝘝', 365),
+    ('𝘞', 373),
+    ('𝘟', 375),
+    ('𝘠', 380),
+    ('𝘡', 382),
+    ('𝘢', 390),
+    ('𝘣', 400),
+    ('𝘤', 409),
+    ('𝘥', 414),
+    ('𝘦', 426),
+    ('𝘧', 429),
+    ('𝘨', 438),
+    ('𝘩', 441),
+    ('𝘪', 444),
+    ('𝘫', 452),
+    ('𝘬', 454),
+    ('𝘭', 456),
+    ('𝘮', 549),
+    ('𝘯', 519),
+    ('𝘰', 524),
+    ('𝘱', 541),
+    ('𝘲', 545),
+    ('𝘳', 547),
+    ('𝘴', 557),
+    ('𝘵', 562),
+    ('𝘶', 571),
+    ('𝘷', 575),
+    ('𝘸', 579),
+    ('𝘹', 583),
+    ('𝘺', 587),
+    ('𝘻', 590),
+    ('𝘼', 283),
+    ('𝘽', 291),
+    ('𝘾', 292),
+    ('𝘿', 297),
+    ('𝙀', 303),
+    ('𝙁', 305),
+    ('𝙂', 308),
+    ('𝙃', 311),
+    ('𝙄', 456),
+    ('𝙅', 315),
+    ('𝙆', 318),
+    ('𝙇', 322),
+    ('𝙈', 326),
+    ('𝙉', 329),
+    ('𝙊', 335),
+    ('𝙋', 345),
+    ('𝙌', 347),
+    ('𝙍', 349),
+    ('𝙎', 351),
+    ('𝙏', 352),
+    ('𝙐', 361),
+    ('𝙑', 365),
+    ('𝙒', 373),
+    ('𝙓', 375),
+    ('𝙔', 380),
+    ('𝙕', 382),
+    ('𝙖', 390),
+    ('𝙗', 400),
+    ('𝙘', 409),
+    ('𝙙', 414),
+    ('𝙚', 426),
+    ('𝙛', 429),
+    ('𝙜', 438),
+    ('𝙝', 441),
+    ('𝙞', 444),
+    ('𝙟', 452),
+    ('𝙠', 454),
+    ('𝙡', 456),
+    ('𝙢', 549),
+    ('𝙣', 519),
+    ('𝙤', 524),
+    ('𝙥', 541),
+    ('𝙦', 545),
+    ('𝙧', 547),
+    ('𝙨', 557),
+    ('𝙩', 562),
+    ('𝙪', 571),
+    ('𝙫', 575),
+    ('𝙬', 579),
+    ('𝙭', 583),
+    ('𝙮', 587),
+    ('𝙯', 590),
+    ('𝙰', 283),
+    ('𝙱', 291),
+    ('𝙲', 292),
+    ('𝙳', 297),
+    ('𝙴', 303),
+    ('𝙵', 305),
+    ('𝙶', 308),
+    ('𝙷', 311),
+    ('𝙸', 456),
+    ('𝙹', 315),
+    ('𝙺', 318),
+    ('𝙻', 322),
+    ('𝙼', 326),
+    ('𝙽', 329),
+    ('𝙾', 335),
+    ('𝙿', 345),
+    ('𝚀', 347),
+    ('𝚁', 349),
+    ('𝚂', 351),
+    ('𝚃', 352),
+    ('𝚄', 361),
+    ('𝚅', 365),
+    ('𝚆', 373),
+    ('𝚇', 375),
+    ('𝚈', 380),
+    ('𝚉', 382),
+    ('𝚊', 390),
+    ('𝚋', 400),
+    ('𝚌', 409),
+    ('𝚍', 414),
+    ('𝚎', 426),
+    ('𝚏', 429),
+    ('𝚐', 438),
+    ('𝚑', 441),
+    ('𝚒', 444),
+    ('𝚓', 452),
+    ('𝚔', 454),
+    ('𝚕', 456),
+    ('𝚖', 549),
+    ('𝚗', 519),
+    ('𝚘', 524),
+    ('𝚙', 541),
+    ('𝚚', 545),
+    ('𝚛', 547),
+    ('𝚜', 557),
+    ('𝚝', 562),
+    ('𝚞', 571),
+    ('𝚟', 575),
+    ('𝚠', 579),
+    ('𝚡', 583),
+    ('𝚢', 587),
+    ('𝚣', 590),
+    ('𝚤', 444),
+    ('𝚥', 747),
+    ('𝚨', 283),
+    ('𝚩', 291),
+    ('𝚪', 833),
+    ('𝚫', 837),
+    ('𝚬', 303),
+    ('𝚭', 382),
+    ('𝚮', 311),
+    ('𝚯', 341),
+    ('𝚰', 456),
+    ('𝚱', 318),
+    ('𝚲', 749),
+    ('𝚳', 326),
+    ('𝚴', 329),
+    ('𝚵', 841),
+    ('𝚶', 335),
+    ('𝚷', 842),
+    ('𝚸', 345),
+    ('𝚹', 341),
+    ('𝚺', 737),
+    ('𝚻', 352),
+    ('𝚼', 380),
+    ('𝚽', 843),
+    ('𝚾', 375),
+    ('𝚿', 844),
+    ('𝛀', 845),
+    ('𝛁', 1912),
+    ('𝛂', 390),
+    ('𝛃', 709),
+    ('𝛄', 587),
+    ('𝛅', 1893),
+    ('𝛆', 3135),
+    ('𝛇', 846),
+    ('𝛈', 522),
+    ('𝛉', 341),
+    ('𝛊', 444),
+    ('𝛋', 722),
+    ('𝛌', 847),
+    ('𝛍', 849),
+    ('𝛎', 575),
+    ('𝛏', 850),
+    ('𝛐', 524),
+    ('𝛑', 851),
+    ('𝛒', 541),
+    ('𝛓', 852),
+    ('𝛔', 524),
+    ('𝛕', 1887),
+    ('𝛖', 571),
+    ('𝛗', 764),
+    ('𝛘', 853),
+    ('𝛙', 854),
+    ('𝛚', 855),
+    ('𝛛', 1909),
+    ('𝛜', 3135),
+    ('𝛝', 341),
+    ('𝛞', 722),
+    ('𝛟', 764),
+    ('𝛠', 541),
+    ('𝛡', 851),
+    ('𝛢', 283),
+    ('𝛣', 291),
+    ('𝛤', 833),
+    ('𝛥', 837),
+    ('𝛦', 303),
+    ('𝛧', 382),
+    ('𝛨', 311),
+    ('𝛩', 341),
+    ('𝛪', 456),
+    ('𝛫', 318),
+    ('𝛬', 749),
+    ('𝛭', 326),
+    ('𝛮', 329),
+    ('𝛯', 841),
+    ('𝛰', 335),
+    ('𝛱', 842),
+    ('𝛲', 345),
+    ('𝛳', 341),
+    ('𝛴', 737),
+    ('𝛵', 352),
+    ('𝛶', 380),
+    ('𝛷', 843),
+    ('𝛸', 375),
+    ('𝛹', 844),
+    ('𝛺', 845),
+    ('𝛻', 1912),
+    ('𝛼', 390),
+    ('𝛽', 709),
+    ('𝛾', 587),
+    ('𝛿', 1893),
+    ('𝜀', 3135),
+    ('𝜁', 846),
+    ('𝜂', 522),
+    ('𝜃', 341),
+    ('𝜄', 444),
+    ('𝜅', 722),
+    ('𝜆', 847),
+    ('𝜇', 849),
+    ('𝜈', 575),
+    ('𝜉', 850),
+    ('𝜊', 524),
+    ('𝜋', 851),
+    ('𝜌', 541),
+    ('𝜍', 852),
+    ('𝜎', 524),
+    ('𝜏', 1887),
+    ('𝜐', 571),
+    ('𝜑', 764),
+    ('𝜒', 853),
+    ('𝜓', 854),
+    ('𝜔', 855),
+    ('𝜕', 1909),
+    ('𝜖', 3135),
+    ('𝜗', 341),
+    ('𝜘', 722),
+    ('𝜙', 764),
+    ('𝜚', 541),
+    ('𝜛', 851),
+    ('𝜜', 283),
+    ('𝜝', 291),
+    ('𝜞', 833),
+    ('𝜟', 837),
+    ('𝜠', 303),
+    ('𝜡', 382),
+    ('𝜢', 311),
+    ('𝜣', 341),
+    ('𝜤', 456),
+    ('𝜥', 318),
+    ('𝜦', 749),
+    ('𝜧', 326),
+    ('𝜨', 329),
+    ('𝜩', 841),
+    ('𝜪', 335),
+    ('𝜫', 842),
+    ('𝜬', 345),
+    ('𝜭', 341),
+    ('𝜮', 737),
+    ('𝜯', 352),
+    ('𝜰', 380),
+    ('𝜱', 843),
+    ('𝜲', 375),
+    ('𝜳', 844),
+    ('𝜴', 845),
+    ('𝜵', 1912),
+    ('𝜶', 390),
+    ('𝜷', 709),
+    ('𝜸', 587),
+    ('𝜹', 1893),
+    ('𝜺', 3135),
+    ('𝜻', 846),
+    ('𝜼', 522),
+    ('𝜽', 341),
+    ('𝜾', 444),
+    ('𝜿', 722),
+    ('𝝀', 847),
+    ('𝝁', 849),
+    ('𝝂', 575),
+    ('𝝃', 850),
+    ('𝝄', 524),
+    ('𝝅', 851),
+    ('𝝆', 541),
+    ('𝝇', 852),
+    ('𝝈', 524),
+    ('𝝉', 1887),
+    ('𝝊', 571),
+    ('𝝋', 764),
+    ('𝝌', 853),
+    ('𝝍', 854),
+    ('𝝎', 855),
+    ('𝝏', 1909),
+    ('𝝐', 3135),
+    ('𝝑', 341),
+    ('𝝒', 722),
+    ('𝝓', 764),
+    ('𝝔', 541),
+    ('𝝕', 851),
+    ('𝝖', 283),
+    ('𝝗', 291),
+    ('𝝘', 833),
+    ('𝝙', 837),
+    ('𝝚', 303),
+    ('𝝛', 382),
+    ('𝝜', 311),
+    ('𝝝', 341),
+    ('𝝞', 456),
+    ('𝝟', 318),
+    ('𝝠', 749),
+    ('𝝡', 326),
+    ('𝝢', 329),
+    ('𝝣', 841),
+    ('𝝤', 335),
+    ('𝝥', 842),
+    ('𝝦', 345),
+    ('𝝧', 341),
+    ('𝝨', 737),
+    ('𝝩', 352),
+    ('𝝪', 380),
+    ('𝝫', 843),
+    ('𝝬', 375),
+    ('𝝭', 844),
+    ('𝝮', 845),
+    ('𝝯', 1912),
+    ('𝝰', 390),
+    ('𝝱', 709),
+    ('𝝲', 587),
+    ('𝝳', 1893),
+    ('𝝴', 3135),
+    ('𝝵', 846),
+    ('𝝶', 522),
+    ('𝝷', 341),
+    ('𝝸', 444),
+    ('𝝹', 722),
+    ('𝝺', 847),
+    ('𝝻', 849),
+    ('𝝼', 575),
+    ('𝝽', 850),
+    ('𝝾', 524),
+    ('𝝿', 851),
+    ('𝞀', 541),
+    ('𝞁', 852),
+    ('𝞂', 524),
+    ('𝞃', 1887),
+    ('𝞄', 571),
+    ('𝞅', 764),
+    ('𝞆', 853),
+    ('𝞇', 854),
+    ('𝞈', 855),
+    ('𝞉', 1909),
+    ('𝞊', 3135),
+    ('𝞋', 341),
+    ('𝞌', 722),
+    ('𝞍', 764),
+    ('𝞎', 541),
+    ('𝞏', 851),
+    ('𝞐', 283),
+    ('𝞑', 291),
+    ('𝞒', 833),
+    ('𝞓', 837),
+    ('𝞔', 303),
+    ('𝞕', 382),
+    ('𝞖', 311),
+    ('𝞗', 341),
+    ('𝞘', 456),
+    ('𝞙', 318),
+    ('𝞚', 749),
+    ('𝞛', 326),
+    ('𝞜', 329),
+    ('𝞝', 841),
+    ('𝞞', 335),
+    ('𝞟', 842),
+    ('𝞠', 345),
+    ('𝞡', 341),
+    ('𝞢', 737),
+    ('𝞣', 352),
+    ('𝞤', 380),
+    ('𝞥', 843),
+    ('𝞦', 375),
+    ('𝞧', 844),
+    ('𝞨', 845),
+    ('𝞩', 1912),
+    ('𝞪', 390),
+    ('𝞫', 709),
+    ('𝞬', 587),
+    ('𝞭', 1893),
+    ('𝞮', 3135),
+    ('𝞯', 846),
+    ('𝞰', 522),
+    ('𝞱', 341),
+    ('𝞲', 444),
+    ('𝞳', 722),
+    ('𝞴', 847),
+    ('𝞵', 849),
+    ('𝞶', 575),
+    ('𝞷', 850),
+    ('𝞸', 524),
+    ('𝞹', 851),
+    ('𝞺', 541),
+    ('𝞻', 852),
+    ('𝞼', 524),
+    ('𝞽', 1887),
+    ('𝞾', 571),
+    ('𝞿', 764),
+    ('𝟀', 853),
+    ('𝟁', 854),
+    ('𝟂', 855),
+    ('𝟃', 1909),
+    ('𝟄', 3135),
+    ('𝟅', 341),
+    ('𝟆', 722),
+    ('𝟇', 764),
+    ('𝟈', 541),
+    ('𝟉', 851),
+    ('𝟊', 305),
+    ('𝟋', 859),
+    ('𝟎', 335),
+    ('𝟏', 456),
+    ('𝟐', 188),
+    ('𝟑', 211),
+    ('𝟒', 220),
+    ('𝟓', 227),
+    ('𝟔', 233),
+    ('𝟕', 239),
+    ('𝟖', 245),
+    ('𝟗', 251),
+    ('𝟘', 335),
+    ('𝟙', 456),
+    ('𝟚', 188),
+    ('𝟛', 211),
+    ('𝟜', 220),
+    ('𝟝', 227),
+    ('𝟞', 233),
+    ('𝟟', 239),
+    ('𝟠', 245),
+    ('𝟡', 251),
+    ('𝟢', 335),
+    ('𝟣', 456),
+    ('𝟤', 188),
+    ('𝟥', 211),
+    ('𝟦', 220),
+    ('𝟧', 227),
+    ('𝟨', 233),
+    ('𝟩', 239),
+    ('𝟪', 245),
+    ('𝟫', 251),
+    ('𝟬', 335),
+    ('𝟭', 456),
+    ('𝟮', 188),
+    ('𝟯', 211),
+    ('𝟰', 220),
+    ('𝟱', 227),
+    ('𝟲', 233),
+    ('𝟳', 239),
+    ('𝟴', 245),
+    ('𝟵', 251),
+    ('𝟶', 335),
+    ('𝟷', 456),
+    ('𝟸', 188),
+    ('𝟹', 211),
+    ('𝟺', 220),
+    ('𝟻', 227),
+    ('𝟼', 233),
+    ('𝟽', 239),
+    ('𝟾', 245),
+    ('𝟿', 251),
+    ('\u{1E8C7}', 456),
+    ('\u{1E8C8}', 1917),
+    ('\u{1E8C9}', 1215),
+    ('\u{1E8CB}', 245),
+    ('\u{1E8CC}', 1909),
+    ('\u{1E8CD}', 1910),
+    ('\u{1EE00}', 456),
+    ('\u{1EE01}', 918),
+    ('\u{1EE02}', 954),
+    ('\u{1EE03}', 975),
+    ('\u{1EE05}', 1156),
+    ('\u{1EE06}', 992),
+    ('\u{1EE07}', 962),
+    ('\u{1EE08}', 1046),
+    ('\u{1EE09}', 1165),
+    ('\u{1EE0A}', 1086),
+    ('\u{1EE0B}', 1097),
+    ('\u{1EE0C}', 1118),
+    ('\u{1EE0D}', 1139),
+    ('\u{1EE0E}', 994),
+    ('\u{1EE0F}', 1056),
+    ('\u{1EE10}', 1070),
+    ('\u{1EE11}', 1023),
+    ('\u{1EE12}', 1078),
+    ('\u{1EE13}', 982),
+    ('\u{1EE14}', 1010),
+    ('\u{1EE15}', 934),
+    ('\u{1EE16}', 1201),
+    ('\u{1EE17}', 970),
+    ('\u{1EE18}', 980),
+    ('\u{1EE19}', 1037),
+    ('\u{1EE1A}', 1054),
+    ('\u{1EE1B}', 1064),
+    ('\u{1EE1C}', 1165),
+    ('\u{1EE1D}', 1165),
+    ('\u{1EE1E}', 1233),
+    ('\u{1EE1F}', 1233),
+    ('\u{1EE21}', 918),
+    ('\u{1EE22}', 954),
+    ('\u{1EE24}', 524),
+    ('\u{1EE27}', 962),
+    ('\u{1EE29}', 1165),
+    ('\u{1EE2A}', 1086),
+    ('\u{1EE2B}', 1097),
+    ('\u{1EE2C}', 1118),
+    ('\u{1EE2D}', 1139),
+    ('\u{1EE2E}', 994),
+    ('\u{1EE2F}', 1056),
+    ('\u{1EE30}', 1070),
+    ('\u{1EE31}', 1023),
+    ('\u{1EE32}', 1078),
+    ('\u{1EE34}', 1010),
+    ('\u{1EE35}', 934),
+    ('\u{1EE36}', 1201),
+    ('\u{1EE37}', 970),
+    ('\u{1EE39}', 1037),
+    ('\u{1EE3B}', 1064),
+    ('\u{1EE42}', 954),
+    ('\u{1EE47}', 962),
+    ('\u{1EE49}', 1165),
+    ('\u{1EE4B}', 1097),
+    ('\u{1EE4D}', 1139),
+    ('\u{1EE4E}', 994),
+    ('\u{1EE4F}', 1056),
+    ('\u{1EE51}', 1023),
+    ('\u{1EE52}', 1078),
+    ('\u{1EE54}', 1010),
+    ('\u{1EE57}', 970),
+    ('\u{1EE59}', 1037),
+    ('\u{1EE5B}', 1064),
+    ('\u{1EE5D}', 1165),
+    ('\u{1EE5F}', 1233),
+    ('\u{1EE61}', 918),
+    ('\u{1EE62}', 954),
+    ('\u{1EE64}', 524),
+    ('\u{1EE67}', 962),
+    ('\u{1EE68}', 1046),
+    ('\u{1EE69}', 1165),
+    ('\u{1EE6A}', 1086),
+    ('\u{1EE6C}', 1118),
+    ('\u{1EE6D}', 1139),
+    ('\u{1EE6E}', 994),
+    ('\u{1EE6F}', 1056),
+    ('\u{1EE70}', 1070),
+    ('\u{1EE71}', 1023),
+    ('\u{1EE72}', 1078),
+    ('\u{1EE74}', 1010),
+    ('\u{1EE75}', 934),
+    ('\u{1EE76}', 1201),
+    ('\u{1EE77}', 970),
+    ('\u{1EE79}', 1037),
+    ('\u{1EE7A}', 1054),
+    ('\u{1EE7B}', 1064),
+    ('\u{1EE7C}', 1165),
+    ('\u{1EE7E}', 1233),
+    ('\u{1EE80}', 456),
+    ('\u{1EE81}', 918),
+    ('\u{1EE82}', 954),
+    ('\u{1EE83}', 975),
+    ('\u{1EE84}', 524),
+    ('\u{1EE85}', 1156),
+    ('\u{1EE86}', 992),
+    ('\u{1EE87}', 962),
+    ('\u{1EE88}', 1046),
+    ('\u{1EE89}', 1165),
+    ('\u{1EE8B}', 1097),
+    ('\u{1EE8C}', 1118),
+    ('\u{1EE8D}', 1139),
+    ('\u{1EE8E}', 994),
+    ('\u{1EE8F}', 1056),
+    ('\u{1EE90}', 1070),
+    ('\u{1EE91}', 1023),
+    ('\u{1EE92}', 1078),
+    ('\u{1EE93}', 982),
+    ('\u{1EE94}', 1010),
+    ('\u{1EE95}', 934),
+    ('\u{1EE96}', 1201),
+    ('\u{1EE97}', 970),
+    ('\u{1EE98}', 980),
+    ('\u{1EE99}', 1037),
+    ('\u{1EE9A}', 1054),
+    ('\u{1EE9B}', 1064),
+    ('\u{1EEA1}', 918),
+    ('\u{1EEA2}', 954),
+    ('\u{1EEA3}', 975),
+    ('\u{1EEA5}', 1156),
+    ('\u{1EEA6}', 992),
+    ('\u{1EEA7}', 962),
+    ('\u{1EEA8}', 1046),
+    ('\u{1EEA9}', 1165),
+    ('\u{1EEAB}', 1097),
+    ('\u{1EEAC}', 1118),
+    ('\u{1EEAD}', 1139),
+    ('\u{1EEAE}', 994),
+    ('\u{1EEAF}', 1056),
+    ('\u{1EEB0}', 1070),
+    ('\u{1EEB1}', 1023),
+    ('\u{1EEB2}', 1078),
+    ('\u{1EEB3}', 982),
+    ('\u{1EEB4}', 1010),
+    ('\u{1EEB5}', 934),
+    ('\u{1EEB6}', 1201),
+    ('\u{1EEB7}', 970),
+    ('\u{1EEB8}', 980),
+    ('\u{1EEB9}', 1037),
+    ('\u{1EEBA}', 1054),
+    ('\u{1EEBB}', 1064),
+    ('🄀', 338),
+    ('🄁', 337),
+    ('🄂', 458),
+    ('🄃', 189),
+    ('🄄', 212),
+    ('🄅', 221),
+    ('🄆', 228),
+    ('🄇', 234),
+    ('🄈', 240),
+    ('🄉', 246),
+    ('🄊', 252),
+    ('🄏', 4),
+    ('🄐', 27),
+    ('🄑', 28),
+    ('🄒', 29),
+    ('🄓', 30),
+    ('🄔', 31),
+    ('🄕', 32),
+    ('🄖', 33),
+    ('🄗', 34),
+    ('🄘', 63),
+    ('🄙', 35),
+    ('🄚', 36),
+    ('🄛', 37),
+    ('🄜', 38),
+    ('🄝', 39),
+    ('🄞', 40),
+    ('🄟', 41),
+    ('🄠', 42),
+    ('🄡', 43),
+    ('🄢', 44),
+    ('🄣', 45),
+    ('🄤', 46),
+    ('🄥', 47),
+    ('🄦', 48),
+    ('🄧', 49),
+    ('🄨', 50),
+    ('🄩', 51),
+    ('🄪', 44),
+    ('🅭', 2015),
+    ('🅮', 295),
+    ('🉀', 129),
+    ('🉁', 104),
+    ('🉂', 106),
+    ('🉃', 122),
+    ('🉄', 133),
+    ('🉅', 123),
+    ('🉆', 135),
+    ('🉇', 114),
+    ('🉈', 124),
+    ('🌒', 1973),
+    ('🌘', 1974),
+    ('🌙', 1973),
+    ('🜀', 348),
+    ('🜁', 3125),
+    ('🜂', 837),
+    ('🜄', 3162),
+    ('🜇', 287),
+    ('🜈', 372),
+    ('🜊', 1972),
+    ('🜔', 341),
+    ('🜨', 3161),
+    ('🜺', 1990),
+    ('🝌', 292),
+    ('🝔', 1852),
+    ('🝕', 1936),
+    ('🝜', 558),
+    ('🝞', 1925),
+    ('🝨', 352),
+    ('🝫', 327),
+    ('🝬', 366),
+    ('🝱', 1935),
+    ('🯰', 335),
+    ('🯱', 456),
+    ('🯲', 188),
+    ('🯳', 211),
+    ('🯴', 220),
+    ('🯵', 227),
+    ('🯶', 233),
+    ('🯷', 239),
+    ('🯸', 245),
+    ('🯹', 251),
+    ('𡿨', 1975),
+    ('\u{2F800}', 2117),
+    ('\u{2F801}', 2115),
+    ('\u{2F802}', 2118),
+    ('\u{2F803}', 3207),
+    ('\u{2F804}', 2134),
+    ('\u{2F805}', 2139),
+    ('\u{2F806}', 2140),
+    ('\u{2F807}', 2135),
+    ('\u{2F808}', 2144),
+    ('\u{2F809}', 2145),
+    ('\u{2F80A}', 2148),
+    ('\u{2F80B}', 2146),
+    ('\u{2F80C}', 2016),
+    ('\u{2F80D}', 3211),
+    ('\u{2F80E}', 2152),
+    ('\u{2F80F}', 2153),
+    ('\u{2F810}', 2154),
+    ('\u{2F811}', 2161),
+    ('\u{2F812}', 3208),
+    ('\u{2F813}', 2017),
+    ('\u{2F814}', 2156),
+    ('\u{2F815}', 2164),
+    ('\u{2F816}', 3210),
+    ('\u{2F817}', 2168),
+    ('\u{2F818}', 2169),
+    ('\u{2F819}', 2132),
+    ('\u{2F81A}', 2171),
+    ('\u{2F81B}', 2172),
+    ('\u{2F81C}', 3305),
+    ('\u{2F81D}', 2179),
+    ('\u{2F81E}', 2182),
+    ('\u{2F81F}', 2019),
+    ('\u{2F820}', 2187),
+    ('\u{2F821}', 2188),
+    ('\u{2F822}', 2189),
+    ('\u{2F823}', 2190),
+    ('\u{2F824}', 2020),
+    ('\u{2F825}', 2195),
+    ('\u{2F826}', 2196),
+    ('\u{2F827}', 2199),
+    ('\u{2F828}', 2202),
+    ('\u{2F829}', 2203),
+    ('\u{2F82A}', 2204),
+    ('\u{2F82B}', 2206),
+    ('\u{2F82C}', 2213),
+    ('\u{2F82D}', 2216),
+    ('\u{2F82E}', 2217),
+    ('\u{2F82F}', 2220),
+    ('\u{2F830}', 2222),
+    ('\u{2F831}', 2223),
+    ('\u{2F832}', 2223),
+    ('\u{2F833}', 2223),
+    ('\u{2F834}', 3214),
+    ('\u{2F835}', 2591),
+    ('\u{2F836}', 2228),
+    ('\u{2F837}', 2229),
+    ('\u{2F838}', 3215),
+    ('\u{2F839}', 2232),
+    ('\u{2F83A}', 2233),
+    ('\u{2F83B}', 2234),
+    ('\u{2F83C}', 2241),
+    ('\u{2F83D}', 2237),
+    ('\u{2F83E}', 2239),
+    ('\u{2F83F}', 2240),
+    ('\u{2F840}', 2242),
+    ('\u{2F841}', 2244),
+    ('\u{2F842}', 2245),
+    ('\u{2F843}', 2246),
+    ('\u{2F844}', 2248),
+    ('\u{2F845}', 2249),
+    ('\u{2F846}', 2249),
+    ('\u{2F847}', 2251),
+    ('\u{2F848}', 2253),
+    ('\u{2F849}', 2254),
+    ('\u{2F84A}', 2256),
+    ('\u{2F84B}', 2263),
+    ('\u{2F84C}', 2258),
+    ('\u{2F84D}', 2264),
+    ('\u{2F84E}', 2259),
+    ('\u{2F84F}', 2261),
+    ('\u{2F850}', 2183),
+    ('\u{2F851}', 2282),
+    ('\u{2F852}', 2267),
+    ('\u{2F853}', 2268),
+    ('\u{2F854}', 2269),
+    ('\u{2F855}', 2266),
+    ('\u{2F856}', 2271),
+    ('\u{2F857}', 2270),
+    ('\u{2F858}', 2278),
+    ('\u{2F859}', 3216),
+    ('\u{2F85A}', 2283),
+    ('\u{2F85B}', 2284),
+    ('\u{2F85C}', 2286),
+    ('\u{2F85D}', 2289),
+    ('\u{2F85E}', 2290),
+    ('\u{2F85F}', 2296),
+    ('\u{2F860}', 3217),
+    ('\u{2F861}', 3218),
+    ('\u{2F862}', 2299),
+    ('\u{2F863}', 2300),
+    ('\u{2F864}', 2301),
+    ('\u{2F865}', 2298),
+    ('\u{2F866}', 2303),
+    ('\u{2F867}', 2024),
+    ('\u{2F868}', 2025),
+    ('\u{2F869}', 2306),
+    ('\u{2F86A}', 2308),
+    ('\u{2F86B}', 2308),
+    ('\u{2F86C}', 3219),
+    ('\u{2F86D}', 2312),
+    ('\u{2F86E}', 2313),
+    ('\u{2F86F}', 2314),
+    ('\u{2F870}', 2316),
+    ('\u{2F871}', 3220),
+    ('\u{2F872}', 2318),
+    ('\u{2F873}', 2319),
+    ('\u{2F874}', 2375),
+    ('\u{2F875}', 2321),
+    ('\u{2F876}', 2026),
+    ('\u{2F877}', 2325),
+    ('\u{2F878}', 2329),
+    ('\u{2F879}', 2332),
+    ('\u{2F87A}', 2331),
+    ('\u{2F87B}', 3222),
+    ('\u{2F87C}', 2334),
+    ('\u{2F87D}', 3223),
+    ('\u{2F87E}', 2337),
+    ('\u{2F87F}', 2336),
+    ('\u{2F880}', 2338),
+    ('\u{2F881}', 2342),
+    ('\u{2F882}', 2343),
+    ('\u{2F883}', 2027),
+    ('\u{2F884}', 2347),
+    ('\u{2F885}', 2350),
+    ('\u{2F886}', 2351),
+    ('\u{2F887}', 2352),
+    ('\u{2F888}', 2028),
+    ('\u{2F889}', 3224),
+    ('\u{2F88A}', 2029),
+    ('\u{2F88B}', 2358),
+    ('\u{2F88C}', 2359),
+    ('\u{2F88D}', 2360),
+    ('\u{2F88E}', 2362),
+    ('\u{2F88F}', 3315),
+    ('\u{2F890}', 2368),
+    ('\u{2F891}', 3226),
+    ('\u{2F892}', 3226),
+    ('\u{2F893}', 2800),
+    ('\u{2F894}', 2372),
+    ('\u{2F895}', 2372),
+    ('\u{2F896}', 2030),
+    ('\u{2F897}', 3233),
+    ('\u{2F898}', 3275),
+    ('\u{2F899}', 2377),
+    ('\u{2F89A}', 2379),
+    ('\u{2F89B}', 2031),
+    ('\u{2F89C}', 2382),
+    ('\u{2F89D}', 2387),
+    ('\u{2F89E}', 2388),
+    ('\u{2F89F}', 2390),
+    ('\u{2F8A0}', 2394),
+    ('\u{2F8A1}', 2034),
+    ('\u{2F8A2}', 2033),
+    ('\u{2F8A3}', 2395),
+    ('\u{2F8A4}', 3227),
+    ('\u{2F8A5}', 2396),
+    ('\u{2F8A6}', 2401),
+    ('\u{2F8A7}', 2402),
+    ('\u{2F8A8}', 2403),
+    ('\u{2F8A9}', 2402),
+    ('\u{2F8AA}', 2406),
+    ('\u{2F8AB}', 2407),
+    ('\u{2F8AC}', 2411),
+    ('\u{2F8AD}', 2409),
+    ('\u{2F8AE}', 2410),
+    ('\u{2F8AF}', 2412),
+    ('\u{2F8B0}', 2413),
+    ('\u{2F8B1}', 2414),
+    ('\u{2F8B2}', 2417),
+    ('\u{2F8B3}', 2418),
+    ('\u{2F8B4}', 2424),
+    ('\u{2F8B5}', 2425),
+    ('\u{2F8B6}', 2429),
+    ('\u{2F8B7}', 2433),
+    ('\u{2F8B8}', 3230),
+    ('\u{2F8B9}', 2432),
+    ('\u{2F8BA}', 2430),
+    ('\u{2F8BB}', 2434),
+    ('\u{2F8BC}', 2436),
+    ('\u{2F8BD}', 2441),
+    ('\u{2F8BE}', 3231),
+    ('\u{2F8BF}', 2443),
+    ('\u{2F8C0}', 2440),
+    ('\u{2F8C1}', 2438),
+    ('\u{2F8C2}', 2035),
+    ('\u{2F8C3}', 2445),
+    ('\u{2F8C4}', 2447),
+    ('\u{2F8C5}', 2449),
+    ('\u{2F8C6}', 2446),
+    ('\u{2F8C7}', 2037),
+    ('\u{2F8C8}', 2454),
+    ('\u{2F8C9}', 2456),
+    ('\u{2F8CA}', 3232),
+    ('\u{2F8CB}', 2468),
+    ('\u{2F8CC}', 2481),
+    ('\u{2F8CD}', 2471),
+    ('\u{2F8CE}', 2041),
+    ('\u{2F8CF}', 2475),
+    ('\u{2F8D0}', 2040),
+    ('\u{2F8D1}', 2039),
+    ('\u{2F8D2}', 2165),
+    ('\u{2F8D3}', 2166),
+    ('\u{2F8D4}', 2482),
+    ('\u{2F8D5}', 2476),
+    ('\u{2F8D6}', 2788),
+    ('\u{2F8D7}', 2071),
+    ('\u{2F8D8}', 2488),
+    ('\u{2F8D9}', 2490),
+    ('\u{2F8DA}', 2491),
+    ('\u{2F8DB}', 2497),
+    ('\u{2F8DC}', 2495),
+    ('\u{2F8DD}', 3237),
+    ('\u{2F8DE}', 2045),
+    ('\u{2F8DF}', 2503),
+    ('\u{2F8E0}', 2500),
+    ('\u{2F8E1}', 2506),
+    ('\u{2F8E2}', 2508),
+    ('\u{2F8E3}', 3239),
+    ('\u{2F8E4}', 2509),
+    ('\u{2F8E5}', 2505),
+    ('\u{2F8E6}', 2511),
+    ('\u{2F8E7}', 2046),
+    ('\u{2F8E8}', 2512),
+    ('\u{2F8E9}', 2514),
+    ('\u{2F8EA}', 2515),
+    ('\u{2F8EB}', 2518),
+    ('\u{2F8EC}', 3240),
+    ('\u{2F8ED}', 2520),
+    ('\u{2F8EE}', 2048),
+    ('\u{2F8EF}', 2523),
+    ('\u{2F8F0}', 3241),
+    ('\u{2F8F1}', 2524),
+    ('\u{2F8F2}', 2049),
+    ('\u{2F8F3}', 2527),
+    ('\u{2F8F4}', 2531),
+    ('\u{2F8F5}', 2534),
+    ('\u{2F8F6}', 2535),
+    ('\u{2F8F7}', 3242),
+    ('\u{2F8F8}', 3221),
+    ('\u{2F8F9}', 3243),
+    ('\u{2F8FA}', 2546),
+    ('\u{2F8FB}', 3244),
+    ('\u{2F8FC}', 2549),
+    ('\u{2F8FD}', 2551),
+    ('\u{2F8FE}', 2547),
+    ('\u{2F8FF}', 2553),
+    ('\u{2F900}', 2557),
+    ('\u{2F901}', 2561),
+    ('\u{2F902}', 2558),
+    ('\u{2F903}', 2559),
+    ('\u{2F904}', 2562),
+    ('\u{2F905}', 2563),
+    ('\u{2F906}', 3245),
+    ('\u{2F907}', 2556),
+    ('\u{2F908}', 2569),
+    ('\u{2F909}', 2570),
+    ('\u{2F90A}', 2050),
+    ('\u{2F90B}', 2575),
+    ('\u{2F90C}', 2574),
+    ('\u{2F90D}', 3246),
+    ('\u{2F90E}', 2567),
+    ('\u{2F90F}', 2581),
+    ('\u{2F910}', 3247),
+    ('\u{2F911}', 3248),
+    ('\u{2F912}', 2582),
+    ('\u{2F913}', 2587),
+    ('\u{2F914}', 2586),
+    ('\u{2F915}', 2585),
+    ('\u{2F916}', 2051),
+    ('\u{2F917}', 2588),
+    ('\u{2F918}', 2593),
+    ('\u{2F919}', 2592),
+    ('\u{2F91A}', 2595),
+    ('\u{2F91B}', 3209),
+    ('\u{2F91C}', 2598),
+    ('\u{2F91D}', 3249),
+    ('\u{2F91E}', 2601),
+    ('\u{2F91F}', 3251),
+    ('\u{2F920}', 2606),
+    ('\u{2F921}', 2609),
+    ('\u{2F922}', 2614),
+    ('\u{2F923}', 3252),
+    ('\u{2F924}', 2618),
+    ('\u{2F925}', 2619),
+    ('\u{2F926}', 3253),
+    ('\u{2F927}', 3254),
+    ('\u{2F928}', 2627),
+    ('\u{2F929}', 2631),
+    ('\u{2F92A}', 2052),
+    ('\u{2F92B}', 2632),
+    ('\u{2F92C}', 2053),
+    ('\u{2F92D}', 2053),
+    ('\u{2F92E}', 2638),
+    ('\u{2F92F}', 2639),
+    ('\u{2F930}', 2641),
+    ('\u{2F931}', 2642),
+    ('\u{2F932}', 2645),
+    ('\u{2F933}', 2054),
+    ('\u{2F934}', 2651),
+    ('\u{2F935}', 3255),
+    ('\u{2F936}', 2655),
+    ('\u{2F937}', 3256),
+    ('\u{2F938}', 2658),
+    ('\u{2F939}', 3225),
+    ('\u{2F93A}', 2662),
+    ('\u{2F93B}', 3257),
+    ('\u{2F93C}', 3258),
+    ('\u{2F93D}', 3259),
+    ('\u{2F93E}', 2055),
+    ('\u{2F93F}', 2056),
+    ('\u{2F940}', 2675),
+    ('\u{2F941}', 3261),
+    ('\u{2F942}', 3260),
+    ('\u{2F943}', 3262),
+    ('\u{2F944}', 3263),
+    ('\u{2F945}', 2677),
+    ('\u{2F946}', 2678),
+    ('\u{2F947}', 2678),
+    ('\u{2F948}', 2680),
+    ('\u{2F949}', 2058),
+    ('\u{2F94A}', 2681),
+    ('\u{2F94B}', 2060),
+    ('\u{2F94C}', 2061),
+    ('\u{2F94D}', 3265),
+    ('\u{2F94E}', 2687),
+    ('\u{2F94F}', 2689),
+    ('\u{2F950}', 2692),
+    ('\u{2F951}', 2062),
+    ('\u{2F952}', 3266),
+    ('\u{2F953}', 2702),
+    ('\u{2F954}', 3267),
+    ('\u{2F955}', 3268),
+    ('\u{2F956}', 2709),
+    ('\u{2F957}', 2714),
+    ('\u{2F958}', 2063),
+    ('\u{2F959}', 2716),
+    ('\u{2F95A}', 2717),
+    ('\u{2F95B}', 2718),
+    ('\u{2F95C}', 3269),
+    ('\u{2F95D}', 3270),
+    ('\u{2F95E}', 3270),
+    ('\u{2F95F}', 2724),
+    ('\u{2F960}', 2064),
+    ('\u{2F961}', 3271),
+    ('\u{2F962}', 2728),
+    ('\u{2F963}', 2729),
+    ('\u{2F964}', 2065),
+    ('\u{2F965}', 3272),
+    ('\u{2F966}', 2736),
+    ('\u{2F967}', 2066),
+    ('\u{2F968}', 2740),
+    ('\u{2F969}', 2738),
+    ('\u{2F96A}', 2743),
+    ('\u{2F96B}', 3274),
+    ('\u{2F96C}', 2749),
+    ('\u{2F96D}', 2067),
+    ('\u{2F96E}', 2752),
+    ('\u{2F96F}', 2754),
+    ('\u{2F970}', 2758),
+    ('\u{2F971}', 2068),
+    ('\u{2F972}', 3276),
+    ('\u{2F973}', 3277),
+    ('\u{2F974}', 2069),
+    ('\u{2F975}', 3278),
+    ('\u{2F976}', 2766),
+    ('\u{2F977}', 3279),
+    ('\u{2F978}', 2769),
+    ('\u{2F979}', 2772),
+    ('\u{2F97A}', 2775),
+    ('\u{2F97B}', 3280),
+    ('\u{2F97C}', 3281),
+    ('\u{2F97D}', 2780),
+    ('\u{2F97E}', 3282),
+    ('\u{2F97F}', 2782),
+    ('\u{2F980}', 3234),
+    ('\u{2F981}', 2070),
+    ('\u{2F982}', 2789),
+    ('\u{2F983}', 2791),
+    ('\u{2F984}', 2072),
+    ('\u{2F985}', 2792),
+    ('\u{2F986}', 2305),
+    ('\u{2F987}', 3283),
+    ('\u{2F988}', 3284),
+    ('\u{2F989}', 3235),
+    ('\u{2F98A}', 3236),
+    ('\u{2F98B}', 2800),
+    ('\u{2F98C}', 2801),
+    ('\u{2F98D}', 2948),
+    ('\u{2F98E}', 2074),
+    ('\u{2F98F}', 2812),
+    ('\u{2F990}', 2811),
+    ('\u{2F991}', 2813),
+    ('\u{2F992}', 2194),
+    ('\u{2F993}', 2814),
+    ('\u{2F994}', 2815),
+    ('\u{2F995}', 2816),
+    ('\u{2F996}', 2818),
+    ('\u{2F997}', 3285),
+    ('\u{2F998}', 2817),
+    ('\u{2F999}', 2819),
+    ('\u{2F99A}', 2824),
+    ('\u{2F99B}', 2825),
+    ('\u{2F99C}', 2820),
+    ('\u{2F99D}', 2826),
+    ('\u{2F99E}', 2831),
+    ('\u{2F99F}', 2836),
+    ('\u{2F9A0}', 2823),
+    ('\u{2F9A1}', 2828),
+    ('\u{2F9A2}', 2829),
+    ('\u{2F9A3}', 2830),
+    ('\u{2F9A4}', 3286),
+    ('\u{2F9A5}', 3288),
+    ('\u{2F9A6}', 3287),
+    ('\u{2F9A7}', 2075),
+    ('\u{2F9A8}', 2839),
+    ('\u{2F9A9}', 2840),
+    ('\u{2F9AA}', 2842),
+    ('\u{2F9AB}', 3292),
+    ('\u{2F9AC}', 2843),
+    ('\u{2F9AD}', 3289),
+    ('\u{2F9AE}', 2076),
+    ('\u{2F9AF}', 2077),
+    ('\u{2F9B0}', 3290),
+    ('\u{2F9B1}', 3291),
+    ('\u{2F9B2}', 2078),
+    ('\u{2F9B3}', 2853),
+    ('\u{2F9B4}', 2854),
+    ('\u{2F9B5}', 2855),
+    ('\u{2F9B6}', 2856),
+    ('\u{2F9B7}', 2859),
+    ('\u{2F9B8}', 2858),
+    ('\u{2F9B9}', 2861),
+    ('\u{2F9BA}', 2860),
+    ('\u{2F9BB}', 2864),
+    ('\u{2F9BC}', 2862),
+    ('\u{2F9BD}', 2863),
+    ('\u{2F9BE}', 2865),
+    ('\u{2F9BF}', 2079),
+    ('\u{2F9C0}', 2867),
+    ('\u{2F9C1}', 2868),
+    ('\u{2F9C2}', 2080),
+    ('\u{2F9C3}', 2872),
+    ('\u{2F9C4}', 2873),
+    ('\u{2F9C5}', 3293),
+    ('\u{2F9C6}', 2877),
+    ('\u{2F9C7}', 2878),
+    ('\u{2F9C8}', 2081),
+    ('\u{2F9C9}', 2881),
+    ('\u{2F9CA}', 2018),
+    ('\u{2F9CB}', 3294),
+    ('\u{2F9CC}', 3295),
+    ('\u{2F9CD}', 2083),
+    ('\u{2F9CE}', 2084),
+    ('\u{2F9CF}', 2895),
+    ('\u{2F9D0}', 2901),
+    ('\u{2F9D1}', 2909),
+    ('\u{2F9D2}', 2914),
+    ('\u{2F9D3}', 3296),
+    ('\u{2F9D4}', 2918),
+    ('\u{2F9D5}', 2919),
+    ('\u{2F9D6}', 2924),
+    ('\u{2F9D7}', 2928),
+    ('\u{2F9D8}', 3298),
+    ('\u{2F9D9}', 3212),
+    ('\u{2F9DA}', 2932),
+    ('\u{2F9DB}', 2931),
+    ('\u{2F9DC}', 2935),
+    ('\u{2F9DD}', 3213),
+    ('\u{2F9DE}', 2939),
+    ('\u{2F9DF}', 2943),
+    ('\u{2F9E0}', 3299),
+    ('\u{2F9E1}', 3300),
+    ('\u{2F9E2}', 2958),
+    ('\u{2F9E3}', 2960),
+    ('\u{2F9E4}', 2962),
+    ('\u{2F9E5}', 3301),
+    ('\u{2F9E6}', 2963),
+    ('\u{2F9E7}', 2973),
+    ('\u{2F9E8}', 2976),
+    ('\u{2F9E9}', 2977),
+    ('\u{2F9EA}', 2975),
+    ('\u{2F9EB}', 2981),
+    ('\u{2F9EC}', 2982),
+    ('\u{2F9ED}', 3302),
+    ('\u{2F9EE}', 2988),
+    ('\u{2F9EF}', 2085),
+    ('\u{2F9F0}', 2990),
+    ('\u{2F9F1}', 3303),
+    ('\u{2F9F2}', 2086),
+    ('\u{2F9F3}', 3005),
+    ('\u{2F9F4}', 2339),
+    ('\u{2F9F5}', 3011),
+    ('\u{2F9F6}', 3304),
+    ('\u{2F9F7}', 3306),
+    ('\u{2F9F8}', 2087),
+    ('\u{2F9F9}', 2088),
+    ('\u{2F9FA}', 3022),
+    ('\u{2F9FB}', 3307),
+    ('\u{2F9FC}', 2089),
+    ('\u{2F9FD}', 3308),
+    ('\u{2F9FE}', 3028),
+    ('\u{2F9FF}', 3028),
+    ('\u{2FA00}', 3030),
+    ('\u{2FA01}', 3309),
+    ('\u{2FA02}', 3040),
+    ('\u{2FA03}', 2090),
+    ('\u{2FA04}', 3044),
+    ('\u{2FA05}', 3048),
+    ('\u{2FA06}', 3050),
+    ('\u{2FA07}', 3052),
+    ('\u{2FA08}', 2091),
+    ('\u{2FA09}', 3310),
+    ('\u{2FA0A}', 3058),
+    ('\u{2FA0B}', 3065),
+    ('\u{2FA0C}', 3069),
+    ('\u{2FA0D}', 2092),
+    ('\u{2FA0E}', 2093),
+    ('\u{2FA0F}', 3070),
+    ('\u{2FA10}', 3311),
+    ('\u{2FA11}', 2094),
+    ('\u{2FA12}', 3312),
+    ('\u{2FA13}', 3313),
+    ('\u{2FA14}', 3314),
+    ('\u{2FA15}', 3081),
+    ('\u{2FA16}', 2095),
+    ('\u{2FA17}', 3087),
+    ('\u{2FA18}', 3089),
+    ('\u{2FA19}', 3090),
+    ('\u{2FA1A}', 3092),
+    ('\u{2FA1B}', 3094),
+    ('\u{2FA1C}', 3096),
+    ('\u{2FA1D}', 3316),
+];
@@ -0,0 +1,304 @@
+//! A text format for lookalikes, to be edited without recompiling.
+
+use {
+    super::{Lookalike, Lookalikes},
+    alloc::{string::String, vec::Vec},
+    core::{
+        error::Error,
+        fmt::{self, Display, Formatter},
+        str::FromStr,
+    },
+};
+
+/// Lookalikes parsed from text, see [`RuleSet::parse`].
+///
+/// Lookalikes are produced by a reference to a rule set, since they borrow the sequences from it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleSet {
+    /// Sorted by the first character of the input
+    rules: Vec<Rule>,
+}
+
+/// The typed sequence `input` could've been meant as `key`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    /// The first character of `input`
+    first: char,
+    input: String,
+    key: String,
+    cost: u32,
+}
+
+/// An error in the text of a [`RuleSet`], at a 1-based line & column, counted in `char`s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A rule doesn't have a `=`
+    MissingEquals,
+    /// A rule doesn't have the typed sequence before the `=`
+    MissingInput,
+    /// A rule has more than 1 typed sequence
+    ExtraInput,
+    /// A rule doesn't have any sequences after the `=`
+    MissingKeys,
+    /// A rule has more than 1 `=`
+    ExtraEquals,
+    /// The typed sequence has a cost
+    UnexpectedCost,
+    /// A cost isn't a non-negative integer that fits into a `u32`
+    InvalidCost,
+    /// A sequence only has a cost
+    EmptySequence,
+    /// A backslash is followed by a character that can't be escaped
+    UnknownEscape(char),
+    /// A `\u{...}` escape isn't a valid character
+    InvalidUnicodeEscape,
+    /// A line ends with a backslash
+    TrailingBackslash,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingEquals => f.write_str("expected `=` after the typed sequence"),
+            Self::MissingInput => f.write_str("expected the typed sequence before `=`"),
+            Self::ExtraInput => {
+                f.write_str("expected `=` after the typed sequence, found another one")
+            }
+            Self::MissingKeys => f.write_str("expected at least 1 sequence after `=`"),
+            Self::ExtraEquals => f.write_str("unexpected `=`, escape it as `\\=`"),
+            Self::UnexpectedCost => f.write_str("the typed sequence can't have a cost"),
+            Self::InvalidCost => f.write_str("expected a non-negative integer as the cost"),
+            Self::EmptySequence => f.write_str("expected a sequence before the cost"),
+            Self::UnknownEscape(ch) => write!(f, "unknown escape sequence `\\{ch}`"),
+            Self::InvalidUnicodeEscape => {
+                f.write_str("expected a hexadecimal character code in `\\u{...}`")
+            }
+            Self::TrailingBackslash => f.write_str("expected a character after `\\`"),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A whitespace-separated part of a line.
+#[derive(Debug)]
+enum Token {
+    Equals,
+    Sequence { text: String, cost: Option<u32> },
+}
+
+/// Parses the `{...}` part of a `\u{...}` escape.
+fn unicode_escape(chars: &mut impl Iterator<Item = (char, usize)>) -> Option<char> {
+    if chars.next()?.0 != '{' {
+        return None;
+    }
+
+    let mut code = 0u32;
+    loop {
+        let (digit, _) = chars.next()?;
+        if digit == '}' {
+            return char::from_u32(code);
+        }
+        code = code.checked_mul(16)?.checked_add(digit.to_digit(16)?)?;
+    }
+}
+
+/// Splits a line into tokens along with their 1-based columns, stopping at a comment.
+fn tokenize(line: &str, line_no: usize) -> Result<Vec<(usize, Token)>, ParseError> {
+    let err = |column, kind| ParseError {
+        line: line_no,
+        column,
+        kind,
+    };
+
+    let mut res = Vec::new();
+    let mut chars = line.chars().zip(1..).peekable();
+    while let Some(&(ch, column)) = chars.peek() {
+        match ch {
+            '#' => break,
+            '=' => {
+                chars.next();
+                res.push((column, Token::Equals));
+                continue;
+            }
+            _ if ch.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            _ => (),
+        }
+
+        let mut text = String::new();
+        let mut cost = None;
+        while let Some(&(ch, column)) = chars.peek() {
+            match ch {
+                '#' | '=' => break,
+                _ if ch.is_whitespace() => break,
+                '\\' => {
+                    chars.next();
+                    let (escaped, escaped_column) = chars
+                        .next()
+                        .ok_or_else(|| err(column, ParseErrorKind::TrailingBackslash))?;
+                    let escaped = match escaped {
+                        '\\' | '#' | '=' | ':' => Ok(escaped),
+                        's' => Ok(' '),
+                        't' => Ok('\t'),
+                        'u' => {
+                            unicode_escape(&mut chars).ok_or(ParseErrorKind::InvalidUnicodeEscape)
+                        }
+                        _ => Err(ParseErrorKind::UnknownEscape(escaped)),
+                    };
+                    text.push(escaped.map_err(|kind| err(escaped_column, kind))?);
+                }
+                ':' => {
+                    chars.next();
+                    let mut digits = String::new();
+                    while let Some(&(digit, _)) = chars.peek() {
+                        if digit.is_whitespace() || matches!(digit, '#' | '=') {
+                            break;
+                        }
+                        digits.push(digit);
+                        chars.next();
+                    }
+                    cost = Some(
+                        digits
+                            .parse()
+                            .map_err(|_| err(column + 1, ParseErrorKind::InvalidCost))?,
+                    );
+                    if text.is_empty() {
+                        return Err(err(column, ParseErrorKind::EmptySequence));
+                    }
+                    break;
+                }
+                _ => {
+                    chars.next();
+                    text.push(ch);
+                }
+            }
+        }
+        res.push((column, Token::Sequence { text, cost }));
+    }
+
+    Ok(res)
+}
+
+impl RuleSet {
+    /// Parses lookalikes from text.
+    ///
+    /// Every line is a rule of the form `input = key key:cost ...`, meaning that the typed
+    /// sequence `input` could've been meant as any of the keys, e.g. `a = á à` or `ph = f:2`. The
+    /// keys without a cost are assigned [`Lookalike::DEFAULT_COST`]. Sequences are separated by
+    /// whitespace, everything after a `#` is a comment. The characters that have a special meaning
+    /// can be escaped as `\\`, `\#`, `\=` & `\:`, a space as `\s`, a tab as `\t`, and any
+    /// character by its code as `\u{301}`.
+    ///
+    /// # Errors
+    /// Returns the position of the first error in the text, see [`ParseErrorKind`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use permissive_search::{lookalikes::{self, Lookalikes, RuleSet}, *};
+    ///
+    /// let rules = RuleSet::parse("
+    ///     e = é è ê  # accents
+    ///     ph = f:2   # spelling
+    /// ")?;
+    ///
+    /// # let root = SearchTree::default();
+    /// let searcher = Searcher::new(&root, lookalikes::qwerty_misclicks.chain(&rules));
+    /// # _ = searcher;
+    /// # Ok::<(), lookalikes::ParseError>(())
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut rules = Vec::new();
+        for (line, line_no) in text.lines().zip(1..) {
+            let err = |column, kind| ParseError {
+                line: line_no,
+                column,
+                kind,
+            };
+
+            let mut tokens = tokenize(line, line_no)?.into_iter();
+            let input = match tokens.next() {
+                None => continue,
+                Some((column, Token::Equals)) => {
+                    return Err(err(column, ParseErrorKind::MissingInput));
+                }
+                Some((column, Token::Sequence { cost: Some(_), .. })) => {
+                    return Err(err(column, ParseErrorKind::UnexpectedCost));
+                }
+                Some((_, Token::Sequence { text, cost: None })) => text,
+            };
+            match tokens.next() {
+                Some((_, Token::Equals)) => (),
+                Some((column, Token::Sequence { .. })) => {
+                    return Err(err(column, ParseErrorKind::ExtraInput));
+                }
+                None => {
+                    let column = line.chars().count() + 1;
+                    return Err(err(column, ParseErrorKind::MissingEquals));
+                }
+            }
+
+            let first = input.chars().next().unwrap_or_default();
+            let n_rules = rules.len();
+            for (column, token) in tokens {
+                let Token::Sequence { text: key, cost } = token else {
+                    return Err(err(column, ParseErrorKind::ExtraEquals));
+                };
+                rules.push(Rule {
+                    first,
+                    input: input.clone(),
+                    key,
+                    cost: cost.unwrap_or(Lookalike::DEFAULT_COST),
+                });
+            }
+            if rules.len() == n_rules {
+                let column = line.chars().count() + 1;
+                return Err(err(column, ParseErrorKind::MissingKeys));
+            }
+        }
+
+        rules.sort_by_key(|rule| rule.first);
+        Ok(Self { rules })
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl<'r> Lookalikes<'r> for &'r RuleSet {
+    fn lookalikes(&self, ch: char, dst: &mut Vec<Lookalike<'r>>) {
+        let start = self.rules.partition_point(|rule| rule.first < ch);
+        dst.extend(
+            self.rules[start..]
+                .iter()
+                .take_while(|rule| rule.first == ch)
+                .map(|rule| {
+                    Lookalike::sequence(&rule.input[ch.len_utf8()..], &rule.key, rule.cost)
+                }),
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Ranges of optional characters, e.g. vowel marks, to be skipped while searching, see
+//! [`Searcher::with_skippable`](crate::Searcher::with_skippable).
+//!
+//! # Example
+//! ```rust
+//! use permissive_search::*;
+//!
+//! let keys = ["مُحَمَّد", "שָׁלוֹם"];
+//! let root: SearchTree = keys.into_iter().enumerate().collect();
+//! let mut searcher =
+//!     Searcher::new(&root, lookalikes::all).with_skippable(&[marks::ARABIC, marks::HEBREW]);
+//! searcher.extend("محمد".chars());
+//! searcher.for_each_candidate::<()>(|i| Ok(println!("{}", keys[i])));
+//! ```
+
+use core::ops::RangeInclusive;
+
+/// Arabic harakat, Quranic annotation marks & the tatweel, `ـ`, which only stretches the letters.
+pub const ARABIC: &[RangeInclusive<char>] = &[
+    '\u{610}'..='\u{61A}',
+    '\u{640}'..='\u{640}',
+    '\u{64B}'..='\u{65F}',
+    '\u{670}'..='\u{670}',
+    '\u{6D6}'..='\u{6DC}',
+    '\u{6DF}'..='\u{6E4}',
+    '\u{6E7}'..='\u{6E8}',
+    '\u{6EA}'..='\u{6ED}',
+];
+
+/// Hebrew niqqud & cantillation marks.
+pub const HEBREW: &[RangeInclusive<char>] = &[
+    '\u{591}'..='\u{5BD}',
+    '\u{5BF}'..='\u{5BF}',
+    '\u{5C1}'..='\u{5C2}',
+    '\u{5C4}'..='\u{5C5}',
+    '\u{5C7}'..='\u{5C7}',
+];
+
+/// The general-purpose combining diacritical marks, e.g. U+0301, the combining acute accent.
+pub const COMBINING: &[RangeInclusive<char>] = &[
+    '\u{300}'..='\u{36F}',
+    '\u{1AB0}'..='\u{1AFF}',
+    '\u{1DC0}'..='\u{1DFF}',
+    '\u{20D0}'..='\u{20FF}',
+    '\u{FE20}'..='\u{FE2F}',
+];
+
+/// Chandrabindu, anusvara & nukta of the Indic scripts from Devanagari to Malayalam, along with
+/// the tippi of Gurmukhi.
+pub const INDIC: &[RangeInclusive<char>] = &[
+    '\u{901}'..='\u{902}',
+    '\u{93C}'..='\u{93C}',
+    '\u{981}'..='\u{982}',
+    '\u{9BC}'..='\u{9BC}',
+    '\u{A01}'..='\u{A02}',
+    '\u{A3C}'..='\u{A3C}',
+    '\u{A70}'..='\u{A70}',
+    '\u{A81}'..='\u{A82}',
+    '\u{ABC}'..='\u{ABC}',
+    '\u{B01}'..='\u{B02}',
+    '\u{B3C}'..='\u{B3C}',
+    '\u{B82}'..='\u{B82}',
+    '\u{C01}'..='\u{C02}',
+    '\u{C3C}'..='\u{C3C}',
+    '\u{C81}'..='\u{C82}',
+    '\u{CBC}'..='\u{CBC}',
+    '\u{D01}'..='\u{D02}',
+];
+
+/// Dependent vowel signs, or matras, of the Indic scripts from Devanagari to Malayalam.
+///
+/// Matras are more often substituted than omitted, see
+/// [`lookalikes::indic`](crate::lookalikes::indic).
+pub const INDIC_VOWEL_SIGNS: &[RangeInclusive<char>] = &[
+    '\u{93E}'..='\u{94C}',
+    '\u{962}'..='\u{963}',
+    '\u{9BE}'..='\u{9CC}',
+    '\u{9E2}'..='\u{9E3}',
+    '\u{A3E}'..='\u{A4C}',
+    '\u{A62}'..='\u{A63}',
+    '\u{ABE}'..='\u{ACC}',
+    '\u{AE2}'..='\u{AE3}',
+    '\u{B3E}'..='\u{B4C}',
+    '\u{B62}'..='\u{B63}',
+    '\u{BBE}'..='\u{BCC}',
+    '\u{BE2}'..='\u{BE3}',
+    '\u{C3E}'..='\u{C4C}',
+    '\u{C62}'..='\u{C63}',
+    '\u{CBE}'..='\u{CCC}',
+    '\u{CE2}'..='\u{CE3}',
+    '\u{D3E}'..='\u{D4C}',
+    '\u{D62}'..='\u{D63}',
+];
+
+/// Whether `ch` is in any of the ranges of any of the sets of marks.
+pub fn contains(marks: &[&[RangeInclusive<char>]], ch: char) -> bool {
+    marks
+        .iter()
+        .flat_map(|set| set.iter())
+        .any(|range| range.contains(&ch))
+}
@@ -0,0 +1,2719 @@
+//! Canonical normalisation of strings, so that e.g. `é` & `e` followed by a combining acute
+//! accent, U+0301, are considered the same.
+//!
+//! Add the keys with [`SearchTree::push_normalized`](crate::SearchTree::push_normalized) & enable
+//! [`Searcher::with_normalization`](crate::Searcher::with_normalization) to normalise the input:
+//! ```rust
+//! use permissive_search::*;
+//!
+//! let keys = ["cafe\u{301}", "resume"];
+//! let mut root = SearchTree::default();
+//! for (i, key) in keys.into_iter().enumerate() {
+//!     root.push_normalized(key, i);
+//! }
+//!
+//! let mut searcher = Searcher::new(&root, lookalikes::all).with_normalization(true);
+//! searcher.extend("café".chars());
+//! searcher.for_each_candidate::<()>(|i| Ok(println!("{}", keys[i])));
+//! ```
+//!
+//! The data covers the canonical decompositions & combining classes of Unicode 15.1. Hangul
+//! syllables are handled by [`hangul`].
+
+use {
+    crate::hangul,
+    alloc::{string::String, vec::Vec},
+    core::ops::RangeInclusive,
+};
+
+/// Canonical decompositions, sorted by the decomposed character. The second character is NUL for
+/// the characters that decompose into a single one.
+static DECOMPOSITIONS: &[(char, char, char)] = &[
+    ('À', 'A', '\u{300}'),
+    ('Á', 'A', '\u{301}'),
+    ('Â', 'A', '\u{302}'),
+    ('Ã', 'A', '\u{303}'),
+    ('Ä', 'A', '\u{308}'),
+    ('Å', 'A', '\u{30A}'),
+    ('Ç', 'C', '\u{327}'),
+    ('È', 'E', '\u{300}'),
+    ('É', 'E', '\u{301}'),
+    ('Ê', 'E', '\u{302}'),
+    ('Ë', 'E', '\u{308}'),
+    ('Ì', 'I', '\u{300}'),
+    ('Í', 'I', '\u{301}'),
+    ('Î', 'I', '\u{302}'),
+    ('Ï', 'I', '\u{308}'),
+    ('Ñ', 'N', '\u{303}'),
+    ('Ò', 'O', '\u{300}'),
+    ('Ó', 'O', '\u{301}'),
+    ('Ô', 'O', '\u{302}'),
+    ('Õ', 'O', '\u{303}'),
+    ('Ö', 'O', '\u{308}'),
+    ('Ù', 'U', '\u{300}'),
+    ('Ú', 'U', '\u{301}'),
+    ('Û', 'U', '\u{302}'),
+    ('Ü', 'U', '\u{308}'),
+    ('Ý', 'Y', '\u{301}'),
+    ('à', 'a', '\u{300}'),
+    ('á', 'a', '\u{301}'),
+    ('â', 'a', '\u{302}'),
+    ('ã', 'a', '\u{303}'),
+    ('ä', 'a', '\u{308}'),
+    ('å', 'a', '\u{30A}'),
+    ('ç', 'c', '\u{327}'),
+    ('è', 'e', '\u{300}'),
+    ('é', 'e', '\u{301}'),
+    ('ê', 'e', '\u{302}'),
+    ('ë', 'e', '\u{308}'),
+    ('ì', 'i', '\u{300}'),
+    ('í', 'i', '\u{301}'),
+    ('î', 'i', '\u{302}'),
+    ('ï', 'i', '\u{308}'),
+    ('ñ', 'n', '\u{303}'),
+    ('ò', 'o', '\u{300}'),
+    ('ó', 'o', '\u{301}'),
+    ('ô', 'o', '\u{302}'),
+    ('õ', 'o', '\u{303}'),
+    ('ö', 'o', '\u{308}'),
+    ('ù', 'u', '\u{300}'),
+    ('ú', 'u', '\u{301}'),
+    ('û', 'u', '\u{302}'),
+    ('ü', 'u', '\u{308}'),
+    ('ý', 'y', '\u{301}'),
+    ('ÿ', 'y', '\u{308}'),
+    ('Ā', 'A', '\u{304}'),
+    ('ā', 'a', '\u{304}'),
+    ('Ă', 'A', '\u{306}'),
+    ('ă', 'a', '\u{306}'),
+    ('Ą', 'A', '\u{328}'),
+    ('ą', 'a', '\u{328}'),
+    ('Ć', 'C', '\u{301}'),
+    ('ć', 'c', '\u{301}'),
+    ('Ĉ', 'C', '\u{302}'),
+    ('ĉ', 'c', '\u{302}'),
+    ('Ċ', 'C', '\u{307}'),
+    ('ċ', 'c', '\u{307}'),
+    ('Č', 'C', '\u{30C}'),
+    ('č', 'c', '\u{30C}'),
+    ('Ď', 'D', '\u{30C}'),
+    ('ď', 'd', '\u{30C}'),
+    ('Ē', 'E', '\u{304}'),
+    ('ē', 'e', '\u{304}'),
+    ('Ĕ', 'E', '\u{306}'),
+    ('ĕ', 'e', '\u{306}'),
+    ('Ė', 'E', '\u{307}'),
+    ('ė', 'e', '\u{307}'),
+    ('Ę', 'E', '\u{328}'),
+    ('ę', 'e', '\u{328}'),
+    ('Ě', 'E', '\u{30C}'),
+    ('ě', 'e', '\u{30C}'),
+    ('Ĝ', 'G', '\u{302}'),
+    ('ĝ', 'g', '\u{302}'),
+    ('Ğ', 'G', '\u{306}'),
+    ('ğ', 'g', '\u{306}'),
+    ('Ġ', 'G', '\u{307}'),
+    ('ġ', 'g', '\u{307}'),
+    ('Ģ', 'G', '\u{327}'),
+    ('ģ', 'g', '\u{327}'),
+    ('Ĥ', 'H', '\u{302}'),
+    ('ĥ', 'h', '\u{302}'),
+    ('Ĩ', 'I', '\u{303}'),
+    ('ĩ', 'i', '\u{303}'),
+    ('Ī', 'I', '\u{304}'),
+    ('ī', 'i', '\u{304}'),
+    ('Ĭ', 'I', '\u{306}'),
+    ('ĭ', 'i', '\u{306}'),
+    ('Į', 'I', '\u{328}'),
+    ('į', 'i', '\u{328}'),
+    ('İ', 'I', '\u{307}'),
+    ('Ĵ', 'J', '\u{302}'),
+    ('ĵ', 'j', '\u{302}'),
+    ('Ķ', 'K', '\u{327}'),
+    ('ķ', 'k', '\u{327}'),
+    ('Ĺ', 'L', '\u{301}'),
+    ('ĺ', 'l', '\u{301}'),
+    ('Ļ', 'L', '\u{327}'),
+    ('ļ', 'l', '\u{327}'),
+    ('Ľ', 'L', '\u{30C}'),
+    ('ľ', 'l', '\u{30C}'),
+    ('Ń', 'N', '\u{301}'),
+    ('ń', 'n', '\u{301}'),
+    ('Ņ', 'N', '\u{327}'),
+    ('ņ', 'n', '\u{327}'),
+    ('Ň', 'N', '\u{30C}'),
+    ('ň', 'n', '\u{30C}'),
+    ('Ō', 'O', '\u{304}'),
+    ('ō', 'o', '\u{304}'),
+    ('Ŏ', 'O', '\u{306}'),
+    ('ŏ', 'o', '\u{306}'),
+    ('Ő', 'O', '\u{30B}'),
+    ('ő', 'o', '\u{30B}'),
+    ('Ŕ', 'R', '\u{301}'),
+    ('ŕ', 'r', '\u{301}'),
+    ('Ŗ', 'R', '\u{327}'),
+    ('ŗ', 'r', '\u{327}'),
+    ('Ř', 'R', '\u{30C}'),
+    ('ř', 'r', '\u{30C}'),
+    ('Ś', 'S', '\u{301}'),
+    ('ś', 's', '\u{301}'),
+    ('Ŝ', 'S', '\u{302}'),
+    ('ŝ', 's', '\u{302}'),
+    ('Ş', 'S', '\u{327}'),
+    ('ş', 's', '\u{327}'),
+    ('Š', 'S', '\u{30C}'),
+    ('š', 's', '\u{30C}'),
+    ('Ţ', 'T', '\u{327}'),
+    ('ţ', 't', '\u{327}'),
+    ('Ť', 'T', '\u{30C}'),
+    ('ť', 't', '\u{30C}'),
+    ('Ũ', 'U', '\u{303}'),
+    ('ũ', 'u', '\u{303}'),
+    ('Ū', 'U', '\u{304}'),
+    ('ū', 'u', '\u{304}'),
+    ('Ŭ', 'U', '\u{306}'),
+    ('ŭ', 'u', '\u{306}'),
+    ('Ů', 'U', '\u{30A}'),
+    ('ů', 'u', '\u{30A}'),
+    ('Ű', 'U', '\u{30B}'),
+    ('ű', 'u', '\u{30B}'),
+    ('Ų', 'U', '\u{328}'),
+    ('ų', 'u', '\u{328}'),
+    ('Ŵ', 'W', '\u{302}'),
+    ('ŵ', 'w', '\u{302}'),
+    ('Ŷ', 'Y', '\u{302}'),
+    ('ŷ', 'y', '\u{302}'),
+    ('Ÿ', 'Y', '\u{308}'),
+    ('Ź', 'Z', '\u{301}'),
+    ('ź', 'z', '\u{301}'),
+    ('Ż', 'Z', '\u{307}'),
+    ('ż', 'z', '\u{307}'),
+    ('Ž', 'Z', '\u{30C}'),
+    ('ž', 'z', '\u{30C}'),
+    ('Ơ', 'O', '\u{31B}'),
+    ('ơ', 'o', '\u{31B}'),
+    ('Ư', 'U', '\u{31B}'),
+    ('ư', 'u', '\u{31B}'),
+    ('Ǎ', 'A', '\u{30C}'),
+    ('ǎ', 'a', '\u{30C}'),
+    ('Ǐ', 'I', '\u{30C}'),
+    ('ǐ', 'i', '\u{30C}'),
+    ('Ǒ', 'O', '\u{30C}'),
+    ('ǒ', 'o', '\u{30C}'),
+    ('Ǔ', 'U', '\u{30C}'),
+    ('ǔ', 'u', '\u{30C}'),
+    ('Ǖ', 'Ü', '\u{304}'),
+    ('ǖ', 'ü', '\u{304}'),
+    ('Ǘ', 'Ü', '\u{301}'),
+    ('ǘ', 'ü', '\u{301}'),
+    ('Ǚ', 'Ü', '\u{30C}'),
+    ('ǚ', 'ü', '\u{30C}'),
+    ('Ǜ', 'Ü', '\u{300}'),
+    ('ǜ', 'ü', '\u{300}'),
+    ('Ǟ', 'Ä', '\u{304}'),
+    ('ǟ', 'ä', '\u{304}'),
+    ('Ǡ', 'Ȧ', '\u{304}'),
+    ('ǡ', 'ȧ', '\u{304}'),
+    ('Ǣ', 'Æ', '\u{304}'),
+    ('ǣ', 'æ', '\u{304}'),
+    ('Ǧ', 'G', '\u{30C}'),
+    ('ǧ', 'g', '\u{30C}'),
+    ('Ǩ', 'K', '\u{30C}'),
+    ('ǩ', 'k', '\u{30C}'),
+    ('Ǫ', 'O', '\u{328}'),
+    ('ǫ', 'o', '\u{328}'),
+    ('Ǭ', 'Ǫ', '\u{304}'),
+    ('ǭ', 'ǫ', '\u{304}'),
+    ('Ǯ', 'Ʒ', '\u{30C}'),
+    ('ǯ', 'ʒ', '\u{30C}'),
+    ('ǰ', 'j', '\u{30C}'),
+    ('Ǵ', 'G', '\u{301}'),
+    ('ǵ', 'g', '\u{301}'),
+    ('Ǹ', 'N', '\u{300}'),
+    ('ǹ', 'n', '\u{300}'),
+    ('Ǻ', 'Å', '\u{301}'),
+    ('ǻ', 'å', '\u{301}'),
+    ('Ǽ', 'Æ', '\u{301}'),
+    ('ǽ', 'æ', '\u{301}'),
+    ('Ǿ', 'Ø', '\u{301}'),
+    ('ǿ', 'ø', '\u{301}'),
+    ('Ȁ', 'A', '\u{30F}'),
+    ('ȁ', 'a', '\u{30F}'),
+    ('Ȃ', 'A', '\u{311}'),
+    ('ȃ', 'a', '\u{311}'),
+    ('Ȅ', 'E', '\u{30F}'),
+    ('ȅ', 'e', '\u{30F}'),
+    ('Ȇ', 'E', '\u{311}'),
+    ('ȇ', 'e', '\u{311}'),
+    ('Ȉ', 'I', '\u{30F}'),
+    ('ȉ', 'i', '\u{30F}'),
+    ('Ȋ', 'I', '\u{311}'),
+    ('ȋ', 'i', '\u{311}'),
+    ('Ȍ', 'O', '\u{30F}'),
+    ('ȍ', 'o', '\u{30F}'),
+    ('Ȏ', 'O', '\u{311}'),
+    ('ȏ', 'o', '\u{311}'),
+    ('Ȑ', 'R', '\u{30F}'),
+    ('ȑ', 'r', '\u{30F}'),
+    ('Ȓ', 'R', '\u{311}'),
+    ('ȓ', 'r', '\u{311}'),
+    ('Ȕ', 'U', '\u{30F}'),
+    ('ȕ', 'u', '\u{30F}'),
+    ('Ȗ', 'U', '\u{311}'),
+    ('ȗ', 'u', '\u{311}'),
+    ('Ș', 'S', '\u{326}'),
+    ('ș', 's', '\u{326}'),
+    ('Ț', 'T', '\u{326}'),
+    ('ț', 't', '\u{326}'),
+    ('Ȟ', 'H', '\u{30C}'),
+    ('ȟ', 'h', '\u{30C}'),
+    ('Ȧ', 'A', '\u{307}'),
+    ('ȧ', 'a', '\u{307}'),
+    ('Ȩ', 'E', '\u{327}'),
+    ('ȩ', 'e', '\u{327}'),
+    ('Ȫ', 'Ö', '\u{304}'),
+    ('ȫ', 'ö', '\u{304}'),
+    ('Ȭ', 'Õ', '\u{304}'),
+    ('ȭ', 'õ', '\u{304}'),
+    ('Ȯ', 'O', '\u{307}'),
+    ('ȯ', 'o', '\u{307}'),
+    ('Ȱ', 'Ȯ', '\u{304}'),
+    ('ȱ', 'ȯ', '\u{304}'),
+    ('Ȳ', 'Y', '\u{304}'),
+    ('ȳ', 'y', '\u{304}'),
+    ('\u{340}', '\u{300}', '\0'),
+    ('\u{341}', '\u{301}', '\0'),
+    ('\u{343}', '\u{313}', '\0'),
+    ('\u{344}', '\u{308}', '\u{301}'),
+    ('\u{374}', 'ʹ', '\0'),
+    ('\u{37E}', ';', '\0'),
+    ('΅', '¨', '\u{301}'),
+    ('Ά', 'Α', '\u{301}'),
+    ('\u{387}', '·', '\0'),
+    ('Έ', 'Ε', '\u{301}'),
+    ('Ή', 'Η', '\u{301}'),
+    ('Ί', 'Ι', '\u{301}'),
+    ('Ό', 'Ο', '\u{301}'),
+    ('Ύ', 'Υ', '\u{301}'),
+    ('Ώ', 'Ω', '\u{301}'),
+    ('ΐ', 'ϊ', '\u{301}'),
+    ('Ϊ', 'Ι', '\u{308}'),
+    ('Ϋ', 'Υ', '\u{308}'),
+    ('ά', 'α', '\u{301}'),
+    ('έ', 'ε', '\u{301}'),
+    ('ή', 'η', '\u{301}'),
+    ('ί', 'ι', '\u{301}'),
+    ('ΰ', 'ϋ', '\u{301}'),
+    ('ϊ', 'ι', '\u{308}'),
+    ('ϋ', 'υ', '\u{308}'),
+    ('ό', 'ο', '\u{301}'),
+    ('ύ', 'υ', '\u{301}'),
+    ('ώ', 'ω', '\u{301}'),
+    ('ϓ', 'ϒ', '\u{301}'),
+    ('ϔ', 'ϒ', '\u{308}'),
+    ('Ѐ', 'Е', '\u{300}'),
+    ('Ё', 'Е', '\u{308}'),
+    ('Ѓ', 'Г', '\u{301}'),
+    ('Ї', 'І', '\u{308}'),
+    ('Ќ', 'К', '\u{301}'),
+    ('Ѝ', 'И', '\u{300}'),
+    ('Ў', 'У', '\u{306}'),
+    ('Й', 'И', '\u{306}'),
+    ('й', 'и', '\u{306}'),
+    ('ѐ', 'е', '\u{300}'),
+    ('ё', 'е', '\u{308}'),
+    ('ѓ', 'г', '\u{301}'),
+    ('ї', 'і', '\u{308}'),
+    ('ќ', 'к', '\u{301}'),
+    ('ѝ', 'и', '\u{300}'),
+    ('ў', 'у', '\u{306}'),
+    ('Ѷ', 'Ѵ', '\u{30F}'),
+    ('ѷ', 'ѵ', '\u{30F}'),
+    ('Ӂ', 'Ж', '\u{306}'),
+    ('ӂ', 'ж', '\u{306}'),
+    ('Ӑ', 'А', '\u{306}'),
+    ('ӑ', 'а', '\u{306}'),
+    ('Ӓ', 'А', '\u{308}'),
+    ('ӓ', 'а', '\u{308}'),
+    ('Ӗ', 'Е', '\u{306}'),
+    ('ӗ', 'е', '\u{306}'),
+    ('Ӛ', 'Ә', '\u{308}'),
+    ('ӛ', 'ә', '\u{308}'),
+    ('Ӝ', 'Ж', '\u{308}'),
+    ('ӝ', 'ж', '\u{308}'),
+    ('Ӟ', 'З', '\u{308}'),
+    ('ӟ', 'з', '\u{308}'),
+    ('Ӣ', 'И', '\u{304}'),
+    ('ӣ', 'и', '\u{304}'),
+    ('Ӥ', 'И', '\u{308}'),
+    ('ӥ', 'и', '\u{308}'),
+    ('Ӧ', 'О', '\u{308}'),
+    ('ӧ', 'о', '\u{308}'),
+    ('Ӫ', 'Ө', '\u{308}'),
+    ('ӫ', 'ө', '\u{308}'),
+    ('Ӭ', 'Э', '\u{308}'),
+    ('ӭ', 'э', '\u{308}'),
+    ('Ӯ', 'У', '\u{304}'),
+    ('ӯ', 'у', '\u{304}'),
+    ('Ӱ', 'У', '\u{308}'),
+    ('ӱ', 'у', '\u{308}'),
+    ('Ӳ', 'У', '\u{30B}'),
+    ('ӳ', 'у', '\u{30B}'),
+    ('Ӵ', 'Ч', '\u{308}'),
+    ('ӵ', 'ч', '\u{308}'),
+    ('Ӹ', 'Ы', '\u{308}'),
+    ('ӹ', 'ы', '\u{308}'),
+    ('آ', 'ا', '\u{653}'),
+    ('أ', 'ا', '\u{654}'),
+    ('ؤ', 'و', '\u{654}'),
+    ('إ', 'ا', '\u{655}'),
+    ('ئ', 'ي', '\u{654}'),
+    ('ۀ', 'ە', '\u{654}'),
+    ('ۂ', 'ہ', '\u{654}'),
+    ('ۓ', 'ے', '\u{654}'),
+    ('ऩ', 'न', '\u{93C}'),
+    ('ऱ', 'र', '\u{93C}'),
+    ('ऴ', 'ळ', '\u{93C}'),
+    ('\u{958}', 'क', '\u{93C}'),
+    ('\u{959}', 'ख', '\u{93C}'),
+    ('\u{95A}', 'ग', '\u{93C}'),
+    ('\u{95B}', 'ज', '\u{93C}'),
+    ('\u{95C}', 'ड', '\u{93C}'),
+    ('\u{95D}', 'ढ', '\u{93C}'),
+    ('\u{95E}', 'फ', '\u{93C}'),
+    ('\u{95F}', 'य', '\u{93C}'),
+    ('\u{9CB}', '\u{9C7}', '\u{9BE}'),
+    ('\u{9CC}', '\u{9C7}', '\u{9D7}'),
+    ('\u{9DC}', 'ড', '\u{9BC}'),
+    ('\u{9DD}', 'ঢ', '\u{9BC}'),
+    ('\u{9DF}', 'য', '\u{9BC}'),
+    ('\u{A33}', 'ਲ', '\u{A3C}'),
+    ('\u{A36}', 'ਸ', '\u{A3C}'),
+    ('\u{A59}', 'ਖ', '\u{A3C}'),
+    ('\u{A5A}', 'ਗ', '\u{A3C}'),
+    ('\u{A5B}', 'ਜ', '\u{A3C}'),
+    ('\u{A5E}', 'ਫ', '\u{A3C}'),
+    ('\u{B48}', '\u{B47}', '\u{B56}'),
+    ('\u{B4B}', '\u{B47}', '\u{B3E}'),
+    ('\u{B4C}', '\u{B47}', '\u{B57}'),
+    ('\u{B5C}', 'ଡ', '\u{B3C}'),
+    ('\u{B5D}', 'ଢ', '\u{B3C}'),
+    ('ஔ', 'ஒ', '\u{BD7}'),
+    ('\u{BCA}', '\u{BC6}', '\u{BBE}'),
+    ('\u{BCB}', '\u{BC7}', '\u{BBE}'),
+    ('\u{BCC}', '\u{BC6}', '\u{BD7}'),
+    ('\u{C48}', '\u{C46}', '\u{C56}'),
+    ('\u{CC0}', '\u{CBF}', '\u{CD5}'),
+    ('\u{CC7}', '\u{CC6}', '\u{CD5}'),
+    ('\u{CC8}', '\u{CC6}', '\u{CD6}'),
+    ('\u{CCA}', '\u{CC6}', '\u{CC2}'),
+    ('\u{CCB}', '\u{CCA}', '\u{CD5}'),
+    ('\u{D4A}', '\u{D46}', '\u{D3E}'),
+    ('\u{D4B}', '\u{D47}', '\u{D3E}'),
+    ('\u{D4C}', '\u{D46}', '\u{D57}'),
+    ('\u{DDA}', '\u{DD9}', '\u{DCA}'),
+    ('\u{DDC}', '\u{DD9}', '\u{DCF}'),
+    ('\u{DDD}', '\u{DDC}', '\u{DCA}'),
+    ('\u{DDE}', '\u{DD9}', '\u{DDF}'),
+    ('\u{F43}', 'ག', '\u{FB7}'),
+    ('\u{F4D}', 'ཌ', '\u{FB7}'),
+    ('\u{F52}', 'ད', '\u{FB7}'),
+    ('\u{F57}', 'བ', '\u{FB7}'),
+    ('\u{F5C}', 'ཛ', '\u{FB7}'),
+    ('\u{F69}', 'ཀ', '\u{FB5}'),
+    ('\u{F73}', '\u{F71}', '\u{F72}'),
+    ('\u{F75}', '\u{F71}', '\u{F74}'),
+    ('\u{F76}', '\u{FB2}', '\u{F80}'),
+    ('\u{F78}', '\u{FB3}', '\u{F80}'),
+    ('\u{F81}', '\u{F71}', '\u{F80}'),
+    ('\u{F93}', '\u{F92}', '\u{FB7}'),
+    ('\u{F9D}', '\u{F9C}', '\u{FB7}'),
+    ('\u{FA2}', '\u{FA1}', '\u{FB7}'),
+    ('\u{FA7}', '\u{FA6}', '\u{FB7}'),
+    ('\u{FAC}', '\u{FAB}', '\u{FB7}'),
+    ('\u{FB9}', '\u{F90}', '\u{FB5}'),
+    ('ဦ', 'ဥ', '\u{102E}'),
+    ('ᬆ', 'ᬅ', '\u{1B35}'),
+    ('ᬈ', 'ᬇ', '\u{1B35}'),
+    ('ᬊ', 'ᬉ', '\u{1B35}'),
+    ('ᬌ', 'ᬋ', '\u{1B35}'),
+    ('ᬎ', 'ᬍ', '\u{1B35}'),
+    ('ᬒ', 'ᬑ', '\u{1B35}'),
+    ('\u{1B3B}', '\u{1B3A}', '\u{1B35}'),
+    ('\u{1B3D}', '\u{1B3C}', '\u{1B35}'),
+    ('\u{1B40}', '\u{1B3E}', '\u{1B35}'),
+    ('\u{1B41}', '\u{1B3F}', '\u{1B35}'),
+    ('\u{1B43}', '\u{1B42}', '\u{1B35}'),
+    ('Ḁ', 'A', '\u{325}'),
+    ('ḁ', 'a', '\u{325}'),
+    ('Ḃ', 'B', '\u{307}'),
+    ('ḃ', 'b', '\u{307}'),
+    ('Ḅ', 'B', '\u{323}'),
+    ('ḅ', 'b', '\u{323}'),
+    ('Ḇ', 'B', '\u{331}'),
+    ('ḇ', 'b', '\u{331}'),
+    ('Ḉ', 'Ç', '\u{301}'),
+    ('ḉ', 'ç', '\u{301}'),
+    ('Ḋ', 'D', '\u{307}'),
+    ('ḋ', 'd', '\u{307}'),
+    ('Ḍ', 'D', '\u{323}'),
+    ('ḍ', 'd', '\u{323}'),
+    ('Ḏ', 'D', '\u{331}'),
+    ('ḏ', 'd', '\u{331}'),
+    ('Ḑ', 'D', '\u{327}'),
+    ('ḑ', 'd', '\u{327}'),
+    ('Ḓ', 'D', '\u{32D}'),
+    ('ḓ', 'd', '\u{32D}'),
+    ('Ḕ', 'Ē', '\u{300}'),
+    ('ḕ', 'ē', '\u{300}'),
+    ('Ḗ', 'Ē', '\u{301}'),
+    ('ḗ', 'ē', '\u{301}'),
+    ('Ḙ', 'E', '\u{32D}'),
+    ('ḙ', 'e', '\u{32D}'),
+    ('Ḛ', 'E', '\u{330}'),
+    ('ḛ', 'e', '\u{330}'),
+    ('Ḝ', 'Ȩ', '\u{306}'),
+    ('ḝ', 'ȩ', '\u{306}'),
+    ('Ḟ', 'F', '\u{307}'),
+    ('ḟ', 'f', '\u{307}'),
+    ('Ḡ', 'G', '\u{304}'),
+    ('ḡ', 'g', '\u{304}'),
+    ('Ḣ', 'H', '\u{307}'),
+    ('ḣ', 'h', '\u{307}'),
+    ('Ḥ', 'H', '\u{323}'),
+    ('ḥ', 'h', '\u{323}'),
+    ('Ḧ', 'H', '\u{308}'),
+    ('ḧ', 'h', '\u{308}'),
+    ('Ḩ', 'H', '\u{327}'),
+    ('ḩ', 'h', '\u{327}'),
+    ('Ḫ', 'H', '\u{32E}'),
+    ('ḫ', 'h', '\u{32E}'),
+    ('Ḭ', 'I', '\u{330}'),
+    ('ḭ', 'i', '\u{330}'),
+    ('Ḯ', 'Ï', '\u{301}'),
+    ('ḯ', 'ï', '\u{301}'),
+    ('Ḱ', 'K', '\u{301}'),
+    ('ḱ', 'k', '\u{301}'),
+    ('Ḳ', 'K', '\u{323}'),
+    ('ḳ', 'k', '\u{323}'),
+    ('Ḵ', 'K', '\u{331}'),
+    ('ḵ', 'k', '\u{331}'),
+    ('Ḷ', 'L', '\u{323}'),
+    ('ḷ', 'l', '\u{323}'),
+    ('Ḹ', 'Ḷ', '\u{304}'),
+    ('ḹ', 'ḷ', '\u{304}'),
+    ('Ḻ', 'L', '\u{331}'),
+    ('ḻ', 'l', '\u{331}'),
+    ('Ḽ', 'L', '\u{32D}'),
+    ('ḽ', 'l', '\u{32D}'),
+    ('Ḿ', 'M', '\u{301}'),
+    ('ḿ', 'm', '\u{301}'),
+    ('Ṁ', 'M', '\u{307}'),
+    ('ṁ', 'm', '\u{307}'),
+    ('Ṃ', 'M', '\u{323}'),
+    ('ṃ', 'm', '\u{323}'),
+    ('Ṅ', 'N', '\u{307}'),
+    ('ṅ', 'n', '\u{307}'),
+    ('Ṇ', 'N', '\u{323}'),
+    ('ṇ', 'n', '\u{323}'),
+    ('Ṉ', 'N', '\u{331}'),
+    ('ṉ', 'n', '\u{331}'),
+    ('Ṋ', 'N', '\u{32D}'),
+    ('ṋ', 'n', '\u{32D}'),
+    ('Ṍ', 'Õ', '\u{301}'),
+    ('ṍ', 'õ', '\u{301}'),
+    ('Ṏ', 'Õ', '\u{308}'),
+    ('ṏ', 'õ', '\u{308}'),
+    ('Ṑ', 'Ō', '\u{300}'),
+    ('ṑ', 'ō', '\u{300}'),
+    ('Ṓ', 'Ō', '\u{301}'),
+    ('ṓ', 'ō', '\u{301}'),
+    ('Ṕ', 'P', '\u{301}'),
+    ('ṕ', 'p', '\u{301}'),
+    ('Ṗ', 'P', '\u{307}'),
+    ('ṗ', 'p', '\u{307}'),
+    ('Ṙ', 'R', '\u{307}'),
+    ('ṙ', 'r', '\u{307}'),
+    ('Ṛ', 'R', '\u{323}'),
+    ('ṛ', 'r', '\u{323}'),
+    ('Ṝ', 'Ṛ', '\u{304}'),
+    ('ṝ', 'ṛ', '\u{304}'),
+    ('Ṟ', 'R', '\u{331}'),
+    ('ṟ', 'r', '\u{331}'),
+    ('Ṡ', 'S', '\u{307}'),
+    ('ṡ', 's', '\u{307}'),
+    ('Ṣ', 'S', '\u{323}'),
+    ('ṣ', 's', '\u{323}'),
+    ('Ṥ', 'Ś', '\u{307}'),
+    ('ṥ', 'ś', '\u{307}'),
+    ('Ṧ', 'Š', '\u{307}'),
+    ('ṧ', 'š', '\u{307}'),
+    ('Ṩ', 'Ṣ', '\u{307}'),
+    ('ṩ', 'ṣ', '\u{307}'),
+    ('Ṫ', 'T', '\u{307}'),
+    ('ṫ', 't', '\u{307}'),
+    ('Ṭ', 'T', '\u{323}'),
+    ('ṭ', 't', '\u{323}'),
+    ('Ṯ', 'T', '\u{331}'),
+    ('ṯ', 't', '\u{331}'),
+    ('Ṱ', 'T', '\u{32D}'),
+    ('ṱ', 't', '\u{32D}'),
+    ('Ṳ', 'U', '\u{324}'),
+    ('ṳ', 'u', '\u{324}'),
+    ('Ṵ', 'U', '\u{330}'),
+    ('ṵ', 'u', '\u{330}'),
+    ('Ṷ', 'U', '\u{32D}'),
+    ('ṷ', 'u', '\u{32D}'),
+    ('Ṹ', 'Ũ', '\u{301}'),
+    ('ṹ', 'ũ', '\u{301}'),
+    ('Ṻ', 'Ū', '\u{308}'),
+    ('ṻ', 'ū', '\u{308}'),
+    ('Ṽ', 'V', '\u{303}'),
+    ('ṽ', 'v', '\u{303}'),
+    ('Ṿ', 'V', '\u{323}'),
+    ('ṿ', 'v', '\u{323}'),
+    ('Ẁ', 'W', '\u{300}'),
+    ('ẁ', 'w', '\u{300}'),
+    ('Ẃ', 'W', '\u{301}'),
+    ('ẃ', 'w', '\u{301}'),
+    ('Ẅ', 'W', '\u{308}'),
+    ('ẅ', 'w', '\u{308}'),
+    ('Ẇ', 'W', '\u{307}'),
+    ('ẇ', 'w', '\u{307}'),
+    ('Ẉ', 'W', '\u{323}'),
+    ('ẉ', 'w', '\u{323}'),
+    ('Ẋ', 'X', '\u{307}'),
+    ('ẋ', 'x', '\u{307}'),
+    ('Ẍ', 'X', '\u{308}'),
+    ('ẍ', 'x', '\u{308}'),
+    ('Ẏ', 'Y', '\u{307}'),
+    ('ẏ', 'y', '\u{307}'),
+    ('Ẑ', 'Z', '\u{302}'),
+    ('ẑ', 'z', '\u{302}'),
+    ('Ẓ', 'Z', '\u{323}'),
+    ('ẓ', 'z', '\u{323}'),
+    ('Ẕ', 'Z', '\u{331}'),
+    ('ẕ', 'z', '\u{331}'),
+    ('ẖ', 'h', '\u{331}'),
+    ('ẗ', 't', '\u{308}'),
+    ('ẘ', 'w', '\u{30A}'),
+    ('ẙ', 'y', '\u{30A}'),
+    ('ẛ', 'ſ', '\u{307}'),
+    ('Ạ', 'A', '\u{323}'),
+    ('ạ', 'a', '\u{323}'),
+    ('Ả', 'A', '\u{309}'),
+    ('ả', 'a', '\u{309}'),
+    ('Ấ', 'Â', '\u{301}'),
+    ('ấ', 'â', '\u{301}'),
+    ('Ầ', 'Â', '\u{300}'),
+    ('ầ', 'â', '\u{300}'),
+    ('Ẩ', 'Â', '\u{309}'),
+    ('ẩ', 'â', '\u{309}'),
+    ('Ẫ', 'Â', '\u{303}'),
+    ('ẫ', 'â', '\u{303}'),
+    ('Ậ', 'Ạ', '\u{302}'),
+    ('ậ', 'ạ', '\u{302}'),
+    ('Ắ', 'Ă', '\u{301}'),
+    ('ắ', 'ă', '\u{301}'),
+    ('Ằ', 'Ă', '\u{300}'),
+    ('ằ', 'ă', '\u{300}'),
+    ('Ẳ', 'Ă', '\u{309}'),
+    ('ẳ', 'ă', '\u{309}'),
+    ('Ẵ', 'Ă', '\u{303}'),
+    ('ẵ', 'ă', '\u{303}'),
+    ('Ặ', 'Ạ', '\u{306}'),
+    ('ặ', 'ạ', '\u{306}'),
+    ('Ẹ', 'E', '\u{323}'),
+    ('ẹ', 'e', '\u{323}'),
+    ('Ẻ', 'E', '\u{309}'),
+    ('ẻ', 'e', '\u{309}'),
+    ('Ẽ', 'E', '\u{303}'),
+    ('ẽ', 'e', '\u{303}'),
+    ('Ế', 'Ê', '\u{301}'),
+    ('ế', 'ê', '\u{301}'),
+    ('Ề', 'Ê', '\u{300}'),
+    ('ề', 'ê', '\u{300}'),
+    ('Ể', 'Ê', '\u{309}'),
+    ('ể', 'ê', '\u{309}'),
+    ('Ễ', 'Ê', '\u{303}'),
+    ('ễ', 'ê', '\u{303}'),
+    ('Ệ', 'Ẹ', '\u{302}'),
+    ('ệ', 'ẹ', '\u{302}'),
+    ('Ỉ', 'I', '\u{309}'),
+    ('ỉ', 'i', '\u{309}'),
+    ('Ị', 'I', '\u{323}'),
+    ('ị', 'i', '\u{323}'),
+    ('Ọ', 'O', '\u{323}'),
+    ('ọ', 'o', '\u{323}'),
+    ('Ỏ', 'O', '\u{309}'),
+    ('ỏ', 'o', '\u{309}'),
+    ('Ố', 'Ô', '\u{301}'),
+    ('ố', 'ô', '\u{301}'),
+    ('Ồ', 'Ô', '\u{300}'),
+    ('ồ', 'ô', '\u{300}'),
+    ('Ổ', 'Ô', '\u{309}'),
+    ('ổ', 'ô', '\u{309}'),
+    ('Ỗ', 'Ô', '\u{303}'),
+    ('ỗ', 'ô', '\u{303}'),
+    ('Ộ', 'Ọ', '\u{302}'),
+    ('ộ', 'ọ', '\u{302}'),
+    ('Ớ', 'Ơ', '\u{301}'),
+    ('ớ', 'ơ', '\u{301}'),
+    ('Ờ', 'Ơ', '\u{300}'),
+    ('ờ', 'ơ', '\u{300}'),
+    ('Ở', 'Ơ', '\u{309}'),
+    ('ở', 'ơ', '\u{309}'),
+    ('Ỡ', 'Ơ', '\u{303}'),
+    ('ỡ', 'ơ', '\u{303}'),
+    ('Ợ', 'Ơ', '\u{323}'),
+    ('ợ', 'ơ', '\u{323}'),
+    ('Ụ', 'U', '\u{323}'),
+    ('ụ', 'u', '\u{323}'),
+    ('Ủ', 'U', '\u{309}'),
+    ('ủ', 'u', '\u{309}'),
+    ('Ứ', 'Ư', '\u{301}'),
+    ('ứ', 'ư', '\u{301}'),
+    ('Ừ', 'Ư', '\u{300}'),
+    ('ừ', 'ư', '\u{300}'),
+    ('Ử', 'Ư', '\u{309}'),
+    ('ử', 'ư', '\u{309}'),
+    ('Ữ', 'Ư', '\u{303}'),
+    ('ữ', 'ư', '\u{303}'),
+    ('Ự', 'Ư', '\u{323}'),
+    ('ự', 'ư', '\u{323}'),
+    ('Ỳ', 'Y', '\u{300}'),
+    ('ỳ', 'y', '\u{300}'),
+    ('Ỵ', 'Y', '\u{323}'),
+    ('ỵ', 'y', '\u{323}'),
+    ('Ỷ', 'Y', '\u{309}'),
+    ('ỷ', 'y', '\u{309}'),
+    ('Ỹ', 'Y', '\u{303}'),
+    ('ỹ', 'y', '\u{303}'),
+    ('ἀ', 'α', '\u{313}'),
+    ('ἁ', 'α', '\u{314}'),
+    ('ἂ', 'ἀ', '\u{300}'),
+    ('ἃ', 'ἁ', '\u{300}'),
+    ('ἄ', 'ἀ', '\u{301}'),
+    ('ἅ', 'ἁ', '\u{301}'),
+    ('ἆ', 'ἀ', '\u{342}'),
+    ('ἇ', 'ἁ', '\u{342}'),
+    ('Ἀ', 'Α', '\u{313}'),
+    ('Ἁ', 'Α', '\u{314}'),
+    ('Ἂ', 'Ἀ', '\u{300}'),
+    ('Ἃ', 'Ἁ', '\u{300}'),
+    ('Ἄ', 'Ἀ', '\u{301}'),
+    ('Ἅ', 'Ἁ', '\u{301}'),
+    ('Ἆ', 'Ἀ', '\u{342}'),
+    ('Ἇ', 'Ἁ', '\u{342}'),
+    ('ἐ', 'ε', '\u{313}'),
+    ('ἑ', 'ε', '\u{314}'),
+    ('ἒ', 'ἐ', '\u{300}'),
+    ('ἓ', 'ἑ', '\u{300}'),
+    ('ἔ', 'ἐ', '\u{301}'),
+    ('ἕ', 'ἑ', '\u{301}'),
+    ('Ἐ', 'Ε', '\u{313}'),
+    ('Ἑ', 'Ε', '\u{314}'),
+    ('Ἒ', 'Ἐ', '\u{300}'),
+    ('Ἓ', 'Ἑ', '\u{300}'),
+    ('Ἔ', 'Ἐ', '\u{301}'),
+    ('Ἕ', 'Ἑ', '\u{301}'),
+    ('ἠ', 'η', '\u{313}'),
+    ('ἡ', 'η', '\u{314}'),
+    ('ἢ', 'ἠ', '\u{300}'),
+    ('ἣ', 'ἡ', '\u{300}'),
+    ('ἤ', 'ἠ', '\u{301}'),
+    ('ἥ', 'ἡ', '\u{301}'),
+    ('ἦ', 'ἠ', '\u{342}'),
+    ('ἧ', 'ἡ', '\u{342}'),
+    ('Ἠ', 'Η', '\u{313}'),
+    ('Ἡ', 'Η', '\u{314}'),
+    ('Ἢ', 'Ἠ', '\u{300}'),
+    ('Ἣ', 'Ἡ', '\u{300}'),
+    ('Ἤ', 'Ἠ', '\u{301}'),
+    ('Ἥ', 'Ἡ', '\u{301}'),
+    ('Ἦ', 'Ἠ', '\u{342}'),
+    ('Ἧ', 'Ἡ', '\u{342}'),
+    ('ἰ', 'ι', '\u{313}'),
+    ('ἱ', 'ι', '\u{314}'),
+    ('ἲ', 'ἰ', '\u{300}'),
+    ('ἳ', 'ἱ', '\u{300}'),
+    ('ἴ', 'ἰ', '\u{301}'),
+    ('ἵ', 'ἱ', '\u{301}'),
+    ('ἶ', 'ἰ', '\u{342}'),
+    ('ἷ', 'ἱ', '\u{342}'),
+    ('Ἰ', 'Ι', '\u{313}'),
+    ('Ἱ', 'Ι', '\u{314}'),
+    ('Ἲ', 'Ἰ', '\u{300}'),
+    ('Ἳ', 'Ἱ', '\u{300}'),
+    ('Ἴ', 'Ἰ', '\u{301}'),
+    ('Ἵ', 'Ἱ', '\u{301}'),
+    ('Ἶ', 'Ἰ', '\u{342}'),
+    ('Ἷ', 'Ἱ', '\u{342}'),
+    ('ὀ', 'ο', '\u{313}'),
+    ('ὁ', 'ο', '\u{314}'),
+    ('ὂ', 'ὀ', '\u{300}'),
+    ('ὃ', 'ὁ', '\u{300}'),
+    ('ὄ', 'ὀ', '\u{301}'),
+    ('ὅ', 'ὁ', '\u{301}'),
+    ('Ὀ', 'Ο', '\u{313}'),
+    ('Ὁ', 'Ο', '\u{314}'),
+    ('Ὂ', 'Ὀ', '\u{300}'),
+    ('Ὃ', 'Ὁ', '\u{300}'),
+    ('Ὄ', 'Ὀ', '\u{301}'),
+    ('Ὅ', 'Ὁ', '\u{301}'),
+    ('ὐ', 'υ', '\u{313}'),
+    ('ὑ', 'υ', '\u{314}'),
+    ('ὒ', 'ὐ', '\u{300}'),
+    ('ὓ', 'ὑ', '\u{300}'),
+    ('ὔ', 'ὐ', '\u{301}'),
+    ('ὕ', 'ὑ', '\u{301}'),
+    ('ὖ', 'ὐ', '\u{342}'),
+    ('ὗ', 'ὑ', '\u{342}'),
+    ('Ὑ', 'Υ', '\u{314}'),
+    ('Ὓ', 'Ὑ', '\u{300}'),
+    ('Ὕ', 'Ὑ', '\u{301}'),
+    ('Ὗ', 'Ὑ', '\u{342}'),
+    ('ὠ', 'ω', '\u{313}'),
+    ('ὡ', 'ω', '\u{314}'),
+    ('ὢ', 'ὠ', '\u{300}'),
+    ('ὣ', 'ὡ', '\u{300}'),
+    ('ὤ', 'ὠ', '\u{301}'),
+    ('ὥ', 'ὡ', '\u{301}'),
+    ('ὦ', 'ὠ', '\u{342}'),
+    ('ὧ', 'ὡ', '\u{342}'),
+    ('Ὠ', 'Ω', '\u{313}'),
+    ('Ὡ', 'Ω', '\u{314}'),
+    ('Ὢ', 'Ὠ', '\u{300}'),
+    ('Ὣ', 'Ὡ', '\u{300}'),
+    ('Ὤ', 'Ὠ', '\u{301}'),
+    ('Ὥ', 'Ὡ', '\u{301}'),
+    ('Ὦ', 'Ὠ', '\u{342}'),
+    ('Ὧ', 'Ὡ', '\u{342}'),
+    ('ὰ', 'α', '\u{300}'),
+    ('\u{1F71}', 'ά', '\0'),
+    ('ὲ', 'ε', '\u{300}'),
+    ('\u{1F73}', 'έ', '\0'),
+    ('ὴ', 'η', '\u{300}'),
+    ('\u{1F75}', 'ή', '\0'),
+    ('ὶ', 'ι', '\u{300}'),
+    ('\u{1F77}', 'ί', '\0'),
+    ('ὸ', 'ο', '\u{300}'),
+    ('\u{1F79}', 'ό', '\0'),
+    ('ὺ', 'υ', '\u{300}'),
+    ('\u{1F7B}', 'ύ', '\0'),
+    ('ὼ', 'ω', '\u{300}'),
+    ('\u{1F7D}', 'ώ', '\0'),
+    ('ᾀ', 'ἀ', '\u{345}'),
+    ('ᾁ', 'ἁ', '\u{345}'),
+    ('ᾂ', 'ἂ', '\u{345}'),
+    ('ᾃ', 'ἃ', '\u{345}'),
+    ('ᾄ', 'ἄ', '\u{345}'),
+    ('ᾅ', 'ἅ', '\u{345}'),
+    ('ᾆ', 'ἆ', '\u{345}'),
+    ('ᾇ', 'ἇ', '\u{345}'),
+    ('ᾈ', 'Ἀ', '\u{345}'),
+    ('ᾉ', 'Ἁ', '\u{345}'),
+    ('ᾊ', 'Ἂ', '\u{345}'),
+    ('ᾋ', 'Ἃ', '\u{345}'),
+    ('ᾌ', 'Ἄ', '\u{345}'),
+    ('ᾍ', 'Ἅ', '\u{345}'),
+    ('ᾎ', 'Ἆ', '\u{345}'),
+    ('ᾏ', 'Ἇ', '\u{345}'),
+    ('ᾐ', 'ἠ', '\u{345}'),
+    ('ᾑ', 'ἡ', '\u{345}'),
+    ('ᾒ', 'ἢ', '\u{345}'),
+    ('ᾓ', 'ἣ', '\u{345}'),
+    ('ᾔ', 'ἤ', '\u{345}'),
+    ('ᾕ', 'ἥ', '\u{345}'),
+    ('ᾖ', 'ἦ', '\u{345}'),
+    ('ᾗ', 'ἧ', '\u{345}'),
+    ('ᾘ', 'Ἠ', '\u{345}'),
+    ('ᾙ', 'Ἡ', '\u{345}'),
+    ('ᾚ', 'Ἢ', '\u{345}'),
+    ('ᾛ', 'Ἣ', '\u{345}'),
+    ('ᾜ', 'Ἤ', '\u{345}'),
+    ('ᾝ', 'Ἥ', '\u{345}'),
+    ('ᾞ', 'Ἦ', '\u{345}'),
+    ('ᾟ', 'Ἧ', '\u{345}'),
+    ('ᾠ', 'ὠ', '\u{345}'),
+    ('ᾡ', 'ὡ', '\u{345}'),
+    ('ᾢ', 'ὢ', '\u{345}'),
+    ('ᾣ', 'ὣ', '\u{345}'),
+    ('ᾤ', 'ὤ', '\u{345}'),
+    ('ᾥ', 'ὥ', '\u{345}'),
+    ('ᾦ', 'ὦ', '\u{345}'),
+    ('ᾧ', 'ὧ', '\u{345}'),
+    ('ᾨ', 'Ὠ', '\u{345}'),
+    ('ᾩ', 'Ὡ', '\u{345}'),
+    ('ᾪ', 'Ὢ', '\u{345}'),
+    ('ᾫ', 'Ὣ', '\u{345}'),
+    ('ᾬ', 'Ὤ', '\u{345}'),
+    ('ᾭ', 'Ὥ', '\u{345}'),
+    ('ᾮ', 'Ὦ', '\u{345}'),
+    ('ᾯ', 'Ὧ', '\u{345}'),
+    ('ᾰ', 'α', '\u{306}'),
+    ('ᾱ', 'α', '\u{304}'),
+    ('ᾲ', 'ὰ', '\u{345}'),
+    ('ᾳ', 'α', '\u{345}'),
+    ('ᾴ', 'ά', '\u{345}'),
+    ('ᾶ', 'α', '\u{342}'),
+    ('ᾷ', 'ᾶ', '\u{345}'),
+    ('Ᾰ', 'Α', '\u{306}'),
+    ('Ᾱ', 'Α', '\u{304}'),
+    ('Ὰ', 'Α', '\u{300}'),
+    ('\u{1FBB}', 'Ά', '\0'),
+    ('ᾼ', 'Α', '\u{345}'),
+    ('\u{1FBE}', 'ι', '\0'),
+    ('῁', '¨', '\u{342}'),
+    ('ῂ', 'ὴ', '\u{345}'),
+    ('ῃ', 'η', '\u{345}'),
+    ('ῄ', 'ή', '\u{345}'),
+    ('ῆ', 'η', '\u{342}'),
+    ('ῇ', 'ῆ', '\u{345}'),
+    ('Ὲ', 'Ε', '\u{300}'),
+    ('\u{1FC9}', 'Έ', '\0'),
+    ('Ὴ', 'Η', '\u{300}'),
+    ('\u{1FCB}', 'Ή', '\0'),
+    ('ῌ', 'Η', '\u{345}'),
+    ('῍', '᾿', '\u{300}'),
+    ('῎', '᾿', '\u{301}'),
+    ('῏', '᾿', '\u{342}'),
+    ('ῐ', 'ι', '\u{306}'),
+    ('ῑ', 'ι', '\u{304}'),
+    ('ῒ', 'ϊ', '\u{300}'),
+    ('\u{1FD3}', 'ΐ', '\0'),
+    ('ῖ', 'ι', '\u{342}'),
+    ('ῗ', 'ϊ', '\u{342}'),
+    ('Ῐ', 'Ι', '\u{306}'),
+    ('Ῑ', 'Ι', '\u{304}'),
+    ('Ὶ', 'Ι', '\u{300}'),
+    ('\u{1FDB}', 'Ί', '\0'),
+    ('῝', '῾', '\u{300}'),
+    ('῞', '῾', '\u{301}'),
+    ('῟', '῾', '\u{342}'),
+    ('ῠ', 'υ', '\u{306}'),
+    ('ῡ', 'υ', '\u{304}'),
+    ('ῢ', 'ϋ', '\u{300}'),
+    ('\u{1FE3}', 'ΰ', '\0'),
+    ('ῤ', 'ρ', '\u{313}'),
+    ('ῥ', 'ρ', '\u{314}'),
+    ('ῦ', 'υ', '\u{342}'),
+    ('ῧ', 'ϋ', '\u{342}'),
+    ('Ῠ', 'Υ', '\u{306}'),
+    ('Ῡ', 'Υ', '\u{304}'),
+    ('Ὺ', 'Υ', '\u{300}'),
+    ('\u{1FEB}', 'Ύ', '\0'),
+    ('Ῥ', 'Ρ', '\u{314}'),
+    ('῭', '¨', '\u{300}'),
+    ('\u{1FEE}', '΅', '\0'),
+    ('\u{1FEF}', '`', '\0'),
+    ('ῲ', 'ὼ', '\u{345}'),
+    ('ῳ', 'ω', '\u{345}'),
+    ('ῴ', 'ώ', '\u{345}'),
+    ('ῶ', 'ω', '\u{342}'),
+    ('ῷ', 'ῶ', '\u{345}'),
+    ('Ὸ', 'Ο', '\u{300}'),
+    ('\u{1FF9}', 'Ό', '\0'),
+    ('Ὼ', 'Ω', '\u{300}'),
+    ('\u{1FFB}', 'Ώ', '\0'),
+    ('ῼ', 'Ω', '\u{345}'),
+    ('\u{1FFD}', '´', '\0'),
+    ('\u{2000}', '\u{2002}', '\0'),
+    ('\u{2001}', '\u{2003}', '\0'),
+    ('\u{2126}', 'Ω', '\0'),
+    ('\u{212A}', 'K', '\0'),
+    ('\u{212B}', 'Å', '\0'),
+    ('↚', '←', '\u{338}'),
+    ('↛', '→', '\u{338}'),
+    ('↮', '↔', '\u{338}'),
+    ('⇍', '⇐', '\u{338}'),
+    ('⇎', '⇔', '\u{338}'),
+    ('⇏', '⇒', '\u{338}'),
+    ('∄', '∃', '\u{338}'),
+    ('∉', '∈', '\u{338}'),
+    ('∌', '∋', '\u{338}'),
+    ('∤', '∣', '\u{338}'),
+    ('∦', '∥', '\u{338}'),
+    ('≁', '∼', '\u{338}'),
+    ('≄', '≃', '\u{338}'),
+    ('≇', '≅', '\u{338}'),
+    ('≉', '≈', '\u{338}'),
+    ('≠', '=', '\u{338}'),
+    ('≢', '≡', '\u{338}'),
+    ('≭', '≍', '\u{338}'),
+    ('≮', '<', '\u{338}'),
+    ('≯', '>', '\u{338}'),
+    ('≰', '≤', '\u{338}'),
+    ('≱', '≥', '\u{338}'),
+    ('≴', '≲', '\u{338}'),
+    ('≵', '≳', '\u{338}'),
+    ('≸', '≶', '\u{338}'),
+    ('≹', '≷', '\u{338}'),
+    ('⊀', '≺', '\u{338}'),
+    ('⊁', '≻', '\u{338}'),
+    ('⊄', '⊂', '\u{338}'),
+    ('⊅', '⊃', '\u{338}'),
+    ('⊈', '⊆', '\u{338}'),
+    ('⊉', '⊇', '\u{338}'),
+    ('⊬', '⊢', '\u{338}'),
+    ('⊭', '⊨', '\u{338}'),
+    ('⊮', '⊩', '\u{338}'),
+    ('⊯', '⊫', '\u{338}'),
+    ('⋠', '≼', '\u{338}'),
+    ('⋡', '≽', '\u{338}'),
+    ('⋢', '⊑', '\u{338}'),
+    ('⋣', '⊒', '\u{338}'),
+    ('⋪', '⊲', '\u{338}'),
+    ('⋫', '⊳', '\u{338}'),
+    ('⋬', '⊴', '\u{338}'),
+    ('⋭', '⊵', '\u{338}'),
+    ('\u{2329}', '〈', '\0'),
+    ('\u{232A}', '〉', '\0'),
+    ('\u{2ADC}', '⫝', '\u{338}'),
+    ('が', 'か', '\u{3099}'),
+    ('ぎ', 'き', '\u{3099}'),
+    ('ぐ', 'く', '\u{3099}'),
+    ('げ', 'け', '\u{3099}'),
+    ('ご', 'こ', '\u{3099}'),
+    ('ざ', 'さ', '\u{3099}'),
+    ('じ', 'し', '\u{3099}'),
+    ('ず', 'す', '\u{3099}'),
+    ('ぜ', 'せ', '\u{3099}'),
+    ('ぞ', 'そ', '\u{3099}'),
+    ('だ', 'た', '\u{3099}'),
+    ('ぢ', 'ち', '\u{3099}'),
+    ('づ', 'つ', '\u{3099}'),
+    ('で', 'て', '\u{3099}'),
+    ('ど', 'と', '\u{3099}'),
+    ('ば', 'は', '\u{3099}'),
+    ('ぱ', 'は', '\u{309A}'),
+    ('び', 'ひ', '\u{3099}'),
+    ('ぴ', 'ひ', '\u{309A}'),
+    ('ぶ', 'ふ', '\u{3099}'),
+    ('ぷ', 'ふ', '\u{309A}'),
+    ('べ', 'へ', '\u{3099}'),
+    ('ぺ', 'へ', '\u{309A}'),
+    ('ぼ', 'ほ', '\u{3099}'),
+    ('ぽ', 'ほ', '\u{309A}'),
+    ('ゔ', 'う', '\u{3099}'),
+    ('ゞ', 'ゝ', '\u{3099}'),
+    ('ガ', 'カ', '\u{3099}'),
+    ('ギ', 'キ', '\u{3099}'),
+    ('グ', 'ク', '\u{3099}'),
+    ('ゲ', 'ケ', '\u{3099}'),
+    ('ゴ', 'コ', '\u{3099}'),
+    ('ザ', 'サ', '\u{3099}'),
+    ('ジ', 'シ', '\u{3099}'),
+    ('ズ', 'ス', '\u{3099}'),
+    ('ゼ', 'セ', '\u{3099}'),
+    ('ゾ', 'ソ', '\u{3099}'),
+    ('ダ', 'タ', '\u{3099}'),
+    ('ヂ', 'チ', '\u{3099}'),
+    ('ヅ', 'ツ', '\u{3099}'),
+    ('デ', 'テ', '\u{3099}'),
+    ('ド', 'ト', '\u{3099}'),
+    ('バ', 'ハ', '\u{3099}'),
+    ('パ', 'ハ', '\u{309A}'),
+    ('ビ', 'ヒ', '\u{3099}'),
+    ('ピ', 'ヒ', '\u{309A}'),
+    ('ブ', 'フ', '\u{3099}'),
+    ('プ', 'フ', '\u{309A}'),
+    ('ベ', 'ヘ', '\u{3099}'),
+    ('ペ', 'ヘ', '\u{309A}'),
+    ('ボ', 'ホ', '\u{3099}'),
+    ('ポ', 'ホ', '\u{309A}'),
+    ('ヴ', 'ウ', '\u{3099}'),
+    ('ヷ', 'ワ', '\u{3099}'),
+    ('ヸ', 'ヰ', '\u{3099}'),
+    ('ヹ', 'ヱ', '\u{3099}'),
+    ('ヺ', 'ヲ', '\u{3099}'),
+    ('ヾ', 'ヽ', '\u{3099}'),
+    ('\u{F900}', '豈', '\0'),
+    ('\u{F901}', '更', '\0'),
+    ('\u{F902}', '車', '\0'),
+    ('\u{F903}', '賈', '\0'),
+    ('\u{F904}', '滑', '\0'),
+    ('\u{F905}', '串', '\0'),
+    ('\u{F906}', '句', '\0'),
+    ('\u{F907}', '龜', '\0'),
+    ('\u{F908}', '龜', '\0'),
+    ('\u{F909}', '契', '\0'),
+    ('\u{F90A}', '金', '\0'),
+    ('\u{F90B}', '喇', '\0'),
+    ('\u{F90C}', '奈', '\0'),
+    ('\u{F90D}', '懶', '\0'),
+    ('\u{F90E}', '癩', '\0'),
+    ('\u{F90F}', '羅', '\0'),
+    ('\u{F910}', '蘿', '\0'),
+    ('\u{F911}', '螺', '\0'),
+    ('\u{F912}', '裸', '\0'),
+    ('\u{F913}', '邏', '\0'),
+    ('\u{F914}', '樂', '\0'),
+    ('\u{F915}', '洛', '\0'),
+    ('\u{F916}', '烙', '\0'),
+    ('\u{F917}', '珞', '\0'),
+    ('\u{F918}', '落', '\0'),
+    ('\u{F919}', '酪', '\0'),
+    ('\u{F91A}', '駱', '\0'),
+    ('\u{F91B}', '亂', '\0'),
+    ('\u{F91C}', '卵', '\0'),
+    ('\u{F91D}', '欄', '\0'),
+    ('\u{F91E}', '爛', '\0'),
+    ('\u{F91F}', '蘭', '\0'),
+    ('\u{F920}', '鸞', '\0'),
+    ('\u{F921}', '嵐', '\0'),
+    ('\u{F922}', '濫', '\0'),
+    ('\u{F923}', '藍', '\0'),
+    ('\u{F924}', '襤', '\0'),
+    ('\u{F925}', '拉', '\0'),
+    ('\u{F926}', '臘', '\0'),
+    ('\u{F927}', '蠟', '\0'),
+    ('\u{F928}', '廊', '\0'),
+    ('\u{F929}', '朗', '\0'),
+    ('\u{F92A}', '浪', '\0'),
+    ('\u{F92B}', '狼', '\0'),
+    ('\u{F92C}', '郎', '\0'),
+    ('\u{F92D}', '來', '\0'),
+    ('\u{F92E}', '冷', '\0'),
+    ('\u{F92F}', '勞', '\0'),
+    ('\u{F930}', '擄', '\0'),
+    ('\u{F931}', '櫓', '\0'),
+    ('\u{F932}', '爐', '\0'),
+    ('\u{F933}', '盧', '\0'),
+    ('\u{F934}', '老', '\0'),
+    ('\u{F935}', '蘆', '\0'),
+    ('\u{F936}', '虜', '\0'),
+    ('\u{F937}', '路', '\0'),
+    ('\u{F938}', '露', '\0'),
+    ('\u{F939}', '魯', '\0'),
+    ('\u{F93A}', '鷺', '\0'),
+    ('\u{F93B}', '碌', '\0'),
+    ('\u{F93C}', '祿', '\0'),
+    ('\u{F93D}', '綠', '\0'),
+    ('\u{F93E}', '菉', '\0'),
+    ('\u{F93F}', '錄', '\0'),
+    ('\u{F940}', '鹿', '\0'),
+    ('\u{F941}', '論', '\0'),
+    ('\u{F942}', '壟', '\0'),
+    ('\u{F943}', '弄', '\0'),
+    ('\u{F944}', '籠', '\0'),
+    ('\u{F945}', '聾', '\0'),
+    ('\u{F946}', '牢', '\0'),
+    ('\u{F947}', '磊', '\0'),
+    ('\u{F948}', '賂', '\0'),
+    ('\u{F949}', '雷', '\0'),
+    ('\u{F94A}', '壘', '\0'),
+    ('\u{F94B}', '屢', '\0'),
+    ('\u{F94C}', '樓', '\0'),
+    ('\u{F94D}', '淚', '\0'),
+    ('\u{F94E}', '漏', '\0'),
+    ('\u{F94F}', '累', '\0'),
+    ('\u{F950}', '縷', '\0'),
+    ('\u{F951}', '陋', '\0'),
+    ('\u{F952}', '勒', '\0'),
+    ('\u{F953}', '肋', '\0'),
+    ('\u{F954}', '凜', '\0'),
+    ('\u{F955}', '凌', '\0'),
+    ('\u{F956}', '稜', '\0'),
+    ('\u{F957}', '綾', '\0'),
+    ('\u{F958}', '菱', '\0'),
+    ('\u{F959}', '陵', '\0'),
+    ('\u{F95A}', '讀', '\0'),
+    ('\u{F95B}', '拏', '\0'),
+    ('\u{F95C}', '樂', '\0'),
+    ('\u{F95D}', '諾', '\0'),
+    ('\u{F95E}', '丹', '\0'),
+    ('\u{F95F}', '寧', '\0'),
+    ('\u{F960}', '怒', '\0'),
+    ('\u{F961}', '率', '\0'),
+    ('\u{F962}', '異', '\0'),
+    ('\u{F963}', '北', '\0'),
+    ('\u{F964}', '磻', '\0'),
+    ('\u{F965}', '便', '\0'),
+    ('\u{F966}', '復', '\0'),
+    ('\u{F967}', '不', '\0'),
+    ('\u{F968}', '泌', '\0'),
+    ('\u{F969}', '數', '\0'),
+    ('\u{F96A}', '索', '\0'),
+    ('\u{F96B}', '參', '\0'),
+    ('\u{F96C}', '塞', '\0'),
+    ('\u{F96D}', '省', '\0'),
+    ('\u{F96E}', '葉', '\0'),
+    ('\u{F96F}', '說', '\0'),
+    ('\u{F970}', '殺', '\0'),
+    ('\u{F971}', '辰', '\0'),
+    ('\u{F972}', '沈', '\0'),
+    ('\u{F973}', '拾', '\0'),
+    ('\u{F974}', '若', '\0'),
+    ('\u{F975}', '掠', '\0'),
+    ('\u{F976}', '略', '\0'),
+    ('\u{F977}', '亮', '\0'),
+    ('\u{F978}', '兩', '\0'),
+    ('\u{F979}', '凉', '\0'),
+    ('\u{F97A}', '梁', '\0'),
+    ('\u{F97B}', '糧', '\0'),
+    ('\u{F97C}', '良', '\0'),
+    ('\u{F97D}', '諒', '\0'),
+    ('\u{F97E}', '量', '\0'),
+    ('\u{F97F}', '勵', '\0'),
+    ('\u{F980}', '呂', '\0'),
+    ('\u{F981}', '女', '\0'),
+    ('\u{F982}', '廬', '\0'),
+    ('\u{F983}', '旅', '\0'),
+    ('\u{F984}', '濾', '\0'),
+    ('\u{F985}', '礪', '\0'),
+    ('\u{F986}', '閭', '\0'),
+    ('\u{F987}', '驪', '\0'),
+    ('\u{F988}', '麗', '\0'),
+    ('\u{F989}', '黎', '\0'),
+    ('\u{F98A}', '力', '\0'),
+    ('\u{F98B}', '曆', '\0'),
+    ('\u{F98C}', '歷', '\0'),
+    ('\u{F98D}', '轢', '\0'),
+    ('\u{F98E}', '年', '\0'),
+    ('\u{F98F}', '憐', '\0'),
+    ('\u{F990}', '戀', '\0'),
+    ('\u{F991}', '撚', '\0'),
+    ('\u{F992}', '漣', '\0'),
+    ('\u{F993}', '煉', '\0'),
+    ('\u{F994}', '璉', '\0'),
+    ('\u{F995}', '秊', '\0'),
+    ('\u{F996}', '練', '\0'),
+    ('\u{F997}', '聯', '\0'),
+    ('\u{F998}', '輦', '\0'),
+    ('\u{F999}', '蓮', '\0'),
+    ('\u{F99A}', '連', '\0'),
+    ('\u{F99B}', '鍊', '\0'),
+    ('\u{F99C}', '列', '\0'),
+    ('\u{F99D}', '劣', '\0'),
+    ('\u{F99E}', '咽', '\0'),
+    ('\u{F99F}', '烈', '\0'),
+    ('\u{F9A0}', '裂', '\0'),
+    ('\u{F9A1}', '說', '\0'),
+    ('\u{F9A2}', '廉', '\0'),
+    ('\u{F9A3}', '念', '\0'),
+    ('\u{F9A4}', '捻', '\0'),
+    ('\u{F9A5}', '殮', '\0'),
+    ('\u{F9A6}', '簾', '\0'),
+    ('\u{F9A7}', '獵', '\0'),
+    ('\u{F9A8}', '令', '\0'),
+    ('\u{F9A9}', '囹', '\0'),
+    ('\u{F9AA}', '寧', '\0'),
+    ('\u{F9AB}', '嶺', '\0'),
+    ('\u{F9AC}', '怜', '\0'),
+    ('\u{F9AD}', '玲', '\0'),
+    ('\u{F9AE}', '瑩', '\0'),
+    ('\u{F9AF}', '羚', '\0'),
+    ('\u{F9B0}', '聆', '\0'),
+    ('\u{F9B1}', '鈴', '\0'),
+    ('\u{F9B2}', '零', '\0'),
+    ('\u{F9B3}', '靈', '\0'),
+    ('\u{F9B4}', '領', '\0'),
+    ('\u{F9B5}', '例', '\0'),
+    ('\u{F9B6}', '禮', '\0'),
+    ('\u{F9B7}', '醴', '\0'),
+    ('\u{F9B8}', '隸', '\0'),
+    ('\u{F9B9}', '惡', '\0'),
+    ('\u{F9BA}', '了', '\0'),
+    ('\u{F9BB}', '僚', '\0'),
+    ('\u{F9BC}', '寮', '\0'),
+    ('\u{F9BD}', '尿', '\0'),
+    ('\u{F9BE}', '料', '\0'),
+    ('\u{F9BF}', '樂', '\0'),
+    ('\u{F9C0}', '燎', '\0'),
+    ('\u{F9C1}', '療', '\0'),
+    ('\u{F9C2}', '蓼', '\0'),
+    ('\u{F9C3}', '遼', '\0'),
+    ('\u{F9C4}', '龍', '\0'),
+    ('\u{F9C5}', '暈', '\0'),
+    ('\u{F9C6}', '阮', '\0'),
+    ('\u{F9C7}', '劉', '\0'),
+    ('\u{F9C8}', '杻', '\0'),
+    ('\u{F9C9}', '柳', '\0'),
+    ('\u{F9CA}', '流', '\0'),
+    ('\u{F9CB}', '溜', '\0'),
+    ('\u{F9CC}', '琉', '\0'),
+    ('\u{F9CD}', '留', '\0'),
+    ('\u{F9CE}', '硫', '\0'),
+    ('\u{F9CF}', '紐', '\0'),
+    ('\u{F9D0}', '類', '\0'),
+    ('\u{F9D1}', '六', '\0'),
+    ('\u{F9D2}', '戮', '\0'),
+    ('\u{F9D3}', '陸', '\0'),
+    ('\u{F9D4}', '倫', '\0'),
+    ('\u{F9D5}', '崙', '\0'),
+    ('\u{F9D6}', '淪', '\0'),
+    ('\u{F9D7}', '輪', '\0'),
+    ('\u{F9D8}', '律', '\0'),
+    ('\u{F9D9}', '慄', '\0'),
+    ('\u{F9DA}', '栗', '\0'),
+    ('\u{F9DB}', '率', '\0'),
+    ('\u{F9DC}', '隆', '\0'),
+    ('\u{F9DD}', '利', '\0'),
+    ('\u{F9DE}', '吏', '\0'),
+    ('\u{F9DF}', '履', '\0'),
+    ('\u{F9E0}', '易', '\0'),
+    ('\u{F9E1}', '李', '\0'),
+    ('\u{F9E2}', '梨', '\0'),
+    ('\u{F9E3}', '泥', '\0'),
+    ('\u{F9E4}', '理', '\0'),
+    ('\u{F9E5}', '痢', '\0'),
+    ('\u{F9E6}', '罹', '\0'),
+    ('\u{F9E7}', '裏', '\0'),
+    ('\u{F9E8}', '裡', '\0'),
+    ('\u{F9E9}', '里', '\0'),
+    ('\u{F9EA}', '離', '\0'),
+    ('\u{F9EB}', '匿', '\0'),
+    ('\u{F9EC}', '溺', '\0'),
+    ('\u{F9ED}', '吝', '\0'),
+    ('\u{F9EE}', '燐', '\0'),
+    ('\u{F9EF}', '璘', '\0'),
+    ('\u{F9F0}', '藺', '\0'),
+    ('\u{F9F1}', '隣', '\0'),
+    ('\u{F9F2}', '鱗', '\0'),
+    ('\u{F9F3}', '麟', '\0'),
+    ('\u{F9F4}', '林', '\0'),
+    ('\u{F9F5}', '淋', '\0'),
+    ('\u{F9F6}', '臨', '\0'),
+    ('\u{F9F7}', '立', '\0'),
+    ('\u{F9F8}', '笠', '\0'),
+    ('\u{F9F9}', '粒', '\0'),
+    ('\u{F9FA}', '狀', '\0'),
+    ('\u{F9FB}', '炙', '\0'),
+    ('\u{F9FC}', '識', '\0'),
+    ('\u{F9FD}', '什', '\0'),
+    ('\u{F9FE}', '茶', '\0'),
+    ('\u{F9FF}', '刺', '\0'),
+    ('\u{FA00}', '切', '\0'),
+    ('\u{FA01}', '度', '\0'),
+    ('\u{FA02}', '拓', '\0'),
+    ('\u{FA03}', '糖', '\0'),
+    ('\u{FA04}', '宅', '\0'),
+    ('\u{FA05}', '洞', '\0'),
+    ('\u{FA06}', '暴', '\0'),
+    ('\u{FA07}', '輻', '\0'),
+    ('\u{FA08}', '行', '\0'),
+    ('\u{FA09}', '降', '\0'),
+    ('\u{FA0A}', '見', '\0'),
+    ('\u{FA0B}', '廓', '\0'),
+    ('\u{FA0C}', '兀', '\0'),
+    ('\u{FA0D}', '嗀', '\0'),
+    ('\u{FA10}', '塚', '\0'),
+    ('\u{FA12}', '晴', '\0'),
+    ('\u{FA15}', '凞', '\0'),
+    ('\u{FA16}', '猪', '\0'),
+    ('\u{FA17}', '益', '\0'),
+    ('\u{FA18}', '礼', '\0'),
+    ('\u{FA19}', '神', '\0'),
+    ('\u{FA1A}', '祥', '\0'),
+    ('\u{FA1B}', '福', '\0'),
+    ('\u{FA1C}', '靖', '\0'),
+    ('\u{FA1D}', '精', '\0'),
+    ('\u{FA1E}', '羽', '\0'),
+    ('\u{FA20}', '蘒', '\0'),
+    ('\u{FA22}', '諸', '\0'),
+    ('\u{FA25}', '逸', '\0'),
+    ('\u{FA26}', '都', '\0'),
+    ('\u{FA2A}', '飯', '\0'),
+    ('\u{FA2B}', '飼', '\0'),
+    ('\u{FA2C}', '館', '\0'),
+    ('\u{FA2D}', '鶴', '\0'),
+    ('\u{FA2E}', '郞', '\0'),
+    ('\u{FA2F}', '隷', '\0'),
+    ('\u{FA30}', '侮', '\0'),
+    ('\u{FA31}', '僧', '\0'),
+    ('\u{FA32}', '免', '\0'),
+    ('\u{FA33}', '勉', '\0'),
+    ('\u{FA34}', '勤', '\0'),
+    ('\u{FA35}', '卑', '\0'),
+    ('\u{FA36}', '喝', '\0'),
+    ('\u{FA37}', '嘆', '\0'),
+    ('\u{FA38}', '器', '\0'),
+    ('\u{FA39}', '塀', '\0'),
+    ('\u{FA3A}', '墨', '\0'),
+    ('\u{FA3B}', '層', '\0'),
+    ('\u{FA3C}', '屮', '\0'),
+    ('\u{FA3D}', '悔', '\0'),
+    ('\u{FA3E}', '慨', '\0'),
+    ('\u{FA3F}', '憎', '\0'),
+    ('\u{FA40}', '懲', '\0'),
+    ('\u{FA41}', '敏', '\0'),
+    ('\u{FA42}', '既', '\0'),
+    ('\u{FA43}', '暑', '\0'),
+    ('\u{FA44}', '梅', '\0'),
+    ('\u{FA45}', '海', '\0'),
+    ('\u{FA46}', '渚', '\0'),
+    ('\u{FA47}', '漢', '\0'),
+    ('\u{FA48}', '煮', '\0'),
+    ('\u{FA49}', '爫', '\0'),
+    ('\u{FA4A}', '琢', '\0'),
+    ('\u{FA4B}', '碑', '\0'),
+    ('\u{FA4C}', '社', '\0'),
+    ('\u{FA4D}', '祉', '\0'),
+    ('\u{FA4E}', '祈', '\0'),
+    ('\u{FA4F}', '祐', '\0'),
+    ('\u{FA50}', '祖', '\0'),
+    ('\u{FA51}', '祝', '\0'),
+    ('\u{FA52}', '禍', '\0'),
+    ('\u{FA53}', '禎', '\0'),
+    ('\u{FA54}', '穀', '\0'),
+    ('\u{FA55}', '突', '\0'),
+    ('\u{FA56}', '節', '\0'),
+    ('\u{FA57}', '練', '\0'),
+    ('\u{FA58}', '縉', '\0'),
+    ('\u{FA59}', '繁', '\0'),
+    ('\u{FA5A}', '署', '\0'),
+    ('\u{FA5B}', '者', '\0'),
+    ('\u{FA5C}', '臭', '\0'),
+    ('\u{FA5D}', '艹', '\0'),
+    ('\u{FA5E}', '艹', '\0'),
+    ('\u{FA5F}', '著', '\0'),
+    ('\u{FA60}', '褐', '\0'),
+    ('\u{FA61}', '視', '\0'),
+    ('\u{FA62}', '謁', '\0'),
+    ('\u{FA63}', '謹', '\0'),
+    ('\u{FA64}', '賓', '\0'),
+    ('\u{FA65}', '贈', '\0'),
+    ('\u{FA66}', '辶', '\0'),
+    ('\u{FA67}', '逸', '\0'),
+    ('\u{FA68}', '難', '\0'),
+    ('\u{FA69}', '響', '\0'),
+    ('\u{FA6A}', '頻', '\0'),
+    ('\u{FA6B}', '恵', '\0'),
+    ('\u{FA6C}', '𤋮', '\0'),
+    ('\u{FA6D}', '舘', '\0'),
+    ('\u{FA70}', '並', '\0'),
+    ('\u{FA71}', '况', '\0'),
+    ('\u{FA72}', '全', '\0'),
+    ('\u{FA73}', '侀', '\0'),
+    ('\u{FA74}', '充', '\0'),
+    ('\u{FA75}', '冀', '\0'),
+    ('\u{FA76}', '勇', '\0'),
+    ('\u{FA77}', '勺', '\0'),
+    ('\u{FA78}', '喝', '\0'),
+    ('\u{FA79}', '啕', '\0'),
+    ('\u{FA7A}', '喙', '\0'),
+    ('\u{FA7B}', '嗢', '\0'),
+    ('\u{FA7C}', '塚', '\0'),
+    ('\u{FA7D}', '墳', '\0'),
+    ('\u{FA7E}', '奄', '\0'),
+    ('\u{FA7F}', '奔', '\0'),
+    ('\u{FA80}', '婢', '\0'),
+    ('\u{FA81}', '嬨', '\0'),
+    ('\u{FA82}', '廒', '\0'),
+    ('\u{FA83}', '廙', '\0'),
+    ('\u{FA84}', '彩', '\0'),
+    ('\u{FA85}', '徭', '\0'),
+    ('\u{FA86}', '惘', '\0'),
+    ('\u{FA87}', '慎', '\0'),
+    ('\u{FA88}', '愈', '\0'),
+    ('\u{FA89}', '憎', '\0'),
+    ('\u{FA8A}', '慠', '\0'),
+    ('\u{FA8B}', '懲', '\0'),
+    ('\u{FA8C}', '戴', '\0'),
+    ('\u{FA8D}', '揄', '\0'),
+    ('\u{FA8E}', '搜', '\0'),
+    ('\u{FA8F}', '摒', '\0'),
+    ('\u{FA90}', '敖', '\0'),
+    ('\u{FA91}', '晴', '\0'),
+    ('\u{FA92}', '朗', '\0'),
+    ('\u{FA93}', '望', '\0'),
+    ('\u{FA94}', '杖', '\0'),
+    ('\u{FA95}', '歹', '\0'),
+    ('\u{FA96}', '殺', '\0'),
+    ('\u{FA97}', '流', '\0'),
+    ('\u{FA98}', '滛', '\0'),
+    ('\u{FA99}', '滋', '\0'),
+    ('\u{FA9A}', '漢', '\0'),
+    ('\u{FA9B}', '瀞', '\0'),
+    ('\u{FA9C}', '煮', '\0'),
+    ('\u{FA9D}', '瞧', '\0'),
+    ('\u{FA9E}', '爵', '\0'),
+    ('\u{FA9F}', '犯', '\0'),
+    ('\u{FAA0}', '猪', '\0'),
+    ('\u{FAA1}', '瑱', '\0'),
+    ('\u{FAA2}', '甆', '\0'),
+    ('\u{FAA3}', '画', '\0'),
+    ('\u{FAA4}', '瘝', '\0'),
+    ('\u{FAA5}', '瘟', '\0'),
+    ('\u{FAA6}', '益', '\0'),
+    ('\u{FAA7}', '盛', '\0'),
+    ('\u{FAA8}', '直', '\0'),
+    ('\u{FAA9}', '睊', '\0'),
+    ('\u{FAAA}', '着', '\0'),
+    ('\u{FAAB}', '磌', '\0'),
+    ('\u{FAAC}', '窱', '\0'),
+    ('\u{FAAD}', '節', '\0'),
+    ('\u{FAAE}', '类', '\0'),
+    ('\u{FAAF}', '絛', '\0'),
+    ('\u{FAB0}', '練', '\0'),
+    ('\u{FAB1}', '缾', '\0'),
+    ('\u{FAB2}', '者', '\0'),
+    ('\u{FAB3}', '荒', '\0'),
+    ('\u{FAB4}', '華', '\0'),
+    ('\u{FAB5}', '蝹', '\0'),
+    ('\u{FAB6}', '襁', '\0'),
+    ('\u{FAB7}', '覆', '\0'),
+    ('\u{FAB8}', '視', '\0'),
+    ('\u{FAB9}', '調', '\0'),
+    ('\u{FABA}', '諸', '\0'),
+    ('\u{FABB}', '請', '\0'),
+    ('\u{FABC}', '謁', '\0'),
+    ('\u{FABD}', '諾', '\0'),
+    ('\u{FABE}', '諭', '\0'),
+    ('\u{FABF}', '謹', '\0'),
+    ('\u{FAC0}', '變', '\0'),
+    ('\u{FAC1}', '贈', '\0'),
+    ('\u{FAC2}', '輸', '\0'),
+    ('\u{FAC3}', '遲', '\0'),
+    ('\u{FAC4}', '醙', '\0'),
+    ('\u{FAC5}', '鉶', '\0'),
+    ('\u{FAC6}', '陼', '\0'),
+    ('\u{FAC7}', '難', '\0'),
+    ('\u{FAC8}', '靖', '\0'),
+    ('\u{FAC9}', '韛', '\0'),
+    ('\u{FACA}', '響', '\0'),
+    ('\u{FACB}', '頋', '\0'),
+    ('\u{FACC}', '頻', '\0'),
+    ('\u{FACD}', '鬒', '\0'),
+    ('\u{FACE}', '龜', '\0'),
+    ('\u{FACF}', '𢡊', '\0'),
+    ('\u{FAD0}', '𢡄', '\0'),
+    ('\u{FAD1}', '𣏕', '\0'),
+    ('\u{FAD2}', '㮝', '\0'),
+    ('\u{FAD3}', '䀘', '\0'),
+    ('\u{FAD4}', '䀹', '\0'),
+    ('\u{FAD5}', '𥉉', '\0'),
+    ('\u{FAD6}', '𥳐', '\0'),
+    ('\u{FAD7}', '𧻓', '\0'),
+    ('\u{FAD8}', '齃', '\0'),
+    ('\u{FAD9}', '龎', '\0'),
+    ('\u{FB1D}', 'י', '\u{5B4}'),
+    ('\u{FB1F}', 'ײ', '\u{5B7}'),
+    ('\u{FB2A}', 'ש', '\u{5C1}'),
+    ('\u{FB2B}', 'ש', '\u{5C2}'),
+    ('\u{FB2C}', '\u{FB49}', '\u{5C1}'),
+    ('\u{FB2D}', '\u{FB49}', '\u{5C2}'),
+    ('\u{FB2E}', 'א', '\u{5B7}'),
+    ('\u{FB2F}', 'א', '\u{5B8}'),
+    ('\u{FB30}', 'א', '\u{5BC}'),
+    ('\u{FB31}', 'ב', '\u{5BC}'),
+    ('\u{FB32}', 'ג', '\u{5BC}'),
+    ('\u{FB33}', 'ד', '\u{5BC}'),
+    ('\u{FB34}', 'ה', '\u{5BC}'),
+    ('\u{FB35}', 'ו', '\u{5BC}'),
+    ('\u{FB36}', 'ז', '\u{5BC}'),
+    ('\u{FB38}', 'ט', '\u{5BC}'),
+    ('\u{FB39}', 'י', '\u{5BC}'),
+    ('\u{FB3A}', 'ך', '\u{5BC}'),
+    ('\u{FB3B}', 'כ', '\u{5BC}'),
+    ('\u{FB3C}', 'ל', '\u{5BC}'),
+    ('\u{FB3E}', 'מ', '\u{5BC}'),
+    ('\u{FB40}', 'נ', '\u{5BC}'),
+    ('\u{FB41}', 'ס', '\u{5BC}'),
+    ('\u{FB43}', 'ף', '\u{5BC}'),
+    ('\u{FB44}', 'פ', '\u{5BC}'),
+    ('\u{FB46}', 'צ', '\u{5BC}'),
+    ('\u{FB47}', 'ק', '\u{5BC}'),
+    ('\u{FB48}', 'ר', '\u{5BC}'),
+    ('\u{FB49}', 'ש', '\u{5BC}'),
+    ('\u{FB4A}', 'ת', '\u{5BC}'),
+    ('\u{FB4B}', 'ו', '\u{5B9}'),
+    ('\u{FB4C}', 'ב', '\u{5BF}'),
+    ('\u{FB4D}', 'כ', '\u{5BF}'),
+    ('\u{FB4E}', 'פ', '\u{5BF}'),
+    ('𑂚', '𑂙', '\u{110BA}'),
+    ('𑂜', '𑂛', '\u{110BA}'),
+    ('𑂫', '𑂥', '\u{110BA}'),
+    ('\u{1112E}', '\u{11131}', '\u{11127}'),
+    ('\u{1112F}', '\u{11132}', '\u{11127}'),
+    ('\u{1134B}', '\u{11347}', '\u{1133E}'),
+    ('\u{1134C}', '\u{11347}', '\u{11357}'),
+    ('\u{114BB}', '\u{114B9}', '\u{114BA}'),
+    ('\u{114BC}', '\u{114B9}', '\u{114B0}'),
+    ('\u{114BE}', '\u{114B9}', '\u{114BD}'),
+    ('\u{115BA}', '\u{115B8}', '\u{115AF}'),
+    ('\u{115BB}', '\u{115B9}', '\u{115AF}'),
+    ('\u{11938}', '\u{11935}', '\u{11930}'),
+    ('\u{1D15E}', '𝅗', '\u{1D165}'),
+    ('\u{1D15F}', '𝅘', '\u{1D165}'),
+    ('\u{1D160}', '\u{1D15F}', '\u{1D16E}'),
+    ('\u{1D161}', '\u{1D15F}', '\u{1D16F}'),
+    ('\u{1D162}', '\u{1D15F}', '\u{1D170}'),
+    ('\u{1D163}', '\u{1D15F}', '\u{1D171}'),
+    ('\u{1D164}', '\u{1D15F}', '\u{1D172}'),
+    ('\u{1D1BB}', '𝆹', '\u{1D165}'),
+    ('\u{1D1BC}', '𝆺', '\u{1D165}'),
+    ('\u{1D1BD}', '\u{1D1BB}', '\u{1D16E}'),
+    ('\u{1D1BE}', '\u{1D1BC}', '\u{1D16E}'),
+    ('\u{1D1BF}', '\u{1D1BB}', '\u{1D16F}'),
+    ('\u{1D1C0}', '\u{1D1BC}', '\u{1D16F}'),
+    ('\u{2F800}', '丽', '\0'),
+    ('\u{2F801}', '丸', '\0'),
+    ('\u{2F802}', '乁', '\0'),
+    ('\u{2F803}', '𠄢', '\0'),
+    ('\u{2F804}', '你', '\0'),
+    ('\u{2F805}', '侮', '\0'),
+    ('\u{2F806}', '侻', '\0'),
+    ('\u{2F807}', '倂', '\0'),
+    ('\u{2F808}', '偺', '\0'),
+    ('\u{2F809}', '備', '\0'),
+    ('\u{2F80A}', '僧', '\0'),
+    ('\u{2F80B}', '像', '\0'),
+    ('\u{2F80C}', '㒞', '\0'),
+    ('\u{2F80D}', '𠘺', '\0'),
+    ('\u{2F80E}', '免', '\0'),
+    ('\u{2F80F}', '兔', '\0'),
+    ('\u{2F810}', '兤', '\0'),
+    ('\u{2F811}', '具', '\0'),
+    ('\u{2F812}', '𠔜', '\0'),
+    ('\u{2F813}', '㒹', '\0'),
+    ('\u{2F814}', '內', '\0'),
+    ('\u{2F815}', '再', '\0'),
+    ('\u{2F816}', '𠕋', '\0'),
+    ('\u{2F817}', '冗', '\0'),
+    ('\u{2F818}', '冤', '\0'),
+    ('\u{2F819}', '仌', '\0'),
+    ('\u{2F81A}', '冬', '\0'),
+    ('\u{2F81B}', '况', '\0'),
+    ('\u{2F81C}', '𩇟', '\0'),
+    ('\u{2F81D}', '凵', '\0'),
+    ('\u{2F81E}', '刃', '\0'),
+    ('\u{2F81F}', '㓟', '\0'),
+    ('\u{2F820}', '刻', '\0'),
+    ('\u{2F821}', '剆', '\0'),
+    ('\u{2F822}', '割', '\0'),
+    ('\u{2F823}', '剷', '\0'),
+    ('\u{2F824}', '㔕', '\0'),
+    ('\u{2F825}', '勇', '\0'),
+    ('\u{2F826}', '勉', '\0'),
+    ('\u{2F827}', '勤', '\0'),
+    ('\u{2F828}', '勺', '\0'),
+    ('\u{2F829}', '包', '\0'),
+    ('\u{2F82A}', '匆', '\0'),
+    ('\u{2F82B}', '北', '\0'),
+    ('\u{2F82C}', '卉', '\0'),
+    ('\u{2F82D}', '卑', '\0'),
+    ('\u{2F82E}', '博', '\0'),
+    ('\u{2F82F}', '即', '\0'),
+    ('\u{2F830}', '卽', '\0'),
+    ('\u{2F831}', '卿', '\0'),
+    ('\u{2F832}', '卿', '\0'),
+    ('\u{2F833}', '卿', '\0'),
+    ('\u{2F834}', '𠨬', '\0'),
+    ('\u{2F835}', '灰', '\0'),
+    ('\u{2F836}', '及', '\0'),
+    ('\u{2F837}', '叟', '\0'),
+    ('\u{2F838}', '𠭣', '\0'),
+    ('\u{2F839}', '叫', '\0'),
+    ('\u{2F83A}', '叱', '\0'),
+    ('\u{2F83B}', '吆', '\0'),
+    ('\u{2F83C}', '咞', '\0'),
+    ('\u{2F83D}', '吸', '\0'),
+    ('\u{2F83E}', '呈', '\0'),
+    ('\u{2F83F}', '周', '\0'),
+    ('\u{2F840}', '咢', '\0'),
+    ('\u{2F841}', '哶', '\0'),
+    ('\u{2F842}', '唐', '\0'),
+    ('\u{2F843}', '啓', '\0'),
+    ('\u{2F844}', '啣', '\0'),
+    ('\u{2F845}', '善', '\0'),
+    ('\u{2F846}', '善', '\0'),
+    ('\u{2F847}', '喙', '\0'),
+    ('\u{2F848}', '喫', '\0'),
+    ('\u{2F849}', '喳', '\0'),
+    ('\u{2F84A}', '嗂', '\0'),
+    ('\u{2F84B}', '圖', '\0'),
+    ('\u{2F84C}', '嘆', '\0'),
+    ('\u{2F84D}', '圗', '\0'),
+    ('\u{2F84E}', '噑', '\0'),
+    ('\u{2F84F}', '噴', '\0'),
+    ('\u{2F850}', '切', '\0'),
+    ('\u{2F851}', '壮', '\0'),
+    ('\u{2F852}', '城', '\0'),
+    ('\u{2F853}', '埴', '\0'),
+    ('\u{2F854}', '堍', '\0'),
+    ('\u{2F855}', '型', '\0'),
+    ('\u{2F856}', '堲', '\0'),
+    ('\u{2F857}', '報', '\0'),
+    ('\u{2F858}', '墬', '\0'),
+    ('\u{2F859}', '𡓤', '\0'),
+    ('\u{2F85A}', '売', '\0'),
+    ('\u{2F85B}', '壷', '\0'),
+    ('\u{2F85C}', '夆', '\0'),
+    ('\u{2F85D}', '多', '\0'),
+    ('\u{2F85E}', '夢', '\0'),
+    ('\u{2F85F}', '奢', '\0'),
+    ('\u{2F860}', '𡚨', '\0'),
+    ('\u{2F861}', '𡛪', '\0'),
+    ('\u{2F862}', '姬', '\0'),
+    ('\u{2F863}', '娛', '\0'),
+    ('\u{2F864}', '娧', '\0'),
+    ('\u{2F865}', '姘', '\0'),
+    ('\u{2F866}', '婦', '\0'),
+    ('\u{2F867}', '㛮', '\0'),
+    ('\u{2F868}', '㛼', '\0'),
+    ('\u{2F869}', '嬈', '\0'),
+    ('\u{2F86A}', '嬾', '\0'),
+    ('\u{2F86B}', '嬾', '\0'),
+    ('\u{2F86C}', '𡧈', '\0'),
+    ('\u{2F86D}', '寃', '\0'),
+    ('\u{2F86E}', '寘', '\0'),
+    ('\u{2F86F}', '寧', '\0'),
+    ('\u{2F870}', '寳', '\0'),
+    ('\u{2F871}', '𡬘', '\0'),
+    ('\u{2F872}', '寿', '\0'),
+    ('\u{2F873}', '将', '\0'),
+    ('\u{2F874}', '当', '\0'),
+    ('\u{2F875}', '尢', '\0'),
+    ('\u{2F876}', '㞁', '\0'),
+    ('\u{2F877}', '屠', '\0'),
+    ('\u{2F878}', '屮', '\0'),
+    ('\u{2F879}', '峀', '\0'),
+    ('\u{2F87A}', '岍', '\0'),
+    ('\u{2F87B}', '𡷤', '\0'),
+    ('\u{2F87C}', '嵃', '\0'),
+    ('\u{2F87D}', '𡷦', '\0'),
+    ('\u{2F87E}', '嵮', '\0'),
+    ('\u{2F87F}', '嵫', '\0'),
+    ('\u{2F880}', '嵼', '\0'),
+    ('\u{2F881}', '巡', '\0'),
+    ('\u{2F882}', '巢', '\0'),
+    ('\u{2F883}', '㠯', '\0'),
+    ('\u{2F884}', '巽', '\0'),
+    ('\u{2F885}', '帨', '\0'),
+    ('\u{2F886}', '帽', '\0'),
+    ('\u{2F887}', '幩', '\0'),
+    ('\u{2F888}', '㡢', '\0'),
+    ('\u{2F889}', '𢆃', '\0'),
+    ('\u{2F88A}', '㡼', '\0'),
+    ('\u{2F88B}', '庰', '\0'),
+    ('\u{2F88C}', '庳', '\0'),
+    ('\u{2F88D}', '庶', '\0'),
+    ('\u{2F88E}', '廊', '\0'),
+    ('\u{2F88F}', '𪎒', '\0'),
+    ('\u{2F890}', '廾', '\0'),
+    ('\u{2F891}', '𢌱', '\0'),
+    ('\u{2F892}', '𢌱', '\0'),
+    ('\u{2F893}', '舁', '\0'),
+    ('\u{2F894}', '弢', '\0'),
+    ('\u{2F895}', '弢', '\0'),
+    ('\u{2F896}', '㣇', '\0'),
+    ('\u{2F897}', '𣊸', '\0'),
+    ('\u{2F898}', '𦇚', '\0'),
+    ('\u{2F899}', '形', '\0'),
+    ('\u{2F89A}', '彫', '\0'),
+    ('\u{2F89B}', '㣣', '\0'),
+    ('\u{2F89C}', '徚', '\0'),
+    ('\u{2F89D}', '忍', '\0'),
+    ('\u{2F89E}', '志', '\0'),
+    ('\u{2F89F}', '忹', '\0'),
+    ('\u{2F8A0}', '悁', '\0'),
+    ('\u{2F8A1}', '㤺', '\0'),
+    ('\u{2F8A2}', '㤜', '\0'),
+    ('\u{2F8A3}', '悔', '\0'),
+    ('\u{2F8A4}', '𢛔', '\0'),
+    ('\u{2F8A5}', '惇', '\0'),
+    ('\u{2F8A6}', '慈', '\0'),
+    ('\u{2F8A7}', '慌', '\0'),
+    ('\u{2F8A8}', '慎', '\0'),
+    ('\u{2F8A9}', '慌', '\0'),
+    ('\u{2F8AA}', '慺', '\0'),
+    ('\u{2F8AB}', '憎', '\0'),
+    ('\u{2F8AC}', '憲', '\0'),
+    ('\u{2F8AD}', '憤', '\0'),
+    ('\u{2F8AE}', '憯', '\0'),
+    ('\u{2F8AF}', '懞', '\0'),
+    ('\u{2F8B0}', '懲', '\0'),
+    ('\u{2F8B1}', '懶', '\0'),
+    ('\u{2F8B2}', '成', '\0'),
+    ('\u{2F8B3}', '戛', '\0'),
+    ('\u{2F8B4}', '扝', '\0'),
+    ('\u{2F8B5}', '抱', '\0'),
+    ('\u{2F8B6}', '拔', '\0'),
+    ('\u{2F8B7}', '捐', '\0'),
+    ('\u{2F8B8}', '𢬌', '\0'),
+    ('\u{2F8B9}', '挽', '\0'),
+    ('\u{2F8BA}', '拼', '\0'),
+    ('\u{2F8BB}', '捨', '\0'),
+    ('\u{2F8BC}', '掃', '\0'),
+    ('\u{2F8BD}', '揤', '\0'),
+    ('\u{2F8BE}', '𢯱', '\0'),
+    ('\u{2F8BF}', '搢', '\0'),
+    ('\u{2F8C0}', '揅', '\0'),
+    ('\u{2F8C1}', '掩', '\0'),
+    ('\u{2F8C2}', '㨮', '\0'),
+    ('\u{2F8C3}', '摩', '\0'),
+    ('\u{2F8C4}', '摾', '\0'),
+    ('\u{2F8C5}', '撝', '\0'),
+    ('\u{2F8C6}', '摷', '\0'),
+    ('\u{2F8C7}', '㩬', '\0'),
+    ('\u{2F8C8}', '敏', '\0'),
+    ('\u{2F8C9}', '敬', '\0'),
+    ('\u{2F8CA}', '𣀊', '\0'),
+    ('\u{2F8CB}', '旣', '\0'),
+    ('\u{2F8CC}', '書', '\0'),
+    ('\u{2F8CD}', '晉', '\0'),
+    ('\u{2F8CE}', '㬙', '\0'),
+    ('\u{2F8CF}', '暑', '\0'),
+    ('\u{2F8D0}', '㬈', '\0'),
+    ('\u{2F8D1}', '㫤', '\0'),
+    ('\u{2F8D2}', '冒', '\0'),
+    ('\u{2F8D3}', '冕', '\0'),
+    ('\u{2F8D4}', '最', '\0'),
+    ('\u{2F8D5}', '暜', '\0'),
+    ('\u{2F8D6}', '肭', '\0'),
+    ('\u{2F8D7}', '䏙', '\0'),
+    ('\u{2F8D8}', '朗', '\0'),
+    ('\u{2F8D9}', '望', '\0'),
+    ('\u{2F8DA}', '朡', '\0'),
+    ('\u{2F8DB}', '杞', '\0'),
+    ('\u{2F8DC}', '杓', '\0'),
+    ('\u{2F8DD}', '𣏃', '\0'),
+    ('\u{2F8DE}', '㭉', '\0'),
+    ('\u{2F8DF}', '柺', '\0'),
+    ('\u{2F8E0}', '枅', '\0'),
+    ('\u{2F8E1}', '桒', '\0'),
+    ('\u{2F8E2}', '梅', '\0'),
+    ('\u{2F8E3}', '𣑭', '\0'),
+    ('\u{2F8E4}', '梎', '\0'),
+    ('\u{2F8E5}', '栟', '\0'),
+    ('\u{2F8E6}', '椔', '\0'),
+    ('\u{2F8E7}', '㮝', '\0'),
+    ('\u{2F8E8}', '楂', '\0'),
+    ('\u{2F8E9}', '榣', '\0'),
+    ('\u{2F8EA}', '槪', '\0'),
+    ('\u{2F8EB}', '檨', '\0'),
+    ('\u{2F8EC}', '𣚣', '\0'),
+    ('\u{2F8ED}', '櫛', '\0'),
+    ('\u{2F8EE}', '㰘', '\0'),
+    ('\u{2F8EF}', '次', '\0'),
+    ('\u{2F8F0}', '𣢧', '\0'),
+    ('\u{2F8F1}', '歔', '\0'),
+    ('\u{2F8F2}', '㱎', '\0'),
+    ('\u{2F8F3}', '歲', '\0'),
+    ('\u{2F8F4}', '殟', '\0'),
+    ('\u{2F8F5}', '殺', '\0'),
+    ('\u{2F8F6}', '殻', '\0'),
+    ('\u{2F8F7}', '𣪍', '\0'),
+    ('\u{2F8F8}', '𡴋', '\0'),
+    ('\u{2F8F9}', '𣫺', '\0'),
+    ('\u{2F8FA}', '汎', '\0'),
+    ('\u{2F8FB}', '𣲼', '\0'),
+    ('\u{2F8FC}', '沿', '\0'),
+    ('\u{2F8FD}', '泍', '\0'),
+    ('\u{2F8FE}', '汧', '\0'),
+    ('\u{2F8FF}', '洖', '\0'),
+    ('\u{2F900}', '派', '\0'),
+    ('\u{2F901}', '海', '\0'),
+    ('\u{2F902}', '流', '\0'),
+    ('\u{2F903}', '浩', '\0'),
+    ('\u{2F904}', '浸', '\0'),
+    ('\u{2F905}', '涅', '\0'),
+    ('\u{2F906}', '𣴞', '\0'),
+    ('\u{2F907}', '洴', '\0'),
+    ('\u{2F908}', '港', '\0'),
+    ('\u{2F909}', '湮', '\0'),
+    ('\u{2F90A}', '㴳', '\0'),
+    ('\u{2F90B}', '滋', '\0'),
+    ('\u{2F90C}', '滇', '\0'),
+    ('\u{2F90D}', '𣻑', '\0'),
+    ('\u{2F90E}', '淹', '\0'),
+    ('\u{2F90F}', '潮', '\0'),
+    ('\u{2F910}', '𣽞', '\0'),
+    ('\u{2F911}', '𣾎', '\0'),
+    ('\u{2F912}', '濆', '\0'),
+    ('\u{2F913}', '瀹', '\0'),
+    ('\u{2F914}', '瀞', '\0'),
+    ('\u{2F915}', '瀛', '\0'),
+    ('\u{2F916}', '㶖', '\0'),
+    ('\u{2F917}', '灊', '\0'),
+    ('\u{2F918}', '災', '\0'),
+    ('\u{2F919}', '灷', '\0'),
+    ('\u{2F91A}', '炭', '\0'),
+    ('\u{2F91B}', '𠔥', '\0'),
+    ('\u{2F91C}', '煅', '\0'),
+    ('\u{2F91D}', '𤉣', '\0'),
+    ('\u{2F91E}', '熜', '\0'),
+    ('\u{2F91F}', '𤎫', '\0'),
+    ('\u{2F920}', '爨', '\0'),
+    ('\u{2F921}', '爵', '\0'),
+    ('\u{2F922}', '牐', '\0'),
+    ('\u{2F923}', '𤘈', '\0'),
+    ('\u{2F924}', '犀', '\0'),
+    ('\u{2F925}', '犕', '\0'),
+    ('\u{2F926}', '𤜵', '\0'),
+    ('\u{2F927}', '𤠔', '\0'),
+    ('\u{2F928}', '獺', '\0'),
+    ('\u{2F929}', '王', '\0'),
+    ('\u{2F92A}', '㺬', '\0'),
+    ('\u{2F92B}', '玥', '\0'),
+    ('\u{2F92C}', '㺸', '\0'),
+    ('\u{2F92D}', '㺸', '\0'),
+    ('\u{2F92E}', '瑇', '\0'),
+    ('\u{2F92F}', '瑜', '\0'),
+    ('\u{2F930}', '瑱', '\0'),
+    ('\u{2F931}', '璅', '\0'),
+    ('\u{2F932}', '瓊', '\0'),
+    ('\u{2F933}', '㼛', '\0'),
+    ('\u{2F934}', '甤', '\0'),
+    ('\u{2F935}', '𤰶', '\0'),
+    ('\u{2F936}', '甾', '\0'),
+    ('\u{2F937}', '𤲒', '\0'),
+    ('\u{2F938}', '異', '\0'),
+    ('\u{2F939}', '𢆟', '\0'),
+    ('\u{2F93A}', '瘐', '\0'),
+    ('\u{2F93B}', '𤾡', '\0'),
+    ('\u{2F93C}', '𤾸', '\0'),
+    ('\u{2F93D}', '𥁄', '\0'),
+    ('\u{2F93E}', '㿼', '\0'),
+    ('\u{2F93F}', '䀈', '\0'),
+    ('\u{2F940}', '直', '\0'),
+    ('\u{2F941}', '𥃳', '\0'),
+    ('\u{2F942}', '𥃲', '\0'),
+    ('\u{2F943}', '𥄙', '\0'),
+    ('\u{2F944}', '𥄳', '\0'),
+    ('\u{2F945}', '眞', '\0'),
+    ('\u{2F946}', '真', '\0'),
+    ('\u{2F947}', '真', '\0'),
+    ('\u{2F948}', '睊', '\0'),
+    ('\u{2F949}', '䀹', '\0'),
+    ('\u{2F94A}', '瞋', '\0'),
+    ('\u{2F94B}', '䁆', '\0'),
+    ('\u{2F94C}', '䂖', '\0'),
+    ('\u{2F94D}', '𥐝', '\0'),
+    ('\u{2F94E}', '硎', '\0'),
+    ('\u{2F94F}', '碌', '\0'),
+    ('\u{2F950}', '磌', '\0'),
+    ('\u{2F951}', '䃣', '\0'),
+    ('\u{2F952}', '𥘦', '\0'),
+    ('\u{2F953}', '祖', '\0'),
+    ('\u{2F954}', '𥚚', '\0'),
+    ('\u{2F955}', '𥛅', '\0'),
+    ('\u{2F956}', '福', '\0'),
+    ('\u{2F957}', '秫', '\0'),
+    ('\u{2F958}', '䄯', '\0'),
+    ('\u{2F959}', '穀', '\0'),
+    ('\u{2F95A}', '穊', '\0'),
+    ('\u{2F95B}', '穏', '\0'),
+    ('\u{2F95C}', '𥥼', '\0'),
+    ('\u{2F95D}', '𥪧', '\0'),
+    ('\u{2F95E}', '𥪧', '\0'),
+    ('\u{2F95F}', '竮', '\0'),
+    ('\u{2F960}', '䈂', '\0'),
+    ('\u{2F961}', '𥮫', '\0'),
+    ('\u{2F962}', '篆', '\0'),
+    ('\u{2F963}', '築', '\0'),
+    ('\u{2F964}', '䈧', '\0'),
+    ('\u{2F965}', '𥲀', '\0'),
+    ('\u{2F966}', '糒', '\0'),
+    ('\u{2F967}', '䊠', '\0'),
+    ('\u{2F968}', '糨', '\0'),
+    ('\u{2F969}', '糣', '\0'),
+    ('\u{2F96A}', '紀', '\0'),
+    ('\u{2F96B}', '𥾆', '\0'),
+    ('\u{2F96C}', '絣', '\0'),
+    ('\u{2F96D}', '䌁', '\0'),
+    ('\u{2F96E}', '緇', '\0'),
+    ('\u{2F96F}', '縂', '\0'),
+    ('\u{2F970}', '繅', '\0'),
+    ('\u{2F971}', '䌴', '\0'),
+    ('\u{2F972}', '𦈨', '\0'),
+    ('\u{2F973}', '𦉇', '\0'),
+    ('\u{2F974}', '䍙', '\0'),
+    ('\u{2F975}', '𦋙', '\0'),
+    ('\u{2F976}', '罺', '\0'),
+    ('\u{2F977}', '𦌾', '\0'),
+    ('\u{2F978}', '羕', '\0'),
+    ('\u{2F979}', '翺', '\0'),
+    ('\u{2F97A}', '者', '\0'),
+    ('\u{2F97B}', '𦓚', '\0'),
+    ('\u{2F97C}', '𦔣', '\0'),
+    ('\u{2F97D}', '聠', '\0'),
+    ('\u{2F97E}', '𦖨', '\0'),
+    ('\u{2F97F}', '聰', '\0'),
+    ('\u{2F980}', '𣍟', '\0'),
+    ('\u{2F981}', '䏕', '\0'),
+    ('\u{2F982}', '育', '\0'),
+    ('\u{2F983}', '脃', '\0'),
+    ('\u{2F984}', '䐋', '\0'),
+    ('\u{2F985}', '脾', '\0'),
+    ('\u{2F986}', '媵', '\0'),
+    ('\u{2F987}', '𦞧', '\0'),
+    ('\u{2F988}', '𦞵', '\0'),
+    ('\u{2F989}', '𣎓', '\0'),
+    ('\u{2F98A}', '𣎜', '\0'),
+    ('\u{2F98B}', '舁', '\0'),
+    ('\u{2F98C}', '舄', '\0'),
+    ('\u{2F98D}', '辞', '\0'),
+    ('\u{2F98E}', '䑫', '\0'),
+    ('\u{2F98F}', '芑', '\0'),
+    ('\u{2F990}', '芋', '\0'),
+    ('\u{2F991}', '芝', '\0'),
+    ('\u{2F992}', '劳', '\0'),
+    ('\u{2F993}', '花', '\0'),
+    ('\u{2F994}', '芳', '\0'),
+    ('\u{2F995}', '芽', '\0'),
+    ('\u{2F996}', '苦', '\0'),
+    ('\u{2F997}', '𦬼', '\0'),
+    ('\u{2F998}', '若', '\0'),
+    ('\u{2F999}', '茝', '\0'),
+    ('\u{2F99A}', '荣', '\0'),
+    ('\u{2F99B}', '莭', '\0'),
+    ('\u{2F99C}', '茣', '\0'),
+    ('\u{2F99D}', '莽', '\0'),
+    ('\u{2F99E}', '菧', '\0'),
+    ('\u{2F99F}', '著', '\0'),
+    ('\u{2F9A0}', '荓', '\0'),
+    ('\u{2F9A1}', '菊', '\0'),
+    ('\u{2F9A2}', '菌', '\0'),
+    ('\u{2F9A3}', '菜', '\0'),
+    ('\u{2F9A4}', '𦰶', '\0'),
+    ('\u{2F9A5}', '𦵫', '\0'),
+    ('\u{2F9A6}', '𦳕', '\0'),
+    ('\u{2F9A7}', '䔫', '\0'),
+    ('\u{2F9A8}', '蓱', '\0'),
+    ('\u{2F9A9}', '蓳', '\0'),
+    ('\u{2F9AA}', '蔖', '\0'),
+    ('\u{2F9AB}', '𧏊', '\0'),
+    ('\u{2F9AC}', '蕤', '\0'),
+    ('\u{2F9AD}', '𦼬', '\0'),
+    ('\u{2F9AE}', '䕝', '\0'),
+    ('\u{2F9AF}', '䕡', '\0'),
+    ('\u{2F9B0}', '𦾱', '\0'),
+    ('\u{2F9B1}', '𧃒', '\0'),
+    ('\u{2F9B2}', '䕫', '\0'),
+    ('\u{2F9B3}', '虐', '\0'),
+    ('\u{2F9B4}', '虜', '\0'),
+    ('\u{2F9B5}', '虧', '\0'),
+    ('\u{2F9B6}', '虩', '\0'),
+    ('\u{2F9B7}', '蚩', '\0'),
+    ('\u{2F9B8}', '蚈', '\0'),
+    ('\u{2F9B9}', '蜎', '\0'),
+    ('\u{2F9BA}', '蛢', '\0'),
+    ('\u{2F9BB}', '蝹', '\0'),
+    ('\u{2F9BC}', '蜨', '\0'),
+    ('\u{2F9BD}', '蝫', '\0'),
+    ('\u{2F9BE}', '螆', '\0'),
+    ('\u{2F9BF}', '䗗', '\0'),
+    ('\u{2F9C0}', '蟡', '\0'),
+    ('\u{2F9C1}', '蠁', '\0'),
+    ('\u{2F9C2}', '䗹', '\0'),
+    ('\u{2F9C3}', '衠', '\0'),
+    ('\u{2F9C4}', '衣', '\0'),
+    ('\u{2F9C5}', '𧙧', '\0'),
+    ('\u{2F9C6}', '裗', '\0'),
+    ('\u{2F9C7}', '裞', '\0'),
+    ('\u{2F9C8}', '䘵', '\0'),
+    ('\u{2F9C9}', '裺', '\0'),
+    ('\u{2F9CA}', '㒻', '\0'),
+    ('\u{2F9CB}', '𧢮', '\0'),
+    ('\u{2F9CC}', '𧥦', '\0'),
+    ('\u{2F9CD}', '䚾', '\0'),
+    ('\u{2F9CE}', '䛇', '\0'),
+    ('\u{2F9CF}', '誠', '\0'),
+    ('\u{2F9D0}', '諭', '\0'),
+    ('\u{2F9D1}', '變', '\0'),
+    ('\u{2F9D2}', '豕', '\0'),
+    ('\u{2F9D3}', '𧲨', '\0'),
+    ('\u{2F9D4}', '貫', '\0'),
+    ('\u{2F9D5}', '賁', '\0'),
+    ('\u{2F9D6}', '贛', '\0'),
+    ('\u{2F9D7}', '起', '\0'),
+    ('\u{2F9D8}', '𧼯', '\0'),
+    ('\u{2F9D9}', '𠠄', '\0'),
+    ('\u{2F9DA}', '跋', '\0'),
+    ('\u{2F9DB}', '趼', '\0'),
+    ('\u{2F9DC}', '跰', '\0'),
+    ('\u{2F9DD}', '𠣞', '\0'),
+    ('\u{2F9DE}', '軔', '\0'),
+    ('\u{2F9DF}', '輸', '\0'),
+    ('\u{2F9E0}', '𨗒', '\0'),
+    ('\u{2F9E1}', '𨗭', '\0'),
+    ('\u{2F9E2}', '邔', '\0'),
+    ('\u{2F9E3}', '郱', '\0'),
+    ('\u{2F9E4}', '鄑', '\0'),
+    ('\u{2F9E5}', '𨜮', '\0'),
+    ('\u{2F9E6}', '鄛', '\0'),
+    ('\u{2F9E7}', '鈸', '\0'),
+    ('\u{2F9E8}', '鋗', '\0'),
+    ('\u{2F9E9}', '鋘', '\0'),
+    ('\u{2F9EA}', '鉼', '\0'),
+    ('\u{2F9EB}', '鏹', '\0'),
+    ('\u{2F9EC}', '鐕', '\0'),
+    ('\u{2F9ED}', '𨯺', '\0'),
+    ('\u{2F9EE}', '開', '\0'),
+    ('\u{2F9EF}', '䦕', '\0'),
+    ('\u{2F9F0}', '閷', '\0'),
+    ('\u{2F9F1}', '𨵷', '\0'),
+    ('\u{2F9F2}', '䧦', '\0'),
+    ('\u{2F9F3}', '雃', '\0'),
+    ('\u{2F9F4}', '嶲', '\0'),
+    ('\u{2F9F5}', '霣', '\0'),
+    ('\u{2F9F6}', '𩅅', '\0'),
+    ('\u{2F9F7}', '𩈚', '\0'),
+    ('\u{2F9F8}', '䩮', '\0'),
+    ('\u{2F9F9}', '䩶', '\0'),
+    ('\u{2F9FA}', '韠', '\0'),
+    ('\u{2F9FB}', '𩐊', '\0'),
+    ('\u{2F9FC}', '䪲', '\0'),
+    ('\u{2F9FD}', '𩒖', '\0'),
+    ('\u{2F9FE}', '頋', '\0'),
+    ('\u{2F9FF}', '頋', '\0'),
+    ('\u{2FA00}', '頩', '\0'),
+    ('\u{2FA01}', '𩖶', '\0'),
+    ('\u{2FA02}', '飢', '\0'),
+    ('\u{2FA03}', '䬳', '\0'),
+    ('\u{2FA04}', '餩', '\0'),
+    ('\u{2FA05}', '馧', '\0'),
+    ('\u{2FA06}', '駂', '\0'),
+    ('\u{2FA07}', '駾', '\0'),
+    ('\u{2FA08}', '䯎', '\0'),
+    ('\u{2FA09}', '𩬰', '\0'),
+    ('\u{2FA0A}', '鬒', '\0'),
+    ('\u{2FA0B}', '鱀', '\0'),
+    ('\u{2FA0C}', '鳽', '\0'),
+    ('\u{2FA0D}', '䳎', '\0'),
+    ('\u{2FA0E}', '䳭', '\0'),
+    ('\u{2FA0F}', '鵧', '\0'),
+    ('\u{2FA10}', '𪃎', '\0'),
+    ('\u{2FA11}', '䳸', '\0'),
+    ('\u{2FA12}', '𪄅', '\0'),
+    ('\u{2FA13}', '𪈎', '\0'),
+    ('\u{2FA14}', '𪊑', '\0'),
+    ('\u{2FA15}', '麻', '\0'),
+    ('\u{2FA16}', '䵖', '\0'),
+    ('\u{2FA17}', '黹', '\0'),
+    ('\u{2FA18}', '黾', '\0'),
+    ('\u{2FA19}', '鼅', '\0'),
+    ('\u{2FA1A}', '鼏', '\0'),
+    ('\u{2FA1B}', '鼖', '\0'),
+    ('\u{2FA1C}', '鼻', '\0'),
+    ('\u{2FA1D}', '𪘀', '\0'),
+];
+
+/// Characters that are excluded from canonical composition despite decomposing into 2 characters.
+static EXCLUSIONS: &[char] = &[
+    '\u{344}',
+    '\u{958}',
+    '\u{959}',
+    '\u{95A}',
+    '\u{95B}',
+    '\u{95C}',
+    '\u{95D}',
+    '\u{95E}',
+    '\u{95F}',
+    '\u{9DC}',
+    '\u{9DD}',
+    '\u{9DF}',
+    '\u{A33}',
+    '\u{A36}',
+    '\u{A59}',
+    '\u{A5A}',
+    '\u{A5B}',
+    '\u{A5E}',
+    '\u{B5C}',
+    '\u{B5D}',
+    '\u{F43}',
+    '\u{F4D}',
+    '\u{F52}',
+    '\u{F57}',
+    '\u{F5C}',
+    '\u{F69}',
+    '\u{F73}',
+    '\u{F75}',
+    '\u{F76}',
+    '\u{F78}',
+    '\u{F81}',
+    '\u{F93}',
+    '\u{F9D}',
+    '\u{FA2}',
+    '\u{FA7}',
+    '\u{FAC}',
+    '\u{FB9}',
+    '\u{2ADC}',
+    '\u{FB1D}',
+    '\u{FB1F}',
+    '\u{FB2A}',
+    '\u{FB2B}',
+    '\u{FB2C}',
+    '\u{FB2D}',
+    '\u{FB2E}',
+    '\u{FB2F}',
+    '\u{FB30}',
+    '\u{FB31}',
+    '\u{FB32}',
+    '\u{FB33}',
+    '\u{FB34}',
+    '\u{FB35}',
+    '\u{FB36}',
+    '\u{FB38}',
+    '\u{FB39}',
+    '\u{FB3A}',
+    '\u{FB3B}',
+    '\u{FB3C}',
+    '\u{FB3E}',
+    '\u{FB40}',
+    '\u{FB41}',
+    '\u{FB43}',
+    '\u{FB44}',
+    '\u{FB46}',
+    '\u{FB47}',
+    '\u{FB48}',
+    '\u{FB49}',
+    '\u{FB4A}',
+    '\u{FB4B}',
+    '\u{FB4C}',
+    '\u{FB4D}',
+    '\u{FB4E}',
+    '\u{1D15E}',
+    '\u{1D15F}',
+    '\u{1D160}',
+    '\u{1D161}',
+    '\u{1D162}',
+    '\u{1D163}',
+    '\u{1D164}',
+    '\u{1D1BB}',
+    '\u{1D1BC}',
+    '\u{1D1BD}',
+    '\u{1D1BE}',
+    '\u{1D1BF}',
+    '\u{1D1C0}',
+];
+
+/// Ranges of characters with the same non-zero canonical combining class, sorted.
+static COMBINING_CLASSES: &[(char, char, u8)] = &[
+    ('\u{300}', '\u{314}', 230),
+    ('\u{315}', '\u{315}', 232),
+    ('\u{316}', '\u{319}', 220),
+    ('\u{31A}', '\u{31A}', 232),
+    ('\u{31B}', '\u{31B}', 216),
+    ('\u{31C}', '\u{320}', 220),
+    ('\u{321}', '\u{322}', 202),
+    ('\u{323}', '\u{326}', 220),
+    ('\u{327}', '\u{328}', 202),
+    ('\u{329}', '\u{333}', 220),
+    ('\u{334}', '\u{338}', 1),
+    ('\u{339}', '\u{33C}', 220),
+    ('\u{33D}', '\u{344}', 230),
+    ('\u{345}', '\u{345}', 240),
+    ('\u{346}', '\u{346}', 230),
+    ('\u{347}', '\u{349}', 220),
+    ('\u{34A}', '\u{34C}', 230),
+    ('\u{34D}', '\u{34E}', 220),
+    ('\u{350}', '\u{352}', 230),
+    ('\u{353}', '\u{356}', 220),
+    ('\u{357}', '\u{357}', 230),
+    ('\u{358}', '\u{358}', 232),
+    ('\u{359}', '\u{35A}', 220),
+    ('\u{35B}', '\u{35B}', 230),
+    ('\u{35C}', '\u{35C}', 233),
+    ('\u{35D}', '\u{35E}', 234),
+    ('\u{35F}', '\u{35F}', 233),
+    ('\u{360}', '\u{361}', 234),
+    ('\u{362}', '\u{362}', 233),
+    ('\u{363}', '\u{36F}', 230),
+    ('\u{483}', '\u{487}', 230),
+    ('\u{591}', '\u{591}', 220),
+    ('\u{592}', '\u{595}', 230),
+    ('\u{596}', '\u{596}', 220),
+    ('\u{597}', '\u{599}', 230),
+    ('\u{59A}', '\u{59A}', 222),
+    ('\u{59B}', '\u{59B}', 220),
+    ('\u{59C}', '\u{5A1}', 230),
+    ('\u{5A2}', '\u{5A7}', 220),
+    ('\u{5A8}', '\u{5A9}', 230),
+    ('\u{5AA}', '\u{5AA}', 220),
+    ('\u{5AB}', '\u{5AC}', 230),
+    ('\u{5AD}', '\u{5AD}', 222),
+    ('\u{5AE}', '\u{5AE}', 228),
+    ('\u{5AF}', '\u{5AF}', 230),
+    ('\u{5B0}', '\u{5B0}', 10),
+    ('\u{5B1}', '\u{5B1}', 11),
+    ('\u{5B2}', '\u{5B2}', 12),
+    ('\u{5B3}', '\u{5B3}', 13),
+    ('\u{5B4}', '\u{5B4}', 14),
+    ('\u{5B5}', '\u{5B5}', 15),
+    ('\u{5B6}', '\u{5B6}', 16),
+    ('\u{5B7}', '\u{5B7}', 17),
+    ('\u{5B8}', '\u{5B8}', 18),
+    ('\u{5B9}', '\u{5BA}', 19),
+    ('\u{5BB}', '\u{5BB}', 20),
+    ('\u{5BC}', '\u{5BC}', 21),
+    ('\u{5BD}', '\u{5BD}', 22),
+    ('\u{5BF}', '\u{5BF}', 23),
+    ('\u{5C1}', '\u{5C1}', 24),
+    ('\u{5C2}', '\u{5C2}', 25),
+    ('\u{5C4}', '\u{5C4}', 230),
+    ('\u{5C5}', '\u{5C5}', 220),
+    ('\u{5C7}', '\u{5C7}', 18),
+    ('\u{610}', '\u{617}', 230),
+    ('\u{618}', '\u{618}', 30),
+    ('\u{619}', '\u{619}', 31),
+    ('\u{61A}', '\u{61A}', 32),
+    ('\u{64B}', '\u{64B}', 27),
+    ('\u{64C}', '\u{64C}', 28),
+    ('\u{64D}', '\u{64D}', 29),
+    ('\u{64E}', '\u{64E}', 30),
+    ('\u{64F}', '\u{64F}', 31),
+    ('\u{650}', '\u{650}', 32),
+    ('\u{651}', '\u{651}', 33),
+    ('\u{652}', '\u{652}', 34),
+    ('\u{653}', '\u{654}', 230),
+    ('\u{655}', '\u{656}', 220),
+    ('\u{657}', '\u{65B}', 230),
+    ('\u{65C}', '\u{65C}', 220),
+    ('\u{65D}', '\u{65E}', 230),
+    ('\u{65F}', '\u{65F}', 220),
+    ('\u{670}', '\u{670}', 35),
+    ('\u{6D6}', '\u{6DC}', 230),
+    ('\u{6DF}', '\u{6E2}', 230),
+    ('\u{6E3}', '\u{6E3}', 220),
+    ('\u{6E4}', '\u{6E4}', 230),
+    ('\u{6E7}', '\u{6E8}', 230),
+    ('\u{6EA}', '\u{6EA}', 220),
+    ('\u{6EB}', '\u{6EC}', 230),
+    ('\u{6ED}', '\u{6ED}', 220),
+    ('\u{711}', '\u{711}', 36),
+    ('\u{730}', '\u{730}', 230),
+    ('\u{731}', '\u{731}', 220),
+    ('\u{732}', '\u{733}', 230),
+    ('\u{734}', '\u{734}', 220),
+    ('\u{735}', '\u{736}', 230),
+    ('\u{737}', '\u{739}', 220),
+    ('\u{73A}', '\u{73A}', 230),
+    ('\u{73B}', '\u{73C}', 220),
+    ('\u{73D}', '\u{73D}', 230),
+    ('\u{73E}', '\u{73E}', 220),
+    ('\u{73F}', '\u{741}', 230),
+    ('\u{742}', '\u{742}', 220),
+    ('\u{743}', '\u{743}', 230),
+    ('\u{744}', '\u{744}', 220),
+    ('\u{745}', '\u{745}', 230),
+    ('\u{746}', '\u{746}', 220),
+    ('\u{747}', '\u{747}', 230),
+    ('\u{748}', '\u{748}', 220),
+    ('\u{749}', '\u{74A}', 230),
+    ('\u{7EB}', '\u{7F1}', 230),
+    ('\u{7F2}', '\u{7F2}', 220),
+    ('\u{7F3}', '\u{7F3}', 230),
+    ('\u{7FD}', '\u{7FD}', 220),
+    ('\u{816}', '\u{819}', 230),
+    ('\u{81B}', '\u{823}', 230),
+    ('\u{825}', '\u{827}', 230),
+    ('\u{829}', '\u{82D}', 230),
+    ('\u{859}', '\u{85B}', 220),
+    ('\u{898}', '\u{898}', 230),
+    ('\u{899}', '\u{89B}', 220),
+    ('\u{89C}', '\u{89F}', 230),
+    ('\u{8CA}', '\u{8CE}', 230),
+    ('\u{8CF}', '\u{8D3}', 220),
+    ('\u{8D4}', '\u{8E1}', 230),
+    ('\u{8E3}', '\u{8E3}', 220),
+    ('\u{8E4}', '\u{8E5}', 230),
+    ('\u{8E6}', '\u{8E6}', 220),
+    ('\u{8E7}', '\u{8E8}', 230),
+    ('\u{8E9}', '\u{8E9}', 220),
+    ('\u{8EA}', '\u{8EC}', 230),
+    ('\u{8ED}', '\u{8EF}', 220),
+    ('\u{8F0}', '\u{8F0}', 27),
+    ('\u{8F1}', '\u{8F1}', 28),
+    ('\u{8F2}', '\u{8F2}', 29),
+    ('\u{8F3}', '\u{8F5}', 230),
+    ('\u{8F6}', '\u{8F6}', 220),
+    ('\u{8F7}', '\u{8F8}', 230),
+    ('\u{8F9}', '\u{8FA}', 220),
+    ('\u{8FB}', '\u{8FF}', 230),
+    ('\u{93C}', '\u{93C}', 7),
+    ('\u{94D}', '\u{94D}', 9),
+    ('\u{951}', '\u{951}', 230),
+    ('\u{952}', '\u{952}', 220),
+    ('\u{953}', '\u{954}', 230),
+    ('\u{9BC}', '\u{9BC}', 7),
+    ('\u{9CD}', '\u{9CD}', 9),
+    ('\u{9FE}', '\u{9FE}', 230),
+    ('\u{A3C}', '\u{A3C}', 7),
+    ('\u{A4D}', '\u{A4D}', 9),
+    ('\u{ABC}', '\u{ABC}', 7),
+    ('\u{ACD}', '\u{ACD}', 9),
+    ('\u{B3C}', '\u{B3C}', 7),
+    ('\u{B4D}', '\u{B4D}', 9),
+    ('\u{BCD}', '\u{BCD}', 9),
+    ('\u{C3C}', '\u{C3C}', 7),
+    ('\u{C4D}', '\u{C4D}', 9),
+    ('\u{C55}', '\u{C55}', 84),
+    ('\u{C56}', '\u{C56}', 91),
+    ('\u{CBC}', '\u{CBC}', 7),
+    ('\u{CCD}', '\u{CCD}', 9),
+    ('\u{D3B}', '\u{D3C}', 9),
+    ('\u{D4D}', '\u{D4D}', 9),
+    ('\u{DCA}', '\u{DCA}', 9),
+    ('\u{E38}', '\u{E39}', 103),
+    ('\u{E3A}', '\u{E3A}', 9),
+    ('\u{E48}', '\u{E4B}', 107),
+    ('\u{EB8}', '\u{EB9}', 118),
+    ('\u{EBA}', '\u{EBA}', 9),
+    ('\u{EC8}', '\u{ECB}', 122),
+    ('\u{F18}', '\u{F19}', 220),
+    ('\u{F35}', '\u{F35}', 220),
+    ('\u{F37}', '\u{F37}', 220),
+    ('\u{F39}', '\u{F39}', 216),
+    ('\u{F71}', '\u{F71}', 129),
+    ('\u{F72}', '\u{F72}', 130),
+    ('\u{F74}', '\u{F74}', 132),
+    ('\u{F7A}', '\u{F7D}', 130),
+    ('\u{F80}', '\u{F80}', 130),
+    ('\u{F82}', '\u{F83}', 230),
+    ('\u{F84}', '\u{F84}', 9),
+    ('\u{F86}', '\u{F87}', 230),
+    ('\u{FC6}', '\u{FC6}', 220),
+    ('\u{1037}', '\u{1037}', 7),
+    ('\u{1039}', '\u{103A}', 9),
+    ('\u{108D}', '\u{108D}', 220),
+    ('\u{135D}', '\u{135F}', 230),
+    ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9),
+    ('\u{17D2}', '\u{17D2}', 9),
+    ('\u{17DD}', '\u{17DD}', 230),
+    ('\u{18A9}', '\u{18A9}', 228),
+    ('\u{1939}', '\u{1939}', 222),
+    ('\u{193A}', '\u{193A}', 230),
+    ('\u{193B}', '\u{193B}', 220),
+    ('\u{1A17}', '\u{1A17}', 230),
+    ('\u{1A18}', '\u{1A18}', 220),
+    ('\u{1A60}', '\u{1A60}', 9),
+    ('\u{1A75}', '\u{1A7C}', 230),
+    ('\u{1A7F}', '\u{1A7F}', 220),
+    ('\u{1AB0}', '\u{1AB4}', 230),
+    ('\u{1AB5}', '\u{1ABA}', 220),
+    ('\u{1ABB}', '\u{1ABC}', 230),
+    ('\u{1ABD}', '\u{1ABD}', 220),
+    ('\u{1ABF}', '\u{1AC0}', 220),
+    ('\u{1AC1}', '\u{1AC2}', 230),
+    ('\u{1AC3}', '\u{1AC4}', 220),
+    ('\u{1AC5}', '\u{1AC9}', 230),
+    ('\u{1ACA}', '\u{1ACA}', 220),
+    ('\u{1ACB}', '\u{1ACE}', 230),
+    ('\u{1B34}', '\u{1B34}', 7),
+    ('\u{1B44}', '\u{1B44}', 9),
+    ('\u{1B6B}', '\u{1B6B}', 230),
+    ('\u{1B6C}', '\u{1B6C}', 220),
+    ('\u{1B6D}', '\u{1B73}', 230),
+    ('\u{1BAA}', '\u{1BAB}', 9),
+    ('\u{1BE6}', '\u{1BE6}', 7),
+    ('\u{1BF2}', '\u{1BF3}', 9),
+    ('\u{1C37}', '\u{1C37}', 7),
+    ('\u{1CD0}', '\u{1CD2}', 230),
+    ('\u{1CD4}', '\u{1CD4}', 1),
+    ('\u{1CD5}', '\u{1CD9}', 220),
+    ('\u{1CDA}', '\u{1CDB}', 230),
+    ('\u{1CDC}', '\u{1CDF}', 220),
+    ('\u{1CE0}', '\u{1CE0}', 230),
+    ('\u{1CE2}', '\u{1CE8}', 1),
+    ('\u{1CED}', '\u{1CED}', 220),
+    ('\u{1CF4}', '\u{1CF4}', 230),
+    ('\u{1CF8}', '\u{1CF9}', 230),
+    ('\u{1DC0}', '\u{1DC1}', 230),
+    ('\u{1DC2}', '\u{1DC2}', 220),
+    ('\u{1DC3}', '\u{1DC9}', 230),
+    ('\u{1DCA}', '\u{1DCA}', 220),
+    ('\u{1DCB}', '\u{1DCC}', 230),
+    ('\u{1DCD}', '\u{1DCD}', 234),
+    ('\u{1DCE}', '\u{1DCE}', 214),
+    ('\u{1DCF}', '\u{1DCF}', 220),
+    ('\u{1DD0}', '\u{1DD0}', 202),
+    ('\u{1DD1}', '\u{1DF5}', 230),
+    ('\u{1DF6}', '\u{1DF6}', 232),
+    ('\u{1DF7}', '\u{1DF8}', 228),
+    ('\u{1DF9}', '\u{1DF9}', 220),
+    ('\u{1DFA}', '\u{1DFA}', 218),
+    ('\u{1DFB}', '\u{1DFB}', 230),
+    ('\u{1DFC}', '\u{1DFC}', 233),
+    ('\u{1DFD}', '\u{1DFD}', 220),
+    ('\u{1DFE}', '\u{1DFE}', 230),
+    ('\u{1DFF}', '\u{1DFF}', 220),
+    ('\u{20D0}', '\u{20D1}', 230),
+    ('\u{20D2}', '\u{20D3}', 1),
+    ('\u{20D4}', '\u{20D7}', 230),
+    ('\u{20D8}', '\u{20DA}', 1),
+    ('\u{20DB}', '\u{20DC}', 230),
+    ('\u{20E1}', '\u{20E1}', 230),
+    ('\u{20E5}', '\u{20E6}', 1),
+    ('\u{20E7}', '\u{20E7}', 230),
+    ('\u{20E8}', '\u{20E8}', 220),
+    ('\u{20E9}', '\u{20E9}', 230),
+    ('\u{20EA}', '\u{20EB}', 1),
+    ('\u{20EC}', '\u{20EF}', 220),
+    ('\u{20F0}', '\u{20F0}', 230),
+    ('\u{2CEF}', '\u{2CF1}', 230),
+    ('\u{2D7F}', '\u{2D7F}', 9),
+    ('\u{2DE0}', '\u{2DFF}', 230),
+    ('\u{302A}', '\u{302A}', 218),
+    ('\u{302B}', '\u{302B}', 228),
+    ('\u{302C}', '\u{302C}', 232),
+    ('\u{302D}', '\u{302D}', 222),
+    ('\u{302E}', '\u{302F}', 224),
+    ('\u{3099}', '\u{309A}', 8),
+    ('\u{A66F}', '\u{A66F}', 230),
+    ('\u{A674}', '\u{A67D}', 230),
+    ('\u{A69E}', '\u{A69F}', 230),
+    ('\u{A6F0}', '\u{A6F1}', 230),
+    ('\u{A806}', '\u{A806}', 9),
+    ('\u{A82C}', '\u{A82C}', 9),
+    ('\u{A8C4}', '\u{A8C4}', 9),
+    ('\u{A8E0}', '\u{A8F1}', 230),
+    ('\u{A92B}', '\u{A92D}', 220),
+    ('\u{A953}', '\u{A953}', 9),
+    ('\u{A9B3}', '\u{A9B3}', 7),
+    ('\u{A9C0}', '\u{A9C0}', 9),
+    ('\u{AAB0}', '\u{AAB0}', 230),
+    ('\u{AAB2}', '\u{AAB3}', 230),
+    ('\u{AAB4}', '\u{AAB4}', 220),
+    ('\u{AAB7}', '\u{AAB8}', 230),
+    ('\u{AABE}', '\u{AABF}', 230),
+    ('\u{AAC1}', '\u{AAC1}', 230),
+    ('\u{AAF6}', '\u{AAF6}', 9),
+    ('\u{ABED}', '\u{ABED}', 9),
+    ('\u{FB1E}', '\u{FB1E}', 26),
+    ('\u{FE20}', '\u{FE26}', 230),
+    ('\u{FE27}', '\u{FE2D}', 220),
+    ('\u{FE2E}', '\u{FE2F}', 230),
+    ('\u{101FD}', '\u{101FD}', 220),
+    ('\u{102E0}', '\u{102E0}', 220),
+    ('\u{10376}', '\u{1037A}', 230),
+    ('\u{10A0D}', '\u{10A0D}', 220),
+    ('\u{10A0F}', '\u{10A0F}', 230),
+    ('\u{10A38}', '\u{10A38}', 230),
+    ('\u{10A39}', '\u{10A39}', 1),
+    ('\u{10A3A}', '\u{10A3A}', 220),
+    ('\u{10A3F}', '\u{10A3F}', 9),
+    ('\u{10AE5}', '\u{10AE5}', 230),
+    ('\u{10AE6}', '\u{10AE6}', 220),
+    ('\u{10D24}', '\u{10D27}', 230),
+    ('\u{10EAB}', '\u{10EAC}', 230),
+    ('\u{10EFD}', '\u{10EFF}', 220),
+    ('\u{10F46}', '\u{10F47}', 220),
+    ('\u{10F48}', '\u{10F4A}', 230),
+    ('\u{10F4B}', '\u{10F4B}', 220),
+    ('\u{10F4C}', '\u{10F4C}', 230),
+    ('\u{10F4D}', '\u{10F50}', 220),
+    ('\u{10F82}', '\u{10F82}', 230),
+    ('\u{10F83}', '\u{10F83}', 220),
+    ('\u{10F84}', '\u{10F84}', 230),
+    ('\u{10F85}', '\u{10F85}', 220),
+    ('\u{11046}', '\u{11046}', 9),
+    ('\u{11070}', '\u{11070}', 9),
+    ('\u{1107F}', '\u{1107F}', 9),
+    ('\u{110B9}', '\u{110B9}', 9),
+    ('\u{110BA}', '\u{110BA}', 7),
+    ('\u{11100}', '\u{11102}', 230),
+    ('\u{11133}', '\u{11134}', 9),
+    ('\u{11173}', '\u{11173}', 7),
+    ('\u{111C0}', '\u{111C0}', 9),
+    ('\u{111CA}', '\u{111CA}', 7),
+    ('\u{11235}', '\u{11235}', 9),
+    ('\u{11236}', '\u{11236}', 7),
+    ('\u{112E9}', '\u{112E9}', 7),
+    ('\u{112EA}', '\u{112EA}', 9),
+    ('\u{1133B}', '\u{1133C}', 7),
+    ('\u{1134D}', '\u{1134D}', 9),
+    ('\u{11366}', '\u{1136C}', 230),
+    ('\u{11370}', '\u{11374}', 230),
+    ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7),
+    ('\u{1145E}', '\u{1145E}', 230),
+    ('\u{114C2}', '\u{114C2}', 9),
+    ('\u{114C3}', '\u{114C3}', 7),
+    ('\u{115BF}', '\u{115BF}', 9),
+    ('\u{115C0}', '\u{115C0}', 7),
+    ('\u{1163F}', '\u{1163F}', 9),
+    ('\u{116B6}', '\u{116B6}', 9),
+    ('\u{116B7}', '\u{116B7}', 7),
+    ('\u{1172B}', '\u{1172B}', 9),
+    ('\u{11839}', '\u{11839}', 9),
+    ('\u{1183A}', '\u{1183A}', 7),
+    ('\u{1193D}', '\u{1193E}', 9),
+    ('\u{11943}', '\u{11943}', 7),
+    ('\u{119E0}', '\u{119E0}', 9),
+    ('\u{11A34}', '\u{11A34}', 9),
+    ('\u{11A47}', '\u{11A47}', 9),
+    ('\u{11A99}', '\u{11A99}', 9),
+    ('\u{11C3F}', '\u{11C3F}', 9),
+    ('\u{11D42}', '\u{11D42}', 7),
+    ('\u{11D44}', '\u{11D45}', 9),
+    ('\u{11D97}', '\u{11D97}', 9),
+    ('\u{11F41}', '\u{11F42}', 9),
+    ('\u{16AF0}', '\u{16AF4}', 1),
+    ('\u{16B30}', '\u{16B36}', 230),
+    ('\u{16FF0}', '\u{16FF1}', 6),
+    ('\u{1BC9E}', '\u{1BC9E}', 1),
+    ('\u{1D165}', '\u{1D166}', 216),
+    ('\u{1D167}', '\u{1D169}', 1),
+    ('\u{1D16D}', '\u{1D16D}', 226),
+    ('\u{1D16E}', '\u{1D172}', 216),
+    ('\u{1D17B}', '\u{1D182}', 220),
+    ('\u{1D185}', '\u{1D189}', 230),
+    ('\u{1D18A}', '\u{1D18B}', 220),
+    ('\u{1D1AA}', '\u{1D1AD}', 230),
+    ('\u{1D242}', '\u{1D244}', 230),
+    ('\u{1E000}', '\u{1E006}', 230),
+    ('\u{1E008}', '\u{1E018}', 230),
+    ('\u{1E01B}', '\u{1E021}', 230),
+    ('\u{1E023}', '\u{1E024}', 230),
+    ('\u{1E026}', '\u{1E02A}', 230),
+    ('\u{1E08F}', '\u{1E08F}', 230),
+    ('\u{1E130}', '\u{1E136}', 230),
+    ('\u{1E2AE}', '\u{1E2AE}', 230),
+    ('\u{1E2EC}', '\u{1E2EF}', 230),
+    ('\u{1E4EC}', '\u{1E4ED}', 232),
+    ('\u{1E4EE}', '\u{1E4EE}', 220),
+    ('\u{1E4EF}', '\u{1E4EF}', 230),
+    ('\u{1E8D0}', '\u{1E8D6}', 220),
+    ('\u{1E944}', '\u{1E949}', 230),
+    ('\u{1E94A}', '\u{1E94A}', 7),
+];
+
+/// The range of the characters with a non-zero combining class.
+pub(crate) const MARKS: RangeInclusive<char> =
+    COMBINING_CLASSES[0].0..=COMBINING_CLASSES[COMBINING_CLASSES.len() - 1].1;
+
+/// The number of the canonical compositions, i.e. of 2-character decompositions that aren't
+/// excluded.
+const N_COMPOSITIONS: usize = {
+    let mut res = 0;
+    let mut i = 0;
+    while i < DECOMPOSITIONS.len() {
+        if DECOMPOSITIONS[i].2 != '\0' {
+            res += 1;
+        }
+        i += 1;
+    }
+    res - EXCLUSIONS.len()
+};
+
+/// Canonical compositions as `(first, second, composed)`, sorted by the composed pair.
+static COMPOSITIONS: [(char, char, char); N_COMPOSITIONS] = const {
+    const fn is_excluded(ch: char) -> bool {
+        let mut i = 0;
+        while i < EXCLUSIONS.len() {
+            if EXCLUSIONS[i] == ch {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    const fn less(a: (char, char, char), b: (char, char, char)) -> bool {
+        a.0 < b.0 || (a.0 == b.0 && a.1 < b.1)
+    }
+
+    let mut res = [('\0', '\0', '\0'); N_COMPOSITIONS];
+    let mut len = 0;
+    let mut i = 0;
+    while i < DECOMPOSITIONS.len() {
+        let (composed, first, second) = DECOMPOSITIONS[i];
+        i += 1;
+        if second == '\0' || is_excluded(composed) {
+            continue;
+        }
+
+        let entry = (first, second, composed);
+        let mut pos = len;
+        while pos > 0 && less(entry, res[pos - 1]) {
+            res[pos] = res[pos - 1];
+            pos -= 1;
+        }
+        res[pos] = entry;
+        len += 1;
+    }
+
+    assert!(
+        len == N_COMPOSITIONS,
+        "an excluded character doesn't decompose"
+    );
+    res
+};
+
+/// The canonical combining class of `ch`, 0 for the characters that don't combine with the
+/// preceding ones.
+pub fn combining_class(ch: char) -> u8 {
+    let i = COMBINING_CLASSES.partition_point(|&(_, last, _)| last < ch);
+    COMBINING_CLASSES
+        .get(i)
+        .filter(|&&(first, _, _)| first <= ch)
+        .map_or(0, |&(_, _, class)| class)
+}
+
+/// Composes 2 characters into one, if they have a canonical composition, e.g. `e` & U+0301 into
+/// `é`.
+pub fn compose(first: char, second: char) -> Option<char> {
+    COMPOSITIONS
+        .binary_search_by_key(&(first, second), |&(first, second, _)| (first, second))
+        .map(|i| COMPOSITIONS[i].2)
+        .ok()
+        .or_else(|| hangul::compose(first, second))
+}
+
+/// Appends the full canonical decomposition of `ch` to `dst`, without reordering the marks.
+fn decompose_into(ch: char, dst: &mut Vec<char>) {
+    if ('가'..='힣').contains(&ch) {
+        dst.extend(hangul::decompose(ch));
+    } else if let Ok(i) = DECOMPOSITIONS.binary_search_by_key(&ch, |&(ch, _, _)| ch) {
+        let (_, first, second) = DECOMPOSITIONS[i];
+        decompose_into(first, dst);
+        if second != '\0' {
+            decompose_into(second, dst);
+        }
+    } else {
+        dst.push(ch);
+    }
+}
+
+/// Decomposes every character of `s` & sorts the runs of combining marks by their combining class.
+fn decomposed(s: &str) -> Vec<char> {
+    let mut res = Vec::with_capacity(s.len());
+    for ch in s.chars() {
+        decompose_into(ch, &mut res);
+    }
+
+    for run in res.split_mut(|&ch| combining_class(ch) == 0) {
+        run.sort_by_key(|&ch| combining_class(ch));
+    }
+    res
+}
+
+/// Converts `s` to the Normalization Form D, i.e. with all characters canonically decomposed,
+/// e.g. `é` into `e` followed by U+0301.
+pub fn nfd(s: &str) -> String {
+    decomposed(s).into_iter().collect()
+}
+
+/// Converts `s` to the Normalization Form C, i.e. with all characters canonically composed, e.g.
+/// `e` followed by U+0301 into `é`.
+pub fn nfc(s: &str) -> String {
+    let mut res = Vec::new();
+    // Index of the last character that marks can be composed with
+    let mut starter = None;
+    // Combining class of the last character that wasn't composed with the starter
+    let mut last_class = 0;
+    for ch in decomposed(s) {
+        let class = combining_class(ch);
+        if let Some(i) = starter {
+            // Other characters between the starter & `ch` may block the composition
+            let blocked = res.len() > i + 1 && (last_class == 0 || last_class >= class);
+            if let Some(composed) = compose(res[i], ch).filter(|_| !blocked) {
+                res[i] = composed;
+                continue;
+            }
+        }
+
+        if class == 0 {
+            starter = Some(res.len());
+        }
+        last_class = class;
+        res.push(ch);
+    }
+
+    res.into_iter().collect()
+}
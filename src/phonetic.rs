@@ -0,0 +1,242 @@
+//! Phonetic codes of words, for finding the keys that sound like the input, e.g. "Shmidt" &
+//! "Schmidt".
+
+use {
+    crate::{Cursor, SearchTree, Searcher, lookalikes::Lookalikes},
+    alloc::{collections::BTreeSet, string::String, vec, vec::Vec},
+    core::convert::Infallible,
+};
+
+/// Code of an English letter in Soundex, `0` for vowels.
+///
+/// Returns `None` for `h`, `w` & non-letters, since those don't separate letters with the same code.
+const fn soundex_code(ch: char) -> Option<char> {
+    Some(match ch.to_ascii_lowercase() {
+        'b' | 'f' | 'p' | 'v' => '1',
+        'c' | 'g' | 'j' | 'k' | 'q' | 's' | 'x' | 'z' => '2',
+        'd' | 't' => '3',
+        'l' => '4',
+        'm' | 'n' => '5',
+        'r' => '6',
+        'a' | 'e' | 'i' | 'o' | 'u' | 'y' => '0',
+        _ => return None,
+    })
+}
+
+/// Whether `ch`, followed by `rest`, is a `t` that sounds like a part of a following `ch`, e.g. in
+/// "Tchaikovsky" or "match".
+fn is_silent_t(ch: char, rest: impl Iterator<Item = char>) -> bool {
+    ch == 't' && rest.take(2).eq(['c', 'h'])
+}
+
+/// Writes the American Soundex code of an English word to `dst`, e.g. `253` for "Schmidt".
+///
+/// Unlike the classic algorithm:
+/// - The first letter is coded like the others rather than kept as is, so that e.g. "Kristina" &
+///   "Christina" have the same code, and a `t` before `ch` is ignored, so that "Chaikovsky" &
+///   "Tchaikovsky" do too.
+/// - The code isn't truncated or padded to 4 characters, so that the code of a word being typed
+///   is a prefix of the code of the complete word.
+///
+/// Non-ASCII letters are ignored.
+pub fn soundex(word: &str, dst: &mut String) {
+    let start = dst.len();
+    let mut letters = word
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|ch| ch.to_ascii_lowercase());
+    let mut last = None;
+
+    while let Some(ch) = letters.next() {
+        if is_silent_t(ch, letters.clone()) {
+            continue;
+        }
+        let Some(code) = soundex_code(ch) else {
+            continue;
+        };
+        // Repeated codes are collapsed, vowels are only kept at the start
+        if last != Some(code) && (code != '0' || dst.len() == start) {
+            dst.push(code);
+        }
+        last = Some(code);
+    }
+}
+
+/// Writes the Cologne phonetics (Kölner Phonetik) code of a German word to `dst`, e.g. `862` for
+/// "Schmidt".
+pub fn cologne(word: &str, dst: &mut String) {
+    let start = dst.len();
+    let mut letters = word
+        .chars()
+        .filter(|ch| ch.is_alphabetic())
+        .flat_map(char::to_lowercase)
+        .peekable();
+    let mut prev = None;
+    let mut last = None;
+
+    while let Some(ch) = letters.next() {
+        let next = letters.peek().copied();
+        let code = match ch {
+            't' if is_silent_t(ch, letters.clone()) => "",
+            'a' | 'e' | 'i' | 'j' | 'o' | 'u' | 'y' | 'ä' | 'ö' | 'ü' => "0",
+            'p' if next == Some('h') => "3",
+            'b' | 'p' => "1",
+            'd' | 't' if matches!(next, Some('c' | 's' | 'z' | 'ß')) => "8",
+            'd' | 't' => "2",
+            'f' | 'v' | 'w' => "3",
+            'g' | 'k' | 'q' => "4",
+            'c' if prev.is_none() => {
+                if matches!(
+                    next,
+                    Some('a' | 'h' | 'k' | 'l' | 'o' | 'q' | 'r' | 'u' | 'x')
+                ) {
+                    "4"
+                } else {
+                    "8"
+                }
+            }
+            'c' => {
+                if matches!(next, Some('a' | 'h' | 'k' | 'o' | 'q' | 'u' | 'x'))
+                    && !matches!(prev, Some('s' | 'z' | 'ß'))
+                {
+                    "4"
+                } else {
+                    "8"
+                }
+            }
+            'x' if matches!(prev, Some('c' | 'k' | 'q')) => "8",
+            'x' => "48",
+            'l' => "5",
+            'm' | 'n' => "6",
+            'r' => "7",
+            's' | 'z' | 'ß' => "8",
+            _ => "",
+        };
+        prev = Some(ch);
+
+        for code in code.chars() {
+            // Repeated codes are collapsed, zeros are only kept at the start
+            if last != Some(code) && (code != '0' || dst.len() == start) {
+                dst.push(code);
+            }
+            last = Some(code);
+        }
+    }
+}
+
+/// Words of a string, i.e. its runs of letters.
+fn words(s: &str) -> impl Iterator<Item = &str> {
+    s.split(|ch: char| !ch.is_alphabetic())
+        .filter(|word| !word.is_empty())
+}
+
+/// A [`SearchTree`] that associates the words of the keys with the indices by their phonetic
+/// codes, to be searched alongside a [`Searcher`].
+///
+/// # Example
+/// ```rust
+/// use permissive_search::{phonetic::{self, PhoneticIndex}, *};
+///
+/// let keys = ["Arnold Schwarzenegger", "Helmut Schmidt"];
+/// let root: SearchTree = keys.into_iter().enumerate().collect();
+/// let mut index = PhoneticIndex::new(phonetic::soundex);
+/// for (i, key) in keys.into_iter().enumerate() {
+///     index.push(key, i);
+/// }
+///
+/// let mut searcher = Searcher::new(&root, lookalikes::all);
+/// searcher.extend("Shmidt".chars());
+/// index.for_each_candidate::<()>(&searcher, |i| Ok(println!("{}", keys[i])));
+/// ```
+#[derive(Debug)]
+pub struct PhoneticIndex {
+    /// Maps phonetic codes to their groups in `groups`
+    tree: SearchTree,
+    /// The indices of the keys that have a word with the same code, in the order of pushing
+    groups: Vec<Vec<usize>>,
+    encode: fn(&str, &mut String),
+}
+
+impl PhoneticIndex {
+    /// Create an empty index.
+    /// - `encode` is the function that writes the phonetic code of a word to a string, e.g.
+    ///   [`soundex`] or [`cologne`].
+    pub fn new(encode: fn(&str, &mut String)) -> Self {
+        Self {
+            tree: SearchTree::default(),
+            groups: vec![],
+            encode,
+        }
+    }
+
+    /// Add every word of a key to the index.
+    pub fn push(&mut self, key: &str, index: usize) {
+        let mut code = String::new();
+        for word in words(key) {
+            code.clear();
+            (self.encode)(word, &mut code);
+            if !code.is_empty() {
+                self.tree.push_to_group(&mut self.groups, &code, index);
+            }
+        }
+    }
+
+    /// Calls a function on every key in which every word of `query` has a word that sounds like it,
+    /// in the order of increasing index.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each_match<E>(
+        &self,
+        query: &str,
+        f: impl FnMut(usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut code = String::new();
+        let mut matches: Option<BTreeSet<usize>> = None;
+        for word in words(query) {
+            code.clear();
+            (self.encode)(word, &mut code);
+            if code.is_empty() {
+                continue;
+            }
+
+            let mut word_matches = BTreeSet::new();
+            if let Some(node) = self.tree.get_str(&code) {
+                let Ok(()) = node.for_each::<Infallible>(|group| {
+                    word_matches.extend(&self.groups[group]);
+                    Ok(())
+                });
+            }
+            matches = Some(match matches {
+                Some(matches) => matches.intersection(&word_matches).copied().collect(),
+                None => word_matches,
+            });
+        }
+
+        matches.into_iter().flatten().try_for_each(f)
+    }
+
+    /// Calls a function on every candidate of `searcher`, and then on every key that sounds like
+    /// its input, but isn't one of its candidates.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each_candidate<'tree, E>(
+        &self,
+        searcher: &Searcher<'tree, impl Lookalikes<'tree>, impl Cursor>,
+        mut f: impl FnMut(usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut visited = BTreeSet::new();
+        searcher.for_each_candidate(|index| {
+            visited.insert(index);
+            f(index)
+        })?;
+        self.for_each_match(searcher.input(), |index| {
+            if visited.contains(&index) {
+                Ok(())
+            } else {
+                f(index)
+            }
+        })
+    }
+}
@@ -0,0 +1,88 @@
+//! Pinyin readings of Han characters, for finding Chinese keys by their romanisation, e.g. "beijing"
+//! or "bj" for `北京`.
+//!
+//! Only available with the `pinyin` feature.
+//! ```rust
+//! use permissive_search::*;
+//!
+//! let keys = ["北京", "上海", "重庆"];
+//! let mut root = SearchTree::default();
+//! for (i, key) in keys.into_iter().enumerate() {
+//!     pinyin::push(&mut root, key, i);
+//! }
+//!
+//! let mut searcher = Searcher::new(&root, lookalikes::all);
+//! searcher.extend("bj".chars());
+//! searcher.for_each_candidate::<()>(|i| Ok(println!("{}", keys[i])));
+//! ```
+
+mod readings;
+
+use {
+    crate::SearchTree,
+    alloc::{string::String, vec, vec::Vec},
+    readings::READINGS,
+};
+
+/// The maximum number of spellings of a key, limits the combinations of the readings of
+/// heteronyms, e.g. `重` (zhong, chong) & `长` (chang, zhang).
+const MAX_SPELLINGS: usize = 8;
+
+/// Toneless pinyin readings of a Han character, the most common one first, e.g. "zhong" & "chong"
+/// for `重`.
+///
+/// Yields nothing for characters without a known reading, e.g. outside of the CJK Unified
+/// Ideographs of the BMP, `U+4E00..=U+9FFF`.
+pub fn readings(ch: char) -> impl Iterator<Item = &'static str> + Clone {
+    let start = READINGS.partition_point(|&(key, _)| key < ch);
+    READINGS[start..]
+        .iter()
+        .take_while(move |&&(key, _)| key == ch)
+        .map(|&(_, reading)| reading)
+}
+
+/// Adds `key` to `tree` along with its pinyin spellings: the full one, e.g. "beijing" for `北京`,
+/// & the initials, e.g. "bj".
+///
+/// Characters without a reading are kept as is in the spellings.
+pub fn push(tree: &mut SearchTree, key: &str, index: usize) {
+    tree.push(key, index);
+    if key.chars().all(|ch| readings(ch).next().is_none()) {
+        return;
+    }
+
+    let mut full = vec![String::new()];
+    let mut initials = vec![String::new()];
+    for ch in key.chars() {
+        if readings(ch).next().is_none() {
+            for spelling in full.iter_mut().chain(&mut initials) {
+                spelling.push(ch);
+            }
+            continue;
+        }
+
+        full = combine(&full, &readings(ch));
+        initials = combine(&initials, &readings(ch).map(|reading| &reading[..1]));
+    }
+
+    for spelling in full.iter().chain(&initials) {
+        tree.push(spelling, index);
+    }
+}
+
+/// Appends every suffix to every prefix, skipping duplicates & keeping at most [`MAX_SPELLINGS`].
+fn combine<'a>(
+    prefixes: &[String],
+    suffixes: &(impl Iterator<Item = &'a str> + Clone),
+) -> Vec<String> {
+    let mut res = Vec::new();
+    for prefix in prefixes {
+        for suffix in suffixes.clone() {
+            let spelling = prefix.clone() + suffix;
+            if res.len() < MAX_SPELLINGS && !res.contains(&spelling) {
+                res.push(spelling);
+            }
+        }
+    }
+    res
+}
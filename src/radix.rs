@@ -0,0 +1,206 @@
+//! A path-compressed representation of a [`SearchTree`](crate::SearchTree), for large sets of
+//! keys with long unique suffixes.
+//!
+//! # Example
+//! ```rust
+//! use permissive_search::{radix::RadixTree, *};
+//!
+//! let keys = ["international", "internet", "interval"];
+//! let root: RadixTree = keys.into_iter().enumerate().collect();
+//! let mut searcher = Searcher::new(&root, lookalikes::all);
+//! searcher.extend("intervla".chars());
+//! searcher.for_each_candidate::<()>(|i| Ok(println!("{}", keys[i])));
+//! ```
+
+use {
+    crate::{Cursor, IntoCursor},
+    alloc::{boxed::Box, vec, vec::Vec},
+    core::{mem::take, ops::RangeInclusive, ptr},
+};
+
+/// A tree that associates string keys with `usize` indices, like
+/// [`SearchTree`](crate::SearchTree), but with every chain of nodes that have 1 child each
+/// stored as a single string fragment.
+#[derive(Debug, Default)]
+pub struct RadixTree {
+    root: RadixNode,
+    /// The largest index pushed so far
+    max_index: Option<usize>,
+    /// Whether an index may be associated with several keys, i.e. the indices weren't pushed in
+    /// increasing order
+    repeats: bool,
+}
+
+/// A node of a [`RadixTree`], reached through a [`RadixCursor`].
+#[derive(Debug, Default)]
+pub struct RadixNode {
+    /// Fragments leading to the children, sorted by their first character. Fragments are never
+    /// empty & no 2 of them start with the same character.
+    nodes: Vec<(Box<str>, Self)>,
+    end: Option<usize>,
+}
+
+/// The first character of a fragment.
+fn first(fragment: &str) -> char {
+    fragment.chars().next().unwrap_or_default()
+}
+
+impl<'key> FromIterator<(usize, &'key str)> for RadixTree {
+    fn from_iter<T: IntoIterator<Item = (usize, &'key str)>>(iter: T) -> Self {
+        let mut res = Self::default();
+        for (index, key) in iter {
+            res.push(key, index);
+        }
+        res
+    }
+}
+
+impl RadixTree {
+    /// Add a key to the tree
+    pub fn push(&mut self, key: &str, index: usize) {
+        self.repeats |= self.max_index.is_some_and(|max| index <= max);
+        self.max_index = self.max_index.max(Some(index));
+        self.root.push(key, index);
+    }
+
+    /// A cursor at the root of the tree.
+    pub const fn cursor(&self) -> RadixCursor<'_> {
+        RadixCursor {
+            node: &self.root,
+            rest: "",
+        }
+    }
+
+    /// Calls a function on all the keys in the tree.
+    ///
+    /// # Errors
+    /// The function doesn't fail itself, but it does propagate errors from the callback
+    pub fn for_each<E>(&self, mut f: impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        self.root.for_each_base(&mut f)
+    }
+}
+
+impl RadixNode {
+    fn push(&mut self, key: &str, index: usize) {
+        let Some(ch) = key.chars().next() else {
+            self.end = Some(index);
+            return;
+        };
+
+        let i = match self
+            .nodes
+            .binary_search_by_key(&ch, |(fragment, _)| first(fragment))
+        {
+            Ok(i) => i,
+            Err(i) => {
+                let leaf = Self {
+                    nodes: vec![],
+                    end: Some(index),
+                };
+                self.nodes.insert(i, (key.into(), leaf));
+                return;
+            }
+        };
+
+        let (fragment, node) = &mut self.nodes[i];
+        let common = fragment
+            .char_indices()
+            .zip(key.chars())
+            .find(|&((_, a), b)| a != b)
+            .map_or_else(|| fragment.len().min(key.len()), |((i, _), _)| i);
+        // Splitting the fragment where the key diverges from it
+        if common < fragment.len() {
+            let tail = (fragment[common..].into(), take(node));
+            *fragment = fragment[..common].into();
+            node.nodes.push(tail);
+        }
+        node.push(&key[common..], index);
+    }
+
+    fn for_each_base<E>(&self, f: &mut impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        self.end.map(&mut *f).transpose()?;
+        self.nodes
+            .iter()
+            .try_for_each(|(_, node)| node.for_each_base(f))
+    }
+}
+
+/// A position in a [`RadixTree`], either at a node or inside the fragment leading to it.
+#[derive(Debug, Clone, Copy)]
+pub struct RadixCursor<'tree> {
+    node: &'tree RadixNode,
+    /// The part of the fragment leading to `node` that's yet to be stepped through
+    rest: &'tree str,
+}
+
+impl<'tree> IntoCursor for &'tree RadixTree {
+    type Cursor = RadixCursor<'tree>;
+
+    fn into_cursor(self) -> Self::Cursor {
+        self.cursor()
+    }
+
+    fn repeats_indices(&self) -> bool {
+        self.repeats
+    }
+}
+
+impl Cursor for RadixCursor<'_> {
+    type Id = (*const RadixNode, usize);
+
+    fn id(self) -> Self::Id {
+        (ptr::from_ref(self.node), self.rest.len())
+    }
+
+    fn get(self, ch: char) -> Option<Self> {
+        if !self.rest.is_empty() {
+            let rest = self.rest.strip_prefix(ch)?;
+            return Some(Self { rest, ..self });
+        }
+
+        let nodes = &self.node.nodes;
+        let i = nodes
+            .binary_search_by_key(&ch, |(fragment, _)| first(fragment))
+            .ok()?;
+        let (fragment, node) = &nodes[i];
+        Some(Self {
+            node,
+            rest: &fragment[ch.len_utf8()..],
+        })
+    }
+
+    fn for_each_child_in(self, range: &RangeInclusive<char>, mut f: impl FnMut(char, Self)) {
+        if let Some(ch) = self.rest.chars().next() {
+            if range.contains(&ch) {
+                f(
+                    ch,
+                    Self {
+                        rest: &self.rest[ch.len_utf8()..],
+                        ..self
+                    },
+                );
+            }
+            return;
+        }
+
+        let nodes = &self.node.nodes;
+        let start = nodes.partition_point(|(fragment, _)| first(fragment) < *range.start());
+        for (fragment, node) in nodes[start..]
+            .iter()
+            .take_while(|(fragment, _)| first(fragment) <= *range.end())
+        {
+            let ch = first(fragment);
+            f(
+                ch,
+                Self {
+                    node,
+                    rest: &fragment[ch.len_utf8()..],
+                },
+            );
+        }
+    }
+
+    fn for_each_index<E>(self, f: &mut impl FnMut(usize) -> Result<(), E>) -> Result<(), E> {
+        self.node.for_each_base(f)
+    }
+}
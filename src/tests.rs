@@ -0,0 +1,501 @@
+use {
+    crate::{
+        Cursor, Penalties, SearchTree, Searcher,
+        frozen::{FormatError, FrozenTree, SerializedTree},
+        hangul,
+        locale::Locale,
+        lookalike_table,
+        lookalikes::{self, Lookalike, Lookalikes, RuleSet},
+        marks, normalization,
+        phonetic::{self, PhoneticIndex},
+        radix::RadixTree,
+    },
+    alloc::{string::String, vec, vec::Vec},
+    core::convert::Infallible,
+};
+
+/// The candidates of `searcher` along with their costs, in the order they're visited.
+fn candidates<'tree>(
+    searcher: &Searcher<'tree, impl Lookalikes<'tree>, impl Cursor>,
+) -> Vec<(usize, u32)> {
+    let mut res = vec![];
+    let Ok(()) = searcher.for_each_candidate_with_cost::<Infallible>(|index, cost| {
+        res.push((index, cost));
+        Ok(())
+    });
+    res
+}
+
+fn tree(keys: &[&str]) -> SearchTree {
+    keys.iter().copied().enumerate().collect()
+}
+
+#[test]
+fn repetition() {
+    let root = tree(&["hungarian", "accommodate"]);
+    for (i, input) in ["hunngarian", "acommodate"].into_iter().enumerate() {
+        let mut searcher = Searcher::new(&root, lookalikes::all);
+        searcher.extend(input.chars());
+        assert_eq!(
+            candidates(&searcher),
+            [(i, Penalties::default().repetition)]
+        );
+    }
+}
+
+#[test]
+fn clone() {
+    let root = tree(&["apple", "banana"]);
+    let mut searcher = Searcher::new(&root, lookalikes::all);
+    searcher.extend("ban".chars());
+    let mut copy = searcher.clone();
+    copy.push('a');
+    assert_eq!(searcher.input(), "ban");
+    assert_eq!(copy.input(), "bana");
+}
+
+#[test]
+fn skippable_combining_marks() {
+    let root = tree(&["x\u{303}"]);
+    let mut searcher = Searcher::new(&root, lookalikes::all)
+        .with_normalization(true)
+        .with_skippable(&[marks::COMBINING]);
+    searcher.push('x');
+    assert_eq!(candidates(&searcher), [(0, 0)]);
+}
+
+#[test]
+fn german_transcriptions() {
+    let root = tree(&["Müller", "Straße", "Stadt"]);
+    for (i, input) in ["Mueller", "Strasse"].into_iter().enumerate() {
+        let mut searcher = Locale::German.searcher(&root);
+        searcher.extend(input.chars());
+        assert_eq!(
+            candidates(&searcher),
+            [(i, Lookalike::TRANSCRIPTION_COST)],
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn lookalike_table() {
+    lookalike_table! {
+        fn digit_letters {
+            '0' => 'O' 'o',
+            '1' => 'l' 'I',
+            '5' | '$' => 'S',
+        }
+    }
+
+    assert!(digit_letters('0').eq(['O', 'o']));
+    assert!(digit_letters('$').eq(['S']));
+    assert!(digit_letters('a').next().is_none());
+}
+
+#[test]
+fn runtime_rules() {
+    let rules = [(String::from("ph"), String::from("f"))];
+    let lookalikes = lookalikes::all.chain(|ch| {
+        rules.iter().filter_map(move |(input, key)| {
+            Some(Lookalike::sequence(input.strip_prefix(ch)?, key, 1))
+        })
+    });
+
+    let root = tree(&["foto"]);
+    for input in ["pho", "fo"] {
+        let mut searcher = Searcher::new(&root, lookalikes.by_ref());
+        searcher.extend(input.chars());
+        assert_eq!(candidates(&searcher).len(), 1, "{input}");
+    }
+}
+
+#[test]
+fn rule_set() {
+    let rules = RuleSet::parse(
+        "
+        e = é è ê  # accents
+        ph = f:2   # spelling
+        ",
+    )
+    .unwrap();
+    let root = tree(&["fédé"]);
+    let mut searcher = Searcher::new(&root, lookalikes::qwerty_misclicks.chain(&rules));
+    searcher.extend("phede".chars());
+    assert_eq!(candidates(&searcher), [(0, 10)]);
+
+    let err = RuleSet::parse("a = b\nc = d:x").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 7));
+}
+
+#[test]
+fn turkic_variants() {
+    assert!(lookalikes::variants('i').any(|ch| ch == 'ı'));
+    assert!(!lookalikes::turkic_variants('i').any(|ch| ch == 'ı'));
+    assert!(lookalikes::turkic_variants('i').any(|ch| ch == 'İ'));
+    assert!(lookalikes::turkic_variants('I').eq(['ı']));
+}
+
+#[test]
+fn homoglyphs() {
+    let homoglyphs = |ch| lookalikes::homoglyphs(ch).collect::<Vec<_>>();
+    assert!(homoglyphs('a').contains(&Lookalike::new('а', Lookalike::DEFAULT_COST)));
+    assert!(homoglyphs('а').contains(&Lookalike::new('a', Lookalike::DEFAULT_COST)));
+    // Characters with the same prototype are homoglyphs of each other
+    assert!(homoglyphs('а').contains(&Lookalike::new('α', Lookalike::DEFAULT_COST)));
+    assert!(homoglyphs('O').contains(&Lookalike::new('0', Lookalike::DEFAULT_COST)));
+    assert!(homoglyphs('m').contains(&Lookalike::sequence("", "rn", Lookalike::DEFAULT_COST)));
+    assert!(homoglyphs('r').contains(&Lookalike::sequence("n", "m", Lookalike::DEFAULT_COST)));
+    assert!(homoglyphs('é').is_empty());
+
+    let root = tree(&["Pаypаl", "modern"]);
+    let mut searcher = Searcher::new(&root, lookalikes::homoglyphs);
+    searcher.extend("Paypal".chars());
+    assert_eq!(candidates(&searcher), [(0, 2 * Lookalike::DEFAULT_COST)]);
+    let mut searcher = Searcher::new(&root, lookalikes::homoglyphs);
+    searcher.extend("modem".chars());
+    assert_eq!(candidates(&searcher), [(1, Lookalike::DEFAULT_COST)]);
+}
+
+#[test]
+fn ocr() {
+    let root = tree(&["modern", "lantern"]);
+    let mut searcher = Searcher::new(&root, lookalikes::ocr);
+    // The keys with an `m` are found as soon as the `r` of `rn` is typed
+    for ch in "rn".chars() {
+        searcher.push(ch);
+        assert_eq!(candidates(&searcher), [(0, Lookalike::DEFAULT_COST)]);
+    }
+}
+
+#[test]
+fn transliterations() {
+    let root = tree(&["Москва"]);
+    let mut searcher = Searcher::new(&root, lookalikes::transliterations);
+    searcher.extend("moskva".chars());
+    assert_eq!(
+        candidates(&searcher),
+        [(0, 6 * Lookalike::TRANSCRIPTION_COST)]
+    );
+}
+
+#[test]
+fn indic() {
+    let root = tree(&["किताब", "ज\u{93C}िंदगी"]);
+    for (i, input) in ["कीताब", "जिदगी"].into_iter().enumerate() {
+        let mut searcher = Searcher::new(&root, lookalikes::indic).with_skippable(&[marks::INDIC]);
+        searcher.extend(input.chars());
+        let found = candidates(&searcher);
+        assert_eq!(found.len(), 1, "{input}");
+        assert_eq!(found[0].0, i, "{input}");
+    }
+}
+
+#[test]
+fn hindi_nukta() {
+    let root = tree(&["\u{95B}िंदगी", "ज\u{93C}िंदगी"]);
+    for (i, input) in ["\u{95B}िंदगी", "ज\u{93C}िंदगी"].into_iter().enumerate()
+    {
+        let mut searcher = Locale::Hindi.searcher(&root);
+        searcher.extend(input.chars());
+        let found = candidates(&searcher);
+        assert_eq!(found.len(), 2, "{input}");
+        assert_eq!(found[0], (i, 0), "{input}");
+    }
+}
+
+#[test]
+fn compatibility() {
+    let root = tree(&["ABC123", "カタカナ"]);
+    let generator = |ch| {
+        lookalikes::all(ch)
+            .map(Lookalike::from)
+            .chain(lookalikes::compatibility(ch))
+    };
+    for (i, input) in ["ＡＢＣ１２", "ｶﾀｶﾅ"].into_iter().enumerate() {
+        let mut searcher = Searcher::new(&root, generator);
+        searcher.extend(input.chars());
+        assert_eq!(candidates(&searcher), [(i, 0)], "{input}");
+    }
+}
+
+#[test]
+fn skippable_marks() {
+    let root = tree(&["مُحَمَّد", "שָׁלוֹם"]);
+    for (i, input) in ["محمد", "שלום"].into_iter().enumerate() {
+        let mut searcher =
+            Searcher::new(&root, lookalikes::all).with_skippable(&[marks::ARABIC, marks::HEBREW]);
+        searcher.extend(input.chars());
+        assert_eq!(candidates(&searcher), [(i, 0)], "{input}");
+    }
+}
+
+#[test]
+fn normalization() {
+    let mut root = SearchTree::default();
+    for (i, key) in ["cafe\u{301}", "resume"].into_iter().enumerate() {
+        root.push_normalized(key, i);
+    }
+    // Outside of the Basic Multilingual Plane
+    assert_eq!(normalization::nfc("\u{110A5}\u{110BA}"), "\u{110AB}");
+    assert_eq!(normalization::nfd("\u{1D15E}"), "\u{1D157}\u{1D165}");
+
+    for input in ["café", "cafe\u{301}"] {
+        let mut searcher = Searcher::new(&root, lookalikes::all).with_normalization(true);
+        searcher.extend(input.chars());
+        assert_eq!(candidates(&searcher), [(0, 0)], "{input}");
+    }
+}
+
+#[test]
+fn combining_marks() {
+    let mut root = SearchTree::default();
+    root.push_normalized("x\u{303}a", 0);
+    let marks = Penalties::default().marks;
+    for (input, cost) in [
+        ("x", 0),
+        ("x\u{303}a", 0),
+        // The key's mark is missing
+        ("xa", marks),
+        // Another mark instead of the key's
+        ("x\u{302}a", marks),
+        // An extra mark
+        ("x\u{303}\u{302}a", marks),
+        // Several differing marks of a character cost once
+        ("x\u{302}\u{301}a", marks),
+        // The marks of a mistyped character are free to differ
+        ("ya", Lookalike::DEFAULT_COST),
+    ] {
+        let mut searcher =
+            Searcher::new(&root, |ch| (ch == 'y').then_some('x')).with_normalization(true);
+        searcher.extend(input.chars());
+        assert_eq!(candidates(&searcher), [(0, cost)], "{input}");
+    }
+}
+
+#[test]
+fn hangul() {
+    let keys = ["한국", "하늘"].map(hangul::decompose_str);
+    let root: SearchTree = keys.iter().map(String::as_str).enumerate().collect();
+    let mut searcher = Searcher::new(&root, lookalikes::jamo);
+    searcher.extend("하".chars().flat_map(hangul::decompose));
+    assert_eq!(candidates(&searcher).len(), 2);
+}
+
+#[test]
+fn phonetic() {
+    let keys = ["Arnold Schwarzenegger", "Helmut Schmidt"];
+    let root = tree(&keys);
+    let mut index = PhoneticIndex::new(phonetic::soundex);
+    for (i, key) in keys.into_iter().enumerate() {
+        index.push(key, i);
+    }
+    let mut searcher = Searcher::new(&root, lookalikes::all);
+    searcher.extend("Shmidt".chars());
+    let mut found = vec![];
+    let Ok(()) = index.for_each_candidate::<Infallible>(&searcher, |i| {
+        found.push(i);
+        Ok(())
+    });
+    assert!(found.contains(&1));
+
+    let keys = ["Pyotr Ilyich Tchaikovsky", "Sergei Rachmaninoff"];
+    for encode in [phonetic::soundex, phonetic::cologne] {
+        let mut index = PhoneticIndex::new(encode);
+        for (i, key) in keys.into_iter().enumerate() {
+            index.push(key, i);
+        }
+        let mut found = vec![];
+        let Ok(()) = index.for_each_match::<Infallible>("Chaikovsky", |i| {
+            found.push(i);
+            Ok(())
+        });
+        assert_eq!(found, [0]);
+    }
+
+    // Words with the same code keep all their keys
+    let mut index = PhoneticIndex::new(phonetic::soundex);
+    for (i, key) in ["Smith", "Smyth", "Smith & Smith"].into_iter().enumerate() {
+        index.push(key, i);
+    }
+    let mut found = vec![];
+    let Ok(()) = index.for_each_match::<Infallible>("Smith", |i| {
+        found.push(i);
+        Ok(())
+    });
+    assert_eq!(found, [0, 1, 2]);
+}
+
+#[test]
+fn radix() {
+    let keys = ["international", "internet", "interval"];
+    let root: RadixTree = keys.into_iter().enumerate().collect();
+    let mut searcher = Searcher::new(&root, lookalikes::all);
+    searcher.extend("intervla".chars());
+    assert_eq!(candidates(&searcher), [(2, 8)]);
+}
+
+#[test]
+fn frozen() {
+    let frozen = FrozenTree::from(&tree(&["apple", "apricot", "banana"]));
+    let mut found = vec![];
+    let Ok(()) = frozen.get_str("ap").unwrap().for_each::<Infallible>(|i| {
+        found.push(i);
+        Ok(())
+    });
+    assert_eq!(found, [0, 1]);
+
+    let mut searcher = Searcher::new(&frozen, lookalikes::all);
+    searcher.extend("bababa".chars());
+    assert_eq!(candidates(&searcher)[0].0, 2);
+}
+
+#[test]
+fn serialized() {
+    let bytes = FrozenTree::from(&tree(&["apple", "banana"])).to_bytes();
+    let serialized = SerializedTree::new(&bytes).unwrap();
+    let mut searcher = Searcher::new(&serialized, lookalikes::all);
+    searcher.extend("banan".chars());
+    assert_eq!(candidates(&searcher), [(1, 0)]);
+}
+
+#[test]
+fn repeated_indices() {
+    let mut root = SearchTree::default();
+    root.push("beijing", 0);
+    root.push("bj", 0);
+    let frozen = FrozenTree::from(&root);
+    let bytes = frozen.to_bytes();
+    let serialized = SerializedTree::new(&bytes).unwrap();
+    let mut radix = RadixTree::default();
+    radix.push("beijing", 0);
+    radix.push("bj", 0);
+
+    // A single node in consideration leads to both keys
+    let mut searcher = Searcher::new(&root, lookalikes::all);
+    searcher.push('b');
+    assert_eq!(candidates(&searcher), [(0, 0)]);
+    let mut searcher = Searcher::new(&frozen, lookalikes::all);
+    searcher.push('b');
+    assert_eq!(candidates(&searcher), [(0, 0)]);
+    let mut searcher = Searcher::new(&serialized, lookalikes::all);
+    searcher.push('b');
+    assert_eq!(candidates(&searcher), [(0, 0)]);
+    let mut searcher = Searcher::new(&radix, lookalikes::all);
+    searcher.push('b');
+    assert_eq!(candidates(&searcher), [(0, 0)]);
+}
+
+#[test]
+fn serialized_errors() {
+    let bytes = FrozenTree::from(&tree(&["apple", "banana"])).to_bytes();
+    let error = |bytes: &[u8]| SerializedTree::new(bytes).unwrap_err();
+
+    assert_eq!(error(&bytes[..bytes.len() - 1]), FormatError::Truncated);
+
+    let mut corrupted = bytes.clone();
+    corrupted[0] = b'X';
+    assert_eq!(error(&corrupted), FormatError::BadMagic);
+
+    let mut corrupted = bytes.clone();
+    corrupted[4..8].copy_from_slice(&2u32.to_le_bytes());
+    assert_eq!(error(&corrupted), FormatError::UnsupportedVersion(2));
+
+    let mut corrupted = bytes.clone();
+    corrupted[16..20].copy_from_slice(&2u32.to_le_bytes());
+    assert_eq!(error(&corrupted), FormatError::InvalidHeader);
+
+    // Node `i` starts at byte `20 + 20 * i`, with its character, the start & the end of its
+    // children, and the start & the end of its indices
+    let node = |i: usize| 20 + 20 * i;
+
+    // Swapping the characters of `a` & `b`, the children of the root, makes them unsorted
+    let mut corrupted = bytes.clone();
+    let (a, b) = (node(1), node(2));
+    let (first, second) = corrupted.split_at_mut(b);
+    first[a..a + 4].swap_with_slice(&mut second[..4]);
+    assert_eq!(error(&corrupted), FormatError::InvalidNode(0));
+
+    // The root being its own child would make a cycle
+    let mut corrupted = bytes.clone();
+    corrupted[node(0) + 4..node(0) + 8].copy_from_slice(&0u32.to_le_bytes());
+    assert_eq!(error(&corrupted), FormatError::InvalidNode(0));
+
+    // Children beyond the last node
+    let mut corrupted = bytes;
+    corrupted[node(1) + 8..node(1) + 12].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(error(&corrupted), FormatError::InvalidNode(1));
+}
+
+#[cfg(feature = "pinyin")]
+#[test]
+fn pinyin() {
+    use crate::pinyin;
+
+    let mut root = SearchTree::default();
+    for (i, key) in ["北京", "上海", "重庆"].into_iter().enumerate() {
+        pinyin::push(&mut root, key, i);
+    }
+    for input in ["beijing", "bj", "chongqin", "shang"] {
+        let mut searcher = Searcher::new(&root, lookalikes::all);
+        searcher.extend(input.chars());
+        assert_eq!(candidates(&searcher).len(), 1, "{input}");
+    }
+
+    // Every index is reported once, even though it was pushed under several spellings
+    let mut root = SearchTree::default();
+    pinyin::push(&mut root, "北京", 0);
+    let searcher = Searcher::new(&root, lookalikes::all);
+    assert_eq!(candidates(&searcher), [(0, 0)]);
+
+    // Rare characters have readings too, from the first to the last of the BMP ideographs
+    for (ch, reading) in [('一', "yi"), ('鑫', "xin"), ('龘', "da"), ('龥', "yu")] {
+        assert_eq!(pinyin::readings(ch).next(), Some(reading), "{ch}");
+    }
+    assert_eq!(pinyin::readings('地').collect::<Vec<_>>(), ["di", "de"]);
+}
+
+#[cfg(feature = "emoji")]
+#[test]
+fn emoji() {
+    use crate::emoji;
+
+    let index = emoji::EmojiIndex::new();
+    let thumbs_up = emoji::EMOJI.iter().position(|e| e.0 == "👍").unwrap();
+    for input in [":thumbs", "thumbs up", "+1"] {
+        let mut searcher = Searcher::new(index.tree(), lookalikes::all);
+        searcher.extend(input.chars());
+        let mut found = vec![];
+        let Ok(()) = index.for_each_candidate::<Infallible>(&searcher, |i| {
+            found.push(i);
+            Ok(())
+        });
+        assert_eq!(
+            found.iter().filter(|&&i| i == thumbs_up).count(),
+            1,
+            "{input}"
+        );
+    }
+
+    // Keywords shared by several symbols find all of them
+    let mut searcher = Searcher::new(index.tree(), lookalikes::all);
+    searcher.extend("arrow".chars());
+    let mut found = vec![];
+    let Ok(()) = index.for_each_candidate::<Infallible>(&searcher, |i| {
+        found.push(emoji::EMOJI[i].0);
+        Ok(())
+    });
+    assert!(found.contains(&"→") && found.contains(&"⬇️"), "{found:?}");
+
+    // Every emoji is found by the words of its name
+    for (input, expected) in [("shaking", "🫨"), ("ball", "🪩"), ("pinata", "🪅")] {
+        let mut searcher = Searcher::new(index.tree(), lookalikes::all);
+        searcher.extend(input.chars());
+        let mut found = vec![];
+        let Ok(()) = index.for_each_candidate::<Infallible>(&searcher, |i| {
+            found.push(emoji::EMOJI[i].0);
+            Ok(())
+        });
+        assert!(found.contains(&expected), "{input}: {found:?}");
+    }
+}